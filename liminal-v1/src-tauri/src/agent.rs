@@ -1,13 +1,18 @@
-use portable_pty::{CommandBuilder, NativePtySystem, PtySize, PtySystem};
+use portable_pty::{Child, CommandBuilder, NativePtySystem, PtySize, PtySystem};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::io::{Read, Write};
 use std::sync::{Arc, Mutex};
 use std::thread;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast;
 use tokio::sync::mpsc::UnboundedSender;
 
 const START_TAG: &str = "<FORGE_EVENT";
 const END_TAG: &str = "</FORGE_EVENT>";
+const OUTPUT_CHANNEL_CAPACITY: usize = 1024;
+const MAX_PENDING_LINE_BYTES: usize = 64 * 1024;
+pub const AGENT_EXIT_EVENT: &str = "AGENT_EXIT";
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AgentEvent {
@@ -15,6 +20,46 @@ pub struct AgentEvent {
     pub event_name: Option<String>,
     pub payload: Value,
     pub raw: String,
+    #[serde(default)]
+    pub signal: Option<AgentSignal>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum AgentSignal {
+    Progress {
+        percent: f32,
+        message: Option<String>,
+    },
+    Artifact {
+        path: String,
+    },
+    Error {
+        message: String,
+    },
+}
+
+impl AgentSignal {
+    pub fn extract(event_name: Option<&str>, payload: &Value) -> Option<Self> {
+        let text = |key: &str| payload.get(key).and_then(Value::as_str).map(str::to_string);
+        match event_name?.to_ascii_uppercase().as_str() {
+            "PROGRESS" => payload
+                .get("percent")
+                .or_else(|| payload.get("progress"))
+                .and_then(Value::as_f64)
+                .map(|percent| AgentSignal::Progress {
+                    percent: percent.clamp(0.0, 100.0) as f32,
+                    message: text("message"),
+                }),
+            "ARTIFACT" => text("path").map(|path| AgentSignal::Artifact { path }),
+            "ERROR" => Some(AgentSignal::Error {
+                message: text("message")
+                    .or_else(|| text("error"))
+                    .unwrap_or_else(|| "unspecified error".to_string()),
+            }),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -127,6 +172,113 @@ impl PtyEventParser {
     }
 }
 
+struct OutputLineSplitter {
+    pending: Vec<u8>,
+}
+
+impl OutputLineSplitter {
+    fn new() -> Self {
+        Self {
+            pending: Vec::new(),
+        }
+    }
+
+    fn feed(&mut self, chunk: &[u8]) -> Vec<String> {
+        self.pending.extend_from_slice(chunk);
+        let mut lines = Vec::new();
+        while let Some(index) = self.pending.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = self.pending.drain(..=index).collect();
+            lines.push(Self::decode(&line[..line.len() - 1]));
+        }
+        while self.pending.len() >= MAX_PENDING_LINE_BYTES {
+            let line: Vec<u8> = self.pending.drain(..MAX_PENDING_LINE_BYTES).collect();
+            lines.push(Self::decode(&line));
+        }
+        lines
+    }
+
+    fn finish(&mut self) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+        let line = std::mem::take(&mut self.pending);
+        Some(Self::decode(&line))
+    }
+
+    fn decode(bytes: &[u8]) -> String {
+        let trimmed = bytes.strip_suffix(b"\r").unwrap_or(bytes);
+        String::from_utf8_lossy(trimmed).into_owned()
+    }
+}
+
+fn output_line_event(agent_id: &str, line: String) -> AgentEvent {
+    AgentEvent {
+        agent_id: agent_id.to_string(),
+        event_name: None,
+        payload: Value::Null,
+        raw: line,
+        signal: None,
+    }
+}
+
+fn pump_output<R: Read>(
+    agent_id: &str,
+    reader: &mut R,
+    events: &UnboundedSender<AgentEvent>,
+    output: &broadcast::Sender<AgentEvent>,
+) {
+    let mut buffer = [0u8; 1024];
+    let mut parser = PtyEventParser::new();
+    let mut lines = OutputLineSplitter::new();
+    'read: loop {
+        match reader.read(&mut buffer) {
+            Ok(len) => {
+                if len == 0 {
+                    break;
+                }
+                let chunk = &buffer[..len];
+                let text = String::from_utf8_lossy(chunk);
+                println!("[Agent {}]: {}", agent_id, text);
+                for line in lines.feed(chunk) {
+                    let _ = output.send(output_line_event(agent_id, line));
+                }
+                for result in parser.feed(chunk) {
+                    match result {
+                        Ok(parsed) => {
+                            let signal =
+                                AgentSignal::extract(parsed.event_name.as_deref(), &parsed.payload);
+                            let event = AgentEvent {
+                                agent_id: agent_id.to_string(),
+                                event_name: parsed.event_name,
+                                payload: parsed.payload,
+                                raw: parsed.raw,
+                                signal,
+                            };
+                            if events.send(event).is_err() {
+                                break 'read;
+                            }
+                        }
+                        Err(err) => {
+                            println!("[Agent {} parse error]: {:?}", agent_id, err);
+                        }
+                    }
+                }
+            }
+            Err(_) => break,
+        }
+    }
+    if let Some(line) = lines.finish() {
+        let _ = output.send(output_line_event(agent_id, line));
+    }
+    let _ = events.send(AgentEvent {
+        agent_id: agent_id.to_string(),
+        event_name: Some(AGENT_EXIT_EVENT.to_string()),
+        payload: Value::Null,
+        raw: String::new(),
+        signal: None,
+    });
+}
+
 #[derive(Clone)]
 pub struct AgentEventSender {
     sender: UnboundedSender<AgentEvent>,
@@ -145,6 +297,8 @@ impl AgentEventSender {
 pub struct AgentProcess {
     pub id: String,
     writer: Arc<Mutex<Box<dyn Write + Send>>>,
+    child: Arc<Mutex<Box<dyn Child + Send + Sync>>>,
+    output: broadcast::Sender<AgentEvent>,
 }
 
 impl AgentProcess {
@@ -162,7 +316,7 @@ impl AgentProcess {
         let mut cmd = CommandBuilder::new(command[0]);
         cmd.args(&command[1..]);
 
-        let mut _child = pair.slave.spawn_command(cmd).unwrap();
+        let child = pair.slave.spawn_command(cmd).unwrap();
         let mut reader = pair.master.try_clone_reader().unwrap();
         let writer = Arc::new(Mutex::new(
             pair.master.take_writer().unwrap() as Box<dyn Write + Send>
@@ -170,48 +324,95 @@ impl AgentProcess {
 
         let agent_id = id.to_string();
         let event_sender = events.clone();
+        let (output, _) = broadcast::channel(OUTPUT_CHANNEL_CAPACITY);
+        let output_sender = output.clone();
         thread::spawn(move || {
-            let mut buffer = [0u8; 1024];
-            let mut parser = PtyEventParser::new();
-            'read: loop {
-                match reader.read(&mut buffer) {
-                    Ok(len) => {
-                        if len == 0 {
-                            break;
-                        }
-                        let chunk = &buffer[..len];
-                        let output = String::from_utf8_lossy(chunk);
-                        println!("[Agent {}]: {}", agent_id, output);
-                        for result in parser.feed(chunk) {
-                            match result {
-                                Ok(parsed) => {
-                                    let event = AgentEvent {
-                                        agent_id: agent_id.clone(),
-                                        event_name: parsed.event_name,
-                                        payload: parsed.payload,
-                                        raw: parsed.raw,
-                                    };
-                                    if event_sender.send(event).is_err() {
-                                        break 'read;
-                                    }
-                                }
-                                Err(err) => {
-                                    println!("[Agent {} parse error]: {:?}", agent_id, err);
-                                }
-                            }
-                        }
-                    }
-                    Err(_) => break,
-                }
-            }
+            pump_output(&agent_id, &mut reader, &event_sender, &output_sender);
         });
 
         Self {
             id: id.to_string(),
             writer,
+            child: Arc::new(Mutex::new(child)),
+            output,
         }
     }
 
+    pub fn subscribe_output(&self) -> broadcast::Receiver<AgentEvent> {
+        self.output.subscribe()
+    }
+
+    pub fn process_id(&self) -> Option<u32> {
+        self.child.lock().unwrap().process_id()
+    }
+
+    pub fn has_exited(&self) -> bool {
+        matches!(self.child.lock().unwrap().try_wait(), Ok(Some(_)))
+    }
+
+    pub fn terminate(&self) -> std::io::Result<()> {
+        let mut child = self.child.lock().unwrap();
+        if matches!(child.try_wait(), Ok(Some(_))) {
+            return Ok(());
+        }
+        #[cfg(unix)]
+        {
+            if let Some(pid) = child.process_id() {
+                // SAFETY: `pid` is our own child, which `try_wait` above showed is still
+                // unreaped; holding the `child` lock keeps it from being reaped and the
+                // pid recycled before the signal is sent.
+                let result = unsafe { libc::kill(pid as i32, libc::SIGTERM) };
+                if result != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                return Ok(());
+            }
+        }
+        child.kill()
+    }
+
+    pub fn kill(&self) -> std::io::Result<()> {
+        let mut child = self.child.lock().unwrap();
+        if matches!(child.try_wait(), Ok(Some(_))) {
+            return Ok(());
+        }
+        #[cfg(unix)]
+        {
+            if let Some(pid) = child.process_id() {
+                // SAFETY: `pid` is our own child, which `try_wait` above showed is still
+                // unreaped; holding the `child` lock until `wait` reaps it keeps the pid
+                // from being recycled before the signal is sent.
+                let result = unsafe { libc::kill(pid as i32, libc::SIGKILL) };
+                if result != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                child.wait()?;
+                return Ok(());
+            }
+        }
+        child.kill()?;
+        child.wait().map(|_| ())
+    }
+
+    pub async fn shutdown_with_grace(&self, grace: Duration) -> std::io::Result<bool> {
+        if self.has_exited() {
+            return Ok(false);
+        }
+        self.terminate()?;
+        let deadline = Instant::now() + grace;
+        while Instant::now() < deadline {
+            if self.has_exited() {
+                return Ok(false);
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+        if self.has_exited() {
+            return Ok(false);
+        }
+        self.kill()?;
+        Ok(true)
+    }
+
     pub fn send_command(&self, command: &str) -> Result<(), Box<dyn std::error::Error>> {
         self.write_line(command)
     }
@@ -266,6 +467,99 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn output_lines_are_forwarded_in_order() {
+        let (events, mut event_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (output, mut output_rx) = broadcast::channel(16);
+        let mut reader = std::io::Cursor::new(
+            b"first line\r\nsecond <FORGE_EVENT name=\"PING\">{}</FORGE_EVENT>\nthird".to_vec(),
+        );
+
+        pump_output("Agent_T", &mut reader, &events, &output);
+
+        let lines: Vec<String> = std::iter::from_fn(|| output_rx.try_recv().ok())
+            .map(|event| {
+                assert_eq!(event.agent_id, "Agent_T");
+                event.raw
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                "first line".to_string(),
+                "second <FORGE_EVENT name=\"PING\">{}</FORGE_EVENT>".to_string(),
+                "third".to_string(),
+            ]
+        );
+        let structured = event_rx.try_recv().unwrap();
+        assert_eq!(structured.event_name.as_deref(), Some("PING"));
+        let exit = event_rx.try_recv().unwrap();
+        assert_eq!(exit.event_name.as_deref(), Some(AGENT_EXIT_EVENT));
+        assert_eq!(exit.agent_id, "Agent_T");
+    }
+
+    #[test]
+    fn unterminated_output_is_flushed_once_it_reaches_the_cap() {
+        let mut splitter = OutputLineSplitter::new();
+        let progress = vec![b'#'; MAX_PENDING_LINE_BYTES / 2];
+        assert!(splitter.feed(&progress).is_empty());
+        let lines = splitter.feed(&[progress.as_slice(), progress.as_slice()].concat());
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].len(), MAX_PENDING_LINE_BYTES);
+        assert_eq!(splitter.pending.len(), MAX_PENDING_LINE_BYTES / 2);
+        assert_eq!(
+            splitter.feed(b" done\n"),
+            vec![format!("{} done", "#".repeat(MAX_PENDING_LINE_BYTES / 2))]
+        );
+        assert!(splitter.finish().is_none());
+    }
+
+    #[test]
+    fn structured_lines_produce_typed_signals() {
+        let (events, mut event_rx) = tokio::sync::mpsc::unbounded_channel();
+        let (output, _output_rx) = broadcast::channel(16);
+        let mut reader = std::io::Cursor::new(
+            concat!(
+                "<FORGE_EVENT name=\"PROGRESS\">{\"percent\": 42.5, \"message\": \"halfway\"}</FORGE_EVENT>\n",
+                "<FORGE_EVENT name=\"artifact\">{\"path\": \"out/report.md\"}</FORGE_EVENT>\n",
+                "<FORGE_EVENT name=\"ERROR\">{\"error\": \"tests failed\"}</FORGE_EVENT>\n",
+                "<FORGE_EVENT name=\"PROGRESS\">{\"percent\": 250}</FORGE_EVENT>\n",
+                "<FORGE_EVENT name=\"PROGRESS\">{\"status\": \"unknown\"}</FORGE_EVENT>\n",
+                "<FORGE_EVENT name=\"PING\">{}</FORGE_EVENT>\n",
+            )
+            .as_bytes()
+            .to_vec(),
+        );
+
+        pump_output("Agent_S", &mut reader, &events, &output);
+
+        let signals: Vec<Option<AgentSignal>> = std::iter::from_fn(|| event_rx.try_recv().ok())
+            .filter(|event| event.event_name.as_deref() != Some(AGENT_EXIT_EVENT))
+            .map(|event| event.signal)
+            .collect();
+        assert_eq!(
+            signals,
+            vec![
+                Some(AgentSignal::Progress {
+                    percent: 42.5,
+                    message: Some("halfway".to_string()),
+                }),
+                Some(AgentSignal::Artifact {
+                    path: "out/report.md".to_string(),
+                }),
+                Some(AgentSignal::Error {
+                    message: "tests failed".to_string(),
+                }),
+                Some(AgentSignal::Progress {
+                    percent: 100.0,
+                    message: None,
+                }),
+                None,
+                None,
+            ]
+        );
+    }
+
     #[test]
     fn structured_events_are_formatted_with_forge_tag() {
         let payload = json!({"foo": "bar"});
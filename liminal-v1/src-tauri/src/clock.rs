@@ -0,0 +1,76 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+pub trait Clock: Send + Sync {
+    fn now_instant(&self) -> Instant;
+
+    fn now_system(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+pub type SharedClock = Arc<dyn Clock>;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+pub fn system_clock() -> SharedClock {
+    Arc::new(SystemClock)
+}
+
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<Mutex<Instant>>,
+    wall: Arc<Mutex<SystemTime>>,
+}
+
+impl MockClock {
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    pub fn starting_at(start: Instant) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(start)),
+            wall: Arc::new(Mutex::new(SystemTime::now())),
+        }
+    }
+
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.now.lock().unwrap();
+        *now += by;
+        let mut wall = self.wall.lock().unwrap();
+        *wall += by;
+    }
+
+    pub fn rewind_wall(&self, by: Duration) {
+        let mut wall = self.wall.lock().unwrap();
+        *wall -= by;
+    }
+
+    pub fn shared(&self) -> SharedClock {
+        Arc::new(self.clone())
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for MockClock {
+    fn now_instant(&self) -> Instant {
+        *self.now.lock().unwrap()
+    }
+
+    fn now_system(&self) -> SystemTime {
+        *self.wall.lock().unwrap()
+    }
+}
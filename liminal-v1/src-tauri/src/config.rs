@@ -1,8 +1,47 @@
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("failed to read config {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("malformed config at `{field}`: {message}")]
+    Parse { field: String, message: String },
+    #[error("invalid config value for `{field}`: {reason}")]
+    Invalid { field: String, reason: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "toml" => Some(ConfigFormat::Toml),
+            "json" => Some(ConfigFormat::Json),
+            _ => None,
+        }
+    }
 
-#[derive(Debug, Deserialize, Clone)]
+    fn extensions() -> [&'static str; 4] {
+        ["yaml", "yml", "toml", "json"]
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct RouterConfig {
     #[serde(default)]
@@ -16,14 +55,32 @@ pub struct RouterConfig {
     #[serde(default)]
     pub max_aging_boosts: Option<u8>,
     #[serde(default)]
+    pub aging_thresholds: Option<HashMap<String, String>>,
+    #[serde(default)]
     pub idle_backoff: Option<String>,
     #[serde(default)]
+    pub idle_backoff_max: Option<String>,
+    #[serde(default)]
+    pub retry_backoff: Option<String>,
+    #[serde(default)]
+    pub retry_backoff_max: Option<String>,
+    #[serde(default)]
     pub queue_depth_warning: Option<usize>,
     #[serde(default)]
     pub queue_depth_critical: Option<usize>,
+    #[serde(default)]
+    pub director_override_capacity: Option<f64>,
+    #[serde(default)]
+    pub director_override_refill_rate: Option<f64>,
+    #[serde(default)]
+    pub max_message_bytes: Option<usize>,
+    #[serde(default)]
+    pub dedup_content_digest: Option<bool>,
+    #[serde(default)]
+    pub exempt_senders: Option<Vec<String>>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TerritoryConfig {
     #[serde(default)]
@@ -41,10 +98,14 @@ pub struct TerritoryConfig {
     #[serde(default)]
     pub escalation_deadlock_timeout: Option<String>,
     #[serde(default)]
+    pub escalation_force_override_after: Option<String>,
+    #[serde(default)]
     pub fairness_starvation_threshold: Option<String>,
     #[serde(default)]
     pub fairness_priority_boost_after: Option<String>,
     #[serde(default)]
+    pub fairness_minimum_lease: Option<String>,
+    #[serde(default)]
     pub consensus_threshold: Option<f32>,
     #[serde(default)]
     pub heat_decay_per_second: Option<f64>,
@@ -52,9 +113,15 @@ pub struct TerritoryConfig {
     pub heat_increment: Option<f64>,
     #[serde(default)]
     pub heat_max: Option<f64>,
+    #[serde(default)]
+    pub override_policy: Option<String>,
+    #[serde(default)]
+    pub override_priority_delta: Option<u8>,
+    #[serde(default)]
+    pub allow_operator_override: Option<bool>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct HealthMonitoringConfig {
     #[serde(default)]
@@ -69,7 +136,7 @@ pub struct HealthMonitoringConfig {
     pub heat_hotspot: Option<HeatHotspotConfig>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct QueueHealthConfig {
     #[serde(default)]
@@ -80,7 +147,7 @@ pub struct QueueHealthConfig {
     pub stale_threshold: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct EscalationRateConfig {
     #[serde(default)]
@@ -89,7 +156,7 @@ pub struct EscalationRateConfig {
     pub critical: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct DeadlockFrequencyConfig {
     #[serde(default)]
@@ -98,7 +165,7 @@ pub struct DeadlockFrequencyConfig {
     pub critical: Option<String>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct ConsensusSuccessConfig {
     #[serde(default)]
@@ -107,7 +174,7 @@ pub struct ConsensusSuccessConfig {
     pub critical_ratio: Option<f64>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub struct HeatHotspotConfig {
     #[serde(default)]
@@ -116,7 +183,7 @@ pub struct HeatHotspotConfig {
     pub critical: Option<f64>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct LedgerConfig {
     #[serde(default = "default_ledger_root")]
@@ -133,6 +200,10 @@ pub struct LedgerConfig {
     pub retain_days: Option<u64>,
     #[serde(default)]
     pub current_epoch: Option<String>,
+    #[serde(default)]
+    pub encryption_key: Option<[u8; 32]>,
+    #[serde(default = "default_degraded_after_failures")]
+    pub degraded_after_failures: u32,
 }
 
 impl Default for LedgerConfig {
@@ -145,11 +216,13 @@ impl Default for LedgerConfig {
             retain_epochs: default_retain_epochs(),
             retain_days: None,
             current_epoch: None,
+            encryption_key: None,
+            degraded_after_failures: default_degraded_after_failures(),
         }
     }
 }
 
-#[derive(Debug, Deserialize, Clone, Default)]
+#[derive(Debug, Deserialize, Clone, Default, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AppConfig {
     #[serde(default)]
@@ -165,11 +238,210 @@ pub struct AppConfig {
 impl AppConfig {
     pub fn load() -> Self {
         resolve_config_path()
-            .and_then(|path| fs::read_to_string(&path).ok())
-            .and_then(|raw| serde_yaml::from_str::<RawConfig>(&raw).ok())
-            .map(|raw| raw.into())
+            .and_then(|path| Self::load_from(&path).ok())
             .unwrap_or_default()
     }
+
+    pub fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        let raw = fs::read_to_string(path).map_err(|source| ConfigError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        let format = ConfigFormat::from_path(path).unwrap_or(ConfigFormat::Yaml);
+        Self::parse(&raw, format)
+    }
+
+    pub fn parse(raw: &str, format: ConfigFormat) -> Result<Self, ConfigError> {
+        let parsed = match format {
+            ConfigFormat::Yaml => {
+                serde_path_to_error::deserialize(serde_yaml::Deserializer::from_str(raw))
+                    .map_err(parse_error)
+            }
+            ConfigFormat::Toml => {
+                serde_path_to_error::deserialize(toml::Deserializer::new(raw)).map_err(parse_error)
+            }
+            ConfigFormat::Json => {
+                let mut deserializer = serde_json::Deserializer::from_str(raw);
+                let parsed: RawConfig =
+                    serde_path_to_error::deserialize(&mut deserializer).map_err(parse_error)?;
+                deserializer.end().map_err(|err| ConfigError::Parse {
+                    field: ".".to_string(),
+                    message: err.to_string(),
+                })?;
+                Ok(parsed)
+            }
+        };
+        parsed.map(|raw: RawConfig| raw.into())
+    }
+
+    pub fn load_validated() -> Result<Self, ConfigError> {
+        match resolve_config_path() {
+            Some(path) => Self::load_validated_from(&path),
+            None => Ok(Self::default().with_director_exemption()),
+        }
+    }
+
+    pub fn load_validated_from(path: &Path) -> Result<Self, ConfigError> {
+        let config = Self::load_from(path)?;
+        config.validate()?;
+        Ok(config.with_director_exemption())
+    }
+
+    pub fn with_director_exemption(mut self) -> Self {
+        let senders = self
+            .router
+            .get_or_insert_with(RouterConfig::default)
+            .exempt_senders
+            .get_or_insert_with(Vec::new);
+        if !senders.iter().any(|sender| sender == "director") {
+            senders.push("director".to_string());
+        }
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(router) = &self.router {
+            check_duration(
+                "performance_slas.message_routing.p50",
+                router.aging_threshold.as_deref(),
+            )?;
+            for (field, value) in [
+                ("router.token_bucket_capacity", router.token_bucket_capacity),
+                (
+                    "router.token_bucket_refill_rate",
+                    router.token_bucket_refill_rate,
+                ),
+                ("router.token_bucket_initial", router.token_bucket_initial),
+                (
+                    "router.director_override_capacity",
+                    router.director_override_capacity,
+                ),
+                (
+                    "router.director_override_refill_rate",
+                    router.director_override_refill_rate,
+                ),
+            ] {
+                check_non_negative(field, value)?;
+            }
+            for (priority, threshold) in router.aging_thresholds.iter().flatten() {
+                check_duration(
+                    &format!("router.aging_thresholds.{}", priority),
+                    Some(threshold),
+                )?;
+            }
+        }
+        if let Some(territory) = &self.territory {
+            check_duration(
+                "territory_config.default_lease_duration",
+                territory.default_lease_duration.as_deref(),
+            )?;
+            check_duration(
+                "territory_config.max_lease_duration",
+                territory.max_lease_duration.as_deref(),
+            )?;
+            check_duration(
+                "territory_config.auto_extend_threshold",
+                territory.auto_extend_threshold.as_deref(),
+            )?;
+            check_duration(
+                "territory_config.negotiation.timeout",
+                territory.negotiation_timeout.as_deref(),
+            )?;
+            check_duration(
+                "territory_config.escalation.deadlock_timeout",
+                territory.escalation_deadlock_timeout.as_deref(),
+            )?;
+            check_duration(
+                "territory_config.escalation.force_override_after",
+                territory.escalation_force_override_after.as_deref(),
+            )?;
+            check_duration(
+                "territory_config.fairness.starvation_threshold",
+                territory.fairness_starvation_threshold.as_deref(),
+            )?;
+            check_duration(
+                "territory_config.fairness.priority_boost_after",
+                territory.fairness_priority_boost_after.as_deref(),
+            )?;
+            check_duration(
+                "territory_config.fairness.minimum_lease",
+                territory.fairness_minimum_lease.as_deref(),
+            )?;
+            check_order(
+                "territory_config.default_lease_duration",
+                territory
+                    .default_lease_duration
+                    .as_deref()
+                    .and_then(parse_duration)
+                    .map(|duration| duration.as_secs_f64()),
+                "territory_config.max_lease_duration",
+                territory
+                    .max_lease_duration
+                    .as_deref()
+                    .and_then(parse_duration)
+                    .map(|duration| duration.as_secs_f64()),
+            )?;
+            check_ratio(
+                "territory_config.consensus_threshold",
+                territory.consensus_threshold.map(f64::from),
+            )?;
+            check_ratio(
+                "territory_config.heat_decay_per_second",
+                territory.heat_decay_per_second,
+            )?;
+        }
+        if let Some(health) = &self.health_monitoring_kpis {
+            if let Some(queue) = &health.queue_health {
+                check_order(
+                    "health_monitoring_kpis.queue_health.warning_depth",
+                    queue.warning_depth.map(|depth| depth as f64),
+                    "health_monitoring_kpis.queue_health.max_depth",
+                    queue.max_depth.map(|depth| depth as f64),
+                )?;
+                check_duration(
+                    "health_monitoring_kpis.queue_health.stale_threshold",
+                    queue.stale_threshold.as_deref(),
+                )?;
+            }
+            if let Some(consensus) = &health.consensus_success {
+                check_ratio(
+                    "health_monitoring_kpis.consensus_success.warning_ratio",
+                    consensus.warning_ratio,
+                )?;
+                check_ratio(
+                    "health_monitoring_kpis.consensus_success.critical_ratio",
+                    consensus.critical_ratio,
+                )?;
+                check_order(
+                    "health_monitoring_kpis.consensus_success.critical_ratio",
+                    consensus.critical_ratio,
+                    "health_monitoring_kpis.consensus_success.warning_ratio",
+                    consensus.warning_ratio,
+                )?;
+            }
+            if let Some(heat) = &health.heat_hotspot {
+                check_order(
+                    "health_monitoring_kpis.heat_hotspot.warning",
+                    heat.warning,
+                    "health_monitoring_kpis.heat_hotspot.critical",
+                    heat.critical,
+                )?;
+            }
+        }
+        if let Some(ledger) = &self.ledger {
+            if ledger.segment_size_bytes == 0 {
+                return Err(ConfigError::Invalid {
+                    field: "ledger.segmentSizeBytes".to_string(),
+                    reason: "must be greater than zero".to_string(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn resolve_path() -> Option<PathBuf> {
+        resolve_config_path()
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -180,6 +452,8 @@ struct RawConfig {
     #[serde(default)]
     performance_slas: Option<RawPerformanceSlas>,
     #[serde(default)]
+    router: Option<RawRouterConfig>,
+    #[serde(default)]
     health_monitoring_kpis: Option<HealthMonitoringConfig>,
     #[serde(default)]
     ledger: Option<LedgerConfig>,
@@ -208,6 +482,12 @@ struct RawTerritoryConfig {
     heat_increment: Option<f64>,
     #[serde(default)]
     heat_max: Option<f64>,
+    #[serde(default)]
+    override_policy: Option<String>,
+    #[serde(default)]
+    override_priority_delta: Option<u8>,
+    #[serde(default)]
+    allow_operator_override: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -226,6 +506,8 @@ struct RawEscalationConfig {
     queue_threshold: Option<usize>,
     #[serde(default)]
     deadlock_timeout: Option<String>,
+    #[serde(default)]
+    force_override_after: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -235,6 +517,17 @@ struct RawFairnessConfig {
     starvation_threshold: Option<String>,
     #[serde(default)]
     priority_boost_after: Option<String>,
+    #[serde(default)]
+    minimum_lease: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+struct RawRouterConfig {
+    #[serde(default)]
+    exempt_senders: Option<Vec<String>>,
+    #[serde(default)]
+    aging_thresholds: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -281,6 +574,10 @@ impl From<RawConfig> for AppConfig {
                 .escalation
                 .as_ref()
                 .and_then(|e| e.deadlock_timeout.clone());
+            let escalation_force_override_after = config
+                .escalation
+                .as_ref()
+                .and_then(|e| e.force_override_after.clone());
             let fairness_starvation_threshold = config
                 .fairness
                 .as_ref()
@@ -289,6 +586,10 @@ impl From<RawConfig> for AppConfig {
                 .fairness
                 .as_ref()
                 .and_then(|f| f.priority_boost_after.clone());
+            let fairness_minimum_lease = config
+                .fairness
+                .as_ref()
+                .and_then(|f| f.minimum_lease.clone());
 
             TerritoryConfig {
                 default_lease_duration: config.default_lease_duration,
@@ -298,34 +599,46 @@ impl From<RawConfig> for AppConfig {
                 negotiation_max_rounds,
                 escalation_queue_threshold,
                 escalation_deadlock_timeout,
+                escalation_force_override_after,
                 fairness_starvation_threshold,
                 fairness_priority_boost_after,
+                fairness_minimum_lease,
                 consensus_threshold: config.consensus_threshold,
                 heat_decay_per_second: config.heat_decay_per_second,
                 heat_increment: config.heat_increment,
                 heat_max: config.heat_max,
+                override_policy: config.override_policy,
+                override_priority_delta: config.override_priority_delta,
+                allow_operator_override: config.allow_operator_override,
             }
         });
 
-        let router = raw.performance_slas.map(|slas| RouterConfig {
-            token_bucket_capacity: None,
-            token_bucket_refill_rate: None,
-            token_bucket_initial: None,
-            aging_threshold: slas
-                .message_routing
-                .as_ref()
-                .and_then(|latency| latency.p50.clone()),
-            max_aging_boosts: None,
-            idle_backoff: None,
-            queue_depth_warning: slas
-                .queue_depths
-                .as_ref()
-                .and_then(|depths| depths.blocking_max),
-            queue_depth_critical: slas
-                .queue_depths
-                .as_ref()
-                .and_then(|depths| depths.critical_max),
-        });
+        let (exempt_senders, aging_thresholds) = raw
+            .router
+            .map(|router| (router.exempt_senders, router.aging_thresholds))
+            .unwrap_or_default();
+        let router = if raw.performance_slas.is_none()
+            && exempt_senders.is_none()
+            && aging_thresholds.is_none()
+        {
+            None
+        } else {
+            let slas = raw.performance_slas.as_ref();
+            Some(RouterConfig {
+                aging_threshold: slas
+                    .and_then(|slas| slas.message_routing.as_ref())
+                    .and_then(|latency| latency.p50.clone()),
+                queue_depth_warning: slas
+                    .and_then(|slas| slas.queue_depths.as_ref())
+                    .and_then(|depths| depths.blocking_max),
+                queue_depth_critical: slas
+                    .and_then(|slas| slas.queue_depths.as_ref())
+                    .and_then(|depths| depths.critical_max),
+                aging_thresholds,
+                exempt_senders,
+                ..RouterConfig::default()
+            })
+        };
 
         Self {
             router,
@@ -336,6 +649,51 @@ impl From<RawConfig> for AppConfig {
     }
 }
 
+fn check_duration(field: &str, value: Option<&str>) -> Result<(), ConfigError> {
+    match value {
+        Some(raw) if parse_duration(raw).is_none() => Err(ConfigError::Invalid {
+            field: field.to_string(),
+            reason: format!("`{}` is not a duration such as 500ms, 30s, 5m or 1h", raw),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn check_non_negative(field: &str, value: Option<f64>) -> Result<(), ConfigError> {
+    match value {
+        Some(number) if !number.is_finite() || number < 0.0 => Err(ConfigError::Invalid {
+            field: field.to_string(),
+            reason: format!("{} must be a finite, non-negative number", number),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn check_ratio(field: &str, value: Option<f64>) -> Result<(), ConfigError> {
+    match value {
+        Some(ratio) if !(0.0..=1.0).contains(&ratio) => Err(ConfigError::Invalid {
+            field: field.to_string(),
+            reason: format!("{} must be between 0 and 1", ratio),
+        }),
+        _ => Ok(()),
+    }
+}
+
+fn check_order(
+    lower_field: &str,
+    lower: Option<f64>,
+    upper_field: &str,
+    upper: Option<f64>,
+) -> Result<(), ConfigError> {
+    match (lower, upper) {
+        (Some(low), Some(high)) if low > high => Err(ConfigError::Invalid {
+            field: lower_field.to_string(),
+            reason: format!("{} exceeds `{}` ({})", low, upper_field, high),
+        }),
+        _ => Ok(()),
+    }
+}
+
 fn default_ledger_root() -> PathBuf {
     PathBuf::from("ledger")
 }
@@ -356,6 +714,10 @@ fn default_retain_epochs() -> usize {
     7
 }
 
+fn default_degraded_after_failures() -> u32 {
+    3
+}
+
 fn resolve_config_path() -> Option<PathBuf> {
     if let Ok(custom) = std::env::var("LIMINAL_CONFIG_PATH") {
         let path = PathBuf::from(custom);
@@ -364,17 +726,26 @@ fn resolve_config_path() -> Option<PathBuf> {
         }
     }
     let cwd = std::env::current_dir().ok()?;
-    let direct = cwd.join("../config/liminal.config.yaml");
-    if direct.exists() {
-        return Some(direct);
-    }
-    let workspace = cwd.join("config/liminal.config.yaml");
-    if workspace.exists() {
-        return Some(workspace);
+    for directory in ["../config", "config"] {
+        for extension in ConfigFormat::extensions() {
+            let candidate = cwd
+                .join(directory)
+                .join(format!("liminal.config.{}", extension));
+            if candidate.exists() {
+                return Some(candidate);
+            }
+        }
     }
     None
 }
 
+fn parse_error<E: std::fmt::Display>(err: serde_path_to_error::Error<E>) -> ConfigError {
+    ConfigError::Parse {
+        field: err.path().to_string(),
+        message: err.into_inner().to_string(),
+    }
+}
+
 pub fn parse_duration(value: &str) -> Option<std::time::Duration> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -400,7 +771,7 @@ pub fn parse_duration(value: &str) -> Option<std::time::Duration> {
         "h" => number * 3600.0,
         _ => number,
     };
-    Some(std::time::Duration::from_secs_f64(seconds))
+    std::time::Duration::try_from_secs_f64(seconds).ok()
 }
 
 pub fn parse_f64(value: &Option<String>) -> Option<f64> {
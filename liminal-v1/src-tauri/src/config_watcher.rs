@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use notify::event::{AccessKind, AccessMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use thiserror::Error;
+use tokio::sync::watch;
+
+use crate::config::AppConfig;
+
+#[derive(Debug, Error)]
+pub enum ConfigWatchError {
+    #[error("config path has no file name: {0}")]
+    InvalidPath(PathBuf),
+    #[error("failed to watch config file: {0}")]
+    Watch(#[from] notify::Error),
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ConfigDiff {
+    pub router: bool,
+    pub territory: bool,
+    pub health: bool,
+    pub ledger: bool,
+}
+
+impl ConfigDiff {
+    pub fn between(previous: &AppConfig, next: &AppConfig) -> Self {
+        Self {
+            router: previous.router != next.router,
+            territory: previous.territory != next.territory,
+            health: previous.health_monitoring_kpis != next.health_monitoring_kpis,
+            ledger: previous.ledger != next.ledger,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !(self.router || self.territory || self.health || self.ledger)
+    }
+}
+
+pub struct ConfigWatcher {
+    updates: Arc<watch::Sender<AppConfig>>,
+    _watcher: RecommendedWatcher,
+}
+
+impl ConfigWatcher {
+    pub fn new(path: impl Into<PathBuf>, initial: AppConfig) -> Result<Self, ConfigWatchError> {
+        let path = path.into();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_os_string())
+            .ok_or_else(|| ConfigWatchError::InvalidPath(path.clone()))?;
+        let directory = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let (updates, _) = watch::channel(initial);
+        let updates = Arc::new(updates);
+        let sender = Arc::clone(&updates);
+        let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+            let Ok(event) = result else {
+                return;
+            };
+            if !matches!(
+                event.kind,
+                EventKind::Create(_)
+                    | EventKind::Modify(_)
+                    | EventKind::Access(AccessKind::Close(AccessMode::Write))
+            ) {
+                return;
+            }
+            if event
+                .paths
+                .iter()
+                .any(|changed| changed.file_name() == Some(file_name.as_os_str()))
+            {
+                reload_into(&path, &sender);
+            }
+        })?;
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+        Ok(Self {
+            updates,
+            _watcher: watcher,
+        })
+    }
+
+    pub fn subscribe(&self) -> watch::Receiver<AppConfig> {
+        self.updates.subscribe()
+    }
+
+    pub fn current(&self) -> AppConfig {
+        self.updates.borrow().clone()
+    }
+}
+
+fn reload_into(path: &Path, updates: &watch::Sender<AppConfig>) {
+    if std::fs::metadata(path).map_or(true, |metadata| metadata.len() == 0) {
+        return;
+    }
+    match AppConfig::load_validated_from(path) {
+        Ok(config) => {
+            updates.send_if_modified(|current| {
+                if *current == config {
+                    false
+                } else {
+                    *current = config;
+                    true
+                }
+            });
+        }
+        Err(err) => {
+            println!(
+                "[ConfigWatcher]: keeping previous config, failed to reload {}: {}",
+                path.display(),
+                err
+            );
+        }
+    }
+}
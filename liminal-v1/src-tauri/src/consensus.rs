@@ -1,20 +1,137 @@
-use std::sync::Arc;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
 
 use blake3::hash as blake3_hash;
+use serde::Serialize;
 use serde_json::to_vec;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
+use tokio::time::Instant;
 
 use crate::ledger::{
     ConsensusEvent, ConsensusSignal, LedgerEvent, LedgerWriter, QuorumVector, QuorumVote,
 };
 use crate::metrics::{MetricsCollector, QuorumMetricsUpdate};
 
+pub const DEFAULT_DECISION_HISTORY: usize = 64;
+pub const DIRECTOR_OVERRIDE_REASON: &str = "directorOverride";
+pub const TIMEOUT_ESCALATION_REASON: &str = DIRECTOR_OVERRIDE_REASON;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoundInfo {
+    pub round_id: u64,
+    pub topic: String,
+    pub resource_id: String,
+    pub reason: String,
+    pub opened_at: SystemTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundOutcome {
+    Resolved(bool),
+    TimedOut,
+    Escalated,
+}
+
+struct OpenRound {
+    rounds: Arc<RwLock<Vec<RoundInfo>>>,
+    ballots: Arc<RwLock<HashMap<u64, watch::Sender<Vec<QuorumVote>>>>>,
+    round_id: u64,
+}
+
+impl Drop for OpenRound {
+    fn drop(&mut self) {
+        let mut rounds = self.rounds.write().unwrap();
+        rounds.retain(|round| round.round_id != self.round_id);
+        self.ballots.write().unwrap().remove(&self.round_id);
+    }
+}
+
+pub struct ConsensusRound {
+    broker: ConsensusBroker,
+    round: OpenRound,
+    topic: String,
+    resource_id: String,
+    reason: String,
+    expected_voters: usize,
+    deadline: Instant,
+    votes: watch::Receiver<Vec<QuorumVote>>,
+}
+
+impl ConsensusRound {
+    pub fn round_id(&self) -> u64 {
+        self.round.round_id
+    }
+
+    pub fn resource_id(&self) -> &str {
+        &self.resource_id
+    }
+
+    pub async fn outcome(mut self) -> RoundOutcome {
+        let expected_voters = self.expected_voters;
+        let collected = tokio::time::timeout_at(
+            self.deadline,
+            self.votes.wait_for(|votes| votes.len() >= expected_voters),
+        )
+        .await
+        .is_ok();
+        let mut votes = self.votes.borrow().clone();
+        let broker = self.broker.clone();
+        if collected {
+            let achieved = broker
+                .decide(&self.topic, &self.resource_id, votes, &self.reason)
+                .await;
+            return RoundOutcome::Resolved(achieved);
+        }
+        drop(self.round);
+        broker.metrics.record_consensus_timeout();
+        let escalated = broker.escalate_on_timeout;
+        normalize_weights(&mut votes);
+        let (total_weight, agree_weight) = tally(&votes);
+        let vector = QuorumVector {
+            resource_id: self.resource_id.clone(),
+            threshold: broker.threshold_for(&self.resource_id),
+            total_weight,
+            agree_weight,
+            achieved: escalated,
+            reason: if escalated {
+                TIMEOUT_ESCALATION_REASON.to_string()
+            } else {
+                self.reason.clone()
+            },
+            votes,
+        };
+        broker
+            .append_consensus_event(ConsensusEvent::Commit(broker.build_signal(
+                &self.topic,
+                "timeout",
+                &vector,
+            )))
+            .await;
+        if escalated {
+            broker.remember_decision(vector);
+            RoundOutcome::Escalated
+        } else {
+            RoundOutcome::TimedOut
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct ConsensusBroker {
     ledger: Option<LedgerWriter>,
     metrics: MetricsCollector,
     default_threshold: f32,
+    resource_thresholds: Arc<RwLock<HashMap<String, f32>>>,
     inflight: Arc<Mutex<()>>,
+    next_round_id: Arc<AtomicU64>,
+    open_rounds: Arc<RwLock<Vec<RoundInfo>>>,
+    ballots: Arc<RwLock<HashMap<u64, watch::Sender<Vec<QuorumVote>>>>>,
+    decisions: Arc<RwLock<VecDeque<QuorumVector>>>,
+    decision_history: usize,
+    escalate_on_timeout: bool,
 }
 
 impl ConsensusBroker {
@@ -27,32 +144,148 @@ impl ConsensusBroker {
             ledger,
             metrics,
             default_threshold,
+            resource_thresholds: Arc::new(RwLock::new(HashMap::new())),
             inflight: Arc::new(Mutex::new(())),
+            next_round_id: Arc::new(AtomicU64::new(1)),
+            open_rounds: Arc::new(RwLock::new(Vec::new())),
+            ballots: Arc::new(RwLock::new(HashMap::new())),
+            decisions: Arc::new(RwLock::new(VecDeque::new())),
+            decision_history: DEFAULT_DECISION_HISTORY,
+            escalate_on_timeout: false,
+        }
+    }
+
+    pub fn with_decision_history(mut self, capacity: usize) -> Self {
+        self.decision_history = capacity.max(1);
+        self
+    }
+
+    pub fn with_timeout_escalation(mut self, enabled: bool) -> Self {
+        self.escalate_on_timeout = enabled;
+        self
+    }
+
+    pub fn recent_decisions(&self, limit: usize) -> Vec<QuorumVector> {
+        let decisions = self.decisions.read().unwrap();
+        decisions.iter().rev().take(limit).cloned().collect()
+    }
+
+    pub fn active_rounds(&self) -> Vec<RoundInfo> {
+        self.open_rounds.read().unwrap().clone()
+    }
+
+    pub fn set_resource_threshold(&self, resource_id: &str, threshold: f32) {
+        let mut thresholds = self.resource_thresholds.write().unwrap();
+        thresholds.insert(resource_id.to_string(), threshold.clamp(0.0, 1.0));
+    }
+
+    pub fn clear_resource_threshold(&self, resource_id: &str) {
+        let mut thresholds = self.resource_thresholds.write().unwrap();
+        thresholds.remove(resource_id);
+    }
+
+    pub fn threshold_for(&self, resource_id: &str) -> f32 {
+        let thresholds = self.resource_thresholds.read().unwrap();
+        thresholds
+            .get(resource_id)
+            .copied()
+            .unwrap_or(self.default_threshold)
+            .clamp(0.0, 1.0)
+    }
+
+    pub fn open_round(
+        &self,
+        resource_id: &str,
+        reason: &str,
+        expected_voters: usize,
+        timeout: Duration,
+    ) -> ConsensusRound {
+        let topic = format!("consensus:{}", resource_id);
+        let (ballot, votes) = watch::channel(Vec::new());
+        let round = self.track_round(&topic, resource_id, reason);
+        self.ballots.write().unwrap().insert(round.round_id, ballot);
+        ConsensusRound {
+            broker: self.clone(),
+            round,
+            topic,
+            resource_id: resource_id.to_string(),
+            reason: reason.to_string(),
+            expected_voters: expected_voters.max(1),
+            deadline: Instant::now() + timeout,
+            votes,
         }
     }
 
+    pub fn cast_vote(&self, round_id: u64, vote: QuorumVote) -> bool {
+        let ballots = self.ballots.read().unwrap();
+        let Some(ballot) = ballots.get(&round_id) else {
+            return false;
+        };
+        ballot.send_modify(|votes| {
+            votes.retain(|existing| existing.agent_id != vote.agent_id);
+            votes.push(vote);
+        });
+        true
+    }
+
     pub async fn record_quorum(
         &self,
         resource_id: &str,
-        mut votes: Vec<QuorumVote>,
+        votes: Vec<QuorumVote>,
         reason: &str,
     ) -> bool {
-        let _guard = self.inflight.lock().await;
+        self.record_correlated_quorum(resource_id, votes, reason, None)
+            .await
+    }
+
+    pub async fn record_correlated_quorum(
+        &self,
+        resource_id: &str,
+        votes: Vec<QuorumVote>,
+        reason: &str,
+        correlation_id: Option<&str>,
+    ) -> bool {
         if votes.is_empty() {
             return true;
         }
-        for vote in votes.iter_mut() {
-            if vote.weight <= 0.0 {
-                vote.weight = 1.0;
-            }
-        }
-        let total_weight: f32 = votes.iter().map(|vote| vote.weight).sum();
-        let agree_weight: f32 = votes
-            .iter()
-            .filter(|vote| vote.vote)
-            .map(|vote| vote.weight)
-            .sum();
-        let threshold = self.default_threshold.max(0.0).min(1.0);
+        let topic = correlation_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| format!("consensus:{}", resource_id));
+        let _round = self.track_round(&topic, resource_id, reason);
+        self.decide(&topic, resource_id, votes, reason).await
+    }
+
+    pub async fn record_director_override(&self, resource_id: &str, correlation_id: Option<&str>) {
+        let topic = correlation_id
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| format!("consensus:{}", resource_id));
+        let vector = QuorumVector {
+            resource_id: resource_id.to_string(),
+            threshold: self.threshold_for(resource_id),
+            total_weight: 0.0,
+            agree_weight: 0.0,
+            achieved: true,
+            reason: DIRECTOR_OVERRIDE_REASON.to_string(),
+            votes: Vec::new(),
+        };
+        self.append_consensus_event(ConsensusEvent::Commit(
+            self.build_signal(&topic, "commit", &vector),
+        ))
+        .await;
+        self.remember_decision(vector);
+    }
+
+    async fn decide(
+        &self,
+        topic: &str,
+        resource_id: &str,
+        mut votes: Vec<QuorumVote>,
+        reason: &str,
+    ) -> bool {
+        let _guard = self.inflight.lock().await;
+        normalize_weights(&mut votes);
+        let (total_weight, agree_weight) = tally(&votes);
+        let threshold = self.threshold_for(resource_id);
         let achieved = if total_weight > f32::EPSILON {
             (agree_weight / total_weight) >= threshold
         } else {
@@ -68,28 +301,57 @@ impl ConsensusBroker {
             votes,
         };
         self.append_consensus_event(ConsensusEvent::Proposal(
-            self.build_signal("proposal", &vector),
+            self.build_signal(topic, "proposal", &vector),
+        ))
+        .await;
+        self.append_consensus_event(ConsensusEvent::Vote(
+            self.build_signal(topic, "vote", &vector),
+        ))
+        .await;
+        self.append_consensus_event(ConsensusEvent::Commit(
+            self.build_signal(topic, "commit", &vector),
         ))
         .await;
-        self.append_consensus_event(ConsensusEvent::Vote(self.build_signal("vote", &vector)))
-            .await;
-        self.append_consensus_event(ConsensusEvent::Commit(self.build_signal("commit", &vector)))
-            .await;
         self.metrics.record_quorum_metrics(QuorumMetricsUpdate {
             resource_id: resource_id.to_string(),
             achieved,
             threshold,
             reason: reason.to_string(),
         });
+        self.remember_decision(vector);
         achieved
     }
 
-    fn build_signal(&self, phase: &str, vector: &QuorumVector) -> ConsensusSignal {
+    fn track_round(&self, topic: &str, resource_id: &str, reason: &str) -> OpenRound {
+        let round_id = self.next_round_id.fetch_add(1, Ordering::Relaxed);
+        self.open_rounds.write().unwrap().push(RoundInfo {
+            round_id,
+            topic: topic.to_string(),
+            resource_id: resource_id.to_string(),
+            reason: reason.to_string(),
+            opened_at: SystemTime::now(),
+        });
+        OpenRound {
+            rounds: Arc::clone(&self.open_rounds),
+            ballots: Arc::clone(&self.ballots),
+            round_id,
+        }
+    }
+
+    fn remember_decision(&self, vector: QuorumVector) {
+        let mut decisions = self.decisions.write().unwrap();
+        decisions.push_back(vector);
+        while decisions.len() > self.decision_history {
+            decisions.pop_front();
+        }
+    }
+
+    fn build_signal(&self, topic: &str, phase: &str, vector: &QuorumVector) -> ConsensusSignal {
         let digest = to_vec(vector)
             .ok()
             .map(|bytes| blake3_hash(&bytes).to_hex().to_string());
         ConsensusSignal {
-            topic: format!("consensus:{}", vector.resource_id),
+            topic: topic.to_string(),
             phase: phase.to_string(),
             agent_id: None,
             territory_id: Some(vector.resource_id.clone()),
@@ -115,6 +377,24 @@ impl ConsensusBroker {
     }
 }
 
+fn normalize_weights(votes: &mut [QuorumVote]) {
+    for vote in votes.iter_mut() {
+        if vote.weight <= 0.0 {
+            vote.weight = 1.0;
+        }
+    }
+}
+
+fn tally(votes: &[QuorumVote]) -> (f32, f32) {
+    let total_weight = votes.iter().map(|vote| vote.weight).sum();
+    let agree_weight = votes
+        .iter()
+        .filter(|vote| vote.vote)
+        .map(|vote| vote.weight)
+        .sum();
+    (total_weight, agree_weight)
+}
+
 pub fn quorum_vote(agent_id: &str, weight: f32, vote: bool) -> QuorumVote {
     QuorumVote {
         agent_id: agent_id.to_string(),
@@ -1,7 +1,8 @@
+use super::retention::OUTPUT_LOG_SUFFIX;
 use super::runbook::{AgentRole, Turn, TurnStatus};
 use crate::agent::{AgentEvent, AgentProcess};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use thiserror::Error;
@@ -34,6 +35,12 @@ pub enum AgentStatus {
     ShuttingDown,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownMode {
+    Graceful(Duration),
+    Immediate,
+}
+
 #[derive(Debug, Clone)]
 pub struct TurnResult {
     pub turn_id: usize,
@@ -54,6 +61,25 @@ pub struct ClaudeCodeAgent {
     turn_start: Arc<Mutex<Option<Instant>>>,
     working_dir: PathBuf,
     output_buffer: Arc<Mutex<Vec<String>>>,
+    command: Vec<String>,
+    epoch_id: Option<String>,
+}
+
+pub fn turn_output_log_path(
+    working_dir: &Path,
+    role: &AgentRole,
+    epoch_id: Option<&str>,
+    turn_id: usize,
+) -> PathBuf {
+    let file_name = match epoch_id {
+        Some(epoch) => format!("{}_turn_{}{}", epoch, turn_id, OUTPUT_LOG_SUFFIX),
+        None => format!("turn_{}{}", turn_id, OUTPUT_LOG_SUFFIX),
+    };
+    working_dir
+        .join(".uncan")
+        .join(format!("{:?}", role).to_lowercase())
+        .join("context")
+        .join(file_name)
 }
 
 impl ClaudeCodeAgent {
@@ -68,25 +94,52 @@ impl ClaudeCodeAgent {
             turn_start: Arc::new(Mutex::new(None)),
             working_dir,
             output_buffer: Arc::new(Mutex::new(Vec::new())),
+            command: Vec::new(),
+            epoch_id: None,
         }
     }
 
+    pub fn set_epoch_id(&mut self, epoch_id: &str) {
+        self.epoch_id = Some(epoch_id.to_string());
+    }
+
+    pub fn agent_id(&self) -> String {
+        format!("claude_{:?}", self.role).to_lowercase()
+    }
+
+    pub fn output_log_path(&self, turn_id: usize) -> PathBuf {
+        turn_output_log_path(
+            &self.working_dir,
+            &self.role,
+            self.epoch_id.as_deref(),
+            turn_id,
+        )
+    }
+
     pub fn spawn(
         &mut self,
         event_sender: UnboundedSender<AgentEvent>,
+    ) -> Result<(), ClaudeAgentError> {
+        self.spawn_command(
+            event_sender,
+            vec!["claude", "--dangerously-skip-permissions", "--verbose"],
+        )
+    }
+
+    pub fn spawn_command(
+        &mut self,
+        event_sender: UnboundedSender<AgentEvent>,
+        command: Vec<&str>,
     ) -> Result<(), ClaudeAgentError> {
         {
             let mut status = self.status.lock().unwrap();
             *status = AgentStatus::Spawning;
         }
 
-        let agent_id = format!("claude_{:?}", self.role).to_lowercase();
+        let agent_id = self.agent_id();
 
-        let process = AgentProcess::spawn(
-            &agent_id,
-            vec!["claude", "--dangerously-skip-permissions", "--verbose"],
-            event_sender,
-        );
+        self.command = command.iter().map(|part| part.to_string()).collect();
+        let process = AgentProcess::spawn(&agent_id, command, event_sender);
 
         self.pty_process = Some(process);
 
@@ -133,6 +186,36 @@ impl ClaudeCodeAgent {
         Ok(())
     }
 
+    pub fn respawn(
+        &mut self,
+        event_sender: UnboundedSender<AgentEvent>,
+    ) -> Result<(), ClaudeAgentError> {
+        if let Some(process) = self.pty_process.take() {
+            if !process.has_exited() {
+                let _ = process.kill();
+            }
+        }
+        self.output_buffer.lock().unwrap().clear();
+        *self.turn_start.lock().unwrap() = None;
+        if self.command.is_empty() {
+            return self.spawn(event_sender);
+        }
+        let command = self.command.clone();
+        self.spawn_command(event_sender, command.iter().map(String::as_str).collect())
+    }
+
+    pub fn process_id(&self) -> Option<u32> {
+        self.pty_process
+            .as_ref()
+            .and_then(|process| process.process_id())
+    }
+
+    pub fn process_exited(&self) -> bool {
+        self.pty_process
+            .as_ref()
+            .is_some_and(|process| process.has_exited())
+    }
+
     fn format_turn_prompt(&self, turn: &Turn) -> String {
         let mut prompt = String::new();
 
@@ -219,12 +302,7 @@ impl ClaudeCodeAgent {
         let duration = start_time.elapsed();
         let artifacts = self.artifacts.lock().unwrap().clone();
 
-        let output_log = self
-            .working_dir
-            .join(".uncan")
-            .join(format!("{:?}", self.role).to_lowercase())
-            .join("context")
-            .join(format!("turn_{}_output.log", turn.id));
+        let output_log = self.output_log_path(turn.id);
 
         Ok(TurnResult {
             turn_id: turn.id,
@@ -273,17 +351,12 @@ impl ClaudeCodeAgent {
             .clone()
             .ok_or_else(|| ClaudeAgentError::NotReady("No current turn".to_string()))?;
 
-        let output_dir = self
-            .working_dir
-            .join(".uncan")
-            .join(format!("{:?}", self.role).to_lowercase())
-            .join("context");
-
-        std::fs::create_dir_all(&output_dir).map_err(|e| {
-            ClaudeAgentError::ExecutionFailed(format!("Failed to create output dir: {}", e))
-        })?;
-
-        let output_path = output_dir.join(format!("turn_{}_output.log", turn.id));
+        let output_path = self.output_log_path(turn.id);
+        if let Some(output_dir) = output_path.parent() {
+            std::fs::create_dir_all(output_dir).map_err(|e| {
+                ClaudeAgentError::ExecutionFailed(format!("Failed to create output dir: {}", e))
+            })?;
+        }
 
         let buffer = self.output_buffer.lock().unwrap();
         let content = buffer.join("\n");
@@ -300,32 +373,45 @@ impl ClaudeCodeAgent {
         buffer.push(line);
     }
 
-    pub fn shutdown(&mut self, force: bool) -> Result<(), ClaudeAgentError> {
+    #[cfg(test)]
+    pub(crate) fn mark_turn_complete(&self) {
+        let mut status = self.status.lock().unwrap();
+        if *status == AgentStatus::ExecutingTurn {
+            *status = AgentStatus::Completed;
+        }
+    }
+
+    pub async fn shutdown(&mut self, mode: ShutdownMode) -> Result<bool, ClaudeAgentError> {
         {
             let mut status = self.status.lock().unwrap();
             *status = AgentStatus::ShuttingDown;
         }
 
-        if let Some(process) = &self.pty_process {
-            if force {
-                process.send_command("\x03").map_err(|e| {
-                    ClaudeAgentError::ExecutionFailed(format!("Failed to send SIGINT: {}", e))
-                })?;
-            } else {
-                process.send_command("exit").map_err(|e| {
-                    ClaudeAgentError::ExecutionFailed(format!("Failed to send exit: {}", e))
-                })?;
+        let mut hard_killed = false;
+        if let Some(process) = self.pty_process.take() {
+            match mode {
+                ShutdownMode::Graceful(grace) => {
+                    let _ = process.send_command("exit");
+                    hard_killed = process.shutdown_with_grace(grace).await.map_err(|e| {
+                        ClaudeAgentError::ExecutionFailed(format!("Failed to stop agent: {}", e))
+                    })?;
+                }
+                ShutdownMode::Immediate if !process.has_exited() => {
+                    process.kill().map_err(|e| {
+                        ClaudeAgentError::ExecutionFailed(format!("Failed to kill agent: {}", e))
+                    })?;
+                    hard_killed = true;
+                }
+                ShutdownMode::Immediate => {}
             }
         }
 
-        self.pty_process = None;
-
         {
             let mut status = self.status.lock().unwrap();
             *status = AgentStatus::Idle;
         }
 
-        Ok(())
+        Ok(hard_killed)
     }
 
     pub fn get_status(&self) -> AgentStatus {
@@ -368,6 +454,48 @@ mod tests {
         assert!(!agent.check_completion("Still working on it"));
     }
 
+    #[tokio::test]
+    async fn test_shutdown_escalates_to_hard_kill() {
+        let mut agent = ClaudeCodeAgent::new(AgentRole::Systems, PathBuf::from("/tmp/test"));
+        let (tx, _rx) = unbounded_channel();
+        agent
+            .spawn_command(
+                tx,
+                vec![
+                    "sh",
+                    "-c",
+                    "trap '' TERM HUP; while true; do sleep 0.05; done",
+                ],
+            )
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let hard_killed = agent
+            .shutdown(ShutdownMode::Graceful(Duration::from_millis(300)))
+            .await
+            .unwrap();
+
+        assert!(hard_killed);
+        assert_eq!(agent.get_status(), AgentStatus::Idle);
+    }
+
+    #[tokio::test]
+    async fn test_immediate_shutdown_kills_without_grace() {
+        let mut agent = ClaudeCodeAgent::new(AgentRole::Systems, PathBuf::from("/tmp/test"));
+        let (tx, _rx) = unbounded_channel();
+        agent
+            .spawn_command(tx, vec!["sh", "-c", "while true; do sleep 0.05; done"])
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let started = std::time::Instant::now();
+        let hard_killed = agent.shutdown(ShutdownMode::Immediate).await.unwrap();
+
+        assert!(hard_killed);
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(agent.get_status(), AgentStatus::Idle);
+    }
+
     #[test]
     fn test_agent_lifecycle() {
         let mut agent = ClaudeCodeAgent::new(AgentRole::Systems, PathBuf::from("/tmp/test"));
@@ -1,4 +1,6 @@
-use super::orchestrator::{DirectorAgent, OrchestratorError, RunbookSummary, TurnUpdate};
+use super::orchestrator::{
+    DirectorAgent, Escalation, OrchestratorError, RunbookSummary, TurnUpdate,
+};
 use super::runbook::{AgentRole, TurnStatus};
 use crate::metrics::MetricsCollector;
 use crate::router::UnifiedMessageRouter;
@@ -55,6 +57,23 @@ pub enum ExecutionEvent {
     RunbookFailed {
         error_message: String,
     },
+    Escalated {
+        turn_id: usize,
+        reason: String,
+        severity: String,
+        timestamp: u64,
+    },
+}
+
+impl From<Escalation> for ExecutionEvent {
+    fn from(escalation: Escalation) -> Self {
+        ExecutionEvent::Escalated {
+            turn_id: escalation.turn_id,
+            reason: escalation.reason,
+            severity: escalation.severity,
+            timestamp: escalation.timestamp,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +84,27 @@ pub struct TurnSummary {
     pub status: TurnStatus,
     pub duration_ms: u64,
     pub error_message: Option<String>,
+    #[serde(default)]
+    pub artifacts_count: usize,
+    #[serde(default)]
+    pub agent_spawn_ms: u64,
+    #[serde(default)]
+    pub leased_resources: Vec<String>,
+}
+
+impl From<TurnUpdate> for TurnSummary {
+    fn from(update: TurnUpdate) -> Self {
+        Self {
+            turn_id: update.turn_id,
+            specialist: update.specialist,
+            status: update.status,
+            duration_ms: update.duration_ms.unwrap_or(0),
+            error_message: update.error_message,
+            artifacts_count: update.artifacts_count,
+            agent_spawn_ms: update.agent_spawn_ms.unwrap_or(0),
+            leased_resources: update.leased_resources,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -164,6 +204,7 @@ impl RunbookExecutor {
                 .as_secs(),
         });
 
+        let escalations = self.orchestrator.subscribe_escalations();
         self.orchestrator.start_execution().await?;
         eprintln!("[Executor] Orchestrator started, monitoring execution...");
 
@@ -172,7 +213,7 @@ impl RunbookExecutor {
         let start_time = Arc::clone(&self.start_time);
 
         let handle = tokio::spawn(async move {
-            Self::monitor_execution(orchestrator, event_tx, start_time).await
+            Self::monitor_execution(orchestrator, event_tx, escalations, start_time).await
         });
 
         {
@@ -199,6 +240,7 @@ impl RunbookExecutor {
     async fn monitor_execution(
         orchestrator: Arc<DirectorAgent>,
         event_tx: broadcast::Sender<ExecutionEvent>,
+        mut escalations: broadcast::Receiver<Escalation>,
         start_time: Arc<RwLock<Option<std::time::Instant>>>,
     ) -> Result<ExecutionSummary, ExecutorError> {
         let mut last_status_map: std::collections::HashMap<usize, TurnStatus> =
@@ -239,7 +281,7 @@ impl RunbookExecutor {
                         let _ = event_tx.send(ExecutionEvent::TurnCompleted {
                             turn_id: update.turn_id,
                             duration_ms: update.duration_ms.unwrap_or(0),
-                            artifacts_count: 0,
+                            artifacts_count: update.artifacts_count,
                         });
                     }
                     (TurnStatus::Failed, Some(TurnStatus::InProgress)) => {
@@ -265,6 +307,22 @@ impl RunbookExecutor {
                 last_status_map.insert(update.turn_id, update.status.clone());
             }
 
+            loop {
+                match escalations.try_recv() {
+                    Ok(escalation) => {
+                        eprintln!(
+                            "[Executor] Turn {} escalated ({}): {}",
+                            escalation.turn_id, escalation.severity, escalation.reason
+                        );
+                        let _ = event_tx.send(ExecutionEvent::from(escalation));
+                    }
+                    Err(broadcast::error::TryRecvError::Lagged(skipped)) => {
+                        eprintln!("[Executor] Missed {} escalations", skipped);
+                    }
+                    Err(_) => break,
+                }
+            }
+
             if summary.in_progress_turns == 0
                 && (summary.completed_turns + summary.failed_turns) == summary.total_turns
             {
@@ -292,16 +350,8 @@ impl RunbookExecutor {
                     });
                 }
 
-                let turn_summaries: Vec<TurnSummary> = turn_updates
-                    .into_iter()
-                    .map(|update| TurnSummary {
-                        turn_id: update.turn_id,
-                        specialist: update.specialist,
-                        status: update.status,
-                        duration_ms: update.duration_ms.unwrap_or(0),
-                        error_message: update.error_message,
-                    })
-                    .collect();
+                let turn_summaries: Vec<TurnSummary> =
+                    turn_updates.into_iter().map(TurnSummary::from).collect();
 
                 return Ok(ExecutionSummary {
                     epoch_id: summary.epoch_id,
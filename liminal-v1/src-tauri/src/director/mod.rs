@@ -2,6 +2,7 @@ pub mod claude_agent;
 pub mod executor;
 pub mod orchestrator;
 pub mod parser;
+pub mod retention;
 pub mod runbook;
 pub mod session;
 
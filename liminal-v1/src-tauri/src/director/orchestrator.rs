@@ -1,20 +1,48 @@
-use super::claude_agent::{AgentStatus, ClaudeAgentError, ClaudeCodeAgent, TurnResult};
+use super::claude_agent::{
+    turn_output_log_path, AgentStatus, ClaudeAgentError, ClaudeCodeAgent, ShutdownMode, TurnResult,
+};
+use super::retention::LogRetention;
 use super::runbook::{AgentRole, Runbook, Turn, TurnStatus};
 use super::session::Session;
+use crate::ledger::{turn_trace_id, DirectorEvent, LedgerEvent, LedgerWriter};
 use crate::metrics::MetricsCollector;
-use crate::router::UnifiedMessageRouter;
+use crate::router::{Message, Priority, UnifiedMessageRouter};
+use crate::territory::{TerritoryEvent, TerritoryManager};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use thiserror::Error;
+use tokio::sync::broadcast;
 use tokio::sync::mpsc::unbounded_channel;
 use tokio::task::JoinHandle;
 
 const DEFAULT_TURN_TIMEOUT_SECS: u64 = 1800;
 const DEFAULT_MAX_PARALLEL: usize = 3;
 const AGENT_SPAWN_RETRY_LIMIT: u32 = 1;
+const DEFAULT_SHUTDOWN_GRACE_SECS: u64 = 5;
+const ESCALATION_CHANNEL_CAPACITY: usize = 64;
+
+fn coordination_priority(runbook: &Runbook, turn: &Turn) -> Priority {
+    if let Some(value) = turn.metadata.get("priority") {
+        if let Some(priority) = (0..=Priority::DirectorOverride.as_index())
+            .map(Priority::from_index)
+            .find(|priority| priority.as_str().eq_ignore_ascii_case(value))
+        {
+            return priority;
+        }
+    }
+    let has_dependents = runbook
+        .turns
+        .iter()
+        .any(|other| other.dependencies.contains(&turn.id));
+    if has_dependents {
+        Priority::Blocking
+    } else {
+        Priority::Coordinate
+    }
+}
 
 #[derive(Debug, Error)]
 pub enum OrchestratorError {
@@ -32,6 +60,12 @@ pub enum OrchestratorError {
     AlreadyExecuting,
     #[error("Orchestrator is paused")]
     Paused,
+    #[error("Turn {0} not found")]
+    TurnNotFound(usize),
+    #[error("Turn {0} is already finished")]
+    TurnFinished(usize),
+    #[error("Turn {0} was cancelled")]
+    TurnCancelled(usize),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +89,12 @@ pub struct TurnUpdate {
     pub completed_at: Option<u64>,
     pub duration_ms: Option<u64>,
     pub error_message: Option<String>,
+    #[serde(default)]
+    pub artifacts_count: usize,
+    #[serde(default)]
+    pub agent_spawn_ms: Option<u64>,
+    #[serde(default)]
+    pub leased_resources: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,6 +106,20 @@ pub struct Escalation {
     pub timestamp: u64,
 }
 
+impl Escalation {
+    fn new(turn_id: usize, reason: String, severity: &str) -> Self {
+        Self {
+            turn_id,
+            reason,
+            severity: severity.to_string(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_secs(),
+        }
+    }
+}
+
 pub struct DirectorAgent {
     current_runbook: Arc<RwLock<Option<Runbook>>>,
     agents: Arc<RwLock<HashMap<AgentRole, ClaudeCodeAgent>>>,
@@ -76,8 +130,13 @@ pub struct DirectorAgent {
     working_dir: PathBuf,
     max_parallel: usize,
     turn_timeout: Duration,
+    shutdown_grace: Duration,
     execution_task: Arc<RwLock<Option<JoinHandle<()>>>>,
     paused: Arc<RwLock<bool>>,
+    escalations: broadcast::Sender<Escalation>,
+    log_retention: LogRetention,
+    ledger: Option<LedgerWriter>,
+    territory: Option<TerritoryManager>,
 }
 
 #[derive(Debug, Clone)]
@@ -87,26 +146,48 @@ struct TurnExecutionState {
     completed_at: Option<Instant>,
     error_message: Option<String>,
     retry_count: u32,
+    artifacts_count: usize,
+    agent_spawn_ms: Option<u64>,
+    leased_resources: Vec<String>,
+}
+
+#[derive(Clone)]
+struct TurnContext {
+    agents: Arc<RwLock<HashMap<AgentRole, ClaudeCodeAgent>>>,
+    turn_status: Arc<RwLock<HashMap<usize, TurnExecutionState>>>,
+    metrics: MetricsCollector,
+    router: Arc<UnifiedMessageRouter>,
+    working_dir: PathBuf,
+    timeout: Duration,
+    escalations: broadcast::Sender<Escalation>,
+    epoch_id: String,
+    territory: Option<TerritoryManager>,
 }
 
 impl DirectorAgent {
     pub fn new(
         working_dir: PathBuf,
         metrics: MetricsCollector,
-        router: UnifiedMessageRouter,
+        router: impl Into<Arc<UnifiedMessageRouter>>,
     ) -> Self {
+        let (escalations, _) = broadcast::channel(ESCALATION_CHANNEL_CAPACITY);
         Self {
             current_runbook: Arc::new(RwLock::new(None)),
             agents: Arc::new(RwLock::new(HashMap::new())),
             turn_status: Arc::new(RwLock::new(HashMap::new())),
             session: Arc::new(RwLock::new(None)),
             metrics,
-            router: Arc::new(router),
+            router: router.into(),
             working_dir,
             max_parallel: DEFAULT_MAX_PARALLEL,
             turn_timeout: Duration::from_secs(DEFAULT_TURN_TIMEOUT_SECS),
+            shutdown_grace: Duration::from_secs(DEFAULT_SHUTDOWN_GRACE_SECS),
             execution_task: Arc::new(RwLock::new(None)),
             paused: Arc::new(RwLock::new(false)),
+            escalations,
+            log_retention: LogRetention::default(),
+            ledger: None,
+            territory: None,
         }
     }
 
@@ -120,6 +201,52 @@ impl DirectorAgent {
         self
     }
 
+    pub fn with_shutdown_grace(mut self, grace: Duration) -> Self {
+        self.shutdown_grace = grace;
+        self
+    }
+
+    fn turn_context(&self, epoch_id: String) -> TurnContext {
+        TurnContext {
+            agents: Arc::clone(&self.agents),
+            turn_status: Arc::clone(&self.turn_status),
+            metrics: self.metrics.clone(),
+            router: Arc::clone(&self.router),
+            working_dir: self.working_dir.clone(),
+            timeout: self.turn_timeout,
+            escalations: self.escalations.clone(),
+            epoch_id,
+            territory: self.territory.clone(),
+        }
+    }
+
+    fn shutdown_mode(&self) -> ShutdownMode {
+        if self.shutdown_grace.is_zero() {
+            ShutdownMode::Immediate
+        } else {
+            ShutdownMode::Graceful(self.shutdown_grace)
+        }
+    }
+
+    pub fn with_log_retention(mut self, retention: LogRetention) -> Self {
+        self.log_retention = retention;
+        self
+    }
+
+    pub fn with_ledger(mut self, ledger: LedgerWriter) -> Self {
+        self.ledger = Some(ledger);
+        self
+    }
+
+    pub fn with_territory(mut self, territory: TerritoryManager) -> Self {
+        self.territory = Some(territory);
+        self
+    }
+
+    pub fn subscribe_escalations(&self) -> broadcast::Receiver<Escalation> {
+        self.escalations.subscribe()
+    }
+
     pub async fn load_runbook(&self, path: &Path) -> Result<RunbookSummary, OrchestratorError> {
         let content = std::fs::read_to_string(path)
             .map_err(|e| OrchestratorError::RunbookLoadFailed(e.to_string()))?;
@@ -161,12 +288,17 @@ impl DirectorAgent {
             }
         }
 
-        {
+        let started = {
             let runbook_guard = self.current_runbook.read().unwrap();
-            if runbook_guard.is_none() {
-                return Err(OrchestratorError::NoRunbookLoaded);
+            match runbook_guard.as_ref() {
+                Some(runbook) => DirectorEvent::RunbookStarted {
+                    runbook_id: runbook.epoch_id.clone(),
+                    total_turns: runbook.turns.len(),
+                },
+                None => return Err(OrchestratorError::NoRunbookLoaded),
             }
-        }
+        };
+        Self::record_director_event(&self.ledger, started).await;
 
         {
             let mut paused = self.paused.write().unwrap();
@@ -183,6 +315,10 @@ impl DirectorAgent {
         let max_parallel = self.max_parallel;
         let turn_timeout = self.turn_timeout;
         let paused = Arc::clone(&self.paused);
+        let escalations = self.escalations.clone();
+        let log_retention = self.log_retention.clone();
+        let ledger = self.ledger.clone();
+        let territory = self.territory.clone();
 
         let handle = tokio::spawn(async move {
             let _ = Self::execute_runbook_loop(
@@ -196,6 +332,10 @@ impl DirectorAgent {
                 max_parallel,
                 turn_timeout,
                 paused,
+                escalations,
+                log_retention,
+                ledger,
+                territory,
             )
             .await;
         });
@@ -219,7 +359,13 @@ impl DirectorAgent {
         max_parallel: usize,
         turn_timeout: Duration,
         paused: Arc<RwLock<bool>>,
+        escalations: broadcast::Sender<Escalation>,
+        log_retention: LogRetention,
+        ledger: Option<LedgerWriter>,
+        territory: Option<TerritoryManager>,
     ) -> Result<(), OrchestratorError> {
+        let mut stalled_turns = HashSet::new();
+
         loop {
             if *paused.read().unwrap() {
                 tokio::time::sleep(Duration::from_millis(500)).await;
@@ -252,10 +398,25 @@ impl DirectorAgent {
                 };
 
                 if all_complete {
+                    if let Some(event) = Self::runbook_completed_event(&current_runbook) {
+                        Self::record_director_event(&ledger, event).await;
+                    }
                     Self::finalize_session(&session);
+                    Self::prune_output_logs(
+                        &working_dir,
+                        &log_retention,
+                        &current_runbook,
+                        &turn_status,
+                    );
                     break;
                 }
 
+                Self::escalate_dependency_stalls(
+                    &current_runbook,
+                    &mut stalled_turns,
+                    &escalations,
+                );
+
                 tokio::time::sleep(Duration::from_millis(500)).await;
                 continue;
             }
@@ -271,34 +432,61 @@ impl DirectorAgent {
                 executable_turns.into_iter().take(1).collect()
             };
 
+            let (epoch_id, priorities): (String, HashMap<usize, Priority>) = current_runbook
+                .read()
+                .unwrap()
+                .as_ref()
+                .map(|runbook| {
+                    let priorities = turns_to_execute
+                        .iter()
+                        .map(|turn| (turn.id, coordination_priority(runbook, turn)))
+                        .collect();
+                    (runbook.epoch_id.clone(), priorities)
+                })
+                .unwrap_or_default();
+            let context = TurnContext {
+                agents: Arc::clone(&agents),
+                turn_status: Arc::clone(&turn_status),
+                metrics: metrics.clone(),
+                router: Arc::clone(&router),
+                working_dir: working_dir.clone(),
+                timeout: turn_timeout,
+                escalations: escalations.clone(),
+                epoch_id,
+                territory: territory.clone(),
+            };
             let mut handles = Vec::new();
 
             for turn in turns_to_execute {
-                let agents_clone = Arc::clone(&agents);
-                let turn_status_clone = Arc::clone(&turn_status);
+                let context = context.clone();
                 let session_clone = Arc::clone(&session);
                 let current_runbook_clone = Arc::clone(&current_runbook);
-                let metrics_clone = metrics.clone();
-                let router_clone = Arc::clone(&router);
-                let working_dir_clone = working_dir.clone();
+                let epoch_id_clone = context.epoch_id.clone();
+                let ledger_clone = ledger.clone();
+                let priority = priorities
+                    .get(&turn.id)
+                    .copied()
+                    .unwrap_or(Priority::Coordinate);
 
                 let handle = tokio::spawn(async move {
-                    let result = Self::execute_turn(
-                        &turn,
-                        agents_clone,
-                        turn_status_clone,
-                        metrics_clone,
-                        router_clone,
-                        working_dir_clone,
-                        turn_timeout,
+                    Self::record_director_event(
+                        &ledger_clone,
+                        DirectorEvent::TurnStarted {
+                            runbook_id: epoch_id_clone.clone(),
+                            turn_id: turn.id,
+                            specialist: format!("{:?}", turn.specialist).to_lowercase(),
+                        },
                     )
                     .await;
+                    let result = Self::execute_turn(&turn, priority, &context).await;
 
                     Self::handle_turn_completion(
                         &turn,
                         result,
                         current_runbook_clone,
                         session_clone,
+                        &ledger_clone,
+                        &epoch_id_clone,
                     )
                     .await;
                 });
@@ -318,17 +506,31 @@ impl DirectorAgent {
 
     async fn execute_turn(
         turn: &Turn,
-        agents: Arc<RwLock<HashMap<AgentRole, ClaudeCodeAgent>>>,
-        turn_status: Arc<RwLock<HashMap<usize, TurnExecutionState>>>,
-        metrics: MetricsCollector,
-        router: Arc<UnifiedMessageRouter>,
-        working_dir: PathBuf,
-        timeout: Duration,
+        priority: Priority,
+        context: &TurnContext,
     ) -> Result<TurnResult, OrchestratorError> {
+        let TurnContext {
+            agents,
+            turn_status,
+            metrics,
+            router,
+            working_dir,
+            timeout,
+            escalations,
+            epoch_id,
+            territory,
+        } = context;
         let start_time = Instant::now();
+        let mut lease_events = territory.as_ref().map(|territory| territory.subscribe());
 
         {
             let mut status_map = turn_status.write().unwrap();
+            if status_map
+                .get(&turn.id)
+                .is_some_and(|state| state.status == TurnStatus::Failed)
+            {
+                return Err(OrchestratorError::TurnCancelled(turn.id));
+            }
             status_map.insert(
                 turn.id,
                 TurnExecutionState {
@@ -337,6 +539,9 @@ impl DirectorAgent {
                     completed_at: None,
                     error_message: None,
                     retry_count: 0,
+                    artifacts_count: 0,
+                    agent_spawn_ms: None,
+                    leased_resources: Vec::new(),
                 },
             );
         }
@@ -345,12 +550,9 @@ impl DirectorAgent {
         let mut retry_count = 0;
 
         loop {
-            let spawn_result = Self::get_or_spawn_agent(
-                &turn.specialist,
-                Arc::clone(&agents),
-                working_dir.clone(),
-            )
-            .await;
+            let spawn_result =
+                Self::get_or_spawn_agent(&turn.specialist, Arc::clone(agents), working_dir.clone())
+                    .await;
 
             match spawn_result {
                 Ok(_) => break,
@@ -363,6 +565,11 @@ impl DirectorAgent {
                             state.error_message = Some(format!("Agent spawn failed: {}", e));
                             state.completed_at = Some(Instant::now());
                         }
+                        let _ = escalations.send(Escalation::new(
+                            turn.id,
+                            format!("Agent spawn failed after {} attempts: {}", retry_count, e),
+                            "critical",
+                        ));
                         return Err(OrchestratorError::AgentSpawnFailed(e));
                     }
                     tokio::time::sleep(Duration::from_millis(1000)).await;
@@ -370,11 +577,19 @@ impl DirectorAgent {
             }
         }
 
-        metrics.record_agent_spawn(agent_spawn_start.elapsed().as_millis() as f64);
+        let agent_spawn_elapsed = agent_spawn_start.elapsed();
+        metrics.record_agent_spawn(agent_spawn_elapsed.as_millis() as f64);
+        {
+            let mut status_map = turn_status.write().unwrap();
+            if let Some(state) = status_map.get_mut(&turn.id) {
+                state.agent_spawn_ms = Some(agent_spawn_elapsed.as_millis() as u64);
+            }
+        }
 
         let send_result = {
             let mut agents_map = agents.write().unwrap();
             if let Some(agent_ref) = agents_map.get_mut(&turn.specialist) {
+                agent_ref.set_epoch_id(epoch_id);
                 agent_ref.send_turn_prompt(turn)
             } else {
                 Err(ClaudeAgentError::NotReady("Agent not found".to_string()))
@@ -388,9 +603,29 @@ impl DirectorAgent {
                 state.error_message = Some(format!("Failed to send turn prompt: {}", e));
                 state.completed_at = Some(Instant::now());
             }
+            let _ = escalations.send(Escalation::new(
+                turn.id,
+                format!("Failed to send turn prompt: {}", e),
+                "critical",
+            ));
             return Err(OrchestratorError::TurnExecutionFailed(e.to_string()));
         }
 
+        let coordination = Message {
+            content: format!("Turn {} assigned to {:?}", turn.id, turn.specialist),
+            priority,
+            sender: "director".to_string(),
+            recipient: format!("{:?}", turn.specialist).to_lowercase(),
+            message_id: None,
+            trace_id: Some(turn_trace_id(epoch_id, turn.id)),
+        };
+        if let Err(e) = router.route_message(coordination).await {
+            println!(
+                "[Director]: Failed to route coordination message for turn {}: {}",
+                turn.id, e
+            );
+        }
+
         let agent_exists = {
             let agents_map = agents.read().unwrap();
             agents_map.contains_key(&turn.specialist)
@@ -405,7 +640,17 @@ impl DirectorAgent {
         let result = loop {
             tokio::time::sleep(Duration::from_millis(500)).await;
 
-            if start_time.elapsed() > timeout {
+            let cancelled = {
+                let status_map = turn_status.read().unwrap();
+                status_map
+                    .get(&turn.id)
+                    .is_some_and(|state| state.status == TurnStatus::Failed)
+            };
+            if cancelled {
+                return Err(OrchestratorError::TurnCancelled(turn.id));
+            }
+
+            if start_time.elapsed() > *timeout {
                 break Err(ClaudeAgentError::TurnTimeout(timeout.as_secs()));
             }
 
@@ -417,6 +662,62 @@ impl DirectorAgent {
                     .unwrap_or(AgentStatus::Failed)
             };
 
+            if status == AgentStatus::ExecutingTurn {
+                let exited = {
+                    let agents_map = agents.read().unwrap();
+                    agents_map
+                        .get(&turn.specialist)
+                        .is_some_and(|agent| agent.process_exited())
+                };
+
+                if exited {
+                    let retries_used = {
+                        let status_map = turn_status.read().unwrap();
+                        status_map
+                            .get(&turn.id)
+                            .map(|state| state.retry_count)
+                            .unwrap_or(0)
+                    };
+
+                    if retries_used >= turn.max_retries {
+                        break Err(ClaudeAgentError::ExecutionFailed(
+                            "Agent process exited unexpectedly".to_string(),
+                        ));
+                    }
+
+                    println!(
+                        "[Director]: Agent {:?} exited during turn {}, restarting (attempt {}/{})",
+                        turn.specialist,
+                        turn.id,
+                        retries_used + 1,
+                        turn.max_retries
+                    );
+
+                    let restart_result = {
+                        let mut agents_map = agents.write().unwrap();
+                        match agents_map.get_mut(&turn.specialist) {
+                            Some(agent) => {
+                                let (tx, _rx) = unbounded_channel();
+                                agent.respawn(tx).and_then(|_| agent.send_turn_prompt(turn))
+                            }
+                            None => Err(ClaudeAgentError::NotReady("Agent not found".to_string())),
+                        }
+                    };
+
+                    {
+                        let mut status_map = turn_status.write().unwrap();
+                        if let Some(state) = status_map.get_mut(&turn.id) {
+                            state.retry_count += 1;
+                        }
+                    }
+
+                    if let Err(e) = restart_result {
+                        break Err(e);
+                    }
+                    continue;
+                }
+            }
+
             if status == AgentStatus::Completed || status == AgentStatus::Failed {
                 let turn_result = {
                     let agents_map = agents.read().unwrap();
@@ -468,9 +769,127 @@ impl DirectorAgent {
             }
         };
 
+        let holder_id = {
+            let agents_map = agents.read().unwrap();
+            agents_map
+                .get(&turn.specialist)
+                .map(|agent| agent.agent_id())
+        };
+        let leased_resources = lease_events
+            .as_mut()
+            .map(|events| {
+                Self::drain_turn_leases(
+                    events,
+                    &turn_trace_id(epoch_id, turn.id),
+                    holder_id.as_deref(),
+                )
+            })
+            .unwrap_or_default();
+        {
+            let mut status_map = turn_status.write().unwrap();
+            if let Some(state) = status_map.get_mut(&turn.id) {
+                state.artifacts_count = result
+                    .as_ref()
+                    .map(|turn_result| turn_result.artifacts.len())
+                    .unwrap_or(0);
+                state.leased_resources = leased_resources;
+            }
+        }
+
+        if let Err(e) = &result {
+            let retries = {
+                let status_map = turn_status.read().unwrap();
+                status_map
+                    .get(&turn.id)
+                    .map(|state| state.retry_count)
+                    .unwrap_or(0)
+            };
+            let escalation = match e {
+                ClaudeAgentError::TurnTimeout(_) => {
+                    Escalation::new(turn.id, e.to_string(), "warning")
+                }
+                _ => Escalation::new(
+                    turn.id,
+                    format!("Turn failed after {} retries: {}", retries, e),
+                    "critical",
+                ),
+            };
+            let _ = escalations.send(escalation);
+        }
+
         result.map_err(|e| OrchestratorError::TurnExecutionFailed(e.to_string()))
     }
 
+    fn drain_turn_leases(
+        events: &mut broadcast::Receiver<TerritoryEvent>,
+        trace_id: &str,
+        holder_id: Option<&str>,
+    ) -> Vec<String> {
+        let mut resources = Vec::new();
+        loop {
+            let lease = match events.try_recv() {
+                Ok(TerritoryEvent::Granted(lease)) => lease,
+                Ok(TerritoryEvent::Overridden { lease, .. }) => *lease,
+                Ok(TerritoryEvent::Transferred { lease, .. }) => *lease,
+                Ok(_) | Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                Err(_) => break,
+            };
+            let owned = holder_id.is_some_and(|holder| lease.holder_id == holder)
+                || lease.tags.iter().any(|tag| tag == trace_id);
+            if owned && !resources.contains(&lease.resource_id) {
+                resources.push(lease.resource_id);
+            }
+        }
+        resources.sort();
+        resources
+    }
+
+    fn escalate_dependency_stalls(
+        current_runbook: &Arc<RwLock<Option<Runbook>>>,
+        stalled_turns: &mut HashSet<usize>,
+        escalations: &broadcast::Sender<Escalation>,
+    ) {
+        let runbook_guard = current_runbook.read().unwrap();
+        let Some(runbook) = runbook_guard.as_ref() else {
+            return;
+        };
+        if runbook
+            .turns
+            .iter()
+            .any(|t| t.status == TurnStatus::InProgress)
+        {
+            return;
+        }
+
+        let unfinished: HashSet<usize> = runbook
+            .turns
+            .iter()
+            .filter(|t| t.status != TurnStatus::Completed)
+            .map(|t| t.id)
+            .collect();
+
+        for turn in runbook
+            .turns
+            .iter()
+            .filter(|t| t.status == TurnStatus::Pending)
+        {
+            let blockers: Vec<usize> = turn
+                .dependencies
+                .iter()
+                .copied()
+                .filter(|dep| unfinished.contains(dep))
+                .collect();
+            if blockers.is_empty() || !stalled_turns.insert(turn.id) {
+                continue;
+            }
+            let _ = escalations.send(Escalation::new(
+                turn.id,
+                format!("Turn blocked on unfinished dependencies {:?}", blockers),
+                "critical",
+            ));
+        }
+    }
+
     async fn get_or_spawn_agent(
         role: &AgentRole,
         agents: Arc<RwLock<HashMap<AgentRole, ClaudeCodeAgent>>>,
@@ -506,12 +925,38 @@ impl DirectorAgent {
         result: Result<TurnResult, OrchestratorError>,
         current_runbook: Arc<RwLock<Option<Runbook>>>,
         session: Arc<RwLock<Option<Session>>>,
+        ledger: &Option<LedgerWriter>,
+        epoch_id: &str,
     ) {
         let new_status = match &result {
             Ok(turn_result) => turn_result.status.clone(),
             Err(_) => TurnStatus::Failed,
         };
 
+        let outcome = match &result {
+            Ok(turn_result) if turn_result.status == TurnStatus::Completed => {
+                DirectorEvent::TurnCompleted {
+                    runbook_id: epoch_id.to_string(),
+                    turn_id: turn.id,
+                    duration_ms: turn_result.duration.as_millis() as u64,
+                }
+            }
+            Ok(turn_result) => DirectorEvent::TurnFailed {
+                runbook_id: epoch_id.to_string(),
+                turn_id: turn.id,
+                error_message: turn_result
+                    .error_message
+                    .clone()
+                    .unwrap_or_else(|| "Unknown error".to_string()),
+            },
+            Err(e) => DirectorEvent::TurnFailed {
+                runbook_id: epoch_id.to_string(),
+                turn_id: turn.id,
+                error_message: e.to_string(),
+            },
+        };
+        Self::record_director_event(ledger, outcome).await;
+
         {
             let mut runbook_guard = current_runbook.write().unwrap();
             if let Some(runbook) = runbook_guard.as_mut() {
@@ -532,6 +977,29 @@ impl DirectorAgent {
         }
     }
 
+    async fn record_director_event(ledger: &Option<LedgerWriter>, event: DirectorEvent) {
+        let Some(ledger) = ledger else {
+            return;
+        };
+        if let Err(e) = ledger.append_async(LedgerEvent::Director(event)).await {
+            println!("[Director]: Failed to record ledger event: {}", e);
+        }
+    }
+
+    fn runbook_completed_event(
+        current_runbook: &Arc<RwLock<Option<Runbook>>>,
+    ) -> Option<DirectorEvent> {
+        let runbook_guard = current_runbook.read().unwrap();
+        let runbook = runbook_guard.as_ref()?;
+        let count =
+            |status: TurnStatus| runbook.turns.iter().filter(|t| t.status == status).count();
+        Some(DirectorEvent::RunbookCompleted {
+            runbook_id: runbook.epoch_id.clone(),
+            completed_turns: count(TurnStatus::Completed),
+            failed_turns: count(TurnStatus::Failed),
+        })
+    }
+
     fn finalize_session(session: &Arc<RwLock<Option<Session>>>) {
         let mut session_guard = session.write().unwrap();
         if let Some(sess) = session_guard.as_mut() {
@@ -540,6 +1008,51 @@ impl DirectorAgent {
         }
     }
 
+    fn active_output_logs(
+        working_dir: &Path,
+        current_runbook: &Arc<RwLock<Option<Runbook>>>,
+        turn_status: &Arc<RwLock<HashMap<usize, TurnExecutionState>>>,
+    ) -> HashSet<PathBuf> {
+        let runbook_guard = current_runbook.read().unwrap();
+        let Some(runbook) = runbook_guard.as_ref() else {
+            return HashSet::new();
+        };
+        let status_map = turn_status.read().unwrap();
+        runbook
+            .turns
+            .iter()
+            .filter(|turn| {
+                status_map
+                    .get(&turn.id)
+                    .is_some_and(|state| state.status == TurnStatus::InProgress)
+            })
+            .map(|turn| {
+                turn_output_log_path(
+                    working_dir,
+                    &turn.specialist,
+                    Some(&runbook.epoch_id),
+                    turn.id,
+                )
+            })
+            .collect()
+    }
+
+    fn prune_output_logs(
+        working_dir: &Path,
+        log_retention: &LogRetention,
+        current_runbook: &Arc<RwLock<Option<Runbook>>>,
+        turn_status: &Arc<RwLock<HashMap<usize, TurnExecutionState>>>,
+    ) {
+        let active = Self::active_output_logs(working_dir, current_runbook, turn_status);
+        match log_retention.prune_working_dir(working_dir, &active) {
+            Ok(removed) if !removed.is_empty() => {
+                println!("[Director]: Pruned {} turn output logs", removed.len());
+            }
+            Ok(_) => {}
+            Err(e) => println!("[Director]: Failed to prune turn output logs: {}", e),
+        }
+    }
+
     pub fn get_turn_status(&self) -> Vec<TurnUpdate> {
         let status_map = self.turn_status.read().unwrap();
         let mut updates = Vec::new();
@@ -566,6 +1079,11 @@ impl DirectorAgent {
                         })
                     }),
                     error_message: state.and_then(|s| s.error_message.clone()),
+                    artifacts_count: state.map(|s| s.artifacts_count).unwrap_or(0),
+                    agent_spawn_ms: state.and_then(|s| s.agent_spawn_ms),
+                    leased_resources: state
+                        .map(|s| s.leased_resources.clone())
+                        .unwrap_or_default(),
                 });
             }
         }
@@ -573,6 +1091,10 @@ impl DirectorAgent {
         updates
     }
 
+    pub fn get_runbook(&self) -> Option<Runbook> {
+        self.current_runbook.read().unwrap().clone()
+    }
+
     pub fn get_summary(&self) -> Option<RunbookSummary> {
         let runbook_guard = self.current_runbook.read().unwrap();
         runbook_guard.as_ref().map(|runbook| {
@@ -624,7 +1146,105 @@ impl DirectorAgent {
         Ok(())
     }
 
-    pub fn handle_escalation(&self, _escalation: Escalation) -> Result<(), OrchestratorError> {
+    pub async fn cancel_turn(&self, turn_id: usize) -> Result<(), OrchestratorError> {
+        let specialist = {
+            let mut runbook_guard = self.current_runbook.write().unwrap();
+            let runbook = runbook_guard
+                .as_mut()
+                .ok_or(OrchestratorError::NoRunbookLoaded)?;
+            let turn = runbook
+                .turns
+                .iter_mut()
+                .find(|turn| turn.id == turn_id)
+                .ok_or(OrchestratorError::TurnNotFound(turn_id))?;
+            if matches!(turn.status, TurnStatus::Completed | TurnStatus::Failed) {
+                return Err(OrchestratorError::TurnFinished(turn_id));
+            }
+            turn.status = TurnStatus::Failed;
+            turn.specialist.clone()
+        };
+
+        let in_flight = {
+            let mut status_map = self.turn_status.write().unwrap();
+            let state = status_map.entry(turn_id).or_insert(TurnExecutionState {
+                status: TurnStatus::Pending,
+                started_at: None,
+                completed_at: None,
+                error_message: None,
+                retry_count: 0,
+                artifacts_count: 0,
+                agent_spawn_ms: None,
+                leased_resources: Vec::new(),
+            });
+            let in_flight = state.status == TurnStatus::InProgress;
+            state.status = TurnStatus::Failed;
+            state.error_message = Some("cancelled".to_string());
+            state.completed_at = Some(Instant::now());
+            in_flight
+        };
+
+        if in_flight {
+            let agent = self.agents.write().unwrap().remove(&specialist);
+            if let Some(mut agent) = agent {
+                let _ = agent
+                    .shutdown(ShutdownMode::Graceful(self.shutdown_grace))
+                    .await;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn run_single_turn(&self, turn_id: usize) -> Result<TurnResult, OrchestratorError> {
+        let executing = self
+            .execution_task
+            .read()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished());
+        if executing {
+            return Err(OrchestratorError::AlreadyExecuting);
+        }
+
+        let (turn, epoch_id, priority) = {
+            let runbook_guard = self.current_runbook.read().unwrap();
+            let runbook = runbook_guard
+                .as_ref()
+                .ok_or(OrchestratorError::NoRunbookLoaded)?;
+            let turn = runbook
+                .turns
+                .iter()
+                .find(|turn| turn.id == turn_id)
+                .cloned()
+                .ok_or(OrchestratorError::TurnNotFound(turn_id))?;
+            let priority = coordination_priority(runbook, &turn);
+            (turn, runbook.epoch_id.clone(), priority)
+        };
+
+        let result = Self::execute_turn(&turn, priority, &self.turn_context(epoch_id)).await;
+
+        let mut status_map = self.turn_status.write().unwrap();
+        if let Some(state) = status_map.get_mut(&turn_id) {
+            if state.status == TurnStatus::InProgress {
+                match &result {
+                    Ok(turn_result) => {
+                        state.status = turn_result.status.clone();
+                        state.error_message = turn_result.error_message.clone();
+                    }
+                    Err(e) => {
+                        state.status = TurnStatus::Failed;
+                        state.error_message = Some(e.to_string());
+                    }
+                }
+                state.completed_at = Some(Instant::now());
+            }
+        }
+
+        result
+    }
+
+    pub fn handle_escalation(&self, escalation: Escalation) -> Result<(), OrchestratorError> {
+        let _ = self.escalations.send(escalation);
         Ok(())
     }
 
@@ -634,6 +1254,13 @@ impl DirectorAgent {
             *paused = true;
         }
 
+        Self::prune_output_logs(
+            &self.working_dir,
+            &self.log_retention,
+            &self.current_runbook,
+            &self.turn_status,
+        );
+
         {
             let mut execution_guard = self.execution_task.write().unwrap();
             if let Some(handle) = execution_guard.take() {
@@ -641,14 +1268,775 @@ impl DirectorAgent {
             }
         }
 
-        {
+        let agents: Vec<ClaudeCodeAgent> = {
             let mut agents_map = self.agents.write().unwrap();
-            for (_, agent) in agents_map.iter_mut() {
-                let _ = agent.shutdown(false);
-            }
-            agents_map.clear();
+            agents_map.drain().map(|(_, agent)| agent).collect()
+        };
+        for mut agent in agents {
+            let _ = agent
+                .shutdown(ShutdownMode::Graceful(self.shutdown_grace))
+                .await;
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LedgerConfig;
+    use crate::director::session::SessionState;
+    use crate::director::TurnSummary;
+    use crate::ledger::{LedgerReader, ReplayCoordinator};
+    use crate::territory::{LeaseDecision, LeaseRequest};
+
+    fn kill_process(pid: u32) {
+        std::process::Command::new("kill")
+            .args(["-9", &pid.to_string()])
+            .status()
+            .unwrap();
+    }
+
+    async fn wait_for_retry(
+        turn_status: &Arc<RwLock<HashMap<usize, TurnExecutionState>>>,
+        turn_id: usize,
+        expected: u32,
+    ) {
+        for _ in 0..40 {
+            let retries = turn_status
+                .read()
+                .unwrap()
+                .get(&turn_id)
+                .map(|state| state.retry_count)
+                .unwrap_or(0);
+            if retries >= expected {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("turn {} never reached retry {}", turn_id, expected);
+    }
+
+    #[tokio::test]
+    async fn crashed_agent_is_respawned_and_turn_retried() {
+        let working_dir = std::env::temp_dir();
+        let mut agent = ClaudeCodeAgent::new(AgentRole::Systems, working_dir.clone());
+        let (tx, _rx) = unbounded_channel();
+        agent
+            .spawn_command(tx, vec!["sh", "-c", "cat > /dev/null"])
+            .unwrap();
+        let original_pid = agent.process_id().unwrap();
+
+        let agents = Arc::new(RwLock::new(HashMap::new()));
+        agents.write().unwrap().insert(AgentRole::Systems, agent);
+        let turn_status = Arc::new(RwLock::new(HashMap::new()));
+        let turn = Turn::new(1, AgentRole::Systems, "Build it".to_string()).with_max_retries(1);
+
+        let task = tokio::spawn({
+            let agents = Arc::clone(&agents);
+            let turn_status = Arc::clone(&turn_status);
+            async move {
+                let context = TurnContext {
+                    agents,
+                    turn_status,
+                    metrics: MetricsCollector::new(),
+                    router: Arc::new(UnifiedMessageRouter::new()),
+                    working_dir,
+                    timeout: Duration::from_secs(20),
+                    escalations: broadcast::channel(ESCALATION_CHANNEL_CAPACITY).0,
+                    epoch_id: "epoch".to_string(),
+                    territory: None,
+                };
+                DirectorAgent::execute_turn(&turn, Priority::Coordinate, &context).await
+            }
+        });
+
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        kill_process(original_pid);
+        wait_for_retry(&turn_status, 1, 1).await;
+
+        let respawned_pid = {
+            let agents_map = agents.read().unwrap();
+            let agent = agents_map.get(&AgentRole::Systems).unwrap();
+            assert_eq!(agent.get_status(), AgentStatus::ExecutingTurn);
+            assert!(agent.get_current_turn().is_some());
+            agent.process_id().unwrap()
+        };
+        assert_ne!(respawned_pid, original_pid);
+
+        kill_process(respawned_pid);
+        let result = task.await.unwrap();
+
+        match result {
+            Err(OrchestratorError::TurnExecutionFailed(message)) => {
+                assert!(message.contains("exited unexpectedly"))
+            }
+            other => panic!("expected exit failure, got {:?}", other.map(|r| r.status)),
+        }
+        assert_eq!(turn_status.read().unwrap().get(&1).unwrap().retry_count, 1);
+    }
+
+    #[tokio::test]
+    async fn failed_turn_broadcasts_escalation() {
+        let working_dir = std::env::temp_dir();
+        let director = DirectorAgent::new(
+            working_dir.clone(),
+            MetricsCollector::new(),
+            UnifiedMessageRouter::new(),
+        )
+        .with_turn_timeout(Duration::from_secs(20));
+        let mut escalations = director.subscribe_escalations();
+
+        let mut agent = ClaudeCodeAgent::new(AgentRole::Testing, working_dir.clone());
+        let (tx, _rx) = unbounded_channel();
+        agent
+            .spawn_command(tx, vec!["sh", "-c", "sleep 0.3"])
+            .unwrap();
+        director
+            .agents
+            .write()
+            .unwrap()
+            .insert(AgentRole::Testing, agent);
+        let turn = Turn::new(7, AgentRole::Testing, "Run tests".to_string()).with_max_retries(0);
+
+        let context = director.turn_context("epoch".to_string());
+        let result = DirectorAgent::execute_turn(&turn, Priority::Coordinate, &context).await;
+        assert!(result.is_err());
+
+        let escalation = escalations.try_recv().unwrap();
+        assert_eq!(escalation.turn_id, 7);
+        assert_eq!(escalation.severity, "critical");
+        assert!(escalation.reason.contains("exited unexpectedly"));
+    }
+
+    #[test]
+    fn dependency_stall_escalates_once_per_turn() {
+        let director = DirectorAgent::new(
+            std::env::temp_dir(),
+            MetricsCollector::new(),
+            UnifiedMessageRouter::new(),
+        );
+        let mut escalations = director.subscribe_escalations();
+
+        let mut failed = Turn::new(1, AgentRole::Systems, "Build".to_string());
+        failed.status = TurnStatus::Failed;
+        let mut blocked = Turn::new(2, AgentRole::Testing, "Test".to_string());
+        blocked.dependencies = vec![1];
+        let mut runbook = Runbook::new("epoch".to_string(), "goal".to_string());
+        runbook.turns = vec![failed, blocked];
+        *director.current_runbook.write().unwrap() = Some(runbook);
+
+        let mut stalled = HashSet::new();
+        DirectorAgent::escalate_dependency_stalls(
+            &director.current_runbook,
+            &mut stalled,
+            &director.escalations,
+        );
+        DirectorAgent::escalate_dependency_stalls(
+            &director.current_runbook,
+            &mut stalled,
+            &director.escalations,
+        );
+
+        let escalation = escalations.try_recv().unwrap();
+        assert_eq!(escalation.turn_id, 2);
+        assert_eq!(escalation.severity, "critical");
+        assert!(escalations.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn shutdown_prunes_old_output_logs_but_keeps_active_turn() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let director = DirectorAgent::new(
+            temp_dir.path().to_path_buf(),
+            MetricsCollector::new(),
+            UnifiedMessageRouter::new(),
+        )
+        .with_log_retention(LogRetention::new(None, Some(3)));
+
+        let now = std::time::SystemTime::now();
+        let mut logs = Vec::new();
+        for turn_id in 1..=6 {
+            let path =
+                turn_output_log_path(temp_dir.path(), &AgentRole::Systems, Some("epoch"), turn_id);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            let file = std::fs::File::create(&path).unwrap();
+            file.set_modified(now - Duration::from_secs(60 * (7 - turn_id as u64)))
+                .unwrap();
+            logs.push(path);
+        }
+
+        let mut runbook = Runbook::new("epoch".to_string(), "goal".to_string());
+        runbook.turns = vec![Turn::new(1, AgentRole::Systems, "Build".to_string())];
+        *director.current_runbook.write().unwrap() = Some(runbook);
+        director.turn_status.write().unwrap().insert(
+            1,
+            TurnExecutionState {
+                status: TurnStatus::InProgress,
+                started_at: Some(Instant::now()),
+                completed_at: None,
+                error_message: None,
+                retry_count: 0,
+                artifacts_count: 0,
+                agent_spawn_ms: None,
+                leased_resources: Vec::new(),
+            },
+        );
+
+        director.shutdown().await.unwrap();
+
+        let remaining: Vec<bool> = logs.iter().map(|path| path.exists()).collect();
+        assert_eq!(remaining, vec![true, false, false, true, true, true]);
+    }
+
+    #[tokio::test]
+    async fn runbook_lifecycle_is_recorded_in_the_ledger() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let ledger_config = LedgerConfig {
+            root_path: temp_dir.path().join("ledger"),
+            current_epoch: Some("director-epoch".to_string()),
+            ..LedgerConfig::default()
+        };
+        let ledger = LedgerWriter::new(&ledger_config).unwrap();
+        let director = DirectorAgent::new(
+            temp_dir.path().to_path_buf(),
+            MetricsCollector::new(),
+            UnifiedMessageRouter::new(),
+        )
+        .with_ledger(ledger.clone());
+
+        let runbook_path = temp_dir.path().join("runbook.md");
+        std::fs::write(
+            &runbook_path,
+            r#"# Runbook: Ledger Epoch
+
+**Epoch Goal:** Record director activity
+
+## Turn 1 — Systems Agent
+**Specialist:** Systems
+**Parallel Group:** 1
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Build
+
+## Turn 2 — Testing Agent
+**Specialist:** Testing
+**Parallel Group:** 1
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Test
+"#,
+        )
+        .unwrap();
+        director.load_runbook(&runbook_path).await.unwrap();
+
+        for role in [AgentRole::Systems, AgentRole::Testing] {
+            let mut agent = ClaudeCodeAgent::new(role.clone(), temp_dir.path().to_path_buf());
+            let (tx, _rx) = unbounded_channel();
+            agent
+                .spawn_command(tx, vec!["sh", "-c", "sleep 0.3"])
+                .unwrap();
+            director.agents.write().unwrap().insert(role, agent);
+        }
+
+        director.start_execution().await.unwrap();
+        let handle = director.execution_task.write().unwrap().take().unwrap();
+        tokio::time::timeout(Duration::from_secs(20), handle)
+            .await
+            .unwrap()
+            .unwrap();
+        ledger.flush().await.unwrap();
+
+        let outcome = ReplayCoordinator::new(LedgerReader::new(ledger_config.root_path.clone()))
+            .replay_epoch("director-epoch")
+            .unwrap();
+        let timeline: Vec<DirectorEvent> = outcome
+            .director_timeline
+            .iter()
+            .map(|entry| entry.event.clone())
+            .collect();
+
+        assert_eq!(timeline.len(), 6);
+        assert_eq!(
+            timeline.first(),
+            Some(&DirectorEvent::RunbookStarted {
+                runbook_id: "Ledger Epoch".to_string(),
+                total_turns: 2,
+            })
+        );
+        assert_eq!(
+            timeline.last(),
+            Some(&DirectorEvent::RunbookCompleted {
+                runbook_id: "Ledger Epoch".to_string(),
+                completed_turns: 0,
+                failed_turns: 2,
+            })
+        );
+        for turn_id in [1, 2] {
+            let position = |matches: fn(&DirectorEvent) -> bool| {
+                timeline
+                    .iter()
+                    .position(|event| event.turn_id() == Some(turn_id) && matches(event))
+                    .unwrap()
+            };
+            let started = position(|event| matches!(event, DirectorEvent::TurnStarted { .. }));
+            let failed = position(|event| matches!(event, DirectorEvent::TurnFailed { .. }));
+            assert!(started < failed);
+        }
+        assert!(outcome
+            .director_timeline
+            .windows(2)
+            .all(|pair| pair[0].sequence < pair[1].sequence));
+    }
+
+    #[tokio::test]
+    async fn starting_a_turn_routes_a_prioritised_handoff() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let router = Arc::new(UnifiedMessageRouter::new());
+        let director = DirectorAgent::new(
+            temp_dir.path().to_path_buf(),
+            MetricsCollector::new(),
+            Arc::clone(&router),
+        );
+        let mut deliveries = router.subscribe();
+
+        let runbook_path = temp_dir.path().join("runbook.md");
+        std::fs::write(
+            &runbook_path,
+            r#"# Runbook: Handoff Epoch
+
+**Epoch Goal:** Route coordination
+
+## Turn 1 — Systems Agent
+**Specialist:** Systems
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Build
+
+## Turn 2 — Testing Agent
+**Specialist:** Testing
+**Priority:** Critical
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Test
+"#,
+        )
+        .unwrap();
+        director.load_runbook(&runbook_path).await.unwrap();
+
+        let mut agent = ClaudeCodeAgent::new(AgentRole::Systems, temp_dir.path().to_path_buf());
+        let (tx, _rx) = unbounded_channel();
+        agent
+            .spawn_command(tx, vec!["sh", "-c", "sleep 0.3"])
+            .unwrap();
+        director
+            .agents
+            .write()
+            .unwrap()
+            .insert(AgentRole::Systems, agent);
+
+        director.start_execution().await.unwrap();
+        let delivery = tokio::time::timeout(Duration::from_secs(10), deliveries.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        if let Some(handle) = director.execution_task.write().unwrap().take() {
+            handle.abort();
+        }
+
+        assert_eq!(delivery.message.sender, "director");
+        assert_eq!(delivery.message.recipient, "systems");
+        assert_eq!(delivery.message.priority, Priority::Blocking);
+        assert_eq!(
+            delivery.message.trace_id,
+            Some(turn_trace_id("Handoff Epoch", 1))
+        );
+
+        let runbook = director.current_runbook.read().unwrap().clone().unwrap();
+        assert_eq!(
+            coordination_priority(&runbook, &runbook.turns[1]),
+            Priority::Critical
+        );
+    }
+
+    #[tokio::test]
+    async fn turn_cancelled_before_start_does_not_run() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let director = DirectorAgent::new(
+            temp_dir.path().to_path_buf(),
+            MetricsCollector::new(),
+            UnifiedMessageRouter::new(),
+        );
+
+        let runbook_path = temp_dir.path().join("runbook.md");
+        std::fs::write(
+            &runbook_path,
+            r#"# Runbook: Early Cancel
+
+**Epoch Goal:** Cancel before start
+
+## Turn 1 — Systems Agent
+**Specialist:** Systems
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Build
+"#,
+        )
+        .unwrap();
+        director.load_runbook(&runbook_path).await.unwrap();
+
+        director.cancel_turn(1).await.unwrap();
+        assert!(matches!(
+            director.run_single_turn(1).await,
+            Err(OrchestratorError::TurnCancelled(1))
+        ));
+
+        let status_map = director.turn_status.read().unwrap();
+        assert_eq!(status_map[&1].status, TurnStatus::Failed);
+        assert_eq!(status_map[&1].error_message, Some("cancelled".to_string()));
+        assert!(status_map[&1].started_at.is_none());
+        assert!(director.agents.read().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn cancelled_turn_fails_while_parallel_turn_completes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let director = DirectorAgent::new(
+            temp_dir.path().to_path_buf(),
+            MetricsCollector::new(),
+            UnifiedMessageRouter::new(),
+        )
+        .with_shutdown_grace(Duration::from_millis(100));
+
+        let runbook_path = temp_dir.path().join("runbook.md");
+        std::fs::write(
+            &runbook_path,
+            r#"# Runbook: Cancel Epoch
+
+**Epoch Goal:** Cancel one turn
+
+## Turn 1 — Systems Agent
+**Specialist:** Systems
+**Parallel Group:** 1
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Build
+
+## Turn 2 — Testing Agent
+**Specialist:** Testing
+**Parallel Group:** 1
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Test
+"#,
+        )
+        .unwrap();
+        director.load_runbook(&runbook_path).await.unwrap();
+
+        for role in [AgentRole::Systems, AgentRole::Testing] {
+            let mut agent = ClaudeCodeAgent::new(role.clone(), temp_dir.path().to_path_buf());
+            let (tx, _rx) = unbounded_channel();
+            agent
+                .spawn_command(tx, vec!["sh", "-c", "sleep 30"])
+                .unwrap();
+            director.agents.write().unwrap().insert(role, agent);
+        }
+
+        director.start_execution().await.unwrap();
+        tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                let started = director
+                    .turn_status
+                    .read()
+                    .unwrap()
+                    .values()
+                    .filter(|state| state.status == TurnStatus::InProgress)
+                    .count();
+                if started == 2 {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        director.cancel_turn(1).await.unwrap();
+        assert!(!director
+            .agents
+            .read()
+            .unwrap()
+            .contains_key(&AgentRole::Systems));
+        director
+            .agents
+            .read()
+            .unwrap()
+            .get(&AgentRole::Testing)
+            .unwrap()
+            .mark_turn_complete();
+
+        let handle = director.execution_task.write().unwrap().take().unwrap();
+        tokio::time::timeout(Duration::from_secs(10), handle)
+            .await
+            .unwrap()
+            .unwrap();
+
+        let runbook = director.current_runbook.read().unwrap().clone().unwrap();
+        let statuses: Vec<TurnStatus> = runbook.turns.iter().map(|t| t.status.clone()).collect();
+        assert_eq!(statuses, vec![TurnStatus::Failed, TurnStatus::Completed]);
+        assert_eq!(
+            director.turn_status.read().unwrap()[&1].error_message,
+            Some("cancelled".to_string())
+        );
+        assert!(matches!(
+            director.cancel_turn(1).await,
+            Err(OrchestratorError::TurnFinished(1))
+        ));
+
+        director.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn single_turn_runs_in_isolation() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let director = Arc::new(DirectorAgent::new(
+            temp_dir.path().to_path_buf(),
+            MetricsCollector::new(),
+            UnifiedMessageRouter::new(),
+        ));
+
+        let runbook_path = temp_dir.path().join("runbook.md");
+        std::fs::write(
+            &runbook_path,
+            r#"# Runbook: Isolation Epoch
+
+**Epoch Goal:** Debug one turn
+
+## Turn 1 — Systems Agent
+**Specialist:** Systems
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Build
+
+## Turn 2 — Testing Agent
+**Specialist:** Testing
+**Dependencies:** Turn 1
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Test
+"#,
+        )
+        .unwrap();
+        director.load_runbook(&runbook_path).await.unwrap();
+        assert!(matches!(
+            director.run_single_turn(7).await,
+            Err(OrchestratorError::TurnNotFound(7))
+        ));
+
+        let mut agent = ClaudeCodeAgent::new(AgentRole::Testing, temp_dir.path().to_path_buf());
+        let (tx, _rx) = unbounded_channel();
+        agent
+            .spawn_command(tx, vec!["sh", "-c", "sleep 30"])
+            .unwrap();
+        director
+            .agents
+            .write()
+            .unwrap()
+            .insert(AgentRole::Testing, agent);
+
+        let runner = Arc::clone(&director);
+        let run = tokio::spawn(async move { runner.run_single_turn(2).await });
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while director
+                .turn_status
+                .read()
+                .unwrap()
+                .get(&2)
+                .is_none_or(|state| state.status != TurnStatus::InProgress)
+            {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .unwrap();
+        director
+            .agents
+            .read()
+            .unwrap()
+            .get(&AgentRole::Testing)
+            .unwrap()
+            .mark_turn_complete();
+
+        let result = tokio::time::timeout(Duration::from_secs(10), run)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.turn_id, 2);
+        assert_eq!(result.status, TurnStatus::Completed);
+
+        let spawned: Vec<AgentRole> = director.agents.read().unwrap().keys().cloned().collect();
+        assert_eq!(spawned, vec![AgentRole::Testing]);
+        let tracked: Vec<usize> = director
+            .turn_status
+            .read()
+            .unwrap()
+            .keys()
+            .copied()
+            .collect();
+        assert_eq!(tracked, vec![2]);
+        assert_eq!(
+            director.turn_status.read().unwrap()[&2].status,
+            TurnStatus::Completed
+        );
+        let runbook = director.current_runbook.read().unwrap().clone().unwrap();
+        assert!(runbook
+            .turns
+            .iter()
+            .all(|turn| turn.status == TurnStatus::Pending));
+        assert!(matches!(
+            director.session.read().unwrap().as_ref().unwrap().state,
+            SessionState::Created
+        ));
+
+        director.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn turn_summary_reports_artifacts_spawn_and_leases() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::process::Command::new("git")
+            .args(["init", "-q"])
+            .current_dir(temp_dir.path())
+            .status()
+            .unwrap();
+        let territory = TerritoryManager::new(MetricsCollector::new(), None);
+        let director = Arc::new(
+            DirectorAgent::new(
+                temp_dir.path().to_path_buf(),
+                MetricsCollector::new(),
+                UnifiedMessageRouter::new(),
+            )
+            .with_territory(territory.clone()),
+        );
+
+        let runbook_path = temp_dir.path().join("runbook.md");
+        std::fs::write(
+            &runbook_path,
+            r#"# Runbook: Accounting Epoch
+
+**Epoch Goal:** Account for one turn
+
+## Turn 1 — Testing Agent
+**Specialist:** Testing
+**Max Retries:** 0
+
+**Prompt to Delegate:**
+> Test
+"#,
+        )
+        .unwrap();
+        let epoch_id = director.load_runbook(&runbook_path).await.unwrap().epoch_id;
+
+        let mut agent = ClaudeCodeAgent::new(AgentRole::Testing, temp_dir.path().to_path_buf());
+        let (tx, _rx) = unbounded_channel();
+        agent
+            .spawn_command(tx, vec!["sh", "-c", "sleep 30"])
+            .unwrap();
+        director
+            .agents
+            .write()
+            .unwrap()
+            .insert(AgentRole::Testing, agent);
+
+        let runner = Arc::clone(&director);
+        let run = tokio::spawn(async move { runner.run_single_turn(1).await });
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while director
+                .turn_status
+                .read()
+                .unwrap()
+                .get(&1)
+                .is_none_or(|state| state.agent_spawn_ms.is_none())
+            {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        std::fs::write(temp_dir.path().join("report.txt"), "done").unwrap();
+        std::fs::write(temp_dir.path().join("notes.txt"), "notes").unwrap();
+        let trace_id = turn_trace_id(&epoch_id, 1);
+        for (resource, tag) in [
+            ("src/tests.rs", trace_id.as_str()),
+            ("src/lib.rs", trace_id.as_str()),
+            ("src/main.rs", "unrelated"),
+        ] {
+            let decision = territory
+                .acquire_lease(
+                    LeaseRequest::builder("testing", resource, Priority::Coordinate)
+                        .tag(tag)
+                        .build(),
+                )
+                .await;
+            assert!(matches!(decision, LeaseDecision::Granted(_)));
+        }
+        let untagged = territory
+            .acquire_lease(
+                LeaseRequest::builder("claude_testing", "src/agent.rs", Priority::Coordinate)
+                    .build(),
+            )
+            .await;
+        assert!(matches!(untagged, LeaseDecision::Granted(_)));
+        director
+            .agents
+            .read()
+            .unwrap()
+            .get(&AgentRole::Testing)
+            .unwrap()
+            .mark_turn_complete();
+
+        let result = tokio::time::timeout(Duration::from_secs(10), run)
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        assert!(result
+            .artifacts
+            .contains(&temp_dir.path().join("report.txt")));
+        assert!(result
+            .artifacts
+            .contains(&temp_dir.path().join("notes.txt")));
+
+        let update = director
+            .get_turn_status()
+            .into_iter()
+            .find(|update| update.turn_id == 1)
+            .unwrap();
+        let summary = TurnSummary::from(update);
+        assert_eq!(summary.status, TurnStatus::Completed);
+        assert_eq!(summary.artifacts_count, result.artifacts.len());
+        assert!(summary.agent_spawn_ms <= summary.duration_ms);
+        assert_eq!(
+            summary.leased_resources,
+            vec![
+                "src/agent.rs".to_string(),
+                "src/lib.rs".to_string(),
+                "src/tests.rs".to_string()
+            ]
+        );
+
+        director.shutdown().await.unwrap();
+    }
+}
@@ -1,5 +1,6 @@
 use super::runbook::{AgentRole, Runbook, Turn};
 use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use serde::Deserialize;
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -9,8 +10,8 @@ pub enum ParseError {
     MissingField(String),
     #[error("Invalid format: {0}")]
     InvalidFormat(String),
-    #[error("Unknown agent role: {0}")]
-    UnknownRole(String),
+    #[error("Unknown agent role '{role}' in turn {turn_id}")]
+    UnknownRole { turn_id: usize, role: String },
     #[error("Invalid turn number: {0}")]
     InvalidTurnNumber(String),
 }
@@ -19,12 +20,132 @@ pub struct RunbookParser {
     content: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct JsonRunbookHeader {
+    epoch_id: Option<String>,
+    goal: Option<String>,
+    #[serde(default)]
+    turns: Vec<JsonTurn>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonTurn {
+    id: usize,
+    specialist: String,
+    prompt: String,
+    #[serde(default)]
+    dependencies: Vec<usize>,
+    #[serde(default)]
+    parallel_group: Option<usize>,
+    #[serde(default)]
+    acceptance_criteria: Vec<String>,
+    #[serde(default)]
+    max_retries: Option<u32>,
+}
+
+impl JsonTurn {
+    fn into_turn(self) -> Result<Turn, ParseError> {
+        let specialist =
+            AgentRole::from_str(&self.specialist).ok_or_else(|| ParseError::UnknownRole {
+                turn_id: self.id,
+                role: self.specialist.clone(),
+            })?;
+        let mut turn = Turn::new(self.id, specialist, self.prompt.trim().to_string())
+            .with_acceptance(self.acceptance_criteria)
+            .with_parallel_group(self.parallel_group)
+            .with_metadata(
+                "dependencies_raw".to_string(),
+                render_dependencies(&self.dependencies),
+            );
+        if let Some(max_retries) = self.max_retries {
+            turn = turn.with_max_retries(max_retries);
+        }
+        Ok(turn)
+    }
+}
+
+fn from_json_value<T: serde::de::DeserializeOwned>(
+    value: serde_json::Value,
+) -> Result<T, ParseError> {
+    serde_json::from_value(value).map_err(|err| ParseError::InvalidFormat(err.to_string()))
+}
+
+fn is_headerless_turn(value: &serde_json::Value) -> bool {
+    value.get("id").is_some() && value.get("epoch_id").is_none() && value.get("goal").is_none()
+}
+
+fn render_dependencies(dependencies: &[usize]) -> String {
+    if dependencies.is_empty() {
+        return "None".to_string();
+    }
+    dependencies
+        .iter()
+        .map(|id| format!("Turn {}", id))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 impl RunbookParser {
     pub fn new(content: String) -> Self {
         Self { content }
     }
 
     pub fn parse(&self) -> Result<Runbook, ParseError> {
+        if self.is_json() {
+            self.parse_json()
+        } else {
+            self.parse_text()
+        }
+    }
+
+    fn is_json(&self) -> bool {
+        let trimmed = self.content.trim_start();
+        trimmed.starts_with('{') || trimmed.starts_with('[')
+    }
+
+    fn parse_json(&self) -> Result<Runbook, ParseError> {
+        let mut values = Vec::new();
+        for value in serde_json::Deserializer::from_str(&self.content).into_iter() {
+            match value.map_err(|err| ParseError::InvalidFormat(err.to_string()))? {
+                serde_json::Value::Array(items) => values.extend(items),
+                value => values.push(value),
+            }
+        }
+        let mut values = values.into_iter();
+        let header: JsonRunbookHeader = match values.next() {
+            Some(value) if is_headerless_turn(&value) => {
+                return Err(ParseError::InvalidFormat(
+                    "JSON runbook must start with an object carrying epoch_id and goal before its turns"
+                        .to_string(),
+                ))
+            }
+            Some(value) => from_json_value(value)?,
+            None => return Err(ParseError::MissingField("epoch_id".to_string())),
+        };
+        let mut json_turns = header.turns;
+        for value in values {
+            json_turns.push(from_json_value(value)?);
+        }
+
+        let epoch_id = header
+            .epoch_id
+            .filter(|value| !value.trim().is_empty())
+            .ok_or_else(|| ParseError::MissingField("epoch_id".to_string()))?;
+        let goal = header
+            .goal
+            .filter(|value| !value.trim().is_empty())
+            .ok_or_else(|| ParseError::MissingField("goal".to_string()))?;
+
+        let mut runbook = Runbook::new(epoch_id.trim().to_string(), goal.trim().to_string());
+        for turn in json_turns {
+            runbook.add_turn(turn.into_turn()?);
+        }
+        runbook.build_dependency_graph();
+
+        Ok(runbook)
+    }
+
+    fn parse_text(&self) -> Result<Runbook, ParseError> {
         let mut epoch_id = String::new();
         let mut goal = String::new();
         let mut turns = Vec::new();
@@ -120,6 +241,7 @@ impl RunbookParser {
         let mut in_turn = false;
         let mut specialist: Option<AgentRole> = None;
         let mut parallel_group: Option<usize> = None;
+        let mut max_retries: Option<u32> = None;
         let mut prompt = String::new();
         let mut acceptance_criteria = Vec::new();
         let mut metadata = HashMap::new();
@@ -139,7 +261,7 @@ impl RunbookParser {
 
                 if line.starts_with("**Specialist:**") {
                     let role_str = line.strip_prefix("**Specialist:**").unwrap_or("").trim();
-                    specialist = AgentRole::from_str(role_str).or_else(|| {
+                    let role = AgentRole::from_str(role_str).or_else(|| {
                         if role_str.contains("Systems") {
                             Some(AgentRole::Systems)
                         } else if role_str.contains("Interface") {
@@ -156,6 +278,10 @@ impl RunbookParser {
                             None
                         }
                     });
+                    specialist = Some(role.ok_or_else(|| ParseError::UnknownRole {
+                        turn_id: turn_num,
+                        role: role_str.to_string(),
+                    })?);
                 } else if line.starts_with("**Parallel Group:**") {
                     let group_str = line
                         .strip_prefix("**Parallel Group:**")
@@ -164,9 +290,19 @@ impl RunbookParser {
                     if !group_str.contains("N/A") && !group_str.contains("Sequential") {
                         parallel_group = group_str.parse::<usize>().ok();
                     }
+                } else if line.starts_with("**Max Retries:**") {
+                    max_retries = line
+                        .strip_prefix("**Max Retries:**")
+                        .unwrap_or("")
+                        .trim()
+                        .parse::<u32>()
+                        .ok();
                 } else if line.starts_with("**Dependencies:**") {
                     let deps_str = line.strip_prefix("**Dependencies:**").unwrap_or("").trim();
                     metadata.insert("dependencies_raw".to_string(), deps_str.to_string());
+                } else if line.starts_with("**Priority:**") {
+                    let priority_str = line.strip_prefix("**Priority:**").unwrap_or("").trim();
+                    metadata.insert("priority".to_string(), priority_str.to_string());
                 } else if line.starts_with("**Prompt to Delegate:**") {
                     in_prompt_block = true;
                     in_acceptance_block = false;
@@ -198,6 +334,10 @@ impl RunbookParser {
                 .with_acceptance(acceptance_criteria)
                 .with_parallel_group(parallel_group);
 
+            if let Some(max_retries) = max_retries {
+                turn = turn.with_max_retries(max_retries);
+            }
+
             for (k, v) in metadata {
                 turn = turn.with_metadata(k, v);
             }
@@ -383,4 +523,199 @@ mod tests {
         assert_eq!(executable.len(), 1);
         assert_eq!(executable[0].id, 2);
     }
+
+    #[test]
+    fn test_dependency_edges_and_levels() {
+        let content = r#"# Runbook: Graph Test
+
+**Epoch Goal:** Draw the plan
+
+## Turn 1 — Systems Agent
+**Specialist:** Systems
+**Parallel Group:** N/A (Sequential)
+
+**Prompt to Delegate:**
+> Scaffold
+
+## Turn 2 — Interface Agent
+**Specialist:** Interface
+**Parallel Group:** 1
+
+**Prompt to Delegate:**
+> Build the UI
+
+## Turn 3 — Router Agent
+**Specialist:** Router
+**Parallel Group:** 1
+
+**Prompt to Delegate:**
+> Build the router
+
+## Turn 4 — Testing Agent
+**Specialist:** Testing
+**Parallel Group:** 2
+
+**Prompt to Delegate:**
+> Test both
+
+## Turn 5 — Research Agent
+**Specialist:** Research
+**Parallel Group:** N/A (Sequential)
+
+**Prompt to Delegate:**
+> Write it up
+"#;
+
+        let runbook = RunbookParser::new(content.to_string()).parse().unwrap();
+        assert_eq!(
+            runbook.dependency_edges(),
+            vec![
+                (1, 2),
+                (1, 3),
+                (1, 4),
+                (1, 5),
+                (2, 4),
+                (2, 5),
+                (3, 4),
+                (3, 5),
+                (4, 5),
+            ]
+        );
+        assert_eq!(
+            runbook.topological_levels(),
+            vec![vec![1], vec![2, 3], vec![4], vec![5]]
+        );
+    }
+
+    #[test]
+    fn test_json_lines_runbook_matches_text_runbook() {
+        let text = r#"# Runbook: Dual Format
+
+**Epoch Goal:** Parse either format
+
+## Turn 1 — Systems Agent
+**Specialist:** Systems
+**Parallel Group:** 1
+**Max Retries:** 3
+**Dependencies:** None
+
+**Prompt to Delegate:**
+> Build the core
+> and wire it up
+
+**Acceptance:**
+- Core compiles
+
+## Turn 2 — Interface Agent
+**Specialist:** Interface
+**Parallel Group:** 1
+**Dependencies:** None
+
+**Prompt to Delegate:**
+> Sketch the panel
+
+**Acceptance:**
+- Panel renders
+- Panel is responsive
+
+## Turn 3 — Testing Agent
+**Specialist:** Testing
+**Parallel Group:** 2
+**Dependencies:** Turn 1, Turn 2
+
+**Prompt to Delegate:**
+> Cover both
+
+**Acceptance:**
+- Tests pass
+"#;
+        let json_lines = r#"{"epoch_id": "Dual Format", "goal": "Parse either format"}
+{"id": 1, "specialist": "Systems", "prompt": "Build the core\nand wire it up", "dependencies": [], "parallel_group": 1, "acceptance_criteria": ["Core compiles"], "max_retries": 3}
+{"id": 2, "specialist": "Interface", "prompt": "Sketch the panel", "dependencies": [], "parallel_group": 1, "acceptance_criteria": ["Panel renders", "Panel is responsive"]}
+{"id": 3, "specialist": "Testing", "prompt": "Cover both", "dependencies": [1, 2], "parallel_group": 2, "acceptance_criteria": ["Tests pass"]}
+"#;
+        let json_document = r#"{
+  "epoch_id": "Dual Format",
+  "goal": "Parse either format",
+  "turns": [
+    {"id": 1, "specialist": "Systems", "prompt": "Build the core\nand wire it up", "dependencies": [], "parallel_group": 1, "acceptance_criteria": ["Core compiles"], "max_retries": 3},
+    {"id": 2, "specialist": "Interface", "prompt": "Sketch the panel", "dependencies": [], "parallel_group": 1, "acceptance_criteria": ["Panel renders", "Panel is responsive"]},
+    {"id": 3, "specialist": "Testing", "prompt": "Cover both", "dependencies": [1, 2], "parallel_group": 2, "acceptance_criteria": ["Tests pass"]}
+  ]
+}"#;
+
+        let json_array = r#"[
+  {"epoch_id": "Dual Format", "goal": "Parse either format"},
+  {"id": 1, "specialist": "Systems", "prompt": "Build the core\nand wire it up", "dependencies": [], "parallel_group": 1, "acceptance_criteria": ["Core compiles"], "max_retries": 3},
+  {"id": 2, "specialist": "Interface", "prompt": "Sketch the panel", "dependencies": [], "parallel_group": 1, "acceptance_criteria": ["Panel renders", "Panel is responsive"]},
+  {"id": 3, "specialist": "Testing", "prompt": "Cover both", "dependencies": [1, 2], "parallel_group": 2, "acceptance_criteria": ["Tests pass"]}
+]"#;
+
+        let from_text = RunbookParser::new(text.to_string()).parse().unwrap();
+        let from_lines = RunbookParser::new(json_lines.to_string()).parse().unwrap();
+        let from_document = RunbookParser::new(json_document.to_string())
+            .parse()
+            .unwrap();
+
+        assert_eq!(from_text.turns.len(), 3);
+        assert_eq!(from_text.turns, from_lines.turns);
+        assert_eq!(from_text, from_lines);
+        assert_eq!(from_text, from_document);
+        assert_eq!(
+            from_text,
+            RunbookParser::new(json_array.to_string()).parse().unwrap()
+        );
+
+        let headerless = r#"[
+  {"id": 1, "specialist": "Systems", "prompt": "Build the core", "dependencies": []},
+  {"id": 2, "specialist": "Testing", "prompt": "Cover it", "dependencies": [1]}
+]"#;
+        assert!(matches!(
+            RunbookParser::new(headerless.to_string()).parse(),
+            Err(ParseError::InvalidFormat(reason)) if reason.contains("epoch_id and goal")
+        ));
+
+        let unknown = r#"{"epoch_id": "Bad", "goal": "Reject"}
+{"id": 1, "specialist": "Gardener", "prompt": "Water"}
+"#;
+        assert!(matches!(
+            RunbookParser::new(unknown.to_string()).parse(),
+            Err(ParseError::UnknownRole { turn_id: 1, role }) if role == "Gardener"
+        ));
+    }
+
+    #[test]
+    fn test_misspelled_role_names_the_turn() {
+        let content = r#"# Runbook: Typo Epoch
+
+**Epoch Goal:** Catch authoring mistakes
+
+## Turn 1 — Systems Agent
+**Specialist:** Systems
+**Parallel Group:** N/A
+
+**Prompt to Delegate:**
+> Fine
+
+**Acceptance:**
+- Done
+
+## Turn 2 — Interface Agent
+**Specialist:** Interfase
+**Parallel Group:** N/A
+
+**Prompt to Delegate:**
+> Broken
+
+**Acceptance:**
+- Done
+"#;
+
+        let err = RunbookParser::new(content.to_string()).parse().unwrap_err();
+        assert!(matches!(
+            &err,
+            ParseError::UnknownRole { turn_id: 2, role } if role == "Interfase"
+        ));
+        assert_eq!(err.to_string(), "Unknown agent role 'Interfase' in turn 2");
+    }
 }
@@ -0,0 +1,89 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+pub const OUTPUT_LOG_SUFFIX: &str = "_output.log";
+const DEFAULT_MAX_AGE_SECS: u64 = 7 * 24 * 60 * 60;
+const DEFAULT_MAX_COUNT: usize = 50;
+
+#[derive(Debug, Clone)]
+pub struct LogRetention {
+    pub max_age: Option<Duration>,
+    pub max_count: Option<usize>,
+}
+
+impl Default for LogRetention {
+    fn default() -> Self {
+        Self {
+            max_age: Some(Duration::from_secs(DEFAULT_MAX_AGE_SECS)),
+            max_count: Some(DEFAULT_MAX_COUNT),
+        }
+    }
+}
+
+impl LogRetention {
+    pub fn new(max_age: Option<Duration>, max_count: Option<usize>) -> Self {
+        Self { max_age, max_count }
+    }
+
+    pub fn prune_working_dir(
+        &self,
+        working_dir: &Path,
+        active: &HashSet<PathBuf>,
+    ) -> std::io::Result<Vec<PathBuf>> {
+        let root = working_dir.join(".uncan");
+        if !root.is_dir() {
+            return Ok(Vec::new());
+        }
+
+        let mut removed = Vec::new();
+        for entry in fs::read_dir(&root)? {
+            let context_dir = entry?.path().join("context");
+            if context_dir.is_dir() {
+                removed.extend(self.prune_dir(&context_dir, active)?);
+            }
+        }
+        Ok(removed)
+    }
+
+    pub fn prune_dir(
+        &self,
+        dir: &Path,
+        active: &HashSet<PathBuf>,
+    ) -> std::io::Result<Vec<PathBuf>> {
+        let mut logs: Vec<(PathBuf, SystemTime)> = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let is_log = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.ends_with(OUTPUT_LOG_SUFFIX));
+            if !is_log || !entry.file_type()?.is_file() {
+                continue;
+            }
+            let modified = entry.metadata()?.modified()?;
+            logs.push((path, modified));
+        }
+
+        logs.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.cmp(&a.0)));
+
+        let now = SystemTime::now();
+        let mut removed = Vec::new();
+        for (index, (path, modified)) in logs.into_iter().enumerate() {
+            if active.contains(&path) {
+                continue;
+            }
+            let over_count = self.max_count.is_some_and(|max| index >= max);
+            let expired = self
+                .max_age
+                .is_some_and(|max_age| now.duration_since(modified).is_ok_and(|age| age > max_age));
+            if over_count || expired {
+                fs::remove_file(&path)?;
+                removed.push(path);
+            }
+        }
+        Ok(removed)
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+pub const DEFAULT_TURN_MAX_RETRIES: u32 = 1;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum AgentRole {
@@ -34,7 +36,7 @@ pub enum TurnStatus {
     Blocked,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Turn {
     pub id: usize,
     pub specialist: AgentRole,
@@ -44,6 +46,12 @@ pub struct Turn {
     pub dependencies: Vec<usize>,
     pub status: TurnStatus,
     pub metadata: HashMap<String, String>,
+    #[serde(default = "default_turn_max_retries")]
+    pub max_retries: u32,
+}
+
+fn default_turn_max_retries() -> u32 {
+    DEFAULT_TURN_MAX_RETRIES
 }
 
 impl Turn {
@@ -57,9 +65,15 @@ impl Turn {
             dependencies: Vec::new(),
             status: TurnStatus::Pending,
             metadata: HashMap::new(),
+            max_retries: DEFAULT_TURN_MAX_RETRIES,
         }
     }
 
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
     pub fn with_acceptance(mut self, criteria: Vec<String>) -> Self {
         self.acceptance_criteria = criteria;
         self
@@ -76,7 +90,7 @@ impl Turn {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Runbook {
     pub epoch_id: String,
     pub goal: String,
@@ -138,6 +152,58 @@ impl Runbook {
         }
     }
 
+    pub fn dependency_edges(&self) -> Vec<(usize, usize)> {
+        let known: HashSet<usize> = self.turns.iter().map(|t| t.id).collect();
+        let mut edges: Vec<(usize, usize)> = self
+            .turns
+            .iter()
+            .flat_map(|turn| {
+                turn.dependencies
+                    .iter()
+                    .filter(|dep| known.contains(dep))
+                    .map(move |&dep| (dep, turn.id))
+            })
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
+
+    pub fn topological_levels(&self) -> Vec<Vec<usize>> {
+        let mut remaining: HashMap<usize, usize> = HashMap::new();
+        let mut dependents: HashMap<usize, Vec<usize>> = HashMap::new();
+        for turn in &self.turns {
+            remaining.entry(turn.id).or_insert(0);
+        }
+        for (from, to) in self.dependency_edges() {
+            *remaining.entry(to).or_insert(0) += 1;
+            dependents.entry(from).or_default().push(to);
+        }
+
+        let mut levels = Vec::new();
+        let mut ready: Vec<usize> = remaining
+            .iter()
+            .filter(|(_, &count)| count == 0)
+            .map(|(&id, _)| id)
+            .collect();
+        while !ready.is_empty() {
+            ready.sort_unstable();
+            let mut next = Vec::new();
+            for id in &ready {
+                for dependent in dependents.get(id).into_iter().flatten() {
+                    if let Some(count) = remaining.get_mut(dependent) {
+                        *count -= 1;
+                        if *count == 0 {
+                            next.push(*dependent);
+                        }
+                    }
+                }
+            }
+            levels.push(std::mem::replace(&mut ready, next));
+        }
+        levels
+    }
+
     pub fn get_executable_turns(&self) -> Vec<&Turn> {
         let completed: std::collections::HashSet<_> = self
             .turns
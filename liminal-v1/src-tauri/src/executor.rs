@@ -1,20 +1,65 @@
+use crate::metrics::MetricsCollector;
+use std::any::Any;
 use std::future::Future;
 use std::pin::Pin;
-use std::sync::Arc;
-use tokio::runtime::Handle;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use thiserror::Error;
+use tokio::runtime::{Handle, Runtime};
 use tokio::sync::mpsc;
-use tokio::task::JoinSet;
+use tokio::task::{JoinError, JoinSet};
 
 type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
 
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 256;
+
+static BLOCKING_RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// Drives `future` to completion, reusing the ambient runtime when one is entered (for example on
+/// `spawn_blocking` threads) and a dedicated runtime otherwise. Panics inside a current-thread async task.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    if let Ok(handle) = Handle::try_current() {
+        return tokio::task::block_in_place(|| handle.block_on(future));
+    }
+    BLOCKING_RUNTIME
+        .get_or_init(|| Runtime::new().expect("Failed to create Tokio runtime"))
+        .block_on(future)
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum SpawnError {
+    #[error("maintenance executor saturated: {pending} of {limit} tasks in flight")]
+    Saturated { pending: usize, limit: usize },
+    #[error("maintenance executor has shut down")]
+    Closed,
+}
+
+struct PendingGuard(Arc<AtomicUsize>);
+
+impl Drop for PendingGuard {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
 #[derive(Clone)]
 pub struct MaintenanceExecutor {
     sender: mpsc::UnboundedSender<BoxedFuture>,
     handle: Handle,
+    pending: Arc<AtomicUsize>,
+    max_in_flight: usize,
 }
 
 impl MaintenanceExecutor {
-    pub fn new(_worker_count: usize) -> Self {
+    pub fn new(worker_count: usize) -> Self {
+        Self::build(worker_count, None)
+    }
+
+    pub fn with_metrics(worker_count: usize, metrics: MetricsCollector) -> Self {
+        Self::build(worker_count, Some(metrics))
+    }
+
+    fn build(_worker_count: usize, metrics: Option<MetricsCollector>) -> Self {
         let handle = match Handle::try_current() {
             Ok(h) => h,
             Err(_) => {
@@ -37,31 +82,53 @@ impl MaintenanceExecutor {
                         });
                     }
                     Some(result) = tasks.join_next() => {
-                        if let Err(e) = result {
-                            eprintln!("Task failed: {:?}", e);
-                        }
+                        report_task_result(result, metrics.as_ref());
                     }
                     else => break,
                 }
 
                 while tasks.len() > 100 {
                     if let Some(result) = tasks.join_next().await {
-                        if let Err(e) = result {
-                            eprintln!("Task failed: {:?}", e);
-                        }
+                        report_task_result(result, metrics.as_ref());
                     }
                 }
             }
         });
 
-        Self { sender, handle }
+        Self {
+            sender,
+            handle,
+            pending: Arc::new(AtomicUsize::new(0)),
+            max_in_flight: DEFAULT_MAX_IN_FLIGHT,
+        }
     }
 
-    pub fn spawn<F>(&self, future: F)
+    pub fn with_max_in_flight(mut self, limit: usize) -> Self {
+        self.max_in_flight = limit.max(1);
+        self
+    }
+
+    pub fn pending(&self) -> usize {
+        self.pending.load(Ordering::SeqCst)
+    }
+
+    pub fn spawn<F>(&self, future: F) -> Result<(), SpawnError>
     where
         F: Future<Output = ()> + Send + 'static,
     {
-        let _ = self.sender.send(Box::pin(future));
+        let limit = self.max_in_flight;
+        self.pending
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |pending| {
+                (pending < limit).then_some(pending + 1)
+            })
+            .map_err(|pending| SpawnError::Saturated { pending, limit })?;
+        let guard = PendingGuard(Arc::clone(&self.pending));
+        self.sender
+            .send(Box::pin(async move {
+                let _guard = guard;
+                future.await;
+            }))
+            .map_err(|_| SpawnError::Closed)
     }
 
     pub fn inner(&self) -> Arc<()> {
@@ -72,15 +139,53 @@ impl MaintenanceExecutor {
         Self {
             sender: self.sender.clone(),
             handle: self.handle.clone(),
+            pending: Arc::clone(&self.pending),
+            max_in_flight: self.max_in_flight,
         }
     }
 }
 
+fn report_task_result(result: Result<(), JoinError>, metrics: Option<&MetricsCollector>) {
+    let Err(error) = result else {
+        return;
+    };
+    if !error.is_panic() {
+        eprintln!("Task failed: {:?}", error);
+        return;
+    }
+    if let Some(metrics) = metrics {
+        metrics.record_maintenance_task_failure();
+    }
+    eprintln!(
+        "Maintenance task panicked: {}",
+        panic_message(error.into_panic())
+    );
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .unwrap_or_else(|| "unknown panic".to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::sync::atomic::{AtomicUsize, Ordering};
 
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn block_on_runs_inside_a_runtime() {
+        let from_blocking_thread = tokio::task::spawn_blocking(|| block_on(async { 7 }))
+            .await
+            .unwrap();
+        assert_eq!(from_blocking_thread, 7);
+        assert_eq!(block_on(async { 11 }), 11);
+    }
+
     #[tokio::test]
     async fn test_executor_spawns_tasks() {
         let executor = MaintenanceExecutor::new(4);
@@ -88,12 +193,68 @@ mod tests {
 
         for _ in 0..10 {
             let counter = counter.clone();
-            executor.spawn(async move {
-                counter.fetch_add(1, Ordering::SeqCst);
-            });
+            executor
+                .spawn(async move {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                })
+                .unwrap();
         }
 
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
         assert_eq!(counter.load(Ordering::SeqCst), 10);
     }
+
+    #[tokio::test]
+    async fn panicking_task_is_counted_as_a_maintenance_failure() {
+        let metrics = MetricsCollector::new();
+        let executor = MaintenanceExecutor::with_metrics(2, metrics.clone());
+        let counter = Arc::new(AtomicUsize::new(0));
+
+        executor
+            .spawn(async {
+                panic!("checkpoint path corrupted");
+            })
+            .unwrap();
+        let survivor = counter.clone();
+        executor
+            .spawn(async move {
+                survivor.fetch_add(1, Ordering::SeqCst);
+            })
+            .unwrap();
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        assert_eq!(counter.load(Ordering::SeqCst), 1);
+        assert_eq!(metrics.get_snapshot().system.maintenance_task_failures, 1);
+        assert_eq!(executor.pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn flooded_executor_reports_and_bounds_backlog() {
+        let executor = MaintenanceExecutor::new(2).with_max_in_flight(4);
+        let gate = Arc::new(tokio::sync::Notify::new());
+
+        let mut accepted = 0;
+        let mut rejected = Vec::new();
+        for _ in 0..10 {
+            let gate = gate.clone();
+            match executor.spawn(async move { gate.notified().await }) {
+                Ok(()) => accepted += 1,
+                Err(err) => rejected.push(err),
+            }
+        }
+        assert_eq!(accepted, 4);
+        assert_eq!(executor.pending(), 4);
+        assert_eq!(rejected.len(), 6);
+        assert!(rejected.iter().all(|err| *err
+            == SpawnError::Saturated {
+                pending: 4,
+                limit: 4
+            }));
+
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        gate.notify_waiters();
+        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
+        assert_eq!(executor.pending(), 0);
+        assert!(executor.spawn(async {}).is_ok());
+    }
 }
@@ -2,10 +2,11 @@ use crate::config::{
     ConsensusSuccessConfig, DeadlockFrequencyConfig, EscalationRateConfig, HealthMonitoringConfig,
     HeatHotspotConfig, QueueHealthConfig,
 };
-use crate::metrics::MetricsSnapshot;
-use serde::Serialize;
+use crate::metrics::{MetricsDelta, MetricsSnapshot};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
-use std::time::{Duration, Instant};
+use std::collections::HashMap;
+use std::time::Duration;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum Severity {
@@ -14,15 +15,42 @@ enum Severity {
     Critical,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HealthCategory {
+    Queue,
+    RateLimit,
+    Escalation,
+    Deadlock,
+    Consensus,
+    Heat,
+    Ledger,
+}
+
 #[derive(Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct HealthAlert {
+    pub category: HealthCategory,
     pub severity: String,
     pub message: String,
     pub context: serde_json::Value,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthSummary {
+    pub overall: String,
+    pub queue: String,
+    pub rate_limit: String,
+    pub escalation: String,
+    pub deadlock: String,
+    pub consensus: String,
+    pub heat: String,
+    pub ledger: String,
+    pub alerts: Vec<HealthAlert>,
+}
+
+#[derive(Debug, Clone)]
 pub struct HealthMonitor {
     queue_warning: Option<usize>,
     queue_critical: Option<usize>,
@@ -35,15 +63,15 @@ pub struct HealthMonitor {
     consensus_critical_ratio: Option<f64>,
     heat_warning: Option<f64>,
     heat_critical: Option<f64>,
-    last_snapshot_at: Option<Instant>,
-    last_rate_limited: u64,
-    last_escalations: u64,
+    last_snapshot: Option<MetricsSnapshot>,
     queue_severity: Severity,
     rate_limit_severity: Severity,
     escalation_severity: Severity,
     deadlock_severity: Severity,
     consensus_severity: Severity,
     heat_severity: Severity,
+    ledger_severity: Severity,
+    acknowledged: HashMap<HealthCategory, Severity>,
 }
 
 impl HealthMonitor {
@@ -60,15 +88,15 @@ impl HealthMonitor {
             consensus_critical_ratio: None,
             heat_warning: None,
             heat_critical: None,
-            last_snapshot_at: None,
-            last_rate_limited: 0,
-            last_escalations: 0,
+            last_snapshot: None,
             queue_severity: Severity::Normal,
             rate_limit_severity: Severity::Normal,
             escalation_severity: Severity::Normal,
             deadlock_severity: Severity::Normal,
             consensus_severity: Severity::Normal,
             heat_severity: Severity::Normal,
+            ledger_severity: Severity::Normal,
+            acknowledged: HashMap::new(),
         };
 
         if let Some(cfg) = config {
@@ -82,6 +110,21 @@ impl HealthMonitor {
         monitor
     }
 
+    pub fn update_config(&mut self, config: Option<&HealthMonitoringConfig>) {
+        let fresh = Self::new(config);
+        self.queue_warning = fresh.queue_warning;
+        self.queue_critical = fresh.queue_critical;
+        self.queue_stale = fresh.queue_stale;
+        self.escalation_warning_per_min = fresh.escalation_warning_per_min;
+        self.escalation_critical_per_min = fresh.escalation_critical_per_min;
+        self.deadlock_warning_per_hour = fresh.deadlock_warning_per_hour;
+        self.deadlock_critical_per_hour = fresh.deadlock_critical_per_hour;
+        self.consensus_warning_ratio = fresh.consensus_warning_ratio;
+        self.consensus_critical_ratio = fresh.consensus_critical_ratio;
+        self.heat_warning = fresh.heat_warning;
+        self.heat_critical = fresh.heat_critical;
+    }
+
     fn apply_queue_config(&mut self, config: Option<&QueueHealthConfig>) {
         if let Some(queue) = config {
             self.queue_warning = queue.warning_depth;
@@ -133,26 +176,70 @@ impl HealthMonitor {
         }
     }
 
+    pub fn summarize(&self, snapshot: &MetricsSnapshot) -> HealthSummary {
+        let mut probe = self.clone();
+        probe.queue_severity = Severity::Normal;
+        probe.rate_limit_severity = Severity::Normal;
+        probe.escalation_severity = Severity::Normal;
+        probe.deadlock_severity = Severity::Normal;
+        probe.consensus_severity = Severity::Normal;
+        probe.heat_severity = Severity::Normal;
+        probe.ledger_severity = Severity::Normal;
+        let alerts = probe.evaluate(snapshot);
+        let overall = [
+            probe.queue_severity,
+            probe.rate_limit_severity,
+            probe.escalation_severity,
+            probe.deadlock_severity,
+            probe.consensus_severity,
+            probe.heat_severity,
+            probe.ledger_severity,
+        ]
+        .into_iter()
+        .max()
+        .unwrap_or(Severity::Normal);
+        HealthSummary {
+            overall: severity_to_str(overall).to_string(),
+            queue: severity_to_str(probe.queue_severity).to_string(),
+            rate_limit: severity_to_str(probe.rate_limit_severity).to_string(),
+            escalation: severity_to_str(probe.escalation_severity).to_string(),
+            deadlock: severity_to_str(probe.deadlock_severity).to_string(),
+            consensus: severity_to_str(probe.consensus_severity).to_string(),
+            heat: severity_to_str(probe.heat_severity).to_string(),
+            ledger: severity_to_str(probe.ledger_severity).to_string(),
+            alerts,
+        }
+    }
+
+    pub fn acknowledge(&mut self, category: HealthCategory) -> bool {
+        let severity = self.severity_of(category);
+        if severity == Severity::Normal {
+            return false;
+        }
+        self.acknowledged.insert(category, severity);
+        true
+    }
+
     pub fn evaluate(&mut self, snapshot: &MetricsSnapshot) -> Vec<HealthAlert> {
         let mut alerts = Vec::new();
-        let now = Instant::now();
-        let elapsed = self
-            .last_snapshot_at
-            .map(|previous| now.saturating_duration_since(previous))
+        let delta = self
+            .last_snapshot
+            .as_ref()
+            .map(|previous| snapshot.delta(previous))
             .unwrap_or_default();
 
         if let Some(alert) = self.evaluate_queue(snapshot) {
             alerts.push(alert);
         }
 
-        if elapsed > Duration::from_secs(0) {
-            if let Some(alert) = self.evaluate_rate_limit(snapshot, elapsed) {
+        if delta.elapsed > Duration::from_secs(0) {
+            if let Some(alert) = self.evaluate_rate_limit(&delta) {
                 alerts.push(alert);
             }
-            if let Some(alert) = self.evaluate_escalations(snapshot, elapsed) {
+            if let Some(alert) = self.evaluate_escalations(&delta) {
                 alerts.push(alert);
             }
-            if let Some(alert) = self.evaluate_deadlocks(snapshot, elapsed) {
+            if let Some(alert) = self.evaluate_deadlocks(&delta) {
                 alerts.push(alert);
             }
         }
@@ -165,15 +252,58 @@ impl HealthMonitor {
             alerts.push(alert);
         }
 
-        self.last_snapshot_at = Some(now);
-        self.last_rate_limited = snapshot.performance.rate_limited_messages;
-        self.last_escalations = snapshot.leases.escalations;
+        if let Some(alert) = self.evaluate_ledger(snapshot) {
+            alerts.push(alert);
+        }
+
+        self.last_snapshot = Some(snapshot.clone());
 
+        self.clear_resolved_acknowledgements();
+        alerts.retain(|alert| self.admit_acknowledged(alert.category));
         alerts
     }
 
+    fn severity_of(&self, category: HealthCategory) -> Severity {
+        match category {
+            HealthCategory::Queue => self.queue_severity,
+            HealthCategory::RateLimit => self.rate_limit_severity,
+            HealthCategory::Escalation => self.escalation_severity,
+            HealthCategory::Deadlock => self.deadlock_severity,
+            HealthCategory::Consensus => self.consensus_severity,
+            HealthCategory::Heat => self.heat_severity,
+            HealthCategory::Ledger => self.ledger_severity,
+        }
+    }
+
+    fn clear_resolved_acknowledgements(&mut self) {
+        let resolved: Vec<HealthCategory> = self
+            .acknowledged
+            .keys()
+            .copied()
+            .filter(|category| self.severity_of(*category) == Severity::Normal)
+            .collect();
+        for category in resolved {
+            self.acknowledged.remove(&category);
+        }
+    }
+
+    fn admit_acknowledged(&mut self, category: HealthCategory) -> bool {
+        let severity = self.severity_of(category);
+        match self.acknowledged.get(&category) {
+            Some(acknowledged) if severity <= *acknowledged => false,
+            Some(_) => {
+                self.acknowledged.remove(&category);
+                true
+            }
+            None => true,
+        }
+    }
+
     fn evaluate_queue(&mut self, snapshot: &MetricsSnapshot) -> Option<HealthAlert> {
-        if self.queue_warning.is_none() && self.queue_critical.is_none() {
+        if self.queue_warning.is_none()
+            && self.queue_critical.is_none()
+            && self.queue_stale.is_none()
+        {
             return None;
         }
         let mut worst_depth = 0usize;
@@ -197,21 +327,40 @@ impl HealthMonitor {
                 }
             }
         }
+        let stale_idle_ms = self
+            .queue_stale
+            .zip(snapshot.router.dispatcher_idle_ms)
+            .filter(|(threshold, idle_ms)| {
+                worst_depth > 0 && *idle_ms >= threshold.as_millis() as u64
+            })
+            .map(|(_, idle_ms)| idle_ms);
+        let message = match stale_idle_ms {
+            Some(idle_ms) if severity == Severity::Normal => {
+                severity = Severity::Warning;
+                format!(
+                    "Dispatcher idle for {}ms with {} messages queued at priority {}",
+                    idle_ms, worst_depth, worst_priority
+                )
+            }
+            _ => format!(
+                "Queue depth {} for priority {} exceeded threshold",
+                worst_depth, worst_priority
+            ),
+        };
         if severity > self.queue_severity {
             self.queue_severity = severity;
             if severity != Severity::Normal {
                 return Some(HealthAlert {
+                    category: HealthCategory::Queue,
                     severity: severity_to_str(severity).to_string(),
-                    message: format!(
-                        "Queue depth {} for priority {} exceeded threshold",
-                        worst_depth, worst_priority
-                    ),
+                    message,
                     context: json!({
                         "priority": worst_priority,
                         "depth": worst_depth,
                         "warning": self.queue_warning,
                         "critical": self.queue_critical,
                         "queueDepths": snapshot.router.queue_depths,
+                        "dispatcherIdleMs": snapshot.router.dispatcher_idle_ms,
                     }),
                 });
             }
@@ -221,25 +370,18 @@ impl HealthMonitor {
         None
     }
 
-    fn evaluate_rate_limit(
-        &mut self,
-        snapshot: &MetricsSnapshot,
-        elapsed: Duration,
-    ) -> Option<HealthAlert> {
+    fn evaluate_rate_limit(&mut self, delta: &MetricsDelta) -> Option<HealthAlert> {
         if self.escalation_warning_per_min.is_none() && self.escalation_critical_per_min.is_none() {
             return None;
         }
-        let delta_hits = snapshot
-            .performance
-            .rate_limited_messages
-            .saturating_sub(self.last_rate_limited);
+        let delta_hits = delta.rate_limited;
         if delta_hits == 0 {
             if self.rate_limit_severity != Severity::Normal {
                 self.rate_limit_severity = Severity::Normal;
             }
             return None;
         }
-        let per_minute = rate_per_minute(delta_hits, elapsed);
+        let per_minute = delta.rate_limited_per_minute();
         let mut severity = Severity::Normal;
         if let Some(critical) = self.escalation_critical_per_min {
             if per_minute >= critical {
@@ -256,6 +398,7 @@ impl HealthMonitor {
         if severity > self.rate_limit_severity {
             self.rate_limit_severity = severity;
             return Some(HealthAlert {
+                category: HealthCategory::RateLimit,
                 severity: severity_to_str(severity).to_string(),
                 message: format!(
                     "Rate limiting at {:.2} hits/min exceeds threshold",
@@ -274,25 +417,18 @@ impl HealthMonitor {
         None
     }
 
-    fn evaluate_escalations(
-        &mut self,
-        snapshot: &MetricsSnapshot,
-        elapsed: Duration,
-    ) -> Option<HealthAlert> {
+    fn evaluate_escalations(&mut self, delta: &MetricsDelta) -> Option<HealthAlert> {
         if self.escalation_warning_per_min.is_none() && self.escalation_critical_per_min.is_none() {
             return None;
         }
-        let delta = snapshot
-            .leases
-            .escalations
-            .saturating_sub(self.last_escalations);
-        if delta == 0 {
+        let escalations = delta.escalations;
+        if escalations == 0 {
             if self.escalation_severity != Severity::Normal {
                 self.escalation_severity = Severity::Normal;
             }
             return None;
         }
-        let per_minute = rate_per_minute(delta, elapsed);
+        let per_minute = delta.escalations_per_minute();
         let mut severity = Severity::Normal;
         if let Some(critical) = self.escalation_critical_per_min {
             if per_minute >= critical {
@@ -309,6 +445,7 @@ impl HealthMonitor {
         if severity > self.escalation_severity {
             self.escalation_severity = severity;
             return Some(HealthAlert {
+                category: HealthCategory::Escalation,
                 severity: severity_to_str(severity).to_string(),
                 message: format!(
                     "Lease escalations at {:.2} per min exceed threshold",
@@ -316,7 +453,7 @@ impl HealthMonitor {
                 ),
                 context: json!({
                     "ratePerMinute": per_minute,
-                    "deltaEscalations": delta,
+                    "deltaEscalations": escalations,
                     "warning": self.escalation_warning_per_min,
                     "critical": self.escalation_critical_per_min,
                 }),
@@ -327,25 +464,18 @@ impl HealthMonitor {
         None
     }
 
-    fn evaluate_deadlocks(
-        &mut self,
-        snapshot: &MetricsSnapshot,
-        elapsed: Duration,
-    ) -> Option<HealthAlert> {
+    fn evaluate_deadlocks(&mut self, delta: &MetricsDelta) -> Option<HealthAlert> {
         if self.deadlock_warning_per_hour.is_none() && self.deadlock_critical_per_hour.is_none() {
             return None;
         }
-        let delta = snapshot
-            .leases
-            .escalations
-            .saturating_sub(self.last_escalations);
-        if delta == 0 {
+        let escalations = delta.escalations;
+        if escalations == 0 {
             if self.deadlock_severity != Severity::Normal {
                 self.deadlock_severity = Severity::Normal;
             }
             return None;
         }
-        let per_hour = rate_per_hour(delta, elapsed);
+        let per_hour = delta.per_hour(escalations);
         let mut severity = Severity::Normal;
         if let Some(critical) = self.deadlock_critical_per_hour {
             if per_hour >= critical {
@@ -362,11 +492,12 @@ impl HealthMonitor {
         if severity > self.deadlock_severity {
             self.deadlock_severity = severity;
             return Some(HealthAlert {
+                category: HealthCategory::Deadlock,
                 severity: severity_to_str(severity).to_string(),
                 message: format!("Deadlock frequency {:.2} per hour is high", per_hour),
                 context: json!({
                     "ratePerHour": per_hour,
-                    "deltaEscalations": delta,
+                    "deltaEscalations": escalations,
                     "warning": self.deadlock_warning_per_hour,
                     "critical": self.deadlock_critical_per_hour,
                 }),
@@ -381,7 +512,7 @@ impl HealthMonitor {
         if self.consensus_warning_ratio.is_none() && self.consensus_critical_ratio.is_none() {
             return None;
         }
-        let ratio = snapshot.consensus.success_ratio;
+        let ratio = snapshot.consensus.window_success_ratio;
         let mut severity = Severity::Normal;
         if let Some(critical) = self.consensus_critical_ratio {
             if ratio <= critical {
@@ -399,12 +530,15 @@ impl HealthMonitor {
             self.consensus_severity = severity;
             if severity != Severity::Normal {
                 return Some(HealthAlert {
+                    category: HealthCategory::Consensus,
                     severity: severity_to_str(severity).to_string(),
                     message: format!("Consensus success ratio {:.2} below threshold", ratio),
                     context: json!({
                         "success": snapshot.consensus.success,
                         "failure": snapshot.consensus.failure,
                         "ratio": ratio,
+                        "windowSize": snapshot.consensus.window_size,
+                        "lifetimeRatio": snapshot.consensus.success_ratio,
                         "threshold": snapshot.consensus.threshold,
                         "lastResource": snapshot.consensus.last_resource,
                         "lastReason": snapshot.consensus.last_reason,
@@ -441,6 +575,7 @@ impl HealthMonitor {
             self.heat_severity = severity;
             if severity != Severity::Normal {
                 return Some(HealthAlert {
+                    category: HealthCategory::Heat,
                     severity: severity_to_str(severity).to_string(),
                     message: format!(
                         "Heat score {:.2} exceeded threshold for resource {}",
@@ -465,6 +600,32 @@ impl HealthMonitor {
         }
         None
     }
+
+    fn evaluate_ledger(&mut self, snapshot: &MetricsSnapshot) -> Option<HealthAlert> {
+        let severity = if snapshot.ledger.degraded {
+            Severity::Critical
+        } else {
+            Severity::Normal
+        };
+        if severity > self.ledger_severity {
+            self.ledger_severity = severity;
+            return Some(HealthAlert {
+                category: HealthCategory::Ledger,
+                severity: severity_to_str(severity).to_string(),
+                message: format!(
+                    "Ledger writes failed {} times in a row; durability is compromised",
+                    snapshot.ledger.consecutive_failures
+                ),
+                context: json!({
+                    "consecutiveFailures": snapshot.ledger.consecutive_failures,
+                    "appendFailures": snapshot.ledger.append_failures,
+                }),
+            });
+        } else if severity == Severity::Normal {
+            self.ledger_severity = Severity::Normal;
+        }
+        None
+    }
 }
 
 fn severity_to_str(severity: Severity) -> &'static str {
@@ -475,18 +636,6 @@ fn severity_to_str(severity: Severity) -> &'static str {
     }
 }
 
-fn rate_per_minute(delta: u64, elapsed: Duration) -> f64 {
-    if elapsed.is_zero() {
-        return delta as f64;
-    }
-    let per_second = delta as f64 / elapsed.as_secs_f64().max(1e-6);
-    per_second * 60.0
-}
-
-fn rate_per_hour(delta: u64, elapsed: Duration) -> f64 {
-    rate_per_minute(delta, elapsed) * 60.0
-}
-
 fn parse_frequency_per_minute(value: &str) -> Option<f64> {
     parse_frequency(value).map(|(amount, unit)| match unit {
         FrequencyUnit::PerHour => amount / 60.0,
@@ -1,20 +1,28 @@
+use crate::clock::{system_clock, SharedClock};
 use crate::config::LedgerConfig;
 use crate::metrics::{
-    ConsensusSnapshot, HeatSnapshot, LeaseSnapshotSummary, MetricsSnapshot, RouterSnapshot,
+    ConsensusSnapshot, HeatSnapshot, LeaseSnapshotSummary, MetricsCollector, MetricsSnapshot,
+    RouterSnapshot,
 };
 use crate::router::Priority;
 use blake3::Hasher;
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, VecDeque};
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufRead, BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
-use tokio::sync::broadcast;
+use tokio::sync::broadcast::{self, error::RecvError};
 
 const DEFAULT_BROADCAST_CAPACITY: usize = 512;
+const ENCRYPTION_NONCE_LEN: usize = 12;
+const ROTATION_TICK_MAX: Duration = Duration::from_secs(1);
+pub const CLOCK_REGRESSION_TOLERANCE: Duration = Duration::from_millis(100);
 
 #[derive(Debug, Error)]
 pub enum LedgerError {
@@ -24,46 +32,62 @@ pub enum LedgerError {
     Serde(#[from] serde_json::Error),
     #[error("ledger task join error: {0}")]
     Join(#[from] tokio::task::JoinError),
+    #[error("ledger hash chain verification failed for epoch {0}")]
+    ChainBroken(String),
+    #[error("ledger encryption error: {0}")]
+    Encrypt(String),
+    #[error("ledger decryption error: {0}")]
+    Decrypt(String),
+    #[error("ledger writer for epoch {0} is closed")]
+    Closed(String),
+    #[error("ledger epoch {0} is active and cannot be compacted")]
+    ActiveEpoch(String),
+    #[error("ledger epoch {0} has no checkpoint to compact from")]
+    NoCheckpoint(String),
 }
 
 pub type LedgerResult<T> = Result<T, LedgerError>;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LogicalClock {
     pub wall_millis: u64,
     pub counter: u32,
 }
 
-impl LogicalClock {
-    fn now(clock: &mut HybridLogicalClock) -> Self {
-        clock.tick(SystemTime::now())
-    }
-}
-
 #[derive(Debug, Default)]
 struct HybridLogicalClock {
     last_wall: u64,
     counter: u32,
+    last_observed: u64,
 }
 
 impl HybridLogicalClock {
-    fn tick(&mut self, now: SystemTime) -> LogicalClock {
+    fn tick(&mut self, now: SystemTime) -> (LogicalClock, Option<Duration>) {
         let wall_millis = now
             .duration_since(UNIX_EPOCH)
             .unwrap_or_default()
             .as_millis()
             .min(u128::from(u64::MAX)) as u64;
+        let regression = Duration::from_millis(self.last_observed.saturating_sub(wall_millis));
+        self.last_observed = wall_millis;
         if wall_millis > self.last_wall {
             self.last_wall = wall_millis;
             self.counter = 0;
+        } else if self.counter == u32::MAX {
+            self.last_wall = self.last_wall.saturating_add(1);
+            self.counter = 0;
         } else {
-            self.counter = self.counter.saturating_add(1);
+            self.counter += 1;
         }
-        LogicalClock {
+        let clock = LogicalClock {
             wall_millis: self.last_wall,
             counter: self.counter,
-        }
+        };
+        (
+            clock,
+            (regression > CLOCK_REGRESSION_TOLERANCE).then_some(regression),
+        )
     }
 }
 
@@ -76,6 +100,65 @@ pub struct EventMetadata {
     pub priority: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EventFilter {
+    #[serde(default)]
+    pub agent_id: Option<String>,
+    #[serde(default)]
+    pub territory_id: Option<String>,
+    #[serde(default)]
+    pub priority: Option<String>,
+    #[serde(default)]
+    pub kind: Option<LedgerEventKind>,
+    #[serde(default)]
+    pub min_sequence: Option<u64>,
+    #[serde(default)]
+    pub max_sequence: Option<u64>,
+    #[serde(default)]
+    pub since_millis: Option<u64>,
+    #[serde(default)]
+    pub until_millis: Option<u64>,
+}
+
+impl EventFilter {
+    pub fn matches(&self, envelope: &EventEnvelope) -> bool {
+        let metadata = &envelope.metadata;
+        if let Some(agent_id) = &self.agent_id {
+            if metadata.agent_id.as_ref() != Some(agent_id) {
+                return false;
+            }
+        }
+        if let Some(territory_id) = &self.territory_id {
+            if metadata.territory_id.as_ref() != Some(territory_id) {
+                return false;
+            }
+        }
+        if let Some(priority) = &self.priority {
+            if metadata.priority.as_ref() != Some(priority) {
+                return false;
+            }
+        }
+        if let Some(kind) = self.kind {
+            if envelope.event.kind() != kind {
+                return false;
+            }
+        }
+        if self.min_sequence.is_some_and(|min| envelope.sequence < min)
+            || self.max_sequence.is_some_and(|max| envelope.sequence > max)
+        {
+            return false;
+        }
+        let wall_millis = envelope.logical_clock.wall_millis;
+        if self.since_millis.is_some_and(|since| wall_millis < since)
+            || self.until_millis.is_some_and(|until| wall_millis > until)
+        {
+            return false;
+        }
+        true
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EventEnvelope {
@@ -96,6 +179,14 @@ impl EventEnvelope {
         }
         value
     }
+
+    fn chain_hash(&self, prev_hash: &str) -> LedgerResult<String> {
+        let serialized_without_hash = serde_json::to_vec(&self.without_hash())?;
+        let mut hasher = Hasher::new();
+        hasher.update(prev_hash.as_bytes());
+        hasher.update(&serialized_without_hash);
+        Ok(hasher.finalize().to_hex().to_string())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,9 +198,48 @@ pub enum LedgerEvent {
     Pty(PtyEvent),
     Health(HealthEvent),
     Checkpoint(StateCheckpoint),
+    Director(DirectorEvent),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LedgerEventKind {
+    Router,
+    Lease,
+    Consensus,
+    Pty,
+    Health,
+    Checkpoint,
+    Director,
+}
+
+impl LedgerEventKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LedgerEventKind::Router => "router",
+            LedgerEventKind::Lease => "lease",
+            LedgerEventKind::Consensus => "consensus",
+            LedgerEventKind::Pty => "pty",
+            LedgerEventKind::Health => "health",
+            LedgerEventKind::Checkpoint => "checkpoint",
+            LedgerEventKind::Director => "director",
+        }
+    }
 }
 
 impl LedgerEvent {
+    pub fn kind(&self) -> LedgerEventKind {
+        match self {
+            LedgerEvent::Router(_) => LedgerEventKind::Router,
+            LedgerEvent::Lease(_) => LedgerEventKind::Lease,
+            LedgerEvent::Consensus(_) => LedgerEventKind::Consensus,
+            LedgerEvent::Pty(_) => LedgerEventKind::Pty,
+            LedgerEvent::Health(_) => LedgerEventKind::Health,
+            LedgerEvent::Checkpoint(_) => LedgerEventKind::Checkpoint,
+            LedgerEvent::Director(_) => LedgerEventKind::Director,
+        }
+    }
+
     fn metadata(&self) -> EventMetadata {
         match self {
             LedgerEvent::Router(event) => event.metadata(),
@@ -118,6 +248,7 @@ impl LedgerEvent {
             LedgerEvent::Pty(event) => event.metadata(),
             LedgerEvent::Health(event) => event.metadata(),
             LedgerEvent::Checkpoint(event) => event.metadata(),
+            LedgerEvent::Director(event) => event.metadata(),
         }
     }
 }
@@ -135,7 +266,10 @@ impl RouterEvent {
             RouterEvent::Dispatched(record) => EventMetadata {
                 agent_id: Some(record.sender.clone()),
                 priority: Some(record.effective_priority.clone()),
-                trace_id: record.message_id.clone(),
+                trace_id: record
+                    .trace_id
+                    .clone()
+                    .or_else(|| record.message_id.clone()),
                 territory_id: None,
             },
             RouterEvent::RateLimited(record) => EventMetadata {
@@ -152,6 +286,8 @@ impl RouterEvent {
 #[serde(rename_all = "camelCase")]
 pub struct RouterDispatchRecord {
     pub message_id: Option<String>,
+    #[serde(default)]
+    pub trace_id: Option<String>,
     pub content_digest: Option<String>,
     pub sender: String,
     pub recipient: String,
@@ -196,12 +332,22 @@ pub struct QuorumVector {
 pub enum LeaseEvent {
     Granted(LeaseRecord),
     Released(LeaseRecord),
+    ForceReleased {
+        lease: LeaseRecord,
+        reason: String,
+    },
     Deferred(LeaseQueueRecord),
+    Withdrawn(LeaseQueueRecord),
     Escalated(LeaseEscalationRecord),
     Overridden {
         previous: LeaseRecord,
         lease: LeaseRecord,
     },
+    Transferred {
+        from: String,
+        to: String,
+        lease: LeaseRecord,
+    },
 }
 
 impl LeaseEvent {
@@ -209,13 +355,15 @@ impl LeaseEvent {
         match self {
             LeaseEvent::Granted(record)
             | LeaseEvent::Released(record)
-            | LeaseEvent::Overridden { lease: record, .. } => EventMetadata {
+            | LeaseEvent::ForceReleased { lease: record, .. }
+            | LeaseEvent::Overridden { lease: record, .. }
+            | LeaseEvent::Transferred { lease: record, .. } => EventMetadata {
                 agent_id: Some(record.holder_id.clone()),
                 territory_id: Some(record.resource_id.clone()),
                 priority: Some(record.priority.clone()),
-                trace_id: Some(format!("lease-{}", record.lease_id)),
+                trace_id: Some(lease_trace_id(record.lease_id)),
             },
-            LeaseEvent::Deferred(record) => EventMetadata {
+            LeaseEvent::Deferred(record) | LeaseEvent::Withdrawn(record) => EventMetadata {
                 agent_id: Some(record.agent_id.clone()),
                 territory_id: Some(record.resource_id.clone()),
                 priority: None,
@@ -231,6 +379,14 @@ impl LeaseEvent {
     }
 }
 
+pub fn lease_trace_id(lease_id: u64) -> String {
+    format!("lease-{lease_id}")
+}
+
+pub fn turn_trace_id(epoch_id: &str, turn_id: usize) -> String {
+    format!("{}:turn-{}", epoch_id, turn_id)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub enum ConsensusEvent {
@@ -314,6 +470,81 @@ impl HealthEvent {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DirectorEvent {
+    RunbookStarted {
+        runbook_id: String,
+        total_turns: usize,
+    },
+    TurnStarted {
+        runbook_id: String,
+        turn_id: usize,
+        specialist: String,
+    },
+    TurnCompleted {
+        runbook_id: String,
+        turn_id: usize,
+        duration_ms: u64,
+    },
+    TurnFailed {
+        runbook_id: String,
+        turn_id: usize,
+        error_message: String,
+    },
+    RunbookCompleted {
+        runbook_id: String,
+        completed_turns: usize,
+        failed_turns: usize,
+    },
+}
+
+impl DirectorEvent {
+    pub fn runbook_id(&self) -> &str {
+        match self {
+            DirectorEvent::RunbookStarted { runbook_id, .. }
+            | DirectorEvent::TurnStarted { runbook_id, .. }
+            | DirectorEvent::TurnCompleted { runbook_id, .. }
+            | DirectorEvent::TurnFailed { runbook_id, .. }
+            | DirectorEvent::RunbookCompleted { runbook_id, .. } => runbook_id,
+        }
+    }
+
+    pub fn turn_id(&self) -> Option<usize> {
+        match self {
+            DirectorEvent::TurnStarted { turn_id, .. }
+            | DirectorEvent::TurnCompleted { turn_id, .. }
+            | DirectorEvent::TurnFailed { turn_id, .. } => Some(*turn_id),
+            DirectorEvent::RunbookStarted { .. } | DirectorEvent::RunbookCompleted { .. } => None,
+        }
+    }
+
+    fn metadata(&self) -> EventMetadata {
+        let trace_id = match self.turn_id() {
+            Some(turn_id) => turn_trace_id(self.runbook_id(), turn_id),
+            None => self.runbook_id().to_string(),
+        };
+        let agent_id = match self {
+            DirectorEvent::TurnStarted { specialist, .. } => Some(specialist.clone()),
+            _ => Some("director".to_string()),
+        };
+        EventMetadata {
+            trace_id: Some(trace_id),
+            agent_id,
+            territory_id: None,
+            priority: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectorTimelineEntry {
+    pub sequence: u64,
+    pub wall_millis: u64,
+    pub event: DirectorEvent,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LeaseRecord {
@@ -321,6 +552,8 @@ pub struct LeaseRecord {
     pub resource_id: String,
     pub holder_id: String,
     pub priority: String,
+    #[serde(default)]
+    pub held_for_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -331,6 +564,8 @@ pub struct LeaseQueueRecord {
     pub resource_id: String,
     pub queue_position: usize,
     pub grace_deadline_ms: Option<u64>,
+    #[serde(default)]
+    pub priority: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -349,6 +584,72 @@ pub struct StateCheckpoint {
     pub router: RouterReplayState,
     pub leases: LeaseReplayState,
     pub metrics: MetricsSnapshot,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<ReplayBaseline>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayBaseline {
+    pub dispatched: u64,
+    pub rate_limited: u64,
+    pub heat_bumps: Vec<HeatBumpRecord>,
+    pub overrides: Vec<OverrideAuthorization>,
+    pub pending_quorums: BTreeMap<String, QuorumVector>,
+    pub director_timeline: Vec<DirectorTimelineEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatBumpRecord {
+    pub resource_id: String,
+    pub priority: Option<String>,
+    pub wall_millis: u64,
+}
+
+impl HeatBumpRecord {
+    fn from_envelope(envelope: &EventEnvelope) -> Option<Self> {
+        let (resource_id, priority) = match &envelope.event {
+            LedgerEvent::Lease(LeaseEvent::Overridden { lease, .. }) => {
+                (&lease.resource_id, Some(lease.priority.clone()))
+            }
+            LedgerEvent::Lease(LeaseEvent::Deferred(record)) => {
+                (&record.resource_id, record.priority.clone())
+            }
+            _ => return None,
+        };
+        Some(Self {
+            resource_id: resource_id.clone(),
+            priority,
+            wall_millis: envelope.logical_clock.wall_millis,
+        })
+    }
+}
+
+impl ReplayBaseline {
+    fn capture(prefix: &[EventEnvelope]) -> Self {
+        let mut outcome = ReplayOutcome::default();
+        let mut heat_bumps = Vec::new();
+        for envelope in prefix {
+            if let LedgerEvent::Checkpoint(StateCheckpoint {
+                baseline: Some(baseline),
+                ..
+            }) = &envelope.event
+            {
+                heat_bumps.extend(baseline.heat_bumps.iter().cloned());
+            }
+            heat_bumps.extend(HeatBumpRecord::from_envelope(envelope));
+            outcome.apply_envelope(envelope);
+        }
+        Self {
+            dispatched: outcome.reconciliation.dispatched,
+            rate_limited: outcome.reconciliation.rate_limited,
+            heat_bumps,
+            overrides: outcome.overrides,
+            pending_quorums: outcome.pending_quorums.into_iter().collect(),
+            director_timeline: outcome.director_timeline,
+        }
+    }
 }
 
 impl StateCheckpoint {
@@ -368,6 +669,10 @@ pub struct RouterReplayState {
     pub total_dispatched: u64,
     pub last_priority: Option<String>,
     pub queue_depths: Vec<usize>,
+    #[serde(default)]
+    pub director_override_dispatched: u64,
+    #[serde(default)]
+    pub total_rate_limited: u64,
 }
 
 impl RouterReplayState {
@@ -375,6 +680,13 @@ impl RouterReplayState {
         self.total_dispatched = self.total_dispatched.saturating_add(1);
         self.last_priority = Some(record.effective_priority.clone());
         self.queue_depths = record.queue_depths.clone();
+        if record.effective_priority == Priority::DirectorOverride.as_str() {
+            self.director_override_dispatched = self.director_override_dispatched.saturating_add(1);
+        }
+    }
+
+    pub fn apply_rate_limited(&mut self) {
+        self.total_rate_limited = self.total_rate_limited.saturating_add(1);
     }
 
     pub fn to_snapshot(&self) -> RouterSnapshot {
@@ -387,7 +699,12 @@ impl RouterReplayState {
             queue_depths: depth_map,
             last_dispatched_priority: self.last_priority.clone(),
             last_dispatched_at: None,
-            rate_limited_messages: 0,
+            dispatcher_idle_ms: None,
+            rate_limited_messages: self.total_rate_limited,
+            deduplicated_messages: 0,
+            director_override_messages: self.director_override_dispatched,
+            wait_time: Default::default(),
+            peak_queue_depths: Default::default(),
         }
     }
 }
@@ -399,6 +716,12 @@ pub struct LeaseReplayState {
     pub deferrals: u64,
     pub overrides: u64,
     pub escalations: u64,
+    #[serde(default)]
+    pub transfers: u64,
+    #[serde(default)]
+    pub director_overrides: u64,
+    #[serde(default)]
+    pub operator_overrides: u64,
 }
 
 impl LeaseReplayState {
@@ -411,21 +734,36 @@ impl LeaseReplayState {
             LeaseEvent::Released(record) => {
                 self.active.remove(&record.resource_id);
             }
+            LeaseEvent::ForceReleased { lease, .. } => {
+                self.operator_overrides = self.operator_overrides.saturating_add(1);
+                self.active.remove(&lease.resource_id);
+            }
             LeaseEvent::Deferred(_) => {
                 self.deferrals = self.deferrals.saturating_add(1);
             }
-            LeaseEvent::Escalated(_) => {
+            LeaseEvent::Withdrawn(_) => {}
+            LeaseEvent::Escalated(record) => {
                 self.escalations = self.escalations.saturating_add(1);
+                if record.reason == "directorOverride" {
+                    self.director_overrides = self.director_overrides.saturating_add(1);
+                }
             }
             LeaseEvent::Overridden { lease, .. } => {
                 self.overrides = self.overrides.saturating_add(1);
                 self.active.insert(lease.resource_id.clone(), lease.clone());
             }
+            LeaseEvent::Transferred { lease, .. } => {
+                self.transfers = self.transfers.saturating_add(1);
+                self.active.insert(lease.resource_id.clone(), lease.clone());
+            }
         }
     }
 
     pub fn to_summary(&self) -> LeaseSnapshotSummary {
         let pending = BTreeMap::new();
+        let mut outstanding: Vec<u64> =
+            self.active.values().map(|record| record.lease_id).collect();
+        outstanding.sort_unstable();
         LeaseSnapshotSummary {
             active_leases: self.active.len(),
             total_pending: pending.values().copied().sum(),
@@ -433,7 +771,12 @@ impl LeaseReplayState {
             deferrals: self.deferrals,
             overrides: self.overrides,
             escalations: self.escalations,
-            outstanding_lease_ids: self.active.values().map(|record| record.lease_id).collect(),
+            transfers: self.transfers,
+            rejections: BTreeMap::new(),
+            director_overrides: self.director_overrides,
+            operator_overrides: self.operator_overrides,
+            outstanding_lease_ids: outstanding,
+            hold_duration: Default::default(),
         }
     }
 }
@@ -447,16 +790,410 @@ pub struct ReplayOutcome {
     pub checkpoints: Vec<StateCheckpoint>,
     pub last_sequence: Option<u64>,
     pub tail_hash: Option<String>,
+    pub overrides: Vec<OverrideAuthorization>,
+    #[serde(default)]
+    pub reconciliation: ReplayReconciliation,
+    #[serde(default)]
+    pub heat: HeatSnapshot,
+    #[serde(default)]
+    pub director_timeline: Vec<DirectorTimelineEntry>,
+    #[serde(skip)]
+    pending_quorums: HashMap<String, QuorumVector>,
+    #[serde(skip)]
+    heat_replay: HeatReplay,
+    #[serde(skip)]
+    checkpoint_retention: CheckpointRetention,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CheckpointRetention {
+    All,
+    #[default]
+    LatestOnly,
+    LastN(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReplayOptions {
+    pub keep_checkpoints: CheckpointRetention,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatCellRecord {
+    pub value: f64,
+    pub updated_millis: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct HeatReplay {
+    parameters: HeatParameters,
+    cells: BTreeMap<String, HeatCellRecord>,
+    latest_millis: u64,
+}
+
+impl HeatReplay {
+    fn new(parameters: HeatParameters) -> Self {
+        Self {
+            parameters: parameters.sanitized(),
+            ..Self::default()
+        }
+    }
+
+    fn observe(&mut self, wall_millis: u64) {
+        self.latest_millis = self.latest_millis.max(wall_millis);
+    }
+
+    fn bump(&mut self, resource: &str, priority: Option<&str>, wall_millis: u64) {
+        self.observe(wall_millis);
+        let weight = priority
+            .and_then(|label| label.parse::<Priority>().ok())
+            .unwrap_or(Priority::Info)
+            .as_index() as f64
+            + 1.0;
+        let parameters = self.parameters;
+        let cell = self
+            .cells
+            .entry(resource.to_string())
+            .or_insert(HeatCellRecord {
+                value: 0.0,
+                updated_millis: wall_millis,
+            });
+        parameters.decay(cell, wall_millis);
+        cell.value = (cell.value + parameters.increment * weight).min(parameters.max_value);
+    }
+
+    fn snapshot(&mut self) -> HeatSnapshot {
+        let now = self.latest_millis;
+        let parameters = self.parameters;
+        for cell in self.cells.values_mut() {
+            parameters.decay(cell, now);
+        }
+        self.cells.retain(|_, cell| cell.value >= 0.01);
+        let mut snapshot = HeatSnapshot {
+            tracked: self.cells.len(),
+            ..HeatSnapshot::default()
+        };
+        for (resource, cell) in self.cells.iter() {
+            if cell.value > snapshot.hottest_score {
+                snapshot.hottest_score = cell.value;
+                snapshot.hottest_resource = Some(resource.clone());
+            }
+        }
+        snapshot
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayReconciliation {
+    pub dispatched: u64,
+    pub rate_limited: u64,
+    pub sequence_gaps: Vec<SequenceGap>,
+    pub checkpoint_mismatches: Vec<CheckpointMismatch>,
+    #[serde(skip)]
+    cursor: Option<(String, u64)>,
+    #[serde(skip)]
+    window: CheckpointWindow,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CheckpointWindow {
+    dispatched: u64,
+    rate_limited: u64,
+    baseline: Option<(u64, u64)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SequenceGap {
+    pub epoch_id: String,
+    pub first_missing: u64,
+    pub last_missing: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckpointMismatch {
+    pub checkpoint_id: String,
+    pub expected_dispatched: u64,
+    pub observed_dispatched: u64,
+    pub expected_rate_limited: u64,
+    pub observed_rate_limited: u64,
+}
+
+impl ReplayReconciliation {
+    pub fn is_consistent(&self) -> bool {
+        self.sequence_gaps.is_empty() && self.checkpoint_mismatches.is_empty()
+    }
+
+    fn observe_sequence(&mut self, envelope: &EventEnvelope) {
+        let expected = match self.cursor.as_ref() {
+            Some((epoch_id, last)) if *epoch_id == envelope.epoch_id => last.saturating_add(1),
+            _ => 1,
+        };
+        if envelope.sequence > expected {
+            self.sequence_gaps.push(SequenceGap {
+                epoch_id: envelope.epoch_id.clone(),
+                first_missing: expected,
+                last_missing: envelope.sequence - 1,
+            });
+        }
+        self.cursor = Some((envelope.epoch_id.clone(), envelope.sequence));
+    }
+
+    fn observe_router(&mut self, event: &RouterEvent) {
+        match event {
+            RouterEvent::Dispatched(_) => {
+                self.dispatched = self.dispatched.saturating_add(1);
+                self.window.dispatched = self.window.dispatched.saturating_add(1);
+            }
+            RouterEvent::RateLimited(_) => {
+                self.rate_limited = self.rate_limited.saturating_add(1);
+                self.window.rate_limited = self.window.rate_limited.saturating_add(1);
+            }
+        }
+    }
+
+    fn seed_checkpoint(&mut self, checkpoint: &StateCheckpoint, baseline: &ReplayBaseline) {
+        self.dispatched = self.dispatched.saturating_add(baseline.dispatched);
+        self.rate_limited = self.rate_limited.saturating_add(baseline.rate_limited);
+        self.window = CheckpointWindow {
+            baseline: Some((
+                checkpoint.metrics.performance.total_messages_routed,
+                checkpoint.metrics.router.rate_limited_messages,
+            )),
+            ..CheckpointWindow::default()
+        };
+    }
+
+    fn observe_checkpoint(&mut self, checkpoint: &StateCheckpoint) {
+        let routed = checkpoint.metrics.performance.total_messages_routed;
+        let rate_limited = checkpoint.metrics.router.rate_limited_messages;
+        let (base_routed, base_rate_limited) = self.window.baseline.unwrap_or_default();
+        let expected_dispatched = routed.saturating_sub(base_routed);
+        let expected_rate_limited = rate_limited.saturating_sub(base_rate_limited);
+        if expected_dispatched != self.window.dispatched
+            || expected_rate_limited != self.window.rate_limited
+        {
+            self.checkpoint_mismatches.push(CheckpointMismatch {
+                checkpoint_id: checkpoint.checkpoint_id.clone(),
+                expected_dispatched,
+                observed_dispatched: self.window.dispatched,
+                expected_rate_limited,
+                observed_rate_limited: self.window.rate_limited,
+            });
+        }
+        self.window = CheckpointWindow {
+            baseline: Some((routed, rate_limited)),
+            ..CheckpointWindow::default()
+        };
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverrideAuthorization {
+    pub trace_id: String,
+    pub previous: LeaseRecord,
+    pub lease: LeaseRecord,
+    pub quorum: Option<QuorumVector>,
 }
 
 impl ReplayOutcome {
+    fn retain_checkpoint(&mut self, checkpoint: &StateCheckpoint) {
+        let keep = match self.checkpoint_retention {
+            CheckpointRetention::All => usize::MAX,
+            CheckpointRetention::LatestOnly => 1,
+            CheckpointRetention::LastN(count) => count,
+        };
+        if keep == 0 {
+            return;
+        }
+        if self.checkpoints.len() >= keep {
+            let excess = self.checkpoints.len() + 1 - keep;
+            self.checkpoints.drain(..excess);
+        }
+        self.checkpoints.push(StateCheckpoint {
+            baseline: None,
+            ..checkpoint.clone()
+        });
+    }
+
+    fn restore_baseline(&mut self, baseline: &ReplayBaseline) {
+        for bump in baseline.heat_bumps.iter() {
+            self.heat_replay.bump(
+                &bump.resource_id,
+                bump.priority.as_deref(),
+                bump.wall_millis,
+            );
+        }
+        self.overrides.extend(baseline.overrides.iter().cloned());
+        self.pending_quorums.extend(
+            baseline
+                .pending_quorums
+                .iter()
+                .map(|(topic, vector)| (topic.clone(), vector.clone())),
+        );
+        self.director_timeline
+            .extend(baseline.director_timeline.iter().cloned());
+    }
+
     fn update_from_checkpoint(&mut self, checkpoint: &StateCheckpoint) {
         self.metrics = Some(checkpoint.metrics.clone());
         self.router = checkpoint.router.clone();
         self.leases = checkpoint.leases.clone();
     }
+
+    fn apply_envelope(&mut self, envelope: &EventEnvelope) {
+        self.reconciliation.observe_sequence(envelope);
+        self.apply_heat(envelope);
+        match &envelope.event {
+            LedgerEvent::Router(event) => {
+                self.reconciliation.observe_router(event);
+                match event {
+                    RouterEvent::Dispatched(record) => self.router.apply_dispatch(record),
+                    RouterEvent::RateLimited(_) => self.router.apply_rate_limited(),
+                }
+            }
+            LedgerEvent::Lease(event) => {
+                if let LeaseEvent::Overridden { previous, lease } = event {
+                    self.link_override(previous, lease);
+                }
+                self.leases.apply(event);
+            }
+            LedgerEvent::Consensus(ConsensusEvent::Commit(signal)) => self.link_commit(signal),
+            LedgerEvent::Consensus(_) => {}
+            LedgerEvent::Pty(_) => {}
+            LedgerEvent::Health(_) => {}
+            LedgerEvent::Director(event) => {
+                self.director_timeline.push(DirectorTimelineEntry {
+                    sequence: envelope.sequence,
+                    wall_millis: envelope.logical_clock.wall_millis,
+                    event: event.clone(),
+                });
+            }
+            LedgerEvent::Checkpoint(checkpoint) => {
+                if let Some(baseline) = checkpoint.baseline.as_ref() {
+                    self.reconciliation.seed_checkpoint(checkpoint, baseline);
+                    self.restore_baseline(baseline);
+                } else {
+                    self.reconciliation.observe_checkpoint(checkpoint);
+                }
+                self.retain_checkpoint(checkpoint);
+                self.update_from_checkpoint(checkpoint);
+            }
+        }
+        self.last_sequence = Some(envelope.sequence);
+        self.tail_hash = Some(envelope.hash_chain.clone());
+    }
+
+    fn apply_heat(&mut self, envelope: &EventEnvelope) {
+        match HeatBumpRecord::from_envelope(envelope) {
+            Some(bump) => {
+                self.heat_replay.bump(
+                    &bump.resource_id,
+                    bump.priority.as_deref(),
+                    bump.wall_millis,
+                );
+            }
+            None => self.heat_replay.observe(envelope.logical_clock.wall_millis),
+        }
+    }
+
+    fn finish(&mut self) {
+        self.heat = self.heat_replay.snapshot();
+        self.ensure_metrics();
+    }
+
+    fn link_override(&mut self, previous: &LeaseRecord, lease: &LeaseRecord) {
+        let trace_id = lease_trace_id(lease.lease_id);
+        let quorum = self.pending_quorums.remove(&trace_id);
+        self.overrides.push(OverrideAuthorization {
+            trace_id,
+            previous: previous.clone(),
+            lease: lease.clone(),
+            quorum,
+        });
+    }
+
+    fn link_commit(&mut self, signal: &ConsensusSignal) {
+        let Some(vector) = signal.vector.clone() else {
+            return;
+        };
+        if let Some(link) = self
+            .overrides
+            .iter_mut()
+            .rev()
+            .find(|link| link.trace_id == signal.topic && link.quorum.is_none())
+        {
+            link.quorum = Some(vector);
+        } else {
+            self.pending_quorums.insert(signal.topic.clone(), vector);
+        }
+    }
+
+    fn ensure_metrics(&mut self) {
+        if self.metrics.is_some() {
+            return;
+        }
+        self.metrics = Some(MetricsSnapshot {
+            performance: Default::default(),
+            router: self.router.to_snapshot(),
+            rate_limits: vec![],
+            leases: self.leases.to_summary(),
+            pty: Default::default(),
+            system: Default::default(),
+            ledger: Default::default(),
+            consensus: ConsensusSnapshot::default(),
+            heat: self.heat.clone(),
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExportFormat {
+    Ndjson,
+    Csv,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochInfo {
+    pub epoch_id: String,
+    pub event_count: usize,
+    pub segment_count: usize,
+    pub total_bytes: u64,
+    pub verified: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactionReport {
+    pub epoch_id: String,
+    pub checkpoint_id: String,
+    pub events_removed: usize,
+    pub events_retained: usize,
+    pub segment_count: usize,
+    pub tail_hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochStats {
+    pub epoch_id: String,
+    pub event_count: usize,
+    pub total_bytes: u64,
+    pub segment_count: usize,
+    pub earliest_clock: Option<LogicalClock>,
+    pub latest_clock: Option<LogicalClock>,
+    pub events_by_kind: BTreeMap<LedgerEventKind, usize>,
+}
+
+const CSV_EXPORT_HEADER: &str =
+    "sequence,wall_millis,counter,kind,agent_id,territory_id,priority,payload_digest";
+
 #[derive(Clone)]
 pub struct LedgerWriter {
     inner: Arc<LedgerInner>,
@@ -468,22 +1205,86 @@ struct LedgerInner {
     epoch_id: String,
     state: Mutex<WriterState>,
     clock: Mutex<HybridLogicalClock>,
+    wall_clock: SharedClock,
     broadcaster: broadcast::Sender<EventEnvelope>,
+    consecutive_failures: AtomicU32,
+    metrics: Mutex<Option<MetricsCollector>>,
 }
 
 #[derive(Clone)]
 pub struct LedgerReader {
     root: PathBuf,
+    encryption_key: Option<[u8; 32]>,
+}
+
+pub struct LedgerFollower {
+    receiver: broadcast::Receiver<EventEnvelope>,
+    reader: LedgerReader,
+    epoch_id: String,
+    next_sequence: u64,
+    backlog: VecDeque<EventEnvelope>,
+    catch_up: bool,
+    backfilled: u64,
 }
 
 pub struct ReplayCoordinator {
     reader: LedgerReader,
+    heat_parameters: HeatParameters,
+    options: ReplayOptions,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatParameters {
+    pub decay_per_second: f64,
+    pub increment: f64,
+    pub max_value: f64,
+}
+
+impl Default for HeatParameters {
+    fn default() -> Self {
+        Self {
+            decay_per_second: 0.15,
+            increment: 1.5,
+            max_value: 10.0,
+        }
+    }
+}
+
+impl HeatParameters {
+    fn sanitized(self) -> Self {
+        Self {
+            decay_per_second: self.decay_per_second.clamp(0.0, 1.0),
+            increment: self.increment.max(0.0),
+            max_value: self.max_value.max(0.0),
+        }
+    }
+
+    fn decay(&self, cell: &mut HeatCellRecord, now_millis: u64) {
+        let elapsed = Duration::from_millis(now_millis.saturating_sub(cell.updated_millis));
+        cell.value *= heat_decay_factor(self.decay_per_second, elapsed);
+        cell.updated_millis = cell.updated_millis.max(now_millis);
+    }
+}
+
+pub(crate) fn heat_decay_factor(decay_per_second: f64, elapsed: Duration) -> f64 {
+    let seconds = elapsed.as_secs_f64();
+    if seconds <= 0.0 || decay_per_second <= 0.0 {
+        return 1.0;
+    }
+    let base = (1.0 - decay_per_second).clamp(0.0, 1.0);
+    if base == 0.0 {
+        0.0
+    } else {
+        base.powf(seconds)
+    }
 }
 
-#[derive(Debug)]
 struct LedgerRuntimeConfig {
     segment_size_bytes: u64,
     segment_duration: Duration,
+    encryption_key: Option<[u8; 32]>,
+    degraded_after_failures: u32,
 }
 
 impl From<&LedgerConfig> for LedgerRuntimeConfig {
@@ -491,32 +1292,88 @@ impl From<&LedgerConfig> for LedgerRuntimeConfig {
         Self {
             segment_size_bytes: config.segment_size_bytes,
             segment_duration: Duration::from_secs(config.segment_duration_secs.max(1)),
+            encryption_key: config.encryption_key,
+            degraded_after_failures: config.degraded_after_failures.max(1),
         }
     }
 }
 
 struct WriterState {
-    file: BufWriter<File>,
+    file: Option<BufWriter<File>>,
     sequence: u64,
     prev_hash: String,
     segment_index: u32,
     bytes_written: u64,
     segment_opened_at: SystemTime,
+    closed: bool,
 }
 
 impl WriterState {
     fn new(file: BufWriter<File>, now: SystemTime) -> Self {
         Self {
-            file,
+            file: Some(file),
+            ..Self::detached(now)
+        }
+    }
+
+    fn detached(now: SystemTime) -> Self {
+        Self {
+            file: None,
             sequence: 0,
             prev_hash: String::from("0"),
             segment_index: 0,
             bytes_written: 0,
             segment_opened_at: now,
+            closed: false,
+        }
+    }
+
+    fn open(
+        root: &Path,
+        epoch_id: &str,
+        config: &LedgerRuntimeConfig,
+    ) -> LedgerResult<(Self, HybridLogicalClock)> {
+        let now = SystemTime::now();
+        let Some((segment_index, segment_path)) = collect_segments(&root.join(epoch_id))?
+            .into_iter()
+            .filter_map(|path| segment_index_of(&path).map(|index| (index, path)))
+            .max_by_key(|(index, _)| *index)
+        else {
+            let state = Self::new(open_segment(root, epoch_id, 0)?, now);
+            return Ok((state, HybridLogicalClock::default()));
+        };
+        truncate_torn_tail(&segment_path)?;
+        let reader = LedgerReader::with_encryption_key(root.to_path_buf(), config.encryption_key);
+        let mut tail = None;
+        reader.scan_epoch(epoch_id, |envelope| {
+            tail = Some(envelope);
+            true
+        })?;
+        let mut segment_opened_at = None;
+        reader.scan_segment(&segment_path, |envelope| {
+            segment_opened_at =
+                Some(UNIX_EPOCH + Duration::from_millis(envelope.logical_clock.wall_millis));
+            false
+        })?;
+        let file = open_segment(root, epoch_id, segment_index)?;
+        let mut state = Self::new(file, segment_opened_at.unwrap_or(now));
+        state.segment_index = segment_index;
+        state.bytes_written = fs::metadata(&segment_path)?.len();
+        let mut clock = HybridLogicalClock::default();
+        if let Some(tail) = tail {
+            state.sequence = tail.sequence;
+            state.prev_hash = tail.hash_chain;
+            clock.last_wall = tail.logical_clock.wall_millis;
+            clock.counter = tail.logical_clock.counter;
+            clock.last_observed = tail.logical_clock.wall_millis;
         }
+        Ok((state, clock))
     }
 
     fn should_rotate(&self, now: SystemTime, config: &LedgerRuntimeConfig) -> bool {
+        if self.bytes_written == 0 {
+            return false;
+        }
         let size_exceeded = self.bytes_written >= config.segment_size_bytes;
         let time_exceeded = now
             .duration_since(self.segment_opened_at)
@@ -528,6 +1385,10 @@ impl WriterState {
 
 impl LedgerWriter {
     pub fn new(config: &LedgerConfig) -> LedgerResult<Self> {
+        Self::with_wall_clock(config, system_clock())
+    }
+
+    pub fn with_wall_clock(config: &LedgerConfig, wall_clock: SharedClock) -> LedgerResult<Self> {
         let root = config.root_path.clone();
         let epoch_id = config
             .current_epoch
@@ -535,19 +1396,61 @@ impl LedgerWriter {
             .unwrap_or_else(|| current_epoch_id());
         let runtime = LedgerRuntimeConfig::from(config);
         fs::create_dir_all(root.join(&epoch_id))?;
-        let initial_file = open_segment(&root, &epoch_id, 0)?;
-        let state = WriterState::new(initial_file, SystemTime::now());
+        let (state, clock) = WriterState::open(&root, &epoch_id, &runtime)?;
+        Ok(Self::from_parts(
+            runtime, root, epoch_id, state, clock, wall_clock,
+        ))
+    }
+
+    pub fn degraded(config: &LedgerConfig) -> Self {
+        let epoch_id = config
+            .current_epoch
+            .clone()
+            .unwrap_or_else(current_epoch_id);
+        Self::from_parts(
+            LedgerRuntimeConfig::from(config),
+            config.root_path.clone(),
+            epoch_id,
+            WriterState::detached(SystemTime::now()),
+            HybridLogicalClock::default(),
+            system_clock(),
+        )
+    }
+
+    fn from_parts(
+        config: LedgerRuntimeConfig,
+        root: PathBuf,
+        epoch_id: String,
+        state: WriterState,
+        clock: HybridLogicalClock,
+        wall_clock: SharedClock,
+    ) -> Self {
         let (tx, _) = broadcast::channel(DEFAULT_BROADCAST_CAPACITY);
-        Ok(Self {
+        Self {
             inner: Arc::new(LedgerInner {
-                config: runtime,
+                config,
                 root,
                 epoch_id,
                 state: Mutex::new(state),
-                clock: Mutex::new(HybridLogicalClock::default()),
+                clock: Mutex::new(clock),
+                wall_clock,
                 broadcaster: tx,
+                consecutive_failures: AtomicU32::new(0),
+                metrics: Mutex::new(None),
             }),
-        })
+        }
+    }
+
+    pub fn set_metrics(&self, metrics: MetricsCollector) {
+        *self.inner.metrics.lock().unwrap() = Some(metrics);
+    }
+
+    pub fn consecutive_failures(&self) -> u32 {
+        self.inner.consecutive_failures.load(Ordering::SeqCst)
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.consecutive_failures() >= self.inner.config.degraded_after_failures
     }
 
     pub fn epoch_id(&self) -> String {
@@ -558,6 +1461,23 @@ impl LedgerWriter {
         self.inner.broadcaster.subscribe()
     }
 
+    pub fn follow(&self, from_sequence: u64) -> LedgerFollower {
+        let receiver = self.inner.broadcaster.subscribe();
+        let current = self.inner.state.lock().unwrap().sequence;
+        LedgerFollower {
+            receiver,
+            reader: LedgerReader::with_encryption_key(
+                self.inner.root.clone(),
+                self.inner.config.encryption_key,
+            ),
+            epoch_id: self.inner.epoch_id.clone(),
+            next_sequence: from_sequence.max(1),
+            backlog: VecDeque::new(),
+            catch_up: from_sequence <= current,
+            backfilled: 0,
+        }
+    }
+
     pub async fn append_async(&self, event: LedgerEvent) -> LedgerResult<EventEnvelope> {
         let inner = self.inner.clone();
         tokio::task::spawn_blocking(move || inner.append(event)).await?
@@ -579,45 +1499,150 @@ impl LedgerWriter {
         tokio::task::spawn_blocking(move || inner.flush()).await??;
         Ok(())
     }
+
+    pub async fn close(&self) -> LedgerResult<()> {
+        let inner = self.inner.clone();
+        tokio::task::spawn_blocking(move || inner.close()).await??;
+        Ok(())
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.inner.state.lock().unwrap().closed
+    }
+
+    pub fn segment_index(&self) -> u32 {
+        self.inner.state.lock().unwrap().segment_index
+    }
+
+    pub async fn compact_epoch(&self, epoch_id: &str) -> LedgerResult<CompactionReport> {
+        if epoch_id == self.inner.epoch_id {
+            return Err(LedgerError::ActiveEpoch(epoch_id.to_string()));
+        }
+        let inner = self.inner.clone();
+        let epoch_id = epoch_id.to_string();
+        tokio::task::spawn_blocking(move || inner.compact_epoch(&epoch_id)).await?
+    }
+
+    pub async fn rotate_if_due(&self) -> LedgerResult<bool> {
+        let inner = self.inner.clone();
+        tokio::task::spawn_blocking(move || inner.rotate_if_due()).await?
+    }
+
+    pub async fn run_rotation_ticker(self) {
+        let period = (self.inner.config.segment_duration / 4).min(ROTATION_TICK_MAX);
+        let mut ticker = tokio::time::interval(period);
+        ticker.tick().await;
+        loop {
+            ticker.tick().await;
+            let _ = self.rotate_if_due().await;
+        }
+    }
 }
 
 impl LedgerInner {
     fn append(&self, event: LedgerEvent) -> LedgerResult<EventEnvelope> {
+        let result = self.write_event(event);
+        self.track_outcome(&result);
+        result
+    }
+
+    fn track_outcome<T>(&self, result: &LedgerResult<T>) {
+        let failures = match result {
+            Ok(_) => {
+                if self.consecutive_failures.swap(0, Ordering::SeqCst) == 0 {
+                    return;
+                }
+                0
+            }
+            Err(LedgerError::Closed(_)) => return,
+            Err(_) => self
+                .consecutive_failures
+                .fetch_add(1, Ordering::SeqCst)
+                .saturating_add(1),
+        };
+        if let Some(metrics) = self.metrics.lock().unwrap().as_ref() {
+            metrics.record_ledger_durability(
+                failures,
+                failures >= self.config.degraded_after_failures,
+            );
+        }
+    }
+
+    fn record_clock_regression(&self, regression: Duration) {
+        println!(
+            "[Ledger]: clock regression in epoch {}: wall clock moved back {}ms",
+            self.epoch_id,
+            regression.as_millis()
+        );
+        if let Some(metrics) = self.metrics.lock().unwrap().as_ref() {
+            metrics.record_ledger_clock_regression();
+        }
+    }
+
+    fn reattach(
+        &self,
+        state: &mut WriterState,
+        clock: &mut HybridLogicalClock,
+    ) -> LedgerResult<()> {
+        fs::create_dir_all(self.root.join(&self.epoch_id))?;
+        let (restored, restored_clock) =
+            WriterState::open(&self.root, &self.epoch_id, &self.config)?;
+        *state = restored;
+        if (restored_clock.last_wall, restored_clock.counter) > (clock.last_wall, clock.counter) {
+            *clock = restored_clock;
+        }
+        Ok(())
+    }
+
+    fn write_event(&self, event: LedgerEvent) -> LedgerResult<EventEnvelope> {
         let mut state = self.state.lock().unwrap();
+        if state.closed {
+            return Err(LedgerError::Closed(self.epoch_id.clone()));
+        }
         let mut clock = self.clock.lock().unwrap();
-        let now = SystemTime::now();
+        if state.file.is_none() {
+            self.reattach(&mut state, &mut clock)?;
+        }
+        let now = self.wall_clock.now_system();
         if state.should_rotate(now, &self.config) {
-            state.segment_index = state.segment_index.saturating_add(1);
-            state.bytes_written = 0;
-            state.segment_opened_at = now;
-            state.file = open_segment(&self.root, &self.epoch_id, state.segment_index)?;
+            self.rotate(&mut state, now)?;
         }
         let metadata = event.metadata();
-        let logical_clock = LogicalClock::now(&mut clock);
+        let (logical_clock, regression) = clock.tick(now);
+        if let Some(regression) = regression {
+            self.record_clock_regression(regression);
+        }
         let payload_bytes = serde_json::to_vec(&event)?;
         let payload_digest = blake3::hash(&payload_bytes).to_hex().to_string();
-        state.sequence = state.sequence.saturating_add(1);
+        let sequence = state.sequence.saturating_add(1);
         let mut envelope = EventEnvelope {
             epoch_id: self.epoch_id.clone(),
-            sequence: state.sequence,
+            sequence,
             logical_clock,
             metadata,
             payload_digest,
             hash_chain: String::new(),
             event,
         };
-        let value = envelope.without_hash();
-        let serialized_without_hash = serde_json::to_vec(&value)?;
-        let mut hasher = Hasher::new();
-        hasher.update(state.prev_hash.as_bytes());
-        hasher.update(&serialized_without_hash);
-        let hash_chain = hasher.finalize().to_hex().to_string();
-        state.prev_hash = hash_chain.clone();
-        envelope.hash_chain = hash_chain;
-        let serialized = serde_json::to_vec(&envelope)?;
-        state.file.write_all(&serialized)?;
-        state.file.write_all(b"\n")?;
-        state.file.flush()?;
+        envelope.hash_chain = envelope.chain_hash(&state.prev_hash)?;
+        let mut serialized = serde_json::to_vec(&envelope)?;
+        if let Some(key) = self.config.encryption_key.as_ref() {
+            serialized = encrypt_line(key, &serialized)?;
+        }
+        let file = state
+            .file
+            .as_mut()
+            .ok_or_else(|| LedgerError::Closed(self.epoch_id.clone()))?;
+        let written = file
+            .write_all(&serialized)
+            .and_then(|_| file.write_all(b"\n"))
+            .and_then(|_| file.flush());
+        if let Err(err) = written {
+            state.file = None;
+            return Err(err.into());
+        }
+        state.sequence = sequence;
+        state.prev_hash = envelope.hash_chain.clone();
         state.bytes_written = state
             .bytes_written
             .saturating_add(serialized.len() as u64 + 1);
@@ -627,50 +1652,404 @@ impl LedgerInner {
 
     fn flush(&self) -> LedgerResult<()> {
         let mut state = self.state.lock().unwrap();
-        state.file.flush()?;
+        if let Some(file) = state.file.as_mut() {
+            file.flush()?;
+        }
+        Ok(())
+    }
+
+    fn close(&self) -> LedgerResult<()> {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        if let Some(file) = state.file.as_mut() {
+            file.flush()?;
+            file.get_ref().sync_all()?;
+        }
+        Ok(())
+    }
+
+    fn rotate_if_due(&self) -> LedgerResult<bool> {
+        let mut state = self.state.lock().unwrap();
+        let now = self.wall_clock.now_system();
+        if state.closed || state.file.is_none() || !state.should_rotate(now, &self.config) {
+            return Ok(false);
+        }
+        self.rotate(&mut state, now)?;
+        Ok(true)
+    }
+
+    fn compact_epoch(&self, epoch_id: &str) -> LedgerResult<CompactionReport> {
+        let reader =
+            LedgerReader::with_encryption_key(self.root.clone(), self.config.encryption_key);
+        if !reader.verify_epoch(epoch_id)? {
+            return Err(LedgerError::ChainBroken(epoch_id.to_string()));
+        }
+        let events = reader.read_epoch(epoch_id)?;
+        let Some((start, checkpoint_id)) =
+            events
+                .iter()
+                .enumerate()
+                .rev()
+                .find_map(|(index, envelope)| match &envelope.event {
+                    LedgerEvent::Checkpoint(checkpoint) => {
+                        Some((index, checkpoint.checkpoint_id.clone()))
+                    }
+                    _ => None,
+                })
+        else {
+            return Err(LedgerError::NoCheckpoint(epoch_id.to_string()));
+        };
+        let epoch_path = self.root.join(epoch_id);
+        let staging_path = self.root.join(format!(".compacting-{epoch_id}"));
+        if staging_path.exists() {
+            fs::remove_dir_all(&staging_path)?;
+        }
+        fs::create_dir_all(&staging_path)?;
+        let captured = ReplayBaseline::capture(&events[..start]);
+        let events_removed = start;
+        let mut prev_hash = String::from("0");
+        let mut segment_count = 0usize;
+        let mut file: Option<BufWriter<File>> = None;
+        let mut bytes_written = 0u64;
+        let mut events_retained = 0usize;
+        let mut captured = Some(captured);
+        for (offset, mut envelope) in events.into_iter().skip(start).enumerate() {
+            if let LedgerEvent::Checkpoint(checkpoint) = &mut envelope.event {
+                if let Some(captured) = captured.take() {
+                    checkpoint.baseline = Some(checkpoint.baseline.take().unwrap_or(captured));
+                }
+            }
+            envelope.sequence = offset as u64 + 1;
+            envelope.hash_chain = envelope.chain_hash(&prev_hash)?;
+            prev_hash = envelope.hash_chain.clone();
+            let mut serialized = serde_json::to_vec(&envelope)?;
+            if let Some(key) = self.config.encryption_key.as_ref() {
+                serialized = encrypt_line(key, &serialized)?;
+            }
+            if file.is_none() || bytes_written >= self.config.segment_size_bytes {
+                if let Some(mut previous) = file.take() {
+                    previous.flush()?;
+                    previous.get_ref().sync_all()?;
+                }
+                let path = staging_path.join(format!("segment_{segment_count:04}.log"));
+                file = Some(BufWriter::new(File::create(&path)?));
+                segment_count += 1;
+                bytes_written = 0;
+            }
+            if let Some(writer) = file.as_mut() {
+                writer.write_all(&serialized)?;
+                writer.write_all(b"\n")?;
+            }
+            bytes_written = bytes_written.saturating_add(serialized.len() as u64 + 1);
+            events_retained += 1;
+        }
+        if let Some(mut last) = file.take() {
+            last.flush()?;
+            last.get_ref().sync_all()?;
+        }
+        File::open(&staging_path)?.sync_all()?;
+        swap_directories(&staging_path, &epoch_path)?;
+        fs::remove_dir_all(&staging_path)?;
+        Ok(CompactionReport {
+            epoch_id: epoch_id.to_string(),
+            checkpoint_id,
+            events_removed,
+            events_retained,
+            segment_count,
+            tail_hash: prev_hash,
+        })
+    }
+
+    fn rotate(&self, state: &mut WriterState, now: SystemTime) -> LedgerResult<()> {
+        if let Some(file) = state.file.as_mut() {
+            file.flush()?;
+        }
+        let segment_index = state.segment_index.saturating_add(1);
+        state.file = Some(open_segment(&self.root, &self.epoch_id, segment_index)?);
+        state.segment_index = segment_index;
+        state.bytes_written = 0;
+        state.segment_opened_at = now;
+        Ok(())
+    }
+}
+
+impl LedgerFollower {
+    pub fn next_sequence(&self) -> u64 {
+        self.next_sequence
+    }
+
+    pub fn backfilled(&self) -> u64 {
+        self.backfilled
+    }
+
+    pub async fn recv(&mut self) -> LedgerResult<Option<EventEnvelope>> {
+        loop {
+            if self.catch_up {
+                self.catch_up = false;
+                self.backfill().await?;
+            }
+            if let Some(envelope) = self.backlog.pop_front() {
+                if envelope.sequence < self.next_sequence {
+                    continue;
+                }
+                self.next_sequence = envelope.sequence.saturating_add(1);
+                return Ok(Some(envelope));
+            }
+            match self.receiver.recv().await {
+                Ok(envelope) if envelope.sequence < self.next_sequence => continue,
+                Ok(envelope) if envelope.sequence == self.next_sequence => {
+                    self.next_sequence = envelope.sequence.saturating_add(1);
+                    return Ok(Some(envelope));
+                }
+                Ok(envelope) => {
+                    self.backfill().await?;
+                    if self
+                        .backlog
+                        .back()
+                        .is_none_or(|last| last.sequence < envelope.sequence)
+                    {
+                        self.backlog.push_back(envelope);
+                    }
+                }
+                Err(RecvError::Lagged(_)) => self.catch_up = true,
+                Err(RecvError::Closed) => return Ok(None),
+            }
+        }
+    }
+
+    async fn backfill(&mut self) -> LedgerResult<()> {
+        let reader = self.reader.clone();
+        let epoch_id = self.epoch_id.clone();
+        let from = self.next_sequence;
+        let missed =
+            tokio::task::spawn_blocking(move || reader.read_since_sequence(&epoch_id, from))
+                .await??;
+        self.backfilled = self.backfilled.saturating_add(missed.len() as u64);
+        self.backlog = missed.into();
         Ok(())
     }
 }
 
 impl LedgerReader {
     pub fn new(root: PathBuf) -> Self {
-        Self { root }
+        Self {
+            root,
+            encryption_key: None,
+        }
+    }
+
+    pub fn with_encryption_key(root: PathBuf, encryption_key: Option<[u8; 32]>) -> Self {
+        Self {
+            root,
+            encryption_key,
+        }
     }
 
     pub fn read_epoch(&self, epoch_id: &str) -> LedgerResult<Vec<EventEnvelope>> {
         let mut entries = Vec::new();
+        self.scan_epoch(epoch_id, |envelope| {
+            entries.push(envelope);
+            true
+        })?;
+        Ok(entries)
+    }
+
+    pub fn read_since_sequence(
+        &self,
+        epoch_id: &str,
+        sequence: u64,
+    ) -> LedgerResult<Vec<EventEnvelope>> {
+        let mut entries = Vec::new();
+        self.scan_epoch(epoch_id, |envelope| {
+            if envelope.sequence >= sequence {
+                entries.push(envelope);
+            }
+            true
+        })?;
+        Ok(entries)
+    }
+
+    pub fn read_window(
+        &self,
+        epoch_id: &str,
+        since_ms: Option<u64>,
+        until_ms: Option<u64>,
+        limit: Option<usize>,
+    ) -> LedgerResult<Vec<EventEnvelope>> {
+        let mut entries = Vec::new();
+        self.scan_epoch(epoch_id, |envelope| {
+            let wall_millis = envelope.logical_clock.wall_millis;
+            if until_ms.is_some_and(|until| wall_millis > until) {
+                return false;
+            }
+            if since_ms.is_none_or(|since| wall_millis >= since) {
+                entries.push(envelope);
+            }
+            true
+        })?;
+        if let Some(limit) = limit {
+            if entries.len() > limit {
+                entries = entries.split_off(entries.len() - limit);
+            }
+        }
+        Ok(entries)
+    }
+
+    fn scan_epoch(
+        &self,
+        epoch_id: &str,
+        mut visit: impl FnMut(EventEnvelope) -> bool,
+    ) -> LedgerResult<()> {
         let epoch_path = self.root.join(epoch_id);
         if !epoch_path.exists() {
-            return Ok(entries);
+            return Ok(());
         }
         let mut segments = collect_segments(&epoch_path)?;
         segments.sort();
         for segment in segments {
-            let file = File::open(&segment)?;
-            let reader = BufReader::new(file);
-            for line in reader.lines() {
-                let line = line?;
-                if line.trim().is_empty() {
-                    continue;
+            if !self.scan_segment(&segment, &mut visit)? {
+                return Ok(());
+            }
+        }
+        Ok(())
+    }
+
+    fn scan_segment(
+        &self,
+        segment: &Path,
+        mut visit: impl FnMut(EventEnvelope) -> bool,
+    ) -> LedgerResult<bool> {
+        let file = File::open(segment)?;
+        let reader = BufReader::new(file);
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let envelope = self.decode_line(line.trim())?;
+            if !visit(envelope) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn decode_line(&self, line: &str) -> LedgerResult<EventEnvelope> {
+        if line.starts_with('{') {
+            return Ok(serde_json::from_str(line)?);
+        }
+        let key = self.encryption_key.as_ref().ok_or_else(|| {
+            LedgerError::Decrypt("segment is encrypted but no key was provided".to_string())
+        })?;
+        let plaintext = decrypt_line(key, line)?;
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+
+    pub fn query_epoch(
+        &self,
+        epoch_id: &str,
+        filter: EventFilter,
+    ) -> LedgerResult<Vec<EventEnvelope>> {
+        let events = self.read_epoch(epoch_id)?;
+        Ok(events
+            .into_iter()
+            .filter(|envelope| filter.matches(envelope))
+            .collect())
+    }
+
+    pub fn export_epoch(
+        &self,
+        epoch_id: &str,
+        format: ExportFormat,
+        writer: impl Write,
+    ) -> LedgerResult<usize> {
+        let events = self.read_epoch(epoch_id)?;
+        let mut writer = BufWriter::new(writer);
+        match format {
+            ExportFormat::Ndjson => {
+                for envelope in events.iter() {
+                    serde_json::to_writer(&mut writer, envelope)?;
+                    writer.write_all(b"\n")?;
+                }
+            }
+            ExportFormat::Csv => {
+                writeln!(writer, "{CSV_EXPORT_HEADER}")?;
+                for envelope in events.iter() {
+                    writeln!(writer, "{}", csv_row(envelope))?;
                 }
-                let envelope: EventEnvelope = serde_json::from_str(&line)?;
-                entries.push(envelope);
             }
         }
-        Ok(entries)
+        writer.flush()?;
+        Ok(events.len())
+    }
+
+    pub fn epoch_stats(&self, epoch_id: &str) -> LedgerResult<EpochStats> {
+        let mut stats = EpochStats {
+            epoch_id: epoch_id.to_string(),
+            ..EpochStats::default()
+        };
+        let epoch_path = self.root.join(epoch_id);
+        let segments = collect_segments(&epoch_path)?;
+        stats.segment_count = segments.len();
+        for segment in segments.iter() {
+            stats.total_bytes = stats
+                .total_bytes
+                .saturating_add(fs::metadata(segment)?.len());
+        }
+        let events = self.read_epoch(epoch_id)?;
+        stats.event_count = events.len();
+        stats.earliest_clock = events.first().map(|event| event.logical_clock.clone());
+        stats.latest_clock = events.last().map(|event| event.logical_clock.clone());
+        for envelope in events.iter() {
+            *stats
+                .events_by_kind
+                .entry(envelope.event.kind())
+                .or_insert(0) += 1;
+        }
+        Ok(stats)
+    }
+
+    pub fn list_epochs(&self) -> LedgerResult<Vec<EpochInfo>> {
+        let mut epochs = Vec::new();
+        if !self.root.is_dir() {
+            return Ok(epochs);
+        }
+        for entry in fs::read_dir(&self.root)? {
+            let path = entry?.path();
+            let Some(epoch_id) = path.file_name().and_then(|name| name.to_str()) else {
+                continue;
+            };
+            if epoch_id.starts_with('.') {
+                continue;
+            }
+            let segments = collect_segments(&path)?;
+            if segments.is_empty() {
+                continue;
+            }
+            let mut info = EpochInfo {
+                epoch_id: epoch_id.to_string(),
+                event_count: 0,
+                segment_count: segments.len(),
+                total_bytes: 0,
+                verified: self.verify_epoch(epoch_id).unwrap_or(false),
+            };
+            for segment in segments.iter() {
+                info.total_bytes = info
+                    .total_bytes
+                    .saturating_add(fs::metadata(segment)?.len());
+                info.event_count += count_lines(segment)?;
+            }
+            epochs.push(info);
+        }
+        epochs.sort_by(|a, b| a.epoch_id.cmp(&b.epoch_id));
+        Ok(epochs)
     }
 
     pub fn verify_epoch(&self, epoch_id: &str) -> LedgerResult<bool> {
         let events = self.read_epoch(epoch_id)?;
         let mut prev_hash = String::from("0");
         for event in events {
-            let value = event.without_hash();
-            let serialized_without_hash = serde_json::to_vec(&value)?;
-            let mut hasher = Hasher::new();
-            hasher.update(prev_hash.as_bytes());
-            hasher.update(&serialized_without_hash);
-            let expected = hasher.finalize().to_hex().to_string();
-            if expected != event.hash_chain {
+            if event.chain_hash(&prev_hash)? != event.hash_chain {
                 return Ok(false);
             }
             prev_hash = event.hash_chain;
@@ -681,46 +2060,61 @@ impl LedgerReader {
 
 impl ReplayCoordinator {
     pub fn new(reader: LedgerReader) -> Self {
-        Self { reader }
+        Self {
+            reader,
+            heat_parameters: HeatParameters::default(),
+            options: ReplayOptions::default(),
+        }
+    }
+
+    pub fn with_heat_parameters(mut self, heat_parameters: HeatParameters) -> Self {
+        self.heat_parameters = heat_parameters;
+        self
+    }
+
+    pub fn with_options(mut self, options: ReplayOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    fn new_outcome(&self) -> ReplayOutcome {
+        ReplayOutcome {
+            heat_replay: HeatReplay::new(self.heat_parameters),
+            checkpoint_retention: self.options.keep_checkpoints,
+            ..ReplayOutcome::default()
+        }
     }
 
     pub fn replay_epoch(&self, epoch_id: &str) -> LedgerResult<ReplayOutcome> {
         let events = self.reader.read_epoch(epoch_id)?;
-        let mut outcome = ReplayOutcome::default();
+        let mut outcome = self.new_outcome();
         for envelope in events.iter() {
-            match &envelope.event {
-                LedgerEvent::Router(event) => match event {
-                    RouterEvent::Dispatched(record) => outcome.router.apply_dispatch(record),
-                    RouterEvent::RateLimited(_) => {}
-                },
-                LedgerEvent::Lease(event) => outcome.leases.apply(event),
-                LedgerEvent::Consensus(_) => {}
-                LedgerEvent::Pty(_) => {}
-                LedgerEvent::Health(_) => {}
-                LedgerEvent::Checkpoint(checkpoint) => {
-                    outcome.checkpoints.push(checkpoint.clone());
-                    outcome.update_from_checkpoint(checkpoint);
-                }
+            outcome.apply_envelope(envelope);
+        }
+        outcome.finish();
+        Ok(outcome)
+    }
+
+    pub fn replay_epochs(&self, epoch_ids: &[String]) -> LedgerResult<ReplayOutcome> {
+        let mut epochs = Vec::with_capacity(epoch_ids.len());
+        for epoch_id in epoch_ids.iter() {
+            if !self.reader.verify_epoch(epoch_id)? {
+                return Err(LedgerError::ChainBroken(epoch_id.clone()));
             }
-            outcome.last_sequence = Some(envelope.sequence);
-            outcome.tail_hash = Some(envelope.hash_chain.clone());
-        }
-        if outcome.metrics.is_none() {
-            let router_snapshot = outcome.router.to_snapshot();
-            let lease_summary = outcome.leases.to_summary();
-            let metrics = MetricsSnapshot {
-                performance: Default::default(),
-                router: router_snapshot,
-                rate_limits: vec![],
-                leases: lease_summary,
-                pty: Default::default(),
-                system: Default::default(),
-                ledger: Default::default(),
-                consensus: ConsensusSnapshot::default(),
-                heat: HeatSnapshot::default(),
-            };
-            outcome.metrics = Some(metrics);
+            let events = self.reader.read_epoch(epoch_id)?;
+            let started_at = events
+                .first()
+                .map(|event| (event.logical_clock.wall_millis, event.logical_clock.counter));
+            epochs.push((started_at, events));
         }
+        epochs.sort_by_key(|(started_at, _)| *started_at);
+        let mut outcome = self.new_outcome();
+        for (_, events) in epochs.iter() {
+            for envelope in events.iter() {
+                outcome.apply_envelope(envelope);
+            }
+        }
+        outcome.finish();
         Ok(outcome)
     }
 }
@@ -737,6 +2131,62 @@ fn open_segment(root: &Path, epoch_id: &str, index: u32) -> LedgerResult<BufWrit
     Ok(BufWriter::new(file))
 }
 
+#[cfg(target_os = "linux")]
+fn swap_directories(staged: &Path, live: &Path) -> LedgerResult<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    let to_c_path = |path: &Path| {
+        CString::new(path.as_os_str().as_bytes())
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))
+    };
+    let (staged_c, live_c) = (to_c_path(staged)?, to_c_path(live)?);
+    // SAFETY: both pointers come from live CStrings that outlive the call, and
+    // AT_FDCWD makes renameat2 resolve them as ordinary paths.
+    let result = unsafe {
+        libc::renameat2(
+            libc::AT_FDCWD,
+            staged_c.as_ptr(),
+            libc::AT_FDCWD,
+            live_c.as_ptr(),
+            libc::RENAME_EXCHANGE,
+        )
+    };
+    if result != 0 {
+        return Err(std::io::Error::last_os_error().into());
+    }
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn swap_directories(staged: &Path, live: &Path) -> LedgerResult<()> {
+    let retired = staged.with_extension("retired");
+    fs::rename(live, &retired)?;
+    fs::rename(staged, live)?;
+    fs::rename(&retired, staged)?;
+    Ok(())
+}
+
+fn truncate_torn_tail(segment: &Path) -> LedgerResult<()> {
+    let bytes = fs::read(segment)?;
+    let complete = bytes
+        .iter()
+        .rposition(|byte| *byte == b'\n')
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    if complete < bytes.len() {
+        println!(
+            "[Ledger]: Truncating {} torn bytes from {}",
+            bytes.len() - complete,
+            segment.display()
+        );
+        OpenOptions::new()
+            .write(true)
+            .open(segment)?
+            .set_len(complete as u64)?;
+    }
+    Ok(())
+}
+
 fn collect_segments(epoch_path: &Path) -> LedgerResult<Vec<PathBuf>> {
     let mut segments = Vec::new();
     if epoch_path.is_dir() {
@@ -756,6 +2206,74 @@ fn collect_segments(epoch_path: &Path) -> LedgerResult<Vec<PathBuf>> {
     Ok(segments)
 }
 
+fn count_lines(segment: &Path) -> LedgerResult<usize> {
+    let mut count = 0;
+    for line in BufReader::new(File::open(segment)?).lines() {
+        if !line?.trim().is_empty() {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+fn segment_index_of(path: &Path) -> Option<u32> {
+    path.file_name()?
+        .to_str()?
+        .strip_prefix("segment_")?
+        .strip_suffix(".log")?
+        .parse()
+        .ok()
+}
+
+fn encrypt_line(key: &[u8; 32], plaintext: &[u8]) -> LedgerResult<Vec<u8>> {
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plaintext)
+        .map_err(|err| LedgerError::Encrypt(err.to_string()))?;
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+    Ok(hex::encode(sealed).into_bytes())
+}
+
+fn decrypt_line(key: &[u8; 32], line: &str) -> LedgerResult<Vec<u8>> {
+    let sealed = hex::decode(line).map_err(|err| LedgerError::Decrypt(err.to_string()))?;
+    if sealed.len() < ENCRYPTION_NONCE_LEN {
+        return Err(LedgerError::Decrypt(
+            "sealed entry is truncated".to_string(),
+        ));
+    }
+    let (nonce, ciphertext) = sealed.split_at(ENCRYPTION_NONCE_LEN);
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| LedgerError::Decrypt("authentication failed; wrong key?".to_string()))
+}
+
+fn csv_row(envelope: &EventEnvelope) -> String {
+    let metadata = &envelope.metadata;
+    [
+        envelope.sequence.to_string(),
+        envelope.logical_clock.wall_millis.to_string(),
+        envelope.logical_clock.counter.to_string(),
+        envelope.event.kind().as_str().to_string(),
+        csv_field(metadata.agent_id.as_deref()),
+        csv_field(metadata.territory_id.as_deref()),
+        csv_field(metadata.priority.as_deref()),
+        csv_field(Some(&envelope.payload_digest)),
+    ]
+    .join(",")
+}
+
+fn csv_field(value: Option<&str>) -> String {
+    let value = value.unwrap_or_default();
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
 fn current_epoch_id() -> String {
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -763,3 +2281,81 @@ fn current_epoch_id() -> String {
         .as_secs();
     format!("epoch-{now}")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_rolls_wall_forward_when_counter_overflows() {
+        let instant = UNIX_EPOCH + Duration::from_millis(1_700_000_000_000);
+        let mut clock = HybridLogicalClock {
+            last_wall: 1_700_000_000_000,
+            counter: u32::MAX - 500,
+            last_observed: 1_700_000_000_000,
+        };
+        let (mut previous, _) = clock.tick(instant);
+        for _ in 0..1_000 {
+            let (next, regression) = clock.tick(instant);
+            assert!(regression.is_none());
+            assert!(
+                next > previous,
+                "{next:?} did not advance past {previous:?}"
+            );
+            previous = next;
+        }
+        assert_eq!(previous.wall_millis, 1_700_000_000_001);
+        assert_eq!(previous.counter, 500);
+    }
+
+    #[test]
+    fn failed_write_reattaches_and_keeps_the_epoch_readable() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let config = LedgerConfig {
+            root_path: temp_dir.path().to_path_buf(),
+            current_epoch: Some("failed-write".to_string()),
+            ..LedgerConfig::default()
+        };
+        let pty_event = |index: u64| {
+            LedgerEvent::Pty(PtyEvent {
+                agent_id: format!("agent-{index}"),
+                event_name: None,
+                timestamp_ms: index,
+            })
+        };
+        let writer = LedgerWriter::new(&config).expect("ledger writer");
+        writer.append_blocking(pty_event(0)).expect("first append");
+
+        let segment = temp_dir
+            .path()
+            .join("failed-write")
+            .join("segment_0000.log");
+        let mut torn = OpenOptions::new()
+            .append(true)
+            .open(&segment)
+            .expect("open segment");
+        torn.write_all(br#"{"epochId":"failed-write","seq"#)
+            .expect("write torn fragment");
+        drop(torn);
+        let read_only = File::open(&segment).expect("read-only segment");
+        writer.inner.state.lock().unwrap().file = Some(BufWriter::new(read_only));
+
+        assert!(writer.append_blocking(pty_event(1)).is_err());
+        assert!(writer.inner.state.lock().unwrap().file.is_none());
+
+        let recovered = writer
+            .append_blocking(pty_event(2))
+            .expect("append after recovery");
+        assert_eq!(recovered.sequence, 2);
+
+        let reader = LedgerReader::new(config.root_path.clone());
+        let sequences: Vec<u64> = reader
+            .read_epoch("failed-write")
+            .expect("read recovered epoch")
+            .iter()
+            .map(|event| event.sequence)
+            .collect();
+        assert_eq!(sequences, vec![1, 2]);
+        assert!(reader.verify_epoch("failed-write").expect("verify epoch"));
+    }
+}
@@ -1,9 +1,12 @@
 pub mod agent;
+pub mod clock;
 pub mod config;
+pub mod config_watcher;
 pub mod director;
 pub mod executor;
 pub mod metrics;
 pub mod router;
+pub mod stream;
 pub mod territory;
 
 #[allow(dead_code)]
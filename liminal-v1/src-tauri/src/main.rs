@@ -2,12 +2,16 @@
 
 mod agent;
 mod config;
+mod config_watcher;
 mod director;
 mod executor;
 mod metrics;
 mod router;
 mod territory;
 
+#[allow(dead_code)]
+mod clock;
+
 #[allow(dead_code)]
 mod consensus;
 
@@ -17,32 +21,41 @@ mod health;
 #[allow(dead_code)]
 mod ledger;
 
-use agent::{AgentEvent, AgentEventSender, AgentProcess};
+#[allow(dead_code)]
+mod stream;
+
+use agent::{AgentEvent, AgentEventSender, AgentProcess, AGENT_EXIT_EVENT};
 use config::{AppConfig, LedgerConfig};
+use config_watcher::{ConfigDiff, ConfigWatcher};
 use consensus::ConsensusBroker;
-use director::{DirectorAgent, RunbookSummary, TurnUpdate};
-use executor::MaintenanceExecutor;
-use metrics::{MetricsCollector, MetricsSnapshot, PerformanceMetrics};
+use director::{DirectorAgent, RunbookSummary, TurnStatus, TurnUpdate};
+use executor::{MaintenanceExecutor, SpawnError};
+use metrics::{MetricsCollector, MetricsSnapshot, MetricsSubsystem, PerformanceMetrics};
 
 #[allow(unused_imports)]
-use health::HealthMonitor;
+use health::{HealthCategory, HealthMonitor, HealthSummary};
 
 #[allow(unused_imports)]
 use ledger::{
-    EventEnvelope, HealthEvent, LeaseReplayState, LedgerEvent, LedgerReader, LedgerWriter,
-    PtyEvent, ReplayCoordinator, ReplayOutcome, RouterReplayState, StateCheckpoint,
+    CompactionReport, EpochInfo, EpochStats, EventEnvelope, EventFilter, ExportFormat, HealthEvent,
+    HeatParameters, LeaseReplayState, LedgerEvent, LedgerReader, LedgerWriter, PtyEvent,
+    ReplayCoordinator, ReplayOutcome, RouterReplayState, StateCheckpoint,
 };
 use router::{Message, Priority, UnifiedMessageRouter};
 use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tauri::async_runtime::JoinHandle;
-use tauri::Emitter;
-use territory::{LeaseDecision, LeaseRequest, TerritoryManager};
-use tokio::sync::{mpsc, Mutex as AsyncMutex};
+use tauri::{Emitter, Manager};
+use territory::{
+    LeaseDecision, LeaseRequest, LeaseSnapshot, QueuedLeaseInfo, TerritoryManager, TerritoryPolicy,
+};
+use tokio::sync::{broadcast, mpsc, Mutex as AsyncMutex};
 
 type SharedHealthMonitor = Arc<AsyncMutex<HealthMonitor>>;
 
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(2);
+
 struct MetricsStreamState {
     handle: AsyncMutex<Option<JoinHandle<()>>>,
     last_checkpoint: Arc<AsyncMutex<Option<Instant>>>,
@@ -57,8 +70,7 @@ fn submit_checkpoint_task(
     router_state: RouterReplayState,
     lease_state: LeaseReplayState,
     snapshot: MetricsSnapshot,
-) {
-    let maintenance = maintenance.clone();
+) -> Result<(), SpawnError> {
     maintenance.spawn(async move {
         let checkpoint = StateCheckpoint {
             checkpoint_id,
@@ -66,6 +78,7 @@ fn submit_checkpoint_task(
             router: router_state,
             leases: lease_state,
             metrics: snapshot,
+            baseline: None,
         };
         let start = Instant::now();
         if ledger.record_checkpoint(checkpoint).await.is_ok() {
@@ -73,7 +86,77 @@ fn submit_checkpoint_task(
         } else {
             metrics.record_ledger_error();
         }
-    });
+    })
+}
+
+async fn shutdown_gracefully(router: &UnifiedMessageRouter, ledger: &LedgerWriter) {
+    let undelivered = router.drain(SHUTDOWN_DRAIN_TIMEOUT).await;
+    if undelivered > 0 {
+        println!("[Shutdown]: {} message(s) left undelivered", undelivered);
+    }
+    if let Err(err) = ledger.close().await {
+        println!("[Shutdown]: failed to close ledger: {}", err);
+    }
+}
+
+fn checkpoint_states(snapshot: &MetricsSnapshot) -> (RouterReplayState, LeaseReplayState) {
+    let router_state = RouterReplayState {
+        total_dispatched: snapshot.performance.total_messages_routed,
+        last_priority: snapshot.router.last_dispatched_priority.clone(),
+        queue_depths: priority_vec_from_map(&snapshot.router.queue_depths),
+        director_override_dispatched: snapshot.router.director_override_messages,
+        total_rate_limited: snapshot.router.rate_limited_messages,
+    };
+    let mut lease_state = LeaseReplayState::default();
+    lease_state.deferrals = snapshot.leases.deferrals;
+    lease_state.overrides = snapshot.leases.overrides;
+    lease_state.escalations = snapshot.leases.escalations;
+    (router_state, lease_state)
+}
+
+fn checkpoint_clock() -> (String, u64) {
+    let captured_at_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64;
+    (format!("checkpoint-{}", captured_at_ms), captured_at_ms)
+}
+
+async fn record_checkpoint_now(
+    ledger: &LedgerWriter,
+    metrics: &MetricsCollector,
+    router: &UnifiedMessageRouter,
+    territory_manager: &TerritoryManager,
+) -> Result<String, String> {
+    let snapshot = metrics.get_snapshot();
+    let (mut router_state, mut lease_state) = checkpoint_states(&snapshot);
+    router_state.queue_depths = router.current_queue_depths().await.to_vec();
+    lease_state.active = territory_manager
+        .lease_records()
+        .await
+        .into_iter()
+        .map(|record| (record.resource_id.clone(), record))
+        .collect();
+    let (checkpoint_id, captured_at_ms) = checkpoint_clock();
+    let checkpoint = StateCheckpoint {
+        checkpoint_id: checkpoint_id.clone(),
+        captured_at_ms,
+        router: router_state,
+        leases: lease_state,
+        metrics: snapshot,
+        baseline: None,
+    };
+    let start = Instant::now();
+    match ledger.record_checkpoint(checkpoint).await {
+        Ok(_) => {
+            metrics.record_ledger_append(start.elapsed());
+            Ok(checkpoint_id)
+        }
+        Err(err) => {
+            metrics.record_ledger_error();
+            Err(err.to_string())
+        }
+    }
 }
 
 impl MetricsStreamState {
@@ -144,28 +227,10 @@ impl MetricsStreamState {
                     .map(|previous| previous.elapsed() >= checkpoint_interval)
                     .unwrap_or(true);
                 if should_checkpoint {
-                    let router_state = RouterReplayState {
-                        total_dispatched: snapshot.performance.total_messages_routed,
-                        last_priority: snapshot.router.last_dispatched_priority.clone(),
-                        queue_depths: priority_vec_from_map(&snapshot.router.queue_depths),
-                    };
-                    let mut lease_state = LeaseReplayState::default();
-                    lease_state.deferrals = snapshot.leases.deferrals;
-                    lease_state.overrides = snapshot.leases.overrides;
-                    lease_state.escalations = snapshot.leases.escalations;
+                    let (router_state, lease_state) = checkpoint_states(&snapshot);
                     let checkpoint_metrics = snapshot.clone();
-                    let checkpoint_id = format!(
-                        "checkpoint-{}",
-                        SystemTime::now()
-                            .duration_since(UNIX_EPOCH)
-                            .unwrap_or_default()
-                            .as_millis()
-                    );
-                    let captured_at_ms = SystemTime::now()
-                        .duration_since(UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_millis() as u64;
-                    submit_checkpoint_task(
+                    let (checkpoint_id, captured_at_ms) = checkpoint_clock();
+                    match submit_checkpoint_task(
                         &maintenance_clone,
                         ledger_clone.clone(),
                         metrics_clone.clone(),
@@ -174,8 +239,10 @@ impl MetricsStreamState {
                         router_state,
                         lease_state,
                         checkpoint_metrics,
-                    );
-                    *last_checkpoint = Some(Instant::now());
+                    ) {
+                        Ok(()) => *last_checkpoint = Some(Instant::now()),
+                        Err(err) => println!("[MetricsStream]: skipping checkpoint: {}", err),
+                    }
                 }
                 tokio::time::sleep(Duration::from_secs(1)).await;
             }
@@ -192,9 +259,8 @@ impl Default for MetricsStreamState {
 
 #[tauri::command]
 async fn start_scenario(
-    router: tauri::State<'_, UnifiedMessageRouter>,
+    router: tauri::State<'_, Arc<UnifiedMessageRouter>>,
     territory_manager: tauri::State<'_, TerritoryManager>,
-    metrics: tauri::State<'_, MetricsCollector>,
     app_handle: tauri::AppHandle,
 ) -> Result<(), String> {
     println!("V1 Hardcoded Scenario Started!");
@@ -205,7 +271,6 @@ async fn start_scenario(
 
     // --- Agent A's Turn ---
     // 1. Acquire lease
-    let lease_start = Instant::now();
     let decision = territory_manager
         .acquire_lease(LeaseRequest::new(
             agent_a_id.clone(),
@@ -217,9 +282,6 @@ async fn start_scenario(
         decision,
         LeaseDecision::Granted(_) | LeaseDecision::Overridden { .. }
     );
-    if acquired {
-        metrics.record_lease_acquisition(lease_start.elapsed().as_millis() as f64);
-    }
     app_handle
         .emit(
             "agent_status",
@@ -237,12 +299,12 @@ async fn start_scenario(
             priority: Priority::Coordinate,
             sender: agent_a_id.clone(),
             recipient: agent_b_id.clone(),
+            message_id: None,
+            trace_id: None,
         };
 
         // Route the message
-        let route_start = Instant::now();
         let _ = router.route_message(msg.clone()).await;
-        metrics.record_message_routing(route_start.elapsed().as_millis() as f64);
 
         app_handle
             .emit(
@@ -265,7 +327,6 @@ async fn start_scenario(
 
     // --- Agent B's Turn ---
     // 1. Acquire lease
-    let lease_start_b = Instant::now();
     let decision_b = territory_manager
         .acquire_lease(LeaseRequest::new(
             agent_b_id.clone(),
@@ -277,9 +338,6 @@ async fn start_scenario(
         decision_b,
         LeaseDecision::Granted(_) | LeaseDecision::Overridden { .. }
     );
-    if acquired_b {
-        metrics.record_lease_acquisition(lease_start_b.elapsed().as_millis() as f64);
-    }
     app_handle
         .emit(
             "agent_status",
@@ -297,12 +355,12 @@ async fn start_scenario(
             priority: Priority::Coordinate,
             sender: agent_b_id.clone(),
             recipient: agent_a_id.clone(),
+            message_id: None,
+            trace_id: None,
         };
 
         // Route the message
-        let route_start = Instant::now();
         let _ = router.route_message(msg.clone()).await;
-        metrics.record_message_routing(route_start.elapsed().as_millis() as f64);
 
         app_handle
             .emit(
@@ -337,7 +395,7 @@ async fn get_agent_status(agent_id: String) -> String {
 
 #[tauri::command]
 async fn start_pty_scenario(
-    router: tauri::State<'_, UnifiedMessageRouter>,
+    router: tauri::State<'_, Arc<UnifiedMessageRouter>>,
     territory_manager: tauri::State<'_, TerritoryManager>,
     agents: tauri::State<'_, Arc<Mutex<HashMap<String, AgentProcess>>>>,
     metrics: tauri::State<'_, MetricsCollector>,
@@ -414,6 +472,8 @@ async fn start_pty_scenario(
             priority: Priority::Coordinate,
             sender: agent_a_id.clone(),
             recipient: agent_b_id.clone(),
+            message_id: None,
+            trace_id: None,
         };
 
         let _ = router.route_message(msg.clone()).await;
@@ -470,6 +530,8 @@ async fn start_pty_scenario(
             priority: Priority::Coordinate,
             sender: agent_b_id.clone(),
             recipient: agent_a_id.clone(),
+            message_id: None,
+            trace_id: None,
         };
 
         let _ = router.route_message(msg.clone()).await;
@@ -513,6 +575,116 @@ async fn get_metrics_snapshot(
     Ok(metrics.get_snapshot())
 }
 
+#[tauri::command]
+async fn health_summary(
+    metrics: tauri::State<'_, MetricsCollector>,
+    health_monitor: tauri::State<'_, SharedHealthMonitor>,
+) -> Result<HealthSummary, String> {
+    let snapshot = metrics.get_snapshot();
+    let monitor = health_monitor.lock().await;
+    Ok(monitor.summarize(&snapshot))
+}
+
+#[tauri::command]
+async fn acknowledge_health_alert(
+    category: HealthCategory,
+    health_monitor: tauri::State<'_, SharedHealthMonitor>,
+) -> Result<bool, String> {
+    let mut monitor = health_monitor.lock().await;
+    Ok(monitor.acknowledge(category))
+}
+
+#[derive(Clone, serde::Serialize)]
+struct AgentOutputLag {
+    agent_id: String,
+    skipped: u64,
+}
+
+#[derive(Default)]
+struct AgentOutputRelays {
+    handles: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl AgentOutputRelays {
+    fn follow(
+        &self,
+        agent_id: String,
+        mut output: broadcast::Receiver<AgentEvent>,
+        app_handle: tauri::AppHandle,
+    ) {
+        let mut handles = self.handles.lock().unwrap();
+        if handles
+            .get(&agent_id)
+            .is_some_and(|handle| !handle.inner().is_finished())
+        {
+            return;
+        }
+        let relay_agent_id = agent_id.clone();
+        let handle = tauri::async_runtime::spawn(async move {
+            loop {
+                let emitted = match output.recv().await {
+                    Ok(line) => app_handle.emit("agent_output", line),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => app_handle.emit(
+                        "agent_output_lagged",
+                        AgentOutputLag {
+                            agent_id: relay_agent_id.clone(),
+                            skipped,
+                        },
+                    ),
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+                if let Err(err) = emitted {
+                    println!("[AgentOutput emit error]: {}", err);
+                    break;
+                }
+            }
+        });
+        handles.insert(agent_id, handle);
+    }
+
+    fn unfollow(&self, agent_id: &str) -> bool {
+        match self.handles.lock().unwrap().remove(agent_id) {
+            Some(handle) => {
+                handle.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn stop_all(&self) {
+        for (_, handle) in self.handles.lock().unwrap().drain() {
+            handle.abort();
+        }
+    }
+}
+
+#[tauri::command]
+async fn agent_follow_output(
+    agent_id: String,
+    agents: tauri::State<'_, Arc<Mutex<HashMap<String, AgentProcess>>>>,
+    relays: tauri::State<'_, AgentOutputRelays>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    let output = {
+        let agents_map = agents.lock().unwrap();
+        agents_map
+            .get(&agent_id)
+            .map(|agent| agent.subscribe_output())
+            .ok_or_else(|| format!("Unknown agent: {}", agent_id))?
+    };
+    relays.follow(agent_id, output, app_handle);
+    Ok(())
+}
+
+#[tauri::command]
+async fn agent_unfollow_output(
+    agent_id: String,
+    relays: tauri::State<'_, AgentOutputRelays>,
+) -> Result<bool, String> {
+    Ok(relays.unfollow(&agent_id))
+}
+
 #[tauri::command]
 async fn start_metrics_stream(
     metrics: tauri::State<'_, MetricsCollector>,
@@ -544,7 +716,7 @@ async fn start_metrics_stream(
 
 #[tauri::command]
 async fn simulate_router_load(
-    router: tauri::State<'_, UnifiedMessageRouter>,
+    router: tauri::State<'_, Arc<UnifiedMessageRouter>>,
 ) -> Result<(), String> {
     let priorities = [
         Priority::Info,
@@ -560,11 +732,13 @@ async fn simulate_router_load(
             priority,
             sender: format!("synthetic_sender_{}", index % 5),
             recipient: format!("synthetic_recipient_{}", index % 3),
+            message_id: None,
+            trace_id: None,
         };
         router
             .route_message(message)
             .await
-            .map_err(|err| format!("failed to route synthetic message: {:?}", err))?;
+            .map_err(|err| format!("failed to route synthetic message: {err}"))?;
     }
     Ok(())
 }
@@ -621,16 +795,41 @@ async fn simulate_lease_contention(
 }
 
 #[tauri::command]
-async fn reset_metrics(metrics: tauri::State<'_, MetricsCollector>) -> Result<(), String> {
-    metrics.reset_metrics();
+async fn reset_metrics(
+    metrics: tauri::State<'_, MetricsCollector>,
+    target: Option<MetricsSubsystem>,
+) -> Result<(), String> {
+    match target {
+        Some(subsystem) => metrics.reset_subsystem(subsystem),
+        None => metrics.reset_metrics(),
+    }
     Ok(())
 }
 
+#[tauri::command]
+async fn ledger_checkpoint_now(
+    ledger_writer: tauri::State<'_, LedgerWriter>,
+    metrics: tauri::State<'_, MetricsCollector>,
+    router: tauri::State<'_, Arc<UnifiedMessageRouter>>,
+    territory_manager: tauri::State<'_, TerritoryManager>,
+) -> Result<String, String> {
+    let checkpoint_id = record_checkpoint_now(
+        ledger_writer.inner(),
+        metrics.inner(),
+        router.inner(),
+        territory_manager.inner(),
+    )
+    .await?;
+    println!("[Ledger]: Recorded checkpoint {}", checkpoint_id);
+    Ok(checkpoint_id)
+}
+
 #[tauri::command]
 async fn ledger_replay(
     ledger_reader: tauri::State<'_, LedgerReader>,
     ledger_writer: tauri::State<'_, LedgerWriter>,
     metrics: tauri::State<'_, MetricsCollector>,
+    territory_manager: tauri::State<'_, TerritoryManager>,
     epoch_id: Option<String>,
 ) -> Result<ReplayOutcome, String> {
     let epoch = epoch_id.unwrap_or_else(|| ledger_writer.epoch_id());
@@ -644,12 +843,153 @@ async fn ledger_replay(
             return Err(err.to_string());
         }
     }
-    let coordinator = ReplayCoordinator::new(ledger_reader.inner().clone());
+    let coordinator = ReplayCoordinator::new(ledger_reader.inner().clone())
+        .with_heat_parameters(HeatParameters::from(&territory_manager.policy()));
     coordinator
         .replay_epoch(&epoch)
         .map_err(|err| err.to_string())
 }
 
+#[derive(serde::Serialize)]
+struct LeaseListing {
+    lease_id: u64,
+    resource_id: String,
+    holder_id: String,
+    holder_role: Option<String>,
+    priority: String,
+    holder_progress: f32,
+    held_ms: u64,
+    expires_in_ms: u64,
+    defer_count: u32,
+    override_count: u32,
+    tags: Vec<String>,
+}
+
+impl LeaseListing {
+    fn from_snapshot(lease: LeaseSnapshot, now: Instant) -> Self {
+        Self {
+            lease_id: lease.lease_id.as_u64(),
+            resource_id: lease.resource_id,
+            holder_id: lease.holder_id,
+            holder_role: lease.holder_role,
+            priority: lease.priority.as_str().to_string(),
+            holder_progress: lease.holder_progress,
+            held_ms: now.saturating_duration_since(lease.granted_at).as_millis() as u64,
+            expires_in_ms: lease.expires_at.saturating_duration_since(now).as_millis() as u64,
+            defer_count: lease.defer_count,
+            override_count: lease.override_count,
+            tags: lease.tags,
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct QueueListing {
+    request_id: u64,
+    resource_id: String,
+    agent_id: String,
+    priority: String,
+    queue_position: usize,
+    age_ms: u64,
+    deferred_for_ms: Option<u64>,
+    state: String,
+    negotiation_round: u32,
+}
+
+impl QueueListing {
+    fn from_info(entry: QueuedLeaseInfo, now: Instant) -> Self {
+        Self {
+            request_id: entry.request_id.as_u64(),
+            resource_id: entry.resource_id,
+            agent_id: entry.agent_id,
+            priority: entry.priority.as_str().to_string(),
+            queue_position: entry.queue_position,
+            age_ms: entry.age.as_millis() as u64,
+            deferred_for_ms: entry
+                .deferred_until
+                .map(|until| until.saturating_duration_since(now).as_millis() as u64),
+            state: entry.state.as_str().to_string(),
+            negotiation_round: entry.negotiation_round,
+        }
+    }
+}
+
+#[tauri::command]
+async fn territory_leases(
+    territory_manager: tauri::State<'_, TerritoryManager>,
+    tag: Option<String>,
+) -> Result<Vec<LeaseListing>, String> {
+    let now = Instant::now();
+    let leases = match tag {
+        Some(tag) => territory_manager.list_leases_tagged(&tag).await,
+        None => territory_manager.list_leases().await,
+    };
+    Ok(leases
+        .into_iter()
+        .map(|lease| LeaseListing::from_snapshot(lease, now))
+        .collect())
+}
+
+#[tauri::command]
+async fn territory_force_release(
+    territory_manager: tauri::State<'_, TerritoryManager>,
+    resource_id: String,
+    reason: String,
+) -> Result<LeaseListing, String> {
+    if !territory_manager.policy().allow_operator_override {
+        return Err("operator overrides are disabled by territory policy".to_string());
+    }
+    let lease = territory_manager
+        .force_release(&resource_id, &reason)
+        .await
+        .ok_or_else(|| format!("no active lease on {}", resource_id))?;
+    println!(
+        "[Territory]: Operator force-released {} from {} ({})",
+        resource_id, lease.holder_id, reason
+    );
+    Ok(LeaseListing::from_snapshot(lease, Instant::now()))
+}
+
+#[tauri::command]
+async fn territory_queue(
+    territory_manager: tauri::State<'_, TerritoryManager>,
+    resource_id: String,
+) -> Result<Vec<QueueListing>, String> {
+    let now = Instant::now();
+    Ok(territory_manager
+        .list_queue(&resource_id)
+        .await
+        .into_iter()
+        .map(|entry| QueueListing::from_info(entry, now))
+        .collect())
+}
+
+#[derive(serde::Serialize)]
+struct FairnessListing {
+    agent_id: String,
+    grants: u64,
+    total_wait_ms: u64,
+    average_wait_ms: u64,
+}
+
+#[tauri::command]
+async fn territory_fairness(
+    territory_manager: tauri::State<'_, TerritoryManager>,
+) -> Result<Vec<FairnessListing>, String> {
+    Ok(territory_manager
+        .fairness_report()
+        .await
+        .agents
+        .into_iter()
+        .map(|entry| FairnessListing {
+            agent_id: entry.agent_id,
+            grants: entry.grants,
+            total_wait_ms: entry.total_wait.as_millis() as u64,
+            average_wait_ms: entry.average_wait.as_millis() as u64,
+        })
+        .collect())
+}
+
 #[derive(serde::Serialize)]
 struct LedgerStatus {
     epoch_id: String,
@@ -657,44 +997,172 @@ struct LedgerStatus {
     verified: bool,
 }
 
+fn read_ledger_status(reader: &LedgerReader, epoch: String) -> Result<LedgerStatus, String> {
+    let events = reader.read_epoch(&epoch).map_err(|err| err.to_string())?;
+    let verified = reader.verify_epoch(&epoch).map_err(|err| err.to_string())?;
+    Ok(LedgerStatus {
+        epoch_id: epoch,
+        event_count: events.len(),
+        verified,
+    })
+}
+
 #[tauri::command]
 async fn ledger_status(
     ledger_reader: tauri::State<'_, LedgerReader>,
     ledger_writer: tauri::State<'_, LedgerWriter>,
 ) -> Result<LedgerStatus, String> {
-    let epoch = ledger_writer.epoch_id();
-    let events = ledger_reader
-        .inner()
-        .read_epoch(&epoch)
-        .map_err(|err| err.to_string())?;
-    let verified = ledger_reader
-        .verify_epoch(&epoch)
-        .map_err(|err| err.to_string())?;
-    Ok(LedgerStatus {
-        epoch_id: epoch,
-        event_count: events.len(),
-        verified,
+    read_ledger_status(ledger_reader.inner(), ledger_writer.epoch_id())
+}
+
+#[derive(serde::Serialize)]
+struct HeatListing {
+    hottest_resource: Option<String>,
+    hottest_score: f64,
+    tracked: usize,
+}
+
+#[derive(serde::Serialize)]
+struct SystemSnapshot {
+    metrics: MetricsSnapshot,
+    leases: Vec<LeaseListing>,
+    queue: Vec<QueueListing>,
+    ledger: LedgerStatus,
+    heat: HeatListing,
+}
+
+async fn capture_system_snapshot(
+    metrics: &MetricsCollector,
+    territory_manager: &TerritoryManager,
+    ledger_reader: &LedgerReader,
+    ledger_writer: &LedgerWriter,
+) -> Result<SystemSnapshot, String> {
+    ledger_writer.flush().await.map_err(|err| err.to_string())?;
+    let now = Instant::now();
+    let inventory = territory_manager.inventory().await;
+    let heat = territory_manager.heat_snapshot().await;
+    let metrics = metrics.get_snapshot();
+    let ledger = read_ledger_status(ledger_reader, ledger_writer.epoch_id())?;
+    Ok(SystemSnapshot {
+        metrics,
+        leases: inventory
+            .leases
+            .into_iter()
+            .map(|lease| LeaseListing::from_snapshot(lease, now))
+            .collect(),
+        queue: inventory
+            .queued
+            .into_iter()
+            .map(|entry| QueueListing::from_info(entry, now))
+            .collect(),
+        ledger,
+        heat: HeatListing {
+            hottest_resource: heat.hottest_resource,
+            hottest_score: heat.hottest_score,
+            tracked: heat.tracked,
+        },
     })
 }
 
+#[tauri::command]
+async fn system_snapshot(
+    metrics: tauri::State<'_, MetricsCollector>,
+    territory_manager: tauri::State<'_, TerritoryManager>,
+    ledger_reader: tauri::State<'_, LedgerReader>,
+    ledger_writer: tauri::State<'_, LedgerWriter>,
+) -> Result<SystemSnapshot, String> {
+    capture_system_snapshot(
+        metrics.inner(),
+        territory_manager.inner(),
+        ledger_reader.inner(),
+        ledger_writer.inner(),
+    )
+    .await
+}
+
 #[tauri::command]
 async fn ledger_tail(
     ledger_reader: tauri::State<'_, LedgerReader>,
     ledger_writer: tauri::State<'_, LedgerWriter>,
     limit: Option<usize>,
     epoch_id: Option<String>,
+    since_ms: Option<u64>,
+    until_ms: Option<u64>,
 ) -> Result<Vec<EventEnvelope>, String> {
     let epoch = epoch_id.unwrap_or_else(|| ledger_writer.epoch_id());
-    let mut events = ledger_reader
+    ledger_reader
+        .inner()
+        .read_window(&epoch, since_ms, until_ms, limit)
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn ledger_query(
+    ledger_reader: tauri::State<'_, LedgerReader>,
+    ledger_writer: tauri::State<'_, LedgerWriter>,
+    filter: EventFilter,
+    epoch_id: Option<String>,
+) -> Result<Vec<EventEnvelope>, String> {
+    let epoch = epoch_id.unwrap_or_else(|| ledger_writer.epoch_id());
+    ledger_reader
+        .inner()
+        .query_epoch(&epoch, filter)
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn ledger_export(
+    ledger_reader: tauri::State<'_, LedgerReader>,
+    ledger_writer: tauri::State<'_, LedgerWriter>,
+    path: String,
+    format: ExportFormat,
+    epoch_id: Option<String>,
+) -> Result<usize, String> {
+    let epoch = epoch_id.unwrap_or_else(|| ledger_writer.epoch_id());
+    let file = std::fs::File::create(&path).map_err(|err| err.to_string())?;
+    ledger_reader
         .inner()
-        .read_epoch(&epoch)
+        .export_epoch(&epoch, format, file)
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn ledger_stats(
+    ledger_reader: tauri::State<'_, LedgerReader>,
+    ledger_writer: tauri::State<'_, LedgerWriter>,
+    epoch_id: Option<String>,
+) -> Result<EpochStats, String> {
+    let epoch = epoch_id.unwrap_or_else(|| ledger_writer.epoch_id());
+    ledger_reader
+        .inner()
+        .epoch_stats(&epoch)
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn ledger_list_epochs(
+    ledger_reader: tauri::State<'_, LedgerReader>,
+) -> Result<Vec<EpochInfo>, String> {
+    ledger_reader
+        .inner()
+        .list_epochs()
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+async fn ledger_compact_epoch(
+    ledger_writer: tauri::State<'_, LedgerWriter>,
+    epoch_id: String,
+) -> Result<CompactionReport, String> {
+    let report = ledger_writer
+        .compact_epoch(&epoch_id)
+        .await
         .map_err(|err| err.to_string())?;
-    if let Some(limit) = limit {
-        if events.len() > limit {
-            events = events.split_off(events.len() - limit);
-        }
-    }
-    Ok(events)
+    println!(
+        "[Ledger]: Compacted epoch {} from checkpoint {} ({} events removed)",
+        report.epoch_id, report.checkpoint_id, report.events_removed
+    );
+    Ok(report)
 }
 
 #[tauri::command]
@@ -743,26 +1211,139 @@ async fn director_resume_execution(
     director.resume_execution().await.map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+async fn director_cancel_turn(
+    director: tauri::State<'_, Arc<DirectorAgent>>,
+    turn_id: usize,
+) -> Result<(), String> {
+    director
+        .cancel_turn(turn_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[derive(serde::Serialize)]
+struct DependencyGraphListing {
+    edges: Vec<(usize, usize)>,
+    levels: Vec<Vec<usize>>,
+}
+
+#[tauri::command]
+async fn director_get_dependency_graph(
+    director: tauri::State<'_, Arc<DirectorAgent>>,
+) -> Result<Option<DependencyGraphListing>, String> {
+    Ok(director
+        .get_runbook()
+        .map(|runbook| DependencyGraphListing {
+            edges: runbook.dependency_edges(),
+            levels: runbook.topological_levels(),
+        }))
+}
+
+#[derive(serde::Serialize)]
+struct TurnRunListing {
+    turn_id: usize,
+    status: TurnStatus,
+    artifacts: Vec<String>,
+    output_log: String,
+    duration_ms: u64,
+    error_message: Option<String>,
+}
+
+#[tauri::command]
+async fn director_run_turn(
+    director: tauri::State<'_, Arc<DirectorAgent>>,
+    turn_id: usize,
+) -> Result<TurnRunListing, String> {
+    let result = director
+        .run_single_turn(turn_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(TurnRunListing {
+        turn_id: result.turn_id,
+        status: result.status,
+        artifacts: result
+            .artifacts
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect(),
+        output_log: result.output_log.display().to_string(),
+        duration_ms: result.duration.as_millis() as u64,
+        error_message: result.error_message,
+    })
+}
+
+fn spawn_config_reloader(
+    watcher: ConfigWatcher,
+    handle: tauri::AppHandle,
+    territory_manager: TerritoryManager,
+    health_monitor: SharedHealthMonitor,
+) {
+    let mut updates = watcher.subscribe();
+    tauri::async_runtime::spawn(async move {
+        let mut previous = watcher.current();
+        while updates.changed().await.is_ok() {
+            let next = updates.borrow_and_update().clone();
+            let diff = ConfigDiff::between(&previous, &next);
+            if diff.router {
+                handle
+                    .state::<Arc<UnifiedMessageRouter>>()
+                    .update_config(next.router.as_ref())
+                    .await;
+            }
+            if diff.territory {
+                territory_manager
+                    .update_policy(TerritoryPolicy::from_config(next.territory.as_ref()))
+                    .await;
+            }
+            if diff.health {
+                health_monitor
+                    .lock()
+                    .await
+                    .update_config(next.health_monitoring_kpis.as_ref());
+            }
+            if diff.ledger {
+                println!("[ConfigWatcher]: ledger changes take effect after restart");
+            }
+            if !diff.is_empty() {
+                println!("[ConfigWatcher]: applied {:?}", diff);
+            }
+            previous = next;
+        }
+        drop(watcher);
+    });
+}
+
 fn main() {
-    let app_config = AppConfig::load();
+    let app_config = AppConfig::load_validated().unwrap_or_else(|err| {
+        println!("[Config error]: {}; falling back to defaults", err);
+        AppConfig::default().with_director_exemption()
+    });
     let ledger_config = app_config.ledger.clone().unwrap_or_default();
-    let ledger_writer =
-        LedgerWriter::new(&ledger_config).expect("failed to initialize ledger writer");
-    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+    let ledger_writer = LedgerWriter::new(&ledger_config).unwrap_or_else(|err| {
+        println!("[Ledger error]: {}; continuing with a degraded ledger", err);
+        LedgerWriter::degraded(&ledger_config)
+    });
+    let ledger_reader = LedgerReader::with_encryption_key(
+        ledger_config.root_path.clone(),
+        ledger_config.encryption_key,
+    );
     let metrics_collector = MetricsCollector::new();
+    ledger_writer.set_metrics(metrics_collector.clone());
     let available_workers = std::thread::available_parallelism()
         .map(|count| count.get())
         .unwrap_or(4);
     let maintenance_workers = available_workers.clamp(2, 8);
-    let maintenance_executor = MaintenanceExecutor::new(maintenance_workers);
+    let maintenance_executor =
+        MaintenanceExecutor::with_metrics(maintenance_workers, metrics_collector.clone());
     let consensus_broker =
         ConsensusBroker::new(Some(ledger_writer.clone()), metrics_collector.clone(), 0.66);
-    let router = UnifiedMessageRouter::with_settings_ledger_and_consensus(
+    let router = Arc::new(UnifiedMessageRouter::with_settings_ledger_and_consensus(
         metrics_collector.clone(),
         app_config.router.as_ref(),
         Some(ledger_writer.clone()),
         Some(consensus_broker.clone()),
-    );
+    ));
     let territory_manager = TerritoryManager::new_with_ledger(
         metrics_collector.clone(),
         app_config.territory.as_ref(),
@@ -773,12 +1354,11 @@ fn main() {
         territory_manager.set_maintenance_executor(maintenance_executor.clone()),
     );
     let working_dir = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
-    let director_router = UnifiedMessageRouter::with_metrics(metrics_collector.clone());
-    let director_agent = Arc::new(DirectorAgent::new(
-        working_dir,
-        metrics_collector.clone(),
-        director_router,
-    ));
+    let director_agent = Arc::new(
+        DirectorAgent::new(working_dir, metrics_collector.clone(), Arc::clone(&router))
+            .with_ledger(ledger_writer.clone())
+            .with_territory(territory_manager.clone()),
+    );
     let agents: Arc<Mutex<HashMap<String, AgentProcess>>> = Arc::new(Mutex::new(HashMap::new()));
     let (event_tx, event_rx) = mpsc::unbounded_channel::<AgentEvent>();
     let event_sender = AgentEventSender::new(event_tx);
@@ -787,6 +1367,14 @@ fn main() {
     let health_monitor: SharedHealthMonitor = Arc::new(AsyncMutex::new(HealthMonitor::new(
         app_config.health_monitoring_kpis.as_ref(),
     )));
+    let config_watcher = AppConfig::resolve_path().and_then(|path| {
+        ConfigWatcher::new(path, app_config.clone())
+            .map_err(|err| println!("[ConfigWatcher error]: {}", err))
+            .ok()
+    });
+    let territory_for_setup = territory_manager.clone();
+    let territory_for_events = territory_manager.clone();
+    let health_for_setup = health_monitor.clone();
     let metrics_stream_state = MetricsStreamState::new();
     let app_config_state = app_config.clone();
     let ledger_for_setup = ledger_writer.clone();
@@ -799,18 +1387,48 @@ fn main() {
         .manage(metrics_collector)
         .manage(event_sender)
         .manage(metrics_stream_state)
+        .manage(AgentOutputRelays::default())
         .manage(health_monitor.clone())
         .manage(app_config_state)
         .manage(ledger_writer.clone())
         .manage(ledger_reader.clone())
         .manage(maintenance_executor.clone())
-        .setup(move |_app| {
+        .setup(move |app| {
+            if let Some(watcher) = config_watcher {
+                spawn_config_reloader(
+                    watcher,
+                    app.handle().clone(),
+                    territory_for_setup,
+                    health_for_setup,
+                );
+            }
+            tauri::async_runtime::spawn(ledger_for_setup.clone().run_rotation_ticker());
+            let exit_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    exit_handle.exit(0);
+                }
+            });
             let mut rx = event_rx.take().expect("agent event receiver missing");
             let metrics = metrics_for_setup.clone();
             let ledger = ledger_for_setup.clone();
+            let territory = territory_for_events;
             tauri::async_runtime::spawn(async move {
                 while let Some(event) = rx.recv().await {
                     metrics.record_agent_event(&event.agent_id, event.event_name.as_deref());
+                    if let Some(signal) = event.signal.as_ref() {
+                        metrics.record_agent_signal(&event.agent_id, signal);
+                    }
+                    if event.event_name.as_deref() == Some(AGENT_EXIT_EVENT) {
+                        let released = territory.release_all_for_agent(&event.agent_id).await;
+                        if !released.is_empty() {
+                            println!(
+                                "[AgentExit {}]: released {} lease(s)",
+                                event.agent_id,
+                                released.len()
+                            );
+                        }
+                    }
                     let name = event
                         .event_name
                         .clone()
@@ -838,35 +1456,64 @@ fn main() {
             start_scenario,
             start_pty_scenario,
             get_agent_status,
+            agent_follow_output,
+            agent_unfollow_output,
             get_performance_metrics,
             get_metrics_snapshot,
+            health_summary,
+            acknowledge_health_alert,
             start_metrics_stream,
             simulate_router_load,
             simulate_lease_contention,
             reset_metrics,
             ledger_replay,
+            ledger_checkpoint_now,
             ledger_status,
             ledger_tail,
+            ledger_query,
+            ledger_export,
+            ledger_stats,
+            ledger_compact_epoch,
+            ledger_list_epochs,
+            territory_leases,
+            territory_force_release,
+            territory_queue,
+            territory_fairness,
+            system_snapshot,
             director_load_runbook,
             director_start_runbook,
             director_get_turn_status,
             director_get_summary,
             director_pause_execution,
-            director_resume_execution
+            director_resume_execution,
+            director_cancel_turn,
+            director_run_turn,
+            director_get_dependency_graph
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            if let tauri::RunEvent::Exit = event {
+                app_handle.state::<AgentOutputRelays>().stop_all();
+                let router = app_handle.state::<Arc<UnifiedMessageRouter>>();
+                let ledger = app_handle.state::<LedgerWriter>();
+                tauri::async_runtime::block_on(shutdown_gracefully(router.inner(), ledger.inner()));
+            }
+        });
 }
 
 #[cfg(test)]
 mod tests {
-    use super::submit_checkpoint_task;
+    use super::{capture_system_snapshot, record_checkpoint_now, submit_checkpoint_task};
     use crate::config::LedgerConfig;
     use crate::executor::MaintenanceExecutor;
     use crate::ledger::{
-        LeaseReplayState, LedgerEvent, LedgerReader, LedgerWriter, RouterReplayState,
+        LeaseReplayState, LedgerEvent, LedgerReader, LedgerWriter, ReplayCoordinator,
+        RouterReplayState,
     };
     use crate::metrics::MetricsCollector;
+    use crate::router::{DispatcherConfig, Message, Priority, UnifiedMessageRouter};
+    use crate::territory::{LeaseRequest, TerritoryManager};
     use std::time::{Duration, SystemTime, UNIX_EPOCH};
     use tempfile::tempdir;
 
@@ -893,7 +1540,8 @@ mod tests {
             RouterReplayState::default(),
             LeaseReplayState::default(),
             metrics.get_snapshot(),
-        );
+        )
+        .expect("checkpoint accepted");
 
         tokio::time::sleep(Duration::from_millis(150)).await;
         ledger_writer.flush().await.expect("flush ledger");
@@ -904,4 +1552,104 @@ mod tests {
             .iter()
             .any(|event| matches!(event.event, LedgerEvent::Checkpoint(_))));
     }
+
+    #[tokio::test]
+    async fn forced_checkpoint_captures_live_state() {
+        let temp_dir = tempdir().expect("temp dir");
+        let mut ledger_config = LedgerConfig::default();
+        ledger_config.root_path = temp_dir.path().to_path_buf();
+        ledger_config.current_epoch = Some("forced-checkpoint".to_string());
+        let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+        let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+        let metrics = MetricsCollector::new();
+        let router = UnifiedMessageRouter::with_config(
+            metrics.clone(),
+            DispatcherConfig {
+                initial_tokens: 0.0,
+                token_refill_rate: 0.0,
+                ..DispatcherConfig::default()
+            },
+        );
+        let territory = TerritoryManager::new(metrics.clone(), None);
+
+        territory
+            .acquire_lease(LeaseRequest::new(
+                "holder".into(),
+                "src/lib.rs".into(),
+                Priority::Coordinate,
+            ))
+            .await;
+        router
+            .route_message(Message {
+                content: "throttled".to_string(),
+                priority: Priority::Blocking,
+                sender: "agent".to_string(),
+                recipient: "peer".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let checkpoint_id = record_checkpoint_now(&ledger_writer, &metrics, &router, &territory)
+            .await
+            .expect("checkpoint");
+        ledger_writer.flush().await.expect("flush ledger");
+
+        let outcome = ReplayCoordinator::new(ledger_reader)
+            .replay_epoch(&ledger_writer.epoch_id())
+            .expect("replay");
+        let checkpoint = outcome
+            .checkpoints
+            .iter()
+            .find(|checkpoint| checkpoint.checkpoint_id == checkpoint_id)
+            .expect("forced checkpoint in epoch");
+        assert_eq!(checkpoint.router.queue_depths.iter().sum::<usize>(), 1);
+        assert!(outcome.leases.active.contains_key("src/lib.rs"));
+        assert_eq!(outcome.router.queue_depths, checkpoint.router.queue_depths);
+    }
+
+    #[tokio::test]
+    async fn system_snapshot_bundles_a_consistent_view() {
+        let temp_dir = tempdir().expect("temp dir");
+        let mut ledger_config = LedgerConfig::default();
+        ledger_config.root_path = temp_dir.path().to_path_buf();
+        ledger_config.current_epoch = Some("system-snapshot".to_string());
+        let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+        let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+        let metrics = MetricsCollector::new();
+        let territory =
+            TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
+
+        for agent in ["holder", "waiter"] {
+            territory
+                .acquire_lease(LeaseRequest::new(
+                    agent.into(),
+                    "src/lib.rs".into(),
+                    Priority::Coordinate,
+                ))
+                .await;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let snapshot =
+            capture_system_snapshot(&metrics, &territory, &ledger_reader, &ledger_writer)
+                .await
+                .expect("system snapshot");
+
+        assert_eq!(snapshot.leases.len(), 1);
+        assert_eq!(snapshot.leases[0].holder_id, "holder");
+        assert_eq!(snapshot.queue.len(), 1);
+        assert_eq!(snapshot.queue[0].agent_id, "waiter");
+        assert_eq!(snapshot.metrics.leases.active_leases, snapshot.leases.len());
+        assert_eq!(snapshot.metrics.leases.total_pending, snapshot.queue.len());
+        assert_eq!(snapshot.ledger.epoch_id, "system-snapshot");
+        assert!(snapshot.ledger.verified);
+        assert!(snapshot.ledger.event_count >= 2);
+        assert_eq!(
+            snapshot.heat.hottest_resource.as_deref(),
+            Some("src/lib.rs")
+        );
+    }
 }
@@ -1,9 +1,15 @@
+use crate::agent::AgentSignal;
 use crate::router::Priority;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant, SystemTime};
 
+const LATENCY_RESERVOIR_CAPACITY: usize = 1024;
+const CONSENSUS_RESOURCE_CAPACITY: usize = 256;
+pub const CONSENSUS_OUTCOME_WINDOW: usize = 100;
+pub const DEFAULT_RATE_LIMIT_CAPACITY: usize = 1024;
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PerformanceMetrics {
@@ -14,6 +20,22 @@ pub struct PerformanceMetrics {
     pub total_leases_acquired: u64,
     pub memory_usage_mb: f64,
     pub rate_limited_messages: u64,
+    #[serde(default)]
+    pub agent_spawn_samples: usize,
+    #[serde(default)]
+    pub agent_spawn_mean_ms: f64,
+    #[serde(default)]
+    pub agent_spawn_max_ms: f64,
+    #[serde(default)]
+    pub agent_spawn_p99_ms: f64,
+    #[serde(default)]
+    pub total_leases_released: u64,
+    #[serde(default)]
+    pub lease_acquisition_samples: u64,
+    #[serde(default)]
+    pub uncontended_lease_acquisition_ms: f64,
+    #[serde(default)]
+    pub contended_lease_acquisition_ms: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -22,7 +44,27 @@ pub struct RouterSnapshot {
     pub queue_depths: BTreeMap<String, usize>,
     pub last_dispatched_priority: Option<String>,
     pub last_dispatched_at: Option<SystemTime>,
+    #[serde(default)]
+    pub dispatcher_idle_ms: Option<u64>,
     pub rate_limited_messages: u64,
+    pub deduplicated_messages: u64,
+    #[serde(default)]
+    pub director_override_messages: u64,
+    #[serde(default)]
+    pub wait_time: WaitTimeSnapshot,
+    #[serde(default)]
+    pub peak_queue_depths: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WaitTimeSnapshot {
+    pub samples: usize,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p99_ms: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -45,7 +87,17 @@ pub struct LeaseSnapshotSummary {
     pub deferrals: u64,
     pub overrides: u64,
     pub escalations: u64,
+    #[serde(default)]
+    pub transfers: u64,
+    #[serde(default)]
+    pub rejections: BTreeMap<String, u64>,
+    #[serde(default)]
+    pub director_overrides: u64,
+    #[serde(default)]
+    pub operator_overrides: u64,
     pub outstanding_lease_ids: Vec<u64>,
+    #[serde(default)]
+    pub hold_duration: WaitTimeSnapshot,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,12 +108,28 @@ pub struct PtyLastEvent {
     pub timestamp: SystemTime,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentActivity {
+    pub total_events: u64,
+    pub events_by_name: BTreeMap<String, u64>,
+    pub last_seen: Option<SystemTime>,
+    #[serde(default)]
+    pub last_progress: Option<f32>,
+    #[serde(default)]
+    pub artifacts: u64,
+    #[serde(default)]
+    pub errors: u64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PtySnapshot {
     pub events_by_name: BTreeMap<String, u64>,
     pub total_events: u64,
     pub last_event: Option<PtyLastEvent>,
+    #[serde(default)]
+    pub agents: BTreeMap<String, AgentActivity>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -69,6 +137,8 @@ pub struct PtySnapshot {
 pub struct SystemSnapshot {
     pub memory_usage_mb: f64,
     pub last_updated: Option<SystemTime>,
+    #[serde(default)]
+    pub maintenance_task_failures: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -85,6 +155,147 @@ pub struct MetricsSnapshot {
     pub heat: HeatSnapshot,
 }
 
+#[derive(Debug, Clone, Serialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsDelta {
+    pub elapsed: Duration,
+    pub messages_routed: u64,
+    pub leases_acquired: u64,
+    pub leases_released: u64,
+    pub rate_limited: u64,
+    pub deduplicated: u64,
+    pub director_overrides: u64,
+    pub deferrals: u64,
+    pub overrides: u64,
+    pub escalations: u64,
+    pub transfers: u64,
+    pub pty_events: u64,
+    pub consensus_success: u64,
+    pub consensus_failure: u64,
+    pub consensus_timeouts: u64,
+    pub ledger_append_failures: u64,
+    pub ledger_integrity_errors: u64,
+    pub maintenance_task_failures: u64,
+}
+
+impl MetricsDelta {
+    pub fn per_minute(&self, count: u64) -> f64 {
+        if self.elapsed.is_zero() {
+            return count as f64;
+        }
+        count as f64 / self.elapsed.as_secs_f64().max(1e-6) * 60.0
+    }
+
+    pub fn per_hour(&self, count: u64) -> f64 {
+        self.per_minute(count) * 60.0
+    }
+
+    pub fn routed_per_minute(&self) -> f64 {
+        self.per_minute(self.messages_routed)
+    }
+
+    pub fn rate_limited_per_minute(&self) -> f64 {
+        self.per_minute(self.rate_limited)
+    }
+
+    pub fn escalations_per_minute(&self) -> f64 {
+        self.per_minute(self.escalations)
+    }
+}
+
+impl MetricsSnapshot {
+    pub fn delta(&self, previous: &MetricsSnapshot) -> MetricsDelta {
+        let elapsed = match (self.system.last_updated, previous.system.last_updated) {
+            (Some(current), Some(earlier)) => current.duration_since(earlier).unwrap_or_default(),
+            _ => Duration::ZERO,
+        };
+        MetricsDelta {
+            elapsed,
+            messages_routed: self
+                .performance
+                .total_messages_routed
+                .saturating_sub(previous.performance.total_messages_routed),
+            leases_acquired: self
+                .performance
+                .total_leases_acquired
+                .saturating_sub(previous.performance.total_leases_acquired),
+            leases_released: self
+                .performance
+                .total_leases_released
+                .saturating_sub(previous.performance.total_leases_released),
+            rate_limited: self
+                .performance
+                .rate_limited_messages
+                .saturating_sub(previous.performance.rate_limited_messages),
+            deduplicated: self
+                .router
+                .deduplicated_messages
+                .saturating_sub(previous.router.deduplicated_messages),
+            director_overrides: self
+                .router
+                .director_override_messages
+                .saturating_sub(previous.router.director_override_messages),
+            deferrals: self
+                .leases
+                .deferrals
+                .saturating_sub(previous.leases.deferrals),
+            overrides: self
+                .leases
+                .overrides
+                .saturating_sub(previous.leases.overrides),
+            escalations: self
+                .leases
+                .escalations
+                .saturating_sub(previous.leases.escalations),
+            transfers: self
+                .leases
+                .transfers
+                .saturating_sub(previous.leases.transfers),
+            pty_events: self
+                .pty
+                .total_events
+                .saturating_sub(previous.pty.total_events),
+            consensus_success: self
+                .consensus
+                .success
+                .saturating_sub(previous.consensus.success),
+            consensus_failure: self
+                .consensus
+                .failure
+                .saturating_sub(previous.consensus.failure),
+            consensus_timeouts: self
+                .consensus
+                .timeouts
+                .saturating_sub(previous.consensus.timeouts),
+            ledger_append_failures: self
+                .ledger
+                .append_failures
+                .saturating_sub(previous.ledger.append_failures),
+            ledger_integrity_errors: self
+                .ledger
+                .integrity_errors
+                .saturating_sub(previous.ledger.integrity_errors),
+            maintenance_task_failures: self
+                .system
+                .maintenance_task_failures
+                .saturating_sub(previous.system.maintenance_task_failures),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MetricsSubsystem {
+    Performance,
+    Router,
+    RateLimits,
+    Leases,
+    Pty,
+    Consensus,
+    Heat,
+    Ledger,
+}
+
 #[derive(Debug, Clone)]
 pub struct QuorumMetricsUpdate {
     pub resource_id: String,
@@ -109,6 +320,22 @@ pub struct ConsensusSnapshot {
     pub success_ratio: f64,
     pub last_resource: Option<String>,
     pub last_reason: Option<String>,
+    #[serde(default)]
+    pub by_resource: BTreeMap<String, ResourceConsensusTally>,
+    #[serde(default)]
+    pub timeouts: u64,
+    #[serde(default)]
+    pub window_size: usize,
+    #[serde(default)]
+    pub window_success_ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceConsensusTally {
+    pub success: u64,
+    pub failure: u64,
+    pub success_ratio: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -126,21 +353,36 @@ struct PerformanceState {
     agent_spawn_time_ms: f64,
     lease_acquisition_time_ms: f64,
     lease_acquisition_samples: u64,
+    uncontended_lease_acquisition_ms: f64,
+    uncontended_lease_acquisition_samples: u64,
+    contended_lease_acquisition_ms: f64,
+    contended_lease_acquisition_samples: u64,
     total_leases_acquired: u64,
+    total_leases_released: u64,
     memory_usage_mb: f64,
     rate_limited_messages: u64,
+    agent_spawn_times: LatencyReservoir,
 }
 
 impl PerformanceState {
     fn to_metrics(&self) -> PerformanceMetrics {
+        let spawn_times = self.agent_spawn_times.to_snapshot();
         PerformanceMetrics {
             message_routing_latency_ms: self.message_routing_latency_ms,
             agent_spawn_time_ms: self.agent_spawn_time_ms,
             lease_acquisition_time_ms: self.lease_acquisition_time_ms,
+            lease_acquisition_samples: self.lease_acquisition_samples,
+            uncontended_lease_acquisition_ms: self.uncontended_lease_acquisition_ms,
+            contended_lease_acquisition_ms: self.contended_lease_acquisition_ms,
             total_messages_routed: self.total_messages_routed,
             total_leases_acquired: self.total_leases_acquired,
             memory_usage_mb: self.memory_usage_mb,
             rate_limited_messages: self.rate_limited_messages,
+            agent_spawn_samples: spawn_times.samples,
+            agent_spawn_mean_ms: spawn_times.mean_ms,
+            agent_spawn_max_ms: spawn_times.max_ms,
+            agent_spawn_p99_ms: spawn_times.p99_ms,
+            total_leases_released: self.total_leases_released,
         }
     }
 }
@@ -151,6 +393,76 @@ struct RouterState {
     last_dispatched_priority: Option<String>,
     last_dispatched_at: Option<SystemTime>,
     rate_limited_messages: u64,
+    deduplicated_messages: u64,
+    director_override_messages: u64,
+    wait_times: LatencyReservoir,
+    peak_queue_depths: Vec<usize>,
+}
+
+impl RouterState {
+    fn observe_queue_depths(&mut self, queue_depths: &[usize]) {
+        if self.peak_queue_depths.len() < queue_depths.len() {
+            self.peak_queue_depths.resize(queue_depths.len(), 0);
+        }
+        for (peak, depth) in self.peak_queue_depths.iter_mut().zip(queue_depths) {
+            *peak = (*peak).max(*depth);
+        }
+        self.queue_depths = queue_depths.to_vec();
+    }
+}
+
+fn priority_depth_map(depths: &[usize]) -> BTreeMap<String, usize> {
+    (0..=Priority::DirectorOverride.as_index())
+        .map(|index| {
+            (
+                Priority::from_index(index).as_str().to_string(),
+                depths.get(index).copied().unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+fn idle_millis_since(at: SystemTime) -> u64 {
+    SystemTime::now()
+        .duration_since(at)
+        .map(|idle| idle.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Default)]
+struct LatencyReservoir {
+    samples_us: VecDeque<u64>,
+}
+
+impl LatencyReservoir {
+    fn record(&mut self, wait: Duration) {
+        if self.samples_us.len() >= LATENCY_RESERVOIR_CAPACITY {
+            self.samples_us.pop_front();
+        }
+        self.samples_us.push_back(wait.as_micros() as u64);
+    }
+
+    fn to_snapshot(&self) -> WaitTimeSnapshot {
+        if self.samples_us.is_empty() {
+            return WaitTimeSnapshot::default();
+        }
+        let mut sorted: Vec<u64> = self.samples_us.iter().copied().collect();
+        sorted.sort_unstable();
+        let to_ms = |micros: u64| micros as f64 / 1000.0;
+        let percentile = |ratio: f64| {
+            let rank = (ratio * (sorted.len() - 1) as f64).round() as usize;
+            to_ms(sorted[rank.min(sorted.len() - 1)])
+        };
+        let mean_us = sorted.iter().sum::<u64>() / sorted.len() as u64;
+        WaitTimeSnapshot {
+            samples: sorted.len(),
+            min_ms: to_ms(sorted[0]),
+            max_ms: to_ms(sorted[sorted.len() - 1]),
+            mean_ms: to_ms(mean_us),
+            p50_ms: percentile(0.5),
+            p99_ms: percentile(0.99),
+        }
+    }
 }
 
 #[derive(Debug, Default)]
@@ -160,6 +472,50 @@ struct RateLimitState {
     refill_rate: f64,
     last_refill: Option<SystemTime>,
     rate_limit_hits: u64,
+    last_update: u64,
+}
+
+#[derive(Debug)]
+struct RateLimitTable {
+    entries: HashMap<String, RateLimitState>,
+    capacity: usize,
+    updates: u64,
+}
+
+impl RateLimitTable {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            updates: 0,
+        }
+    }
+
+    fn touch(&mut self, sender: &str) -> &mut RateLimitState {
+        self.updates = self.updates.saturating_add(1);
+        let updates = self.updates;
+        if !self.entries.contains_key(sender) {
+            while self.entries.len() >= self.capacity {
+                let stalest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, state)| state.last_update)
+                    .map(|(sender, _)| sender.clone());
+                match stalest {
+                    Some(stale_sender) => self.entries.remove(&stale_sender),
+                    None => break,
+                };
+            }
+        }
+        let entry = self.entries.entry(sender.to_string()).or_default();
+        entry.last_update = updates;
+        entry
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.updates = 0;
+    }
 }
 
 #[derive(Debug, Default)]
@@ -169,7 +525,12 @@ struct LeaseState {
     deferrals: u64,
     overrides: u64,
     escalations: u64,
+    transfers: u64,
+    rejections: BTreeMap<String, u64>,
+    director_overrides: u64,
+    operator_overrides: u64,
     outstanding_leases: HashSet<u64>,
+    hold_durations: LatencyReservoir,
 }
 
 #[derive(Debug, Default)]
@@ -177,21 +538,85 @@ struct PtyState {
     events_by_name: HashMap<String, u64>,
     total_events: u64,
     last_event: Option<PtyLastEvent>,
+    agents: HashMap<String, AgentActivity>,
 }
 
 #[derive(Debug, Default)]
 struct SystemState {
     memory_usage_mb: f64,
     last_updated: Option<SystemTime>,
+    maintenance_task_failures: u64,
 }
 
 #[derive(Debug, Default)]
 struct ConsensusState {
     success: u64,
     failure: u64,
+    timeouts: u64,
     threshold: f32,
     last_resource: Option<String>,
     last_reason: Option<String>,
+    by_resource: HashMap<String, ResourceConsensusState>,
+    recent: VecDeque<bool>,
+    updates: u64,
+}
+
+#[derive(Debug, Default)]
+struct ResourceConsensusState {
+    success: u64,
+    failure: u64,
+    last_update: u64,
+}
+
+impl ConsensusState {
+    fn record_outcome(&mut self, achieved: bool) {
+        if self.recent.len() >= CONSENSUS_OUTCOME_WINDOW {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(achieved);
+    }
+
+    fn window_success_ratio(&self) -> f64 {
+        let success = self.recent.iter().filter(|achieved| **achieved).count() as u64;
+        success_ratio(success, self.recent.len() as u64 - success)
+    }
+
+    fn record_resource(&mut self, resource_id: &str, achieved: bool) {
+        self.updates = self.updates.saturating_add(1);
+        let updates = self.updates;
+        let tally = self.by_resource.entry(resource_id.to_string()).or_default();
+        if achieved {
+            tally.success = tally.success.saturating_add(1);
+        } else {
+            tally.failure = tally.failure.saturating_add(1);
+        }
+        tally.last_update = updates;
+        while self.by_resource.len() > CONSENSUS_RESOURCE_CAPACITY {
+            let stalest = self
+                .by_resource
+                .iter()
+                .min_by_key(|(_, tally)| tally.last_update)
+                .map(|(resource, _)| resource.clone());
+            match stalest {
+                Some(resource) => self.by_resource.remove(&resource),
+                None => break,
+            };
+        }
+    }
+}
+
+fn running_mean(mean: &mut f64, samples: &mut u64, value: f64) {
+    *samples += 1;
+    *mean += (value - *mean) / *samples as f64;
+}
+
+fn success_ratio(success: u64, failure: u64) -> f64 {
+    let total = success + failure;
+    if total > 0 {
+        success as f64 / total as f64
+    } else {
+        1.0
+    }
 }
 
 #[derive(Debug, Default)]
@@ -206,6 +631,9 @@ struct LedgerState {
     last_append_latency_ms: f64,
     append_failures: u64,
     integrity_errors: u64,
+    consecutive_failures: u32,
+    degraded: bool,
+    clock_regressions: u64,
 }
 
 impl LedgerState {
@@ -214,6 +642,9 @@ impl LedgerState {
             last_append_latency_ms: self.last_append_latency_ms,
             append_failures: self.append_failures,
             integrity_errors: self.integrity_errors,
+            consecutive_failures: self.consecutive_failures,
+            degraded: self.degraded,
+            clock_regressions: self.clock_regressions,
         }
     }
 }
@@ -224,13 +655,19 @@ pub struct LedgerSnapshot {
     pub last_append_latency_ms: f64,
     pub append_failures: u64,
     pub integrity_errors: u64,
+    #[serde(default)]
+    pub consecutive_failures: u32,
+    #[serde(default)]
+    pub degraded: bool,
+    #[serde(default)]
+    pub clock_regressions: u64,
 }
 
 #[derive(Debug, Clone)]
 pub struct MetricsCollector {
     performance: Arc<RwLock<PerformanceState>>,
     router: Arc<RwLock<RouterState>>,
-    rate_limits: Arc<RwLock<HashMap<String, RateLimitState>>>,
+    rate_limits: Arc<RwLock<RateLimitTable>>,
     leases: Arc<RwLock<LeaseState>>,
     pty: Arc<RwLock<PtyState>>,
     system: Arc<RwLock<SystemState>>,
@@ -245,7 +682,9 @@ impl MetricsCollector {
         Self {
             performance: Arc::new(RwLock::new(PerformanceState::default())),
             router: Arc::new(RwLock::new(RouterState::default())),
-            rate_limits: Arc::new(RwLock::new(HashMap::new())),
+            rate_limits: Arc::new(RwLock::new(RateLimitTable::new(
+                DEFAULT_RATE_LIMIT_CAPACITY,
+            ))),
             leases: Arc::new(RwLock::new(LeaseState::default())),
             pty: Arc::new(RwLock::new(PtyState::default())),
             system: Arc::new(RwLock::new(SystemState::default())),
@@ -256,6 +695,11 @@ impl MetricsCollector {
         }
     }
 
+    pub fn with_rate_limit_capacity(self, capacity: usize) -> Self {
+        *self.rate_limits.write().unwrap() = RateLimitTable::new(capacity);
+        self
+    }
+
     pub fn start_timer(&self, timer_name: &str) {
         let mut timers = self.timers.write().unwrap();
         timers.insert(timer_name.to_string(), Instant::now());
@@ -278,7 +722,13 @@ impl MetricsCollector {
 
     pub fn update_queue_depths(&self, queue_depths: &[usize]) {
         let mut router = self.router.write().unwrap();
-        router.queue_depths = queue_depths.to_vec();
+        router.observe_queue_depths(queue_depths);
+    }
+
+    pub fn reset_peak_queue_depths(&self) {
+        let mut router = self.router.write().unwrap();
+        let current = router.queue_depths.clone();
+        router.peak_queue_depths = current;
     }
 
     pub fn record_router_delivery(
@@ -291,7 +741,16 @@ impl MetricsCollector {
         let mut router = self.router.write().unwrap();
         router.last_dispatched_priority = Some(priority.as_str().to_string());
         router.last_dispatched_at = Some(SystemTime::now());
-        router.queue_depths = queue_depths.to_vec();
+        router.observe_queue_depths(queue_depths);
+        router.wait_times.record(wait_duration);
+        if priority == Priority::DirectorOverride {
+            router.director_override_messages += 1;
+        }
+    }
+
+    pub fn increment_deduplicated(&self) {
+        let mut router = self.router.write().unwrap();
+        router.deduplicated_messages += 1;
     }
 
     pub fn increment_rate_limited(&self, sender: &str) {
@@ -304,9 +763,7 @@ impl MetricsCollector {
             router.rate_limited_messages += 1;
         }
         let mut buckets = self.rate_limits.write().unwrap();
-        let entry = buckets
-            .entry(sender.to_string())
-            .or_insert_with(RateLimitState::default);
+        let entry = buckets.touch(sender);
         entry.rate_limit_hits = entry.rate_limit_hits.saturating_add(1);
     }
 
@@ -319,9 +776,7 @@ impl MetricsCollector {
         last_refill: Option<SystemTime>,
     ) {
         let mut buckets = self.rate_limits.write().unwrap();
-        let entry = buckets
-            .entry(sender.to_string())
-            .or_insert_with(RateLimitState::default);
+        let entry = buckets.touch(sender);
         entry.tokens_remaining = tokens_remaining;
         entry.capacity = capacity;
         entry.refill_rate = refill_rate;
@@ -331,21 +786,39 @@ impl MetricsCollector {
     pub fn record_agent_spawn(&self, duration_ms: f64) {
         let mut performance = self.performance.write().unwrap();
         performance.agent_spawn_time_ms = duration_ms;
+        performance
+            .agent_spawn_times
+            .record(Duration::try_from_secs_f64(duration_ms / 1000.0).unwrap_or_default());
     }
 
     pub fn record_agent_event(&self, agent_id: &str, event_name: Option<&str>) {
         let mut pty = self.pty.write().unwrap();
         let key = event_name.unwrap_or("unknown").to_string();
+        let now = SystemTime::now();
+        let activity = pty.agents.entry(agent_id.to_string()).or_default();
+        activity.total_events += 1;
+        *activity.events_by_name.entry(key.clone()).or_insert(0) += 1;
+        activity.last_seen = Some(now);
         let entry = pty.events_by_name.entry(key).or_insert(0);
         *entry += 1;
         pty.total_events += 1;
         pty.last_event = Some(PtyLastEvent {
             agent_id: agent_id.to_string(),
             event_name: event_name.map(|value| value.to_string()),
-            timestamp: SystemTime::now(),
+            timestamp: now,
         });
     }
 
+    pub fn record_agent_signal(&self, agent_id: &str, signal: &AgentSignal) {
+        let mut pty = self.pty.write().unwrap();
+        let activity = pty.agents.entry(agent_id.to_string()).or_default();
+        match signal {
+            AgentSignal::Progress { percent, .. } => activity.last_progress = Some(*percent),
+            AgentSignal::Artifact { .. } => activity.artifacts += 1,
+            AgentSignal::Error { .. } => activity.errors += 1,
+        }
+    }
+
     pub fn record_quorum_metrics(&self, update: QuorumMetricsUpdate) {
         let mut consensus = self.consensus.write().unwrap();
         if update.achieved {
@@ -354,10 +827,17 @@ impl MetricsCollector {
             consensus.failure = consensus.failure.saturating_add(1);
         }
         consensus.threshold = update.threshold;
+        consensus.record_outcome(update.achieved);
+        consensus.record_resource(&update.resource_id, update.achieved);
         consensus.last_resource = Some(update.resource_id);
         consensus.last_reason = Some(update.reason);
     }
 
+    pub fn record_consensus_timeout(&self) {
+        let mut consensus = self.consensus.write().unwrap();
+        consensus.timeouts = consensus.timeouts.saturating_add(1);
+    }
+
     pub fn update_heat_summary(&self, summary: HeatSummary) {
         let mut heat = self.heat.write().unwrap();
         let HeatSummary {
@@ -380,19 +860,48 @@ impl MetricsCollector {
         ledger.append_failures = ledger.append_failures.saturating_add(1);
     }
 
+    pub fn record_ledger_durability(&self, consecutive_failures: u32, degraded: bool) {
+        let mut ledger = self.ledger.write().unwrap();
+        ledger.consecutive_failures = consecutive_failures;
+        ledger.degraded = degraded;
+    }
+
     pub fn record_ledger_integrity_failure(&self) {
         let mut ledger = self.ledger.write().unwrap();
         ledger.integrity_errors = ledger.integrity_errors.saturating_add(1);
     }
 
-    pub fn record_lease_acquisition(&self, duration_ms: f64) {
+    pub fn record_ledger_clock_regression(&self) {
+        let mut ledger = self.ledger.write().unwrap();
+        ledger.clock_regressions = ledger.clock_regressions.saturating_add(1);
+    }
+
+    pub fn record_maintenance_task_failure(&self) {
+        let mut system = self.system.write().unwrap();
+        system.maintenance_task_failures = system.maintenance_task_failures.saturating_add(1);
+    }
+
+    pub fn record_lease_acquisition(&self, duration_ms: f64, contended: bool) {
         let mut performance = self.performance.write().unwrap();
-        performance.lease_acquisition_samples += 1;
-        let total = performance.lease_acquisition_samples.max(1);
-        performance.lease_acquisition_time_ms = ((performance.lease_acquisition_time_ms
-            * (total.saturating_sub(1) as f64))
-            + duration_ms)
-            / total as f64;
+        let performance = &mut *performance;
+        running_mean(
+            &mut performance.lease_acquisition_time_ms,
+            &mut performance.lease_acquisition_samples,
+            duration_ms,
+        );
+        if contended {
+            running_mean(
+                &mut performance.contended_lease_acquisition_ms,
+                &mut performance.contended_lease_acquisition_samples,
+                duration_ms,
+            );
+        } else {
+            running_mean(
+                &mut performance.uncontended_lease_acquisition_ms,
+                &mut performance.uncontended_lease_acquisition_samples,
+                duration_ms,
+            );
+        }
     }
 
     pub fn record_lease_grant(&self) {
@@ -400,7 +909,14 @@ impl MetricsCollector {
         performance.total_leases_acquired += 1;
     }
 
-    pub fn record_lease_release(&self) {}
+    pub fn record_lease_release(&self, held_for: Duration) {
+        {
+            let mut performance = self.performance.write().unwrap();
+            performance.total_leases_released = performance.total_leases_released.saturating_add(1);
+        }
+        let mut leases = self.leases.write().unwrap();
+        leases.hold_durations.record(held_for);
+    }
 
     pub fn record_lease_deferral(&self) {
         let mut leases = self.leases.write().unwrap();
@@ -412,11 +928,33 @@ impl MetricsCollector {
         leases.overrides = leases.overrides.saturating_add(1);
     }
 
+    pub fn record_operator_override(&self) {
+        let mut leases = self.leases.write().unwrap();
+        leases.operator_overrides = leases.operator_overrides.saturating_add(1);
+    }
+
+    pub fn record_lease_transfer(&self) {
+        let mut leases = self.leases.write().unwrap();
+        leases.transfers = leases.transfers.saturating_add(1);
+    }
+
+    pub fn record_lease_rejected(&self, reason: &str) {
+        let mut leases = self.leases.write().unwrap();
+        let count = leases.rejections.entry(reason.to_string()).or_default();
+        *count = count.saturating_add(1);
+    }
+
     pub fn record_lease_escalation(&self) {
         let mut leases = self.leases.write().unwrap();
         leases.escalations = leases.escalations.saturating_add(1);
     }
 
+    pub fn record_lease_director_override(&self) {
+        let mut leases = self.leases.write().unwrap();
+        leases.escalations = leases.escalations.saturating_add(1);
+        leases.director_overrides = leases.director_overrides.saturating_add(1);
+    }
+
     pub fn update_lease_inventory(
         &self,
         active_leases: usize,
@@ -444,25 +982,23 @@ impl MetricsCollector {
 
         let router_snapshot = {
             let router = self.router.read().unwrap();
-            let mut depths = BTreeMap::new();
-            let stored = &router.queue_depths;
-            let max_index = Priority::DirectorOverride.as_index();
-            for index in 0..=max_index {
-                let priority = Priority::from_index(index);
-                let depth = stored.get(index).copied().unwrap_or_default();
-                depths.insert(priority.as_str().to_string(), depth);
-            }
             RouterSnapshot {
-                queue_depths: depths,
+                queue_depths: priority_depth_map(&router.queue_depths),
                 last_dispatched_priority: router.last_dispatched_priority.clone(),
                 last_dispatched_at: router.last_dispatched_at,
+                dispatcher_idle_ms: router.last_dispatched_at.map(idle_millis_since),
                 rate_limited_messages: router.rate_limited_messages,
+                deduplicated_messages: router.deduplicated_messages,
+                director_override_messages: router.director_override_messages,
+                wait_time: router.wait_times.to_snapshot(),
+                peak_queue_depths: priority_depth_map(&router.peak_queue_depths),
             }
         };
 
         let rate_limit_snapshot = {
             let buckets = self.rate_limits.read().unwrap();
             let mut entries: Vec<RateLimitSnapshot> = buckets
+                .entries
                 .iter()
                 .map(|(sender, state)| RateLimitSnapshot {
                     sender: sender.clone(),
@@ -483,7 +1019,8 @@ impl MetricsCollector {
             for (resource, depth) in leases.pending_by_resource.iter() {
                 pending.insert(resource.clone(), *depth);
             }
-            let outstanding = leases.outstanding_leases.iter().copied().collect();
+            let mut outstanding: Vec<u64> = leases.outstanding_leases.iter().copied().collect();
+            outstanding.sort_unstable();
             let total_pending = pending.values().copied().sum();
             LeaseSnapshotSummary {
                 active_leases: leases.active_leases,
@@ -492,7 +1029,12 @@ impl MetricsCollector {
                 deferrals: leases.deferrals,
                 overrides: leases.overrides,
                 escalations: leases.escalations,
+                transfers: leases.transfers,
+                rejections: leases.rejections.clone(),
+                director_overrides: leases.director_overrides,
+                operator_overrides: leases.operator_overrides,
                 outstanding_lease_ids: outstanding,
+                hold_duration: leases.hold_durations.to_snapshot(),
             }
         };
 
@@ -506,6 +1048,11 @@ impl MetricsCollector {
                 events_by_name: counts,
                 total_events: pty.total_events,
                 last_event: pty.last_event.clone(),
+                agents: pty
+                    .agents
+                    .iter()
+                    .map(|(agent_id, activity)| (agent_id.clone(), activity.clone()))
+                    .collect(),
             }
         };
 
@@ -514,6 +1061,7 @@ impl MetricsCollector {
             SystemSnapshot {
                 memory_usage_mb: system.memory_usage_mb,
                 last_updated: system.last_updated,
+                maintenance_task_failures: system.maintenance_task_failures,
             }
         };
 
@@ -524,19 +1072,30 @@ impl MetricsCollector {
 
         let consensus_snapshot = {
             let consensus = self.consensus.read().unwrap();
-            let total = consensus.success + consensus.failure;
-            let ratio = if total > 0 {
-                consensus.success as f64 / total as f64
-            } else {
-                1.0
-            };
             ConsensusSnapshot {
                 success: consensus.success,
                 failure: consensus.failure,
                 threshold: consensus.threshold,
-                success_ratio: ratio,
+                success_ratio: success_ratio(consensus.success, consensus.failure),
                 last_resource: consensus.last_resource.clone(),
                 last_reason: consensus.last_reason.clone(),
+                by_resource: consensus
+                    .by_resource
+                    .iter()
+                    .map(|(resource, tally)| {
+                        (
+                            resource.clone(),
+                            ResourceConsensusTally {
+                                success: tally.success,
+                                failure: tally.failure,
+                                success_ratio: success_ratio(tally.success, tally.failure),
+                            },
+                        )
+                    })
+                    .collect(),
+                timeouts: consensus.timeouts,
+                window_size: consensus.recent.len(),
+                window_success_ratio: consensus.window_success_ratio(),
             }
         };
 
@@ -575,6 +1134,25 @@ impl MetricsCollector {
         self.timers.write().unwrap().clear();
     }
 
+    pub fn reset_subsystem(&self, subsystem: MetricsSubsystem) {
+        match subsystem {
+            MetricsSubsystem::Performance => {
+                *self.performance.write().unwrap() = PerformanceState::default();
+            }
+            MetricsSubsystem::Router => {
+                *self.router.write().unwrap() = RouterState::default();
+            }
+            MetricsSubsystem::RateLimits => self.rate_limits.write().unwrap().clear(),
+            MetricsSubsystem::Leases => *self.leases.write().unwrap() = LeaseState::default(),
+            MetricsSubsystem::Pty => *self.pty.write().unwrap() = PtyState::default(),
+            MetricsSubsystem::Consensus => {
+                *self.consensus.write().unwrap() = ConsensusState::default();
+            }
+            MetricsSubsystem::Heat => *self.heat.write().unwrap() = HeatState::default(),
+            MetricsSubsystem::Ledger => *self.ledger.write().unwrap() = LedgerState::default(),
+        }
+    }
+
     fn update_memory_usage(&self) {
         let mut usage_mb = 0.0;
         #[cfg(target_os = "macos")]
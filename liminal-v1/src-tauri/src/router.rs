@@ -1,6 +1,8 @@
+use crate::clock::{system_clock, SharedClock};
 use crate::config::{parse_duration as parse_duration_str, RouterConfig};
-use crate::executor::MaintenanceExecutor;
+use crate::executor::{block_on, MaintenanceExecutor};
 use crate::metrics::MetricsCollector;
+use crate::stream::EventStream;
 
 #[allow(unused_imports)]
 use crate::consensus::ConsensusBroker;
@@ -10,14 +12,23 @@ use crate::ledger::{
     LedgerEvent, LedgerWriter, RateLimitedRecord, RouterDispatchRecord, RouterEvent,
 };
 use blake3::hash as blake3_hash;
-use std::collections::{HashMap, VecDeque};
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::task::{Context, Poll};
 use std::time::{Duration, Instant, SystemTime};
-use tokio::sync::{broadcast, watch, Mutex, Notify, RwLock};
+use thiserror::Error;
+use tokio::sync::{broadcast, oneshot, watch, Mutex, Notify, RwLock};
 use tokio::task::JoinHandle;
 
 const PRIORITY_LEVELS: usize = 5;
+const DIRECTOR_OVERRIDE_TOKEN_COST: f64 = 1.0;
+const DEFAULT_MAX_MESSAGE_BYTES: usize = 256 * 1024;
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(5);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Priority {
@@ -69,15 +80,66 @@ impl Priority {
     }
 }
 
+pub type RouterDeliveryStream = EventStream<RouterDelivery>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePriorityError(pub String);
+
+impl std::fmt::Display for ParsePriorityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown priority '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParsePriorityError {}
+
+impl FromStr for Priority {
+    type Err = ParsePriorityError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        (0..PRIORITY_LEVELS)
+            .map(Priority::from_index)
+            .find(|priority| priority.as_str() == value)
+            .ok_or_else(|| ParsePriorityError(value.to_string()))
+    }
+}
+
+impl TryFrom<&str> for Priority {
+    type Error = ParsePriorityError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Message {
     pub content: String,
     pub priority: Priority,
     pub sender: String,
     pub recipient: String,
+    pub message_id: Option<String>,
+    pub trace_id: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+impl Message {
+    pub fn dedup_key(&self) -> String {
+        if let Some(id) = &self.message_id {
+            return id.clone();
+        }
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.sender.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(self.recipient.as_bytes());
+        hasher.update(&[0]);
+        hasher.update(self.priority.as_str().as_bytes());
+        hasher.update(&[0]);
+        hasher.update(self.content.as_bytes());
+        hasher.finalize().to_hex().to_string()
+    }
+}
+
+#[derive(Debug)]
 struct QueuedMessage {
     message: Message,
     enqueued_at: Instant,
@@ -85,27 +147,130 @@ struct QueuedMessage {
     aging_boosts: u8,
     retry_count: u32,
     last_attempt_at: Option<Instant>,
+    next_eligible_at: Option<Instant>,
+    ack: Option<oneshot::Sender<Result<RouterDelivery, DeliveryError>>>,
 }
 
 impl QueuedMessage {
-    fn new(message: Message) -> Self {
+    fn new(message: Message, now: Instant) -> Self {
         Self {
             effective_priority: message.priority,
             message,
-            enqueued_at: Instant::now(),
+            enqueued_at: now,
             aging_boosts: 0,
             retry_count: 0,
             last_attempt_at: None,
+            next_eligible_at: None,
+            ack: None,
         }
     }
 
-    fn eligible_for_boost(&self, threshold: Duration, max_boosts: u8) -> bool {
-        self.aging_boosts < max_boosts && self.enqueued_at.elapsed() >= threshold
+    fn released(mut self, now: Instant) -> Self {
+        self.enqueued_at = now;
+        self
     }
 
-    fn record_attempt(&mut self) {
+    fn eligible_for_boost(&self, threshold: Duration, max_boosts: u8, now: Instant) -> bool {
+        self.aging_boosts < max_boosts
+            && now.saturating_duration_since(self.enqueued_at) >= threshold
+    }
+
+    fn record_attempt(&mut self, now: Instant, config: &DispatcherConfig) {
         self.retry_count += 1;
-        self.last_attempt_at = Some(Instant::now());
+        self.last_attempt_at = Some(now);
+        self.next_eligible_at = Some(now + self.retry_backoff(config));
+    }
+
+    fn is_eligible(&self, now: Instant) -> bool {
+        self.next_eligible_at.is_none_or(|at| at <= now)
+    }
+
+    fn retry_backoff(&self, config: &DispatcherConfig) -> Duration {
+        let exponent = self.retry_count.saturating_sub(1).min(16);
+        let ceiling = config
+            .retry_backoff
+            .saturating_mul(1 << exponent)
+            .min(config.retry_backoff_max);
+        let half = ceiling / 2;
+        let span = half.as_nanos() as u64;
+        if span == 0 {
+            return ceiling;
+        }
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(self.message.dedup_key().as_bytes());
+        hasher.update(&self.retry_count.to_le_bytes());
+        let digest = hasher.finalize();
+        let mut seed = [0u8; 8];
+        seed.copy_from_slice(&digest.as_bytes()[..8]);
+        half + Duration::from_nanos(u64::from_le_bytes(seed) % (span + 1))
+    }
+}
+
+#[derive(Debug)]
+struct ScheduledMessage {
+    deliver_at: Instant,
+    sequence: u64,
+    queued: QueuedMessage,
+}
+
+impl PartialEq for ScheduledMessage {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+impl Eq for ScheduledMessage {}
+
+impl PartialOrd for ScheduledMessage {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ScheduledMessage {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        other
+            .deliver_at
+            .cmp(&self.deliver_at)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+#[derive(Debug, Default)]
+struct DelayQueue {
+    entries: BinaryHeap<ScheduledMessage>,
+    next_sequence: u64,
+}
+
+impl DelayQueue {
+    fn schedule(&mut self, queued: QueuedMessage, deliver_at: Instant) {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.entries.push(ScheduledMessage {
+            deliver_at,
+            sequence,
+            queued,
+        });
+    }
+
+    fn pop_due(&mut self, now: Instant) -> Option<QueuedMessage> {
+        if self.entries.peek()?.deliver_at > now {
+            return None;
+        }
+        self.entries.pop().map(|entry| entry.queued)
+    }
+
+    fn next_deadline(&self) -> Option<Instant> {
+        self.entries.peek().map(|entry| entry.deliver_at)
+    }
+
+    fn take_all(&mut self) -> Vec<QueuedMessage> {
+        std::mem::take(&mut self.entries)
+            .into_sorted_vec()
+            .into_iter()
+            .rev()
+            .map(|entry| entry.queued)
+            .collect()
     }
 }
 
@@ -119,25 +284,71 @@ pub struct RouterDelivery {
     pub retry_count: u32,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryError {
+    Dropped,
+}
+
+#[derive(Debug)]
+pub struct DeliveryToken {
+    receiver: oneshot::Receiver<Result<RouterDelivery, DeliveryError>>,
+}
+
+impl Future for DeliveryToken {
+    type Output = Result<RouterDelivery, DeliveryError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.receiver)
+            .poll(cx)
+            .map(|result| result.unwrap_or(Err(DeliveryError::Dropped)))
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct DispatcherConfig {
     pub aging_threshold: Duration,
+    pub aging_thresholds: Option<[Duration; PRIORITY_LEVELS]>,
     pub max_aging_boosts: u8,
     pub idle_backoff: Duration,
+    pub idle_backoff_max: Duration,
+    pub retry_backoff: Duration,
+    pub retry_backoff_max: Duration,
     pub token_capacity: f64,
     pub token_refill_rate: f64,
     pub initial_tokens: f64,
+    pub dedup_capacity: usize,
+    pub dedup_window: Duration,
+    pub dedup_content_digest: bool,
+    pub queue_depth_warning: usize,
+    pub queue_depth_critical: usize,
+    pub director_override_capacity: Option<f64>,
+    pub director_override_refill_rate: f64,
+    pub max_message_bytes: usize,
+    pub exempt_senders: Arc<HashSet<String>>,
 }
 
 impl Default for DispatcherConfig {
     fn default() -> Self {
         Self {
             aging_threshold: Duration::from_millis(500),
+            aging_thresholds: None,
             max_aging_boosts: 2,
             idle_backoff: Duration::from_millis(5),
+            idle_backoff_max: Duration::from_millis(80),
+            retry_backoff: Duration::from_millis(5),
+            retry_backoff_max: Duration::from_millis(250),
             token_capacity: 200.0,
             token_refill_rate: 60.0,
             initial_tokens: 200.0,
+            dedup_capacity: 1024,
+            dedup_window: Duration::from_secs(30),
+            dedup_content_digest: false,
+            queue_depth_warning: 50,
+            queue_depth_critical: 100,
+            director_override_capacity: None,
+            director_override_refill_rate: 10.0,
+            max_message_bytes: DEFAULT_MAX_MESSAGE_BYTES,
+            exempt_senders: Arc::new(HashSet::new()),
         }
     }
 }
@@ -146,31 +357,94 @@ impl DispatcherConfig {
     pub fn from_router_config(config: Option<&RouterConfig>) -> Self {
         let mut current = Self::default();
         if let Some(cfg) = config {
-            if let Some(capacity) = cfg.token_bucket_capacity {
-                current.token_capacity = capacity;
-            }
-            if let Some(refill) = cfg.token_bucket_refill_rate {
-                current.token_refill_rate = refill;
-            }
-            if let Some(initial) = cfg.token_bucket_initial {
-                current.initial_tokens = initial;
-            } else if cfg.token_bucket_capacity.is_some() {
-                current.initial_tokens = current.token_capacity;
-            }
-            if let Some(duration) = cfg.aging_threshold.as_deref().and_then(parse_duration_str) {
-                current.aging_threshold = duration;
-            }
-            if let Some(boosts) = cfg.max_aging_boosts {
-                current.max_aging_boosts = boosts;
-            }
-            if let Some(duration) = cfg.idle_backoff.as_deref().and_then(parse_duration_str) {
-                current.idle_backoff = duration;
+            current.merge_router_config(cfg);
+        }
+        current
+    }
+
+    pub fn merge_router_config(&mut self, cfg: &RouterConfig) {
+        if let Some(capacity) = cfg.token_bucket_capacity {
+            self.token_capacity = capacity;
+        }
+        if let Some(refill) = cfg.token_bucket_refill_rate {
+            self.token_refill_rate = refill;
+        }
+        if let Some(initial) = cfg.token_bucket_initial {
+            self.initial_tokens = initial;
+        } else if cfg.token_bucket_capacity.is_some() {
+            self.initial_tokens = self.token_capacity;
+        }
+        if let Some(duration) = cfg.aging_threshold.as_deref().and_then(parse_duration_str) {
+            self.aging_threshold = duration;
+        }
+        if let Some(boosts) = cfg.max_aging_boosts {
+            self.max_aging_boosts = boosts;
+        }
+        if let Some(thresholds) = &cfg.aging_thresholds {
+            let mut bands = [self.aging_threshold; PRIORITY_LEVELS];
+            for (label, raw) in thresholds {
+                if let (Ok(priority), Some(duration)) =
+                    (label.parse::<Priority>(), parse_duration_str(raw))
+                {
+                    bands[priority.as_index()] = duration;
+                }
             }
+            self.aging_thresholds = Some(bands);
         }
-        if current.initial_tokens > current.token_capacity {
-            current.initial_tokens = current.token_capacity;
+        if let Some(duration) = cfg.idle_backoff.as_deref().and_then(parse_duration_str) {
+            self.idle_backoff = duration;
+        }
+        if let Some(duration) = cfg.idle_backoff_max.as_deref().and_then(parse_duration_str) {
+            self.idle_backoff_max = duration;
+        }
+        if let Some(duration) = cfg.retry_backoff.as_deref().and_then(parse_duration_str) {
+            self.retry_backoff = duration;
+        }
+        if let Some(duration) = cfg
+            .retry_backoff_max
+            .as_deref()
+            .and_then(parse_duration_str)
+        {
+            self.retry_backoff_max = duration;
+        }
+        if let Some(warning) = cfg.queue_depth_warning {
+            self.queue_depth_warning = warning;
+        }
+        if let Some(critical) = cfg.queue_depth_critical {
+            self.queue_depth_critical = critical;
+        }
+        if let Some(capacity) = cfg.director_override_capacity {
+            self.director_override_capacity = Some(capacity);
+        }
+        if let Some(refill) = cfg.director_override_refill_rate {
+            self.director_override_refill_rate = refill;
+        }
+        if let Some(limit) = cfg.max_message_bytes {
+            self.max_message_bytes = limit;
+        }
+        if let Some(enabled) = cfg.dedup_content_digest {
+            self.dedup_content_digest = enabled;
+        }
+        if let Some(senders) = &cfg.exempt_senders {
+            self.exempt_senders = Arc::new(senders.iter().cloned().collect());
+        }
+        if self.initial_tokens > self.token_capacity {
+            self.initial_tokens = self.token_capacity;
         }
-        current
+    }
+
+    pub fn aging_threshold_for(&self, priority: Priority) -> Duration {
+        self.aging_thresholds
+            .map(|bands| bands[priority.as_index()])
+            .unwrap_or(self.aging_threshold)
+    }
+
+    fn aging_tick(&self) -> Duration {
+        let shortest = self
+            .aging_thresholds
+            .and_then(|bands| bands.into_iter().min())
+            .unwrap_or(self.aging_threshold);
+        std::cmp::max(shortest, Duration::from_millis(20))
     }
 }
 
@@ -183,13 +457,13 @@ struct TokenBucket {
 }
 
 impl TokenBucket {
-    fn new(capacity: f64, refill_rate: f64, initial: f64) -> Self {
+    fn new(capacity: f64, refill_rate: f64, initial: f64, now: Instant) -> Self {
         let tokens = initial.min(capacity);
         Self {
             capacity,
             tokens,
             refill_rate,
-            last_refill: Instant::now(),
+            last_refill: now,
         }
     }
 
@@ -202,6 +476,12 @@ impl TokenBucket {
         }
     }
 
+    fn reconfigure(&mut self, capacity: f64, refill_rate: f64) {
+        self.capacity = capacity;
+        self.refill_rate = refill_rate;
+        self.tokens = self.tokens.min(capacity);
+    }
+
     fn top_up(&mut self, now: Instant) {
         let elapsed = now
             .saturating_duration_since(self.last_refill)
@@ -212,6 +492,17 @@ impl TokenBucket {
         }
     }
 
+    fn time_until(&self, cost: f64, now: Instant) -> Option<Duration> {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        let available = (self.tokens + elapsed * self.refill_rate).min(self.capacity);
+        if available >= cost || self.refill_rate <= 0.0 || cost > self.capacity {
+            return None;
+        }
+        Duration::try_from_secs_f64((cost - available) / self.refill_rate).ok()
+    }
+
     fn snapshot(&self, now: Instant) -> (f64, f64, f64, Duration) {
         (
             self.tokens,
@@ -223,23 +514,89 @@ impl TokenBucket {
 }
 
 #[derive(Debug)]
+struct RecentMessageIds {
+    capacity: usize,
+    window: Duration,
+    order: VecDeque<(String, Instant)>,
+    seen: HashMap<String, Instant>,
+}
+
+impl RecentMessageIds {
+    fn new(capacity: usize, window: Duration) -> Self {
+        Self {
+            capacity,
+            window,
+            order: VecDeque::new(),
+            seen: HashMap::new(),
+        }
+    }
+
+    fn observe(&mut self, id: String, now: Instant) -> bool {
+        while let Some((oldest, seen_at)) = self.order.front() {
+            let expired = now.saturating_duration_since(*seen_at) >= self.window;
+            if !expired && self.order.len() < self.capacity.max(1) {
+                break;
+            }
+            if self.seen.get(oldest) == Some(seen_at) {
+                self.seen.remove(oldest);
+            }
+            self.order.pop_front();
+        }
+        if self.seen.contains_key(&id) {
+            return false;
+        }
+        self.seen.insert(id.clone(), now);
+        self.order.push_back((id, now));
+        true
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteOutcome {
+    pub priority: Priority,
+    pub queue_depth: usize,
+    pub near_capacity: bool,
+    pub critical: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
 pub enum RouteError {
-    RouterShuttingDown,
+    #[error("router is shutting down and no longer accepts messages")]
+    ShuttingDown,
+    #[error("duplicate message `{message_id}` was dropped")]
+    Duplicate { message_id: String },
+    #[error("message is {size} bytes, exceeding the {limit} byte limit")]
+    TooLarge { size: usize, limit: usize },
+    #[error("message rejected by middleware: {0}")]
+    Rejected(String),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MiddlewareDecision {
+    Continue,
+    Drop(String),
+}
+
+pub type RouterMiddleware = Box<dyn Fn(&mut Message) -> MiddlewareDecision + Send + Sync>;
+
 pub struct UnifiedMessageRouter {
     queues: Vec<Arc<RwLock<VecDeque<QueuedMessage>>>>,
+    scheduled: Arc<Mutex<DelayQueue>>,
     notify: Arc<Notify>,
     token_buckets: Arc<RwLock<HashMap<String, TokenBucket>>>,
+    recent_ids: Mutex<RecentMessageIds>,
     metrics: MetricsCollector,
     dispatcher: Mutex<Option<JoinHandle<()>>>,
     maintenance_executor: Mutex<Option<MaintenanceExecutor>>,
     maintenance_started: AtomicBool,
     shutdown: watch::Sender<bool>,
+    paused: watch::Sender<bool>,
     deliveries: broadcast::Sender<RouterDelivery>,
-    config: DispatcherConfig,
+    config: watch::Sender<DispatcherConfig>,
     ledger: Option<LedgerWriter>,
     consensus: Option<ConsensusBroker>,
+    middleware: std::sync::RwLock<Vec<RouterMiddleware>>,
+    clock: SharedClock,
 }
 
 impl UnifiedMessageRouter {
@@ -296,31 +653,81 @@ impl UnifiedMessageRouter {
         let notify = Arc::new(Notify::new());
         let token_buckets = Arc::new(RwLock::new(HashMap::new()));
         let (shutdown, _) = watch::channel(false);
+        let (paused, _) = watch::channel(false);
         let (deliveries, _) = broadcast::channel(256);
+        let recent_ids = RecentMessageIds::new(config.dedup_capacity, config.dedup_window);
+        let (config, _) = watch::channel(config);
         Self {
             queues,
+            scheduled: Arc::new(Mutex::new(DelayQueue::default())),
             notify,
             token_buckets,
+            recent_ids: Mutex::new(recent_ids),
             metrics,
             dispatcher: Mutex::new(None),
             maintenance_executor: Mutex::new(None),
             maintenance_started: AtomicBool::new(false),
             shutdown,
+            paused,
             deliveries,
             config,
             ledger,
             consensus,
+            middleware: std::sync::RwLock::new(Vec::new()),
+            clock: system_clock(),
         }
     }
 
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn pause(&self) {
+        self.paused.send_replace(true);
+    }
+
+    pub fn resume(&self) {
+        self.paused.send_replace(false);
+        self.notify.notify_waiters();
+    }
+
+    pub fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
     pub fn dispatcher_config(&self) -> DispatcherConfig {
-        self.config
+        self.config.borrow().clone()
+    }
+
+    pub async fn update_config(&self, router_config: Option<&RouterConfig>) {
+        let mut config = self.config.borrow().clone();
+        if let Some(cfg) = router_config {
+            config.merge_router_config(cfg);
+        }
+        {
+            let mut buckets = self.token_buckets.write().await;
+            for bucket in buckets.values_mut() {
+                bucket.reconfigure(config.token_capacity, config.token_refill_rate);
+            }
+        }
+        {
+            let mut recent_ids = self.recent_ids.lock().await;
+            recent_ids.capacity = config.dedup_capacity;
+            recent_ids.window = config.dedup_window;
+        }
+        self.config.send_replace(config);
+        self.notify.notify_waiters();
     }
 
     pub fn subscribe(&self) -> broadcast::Receiver<RouterDelivery> {
         self.deliveries.subscribe()
     }
 
+    pub fn subscribe_stream(&self) -> RouterDeliveryStream {
+        EventStream::new(self.deliveries.subscribe())
+    }
+
     pub async fn set_maintenance_executor(&self, executor: MaintenanceExecutor) {
         let mut guard = self.maintenance_executor.lock().await;
         *guard = Some(executor);
@@ -332,20 +739,174 @@ impl UnifiedMessageRouter {
         self.maintenance_executor.lock().await.clone()
     }
 
+    pub fn add_middleware(&self, middleware: RouterMiddleware) {
+        self.middleware.write().unwrap().push(middleware);
+    }
+
+    fn apply_middleware(&self, msg: &mut Message) -> Result<(), RouteError> {
+        let chain = self.middleware.read().unwrap();
+        for middleware in chain.iter() {
+            if let MiddlewareDecision::Drop(reason) = middleware(msg) {
+                return Err(RouteError::Rejected(reason));
+            }
+        }
+        Ok(())
+    }
+
     pub async fn route_message(&self, msg: Message) -> Result<(), RouteError> {
+        self.try_route_message(msg).await.map(|_| ())
+    }
+
+    /// Blocking variant of [`Self::route_message`]. Must not be called from a current-thread async task.
+    pub fn route_message_blocking(&self, msg: Message) -> Result<(), RouteError> {
+        block_on(self.route_message(msg))
+    }
+
+    pub async fn route_message_tracked(&self, msg: Message) -> Result<DeliveryToken, RouteError> {
+        let (ack, receiver) = oneshot::channel();
+        self.enqueue(msg, Some(ack)).await?;
+        Ok(DeliveryToken { receiver })
+    }
+
+    pub async fn try_route_message(&self, msg: Message) -> Result<RouteOutcome, RouteError> {
+        self.enqueue(msg, None).await
+    }
+
+    pub async fn route_message_at(
+        &self,
+        msg: Message,
+        deliver_after: Duration,
+    ) -> Result<(), RouteError> {
+        self.enqueue_after(msg, None, deliver_after)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn scheduled_count(&self) -> usize {
+        self.scheduled.lock().await.entries.len()
+    }
+
+    pub async fn time_until_tokens(&self, sender: &str, cost: f64) -> Option<Duration> {
+        let config = self.dispatcher_config();
+        if config.exempt_senders.contains(sender) {
+            return None;
+        }
+        let now = self.clock.now_instant();
+        let buckets = self.token_buckets.read().await;
+        match buckets.get(sender) {
+            Some(bucket) => bucket.time_until(cost, now),
+            None => TokenBucket::new(
+                config.token_capacity,
+                config.token_refill_rate,
+                config.initial_tokens,
+                now,
+            )
+            .time_until(cost, now),
+        }
+    }
+
+    async fn enqueue(
+        &self,
+        msg: Message,
+        ack: Option<oneshot::Sender<Result<RouterDelivery, DeliveryError>>>,
+    ) -> Result<RouteOutcome, RouteError> {
+        self.enqueue_after(msg, ack, Duration::ZERO).await
+    }
+
+    async fn enqueue_after(
+        &self,
+        mut msg: Message,
+        ack: Option<oneshot::Sender<Result<RouterDelivery, DeliveryError>>>,
+        deliver_after: Duration,
+    ) -> Result<RouteOutcome, RouteError> {
         if *self.shutdown.borrow() {
-            return Err(RouteError::RouterShuttingDown);
+            return Err(RouteError::ShuttingDown);
+        }
+        self.apply_middleware(&mut msg)?;
+        let limit = self.config.borrow().max_message_bytes;
+        let size = msg.content.len();
+        if size > limit {
+            return Err(RouteError::TooLarge { size, limit });
         }
         self.ensure_dispatcher_started().await;
-        let queued = QueuedMessage::new(msg);
-        let index = queued.effective_priority.as_index();
+        let priority = msg.priority;
+        let index = priority.as_index();
+        let now = self.clock.now_instant();
+        let dedup_key = match &msg.message_id {
+            Some(id) => Some(id.clone()),
+            None if self.config.borrow().dedup_content_digest => Some(msg.dedup_key()),
+            None => None,
+        };
+        if let Some(key) = dedup_key {
+            if !self.recent_ids.lock().await.observe(key.clone(), now) {
+                self.metrics.increment_deduplicated();
+                return Err(RouteError::Duplicate { message_id: key });
+            }
+        }
+        let mut queued = QueuedMessage::new(msg, now);
+        queued.ack = ack;
+        if !deliver_after.is_zero() {
+            self.scheduled
+                .lock()
+                .await
+                .schedule(queued, now + deliver_after);
+            self.notify.notify_one();
+            let queue_depth = self.queues[index].read().await.len();
+            return Ok(self.route_outcome(priority, queue_depth));
+        }
         let mut queue = self.queues[index].write().await;
         queue.push_back(queued);
+        let queue_depth = queue.len();
         drop(queue);
         let depths = queue_depths(&self.queues).await;
         self.metrics.update_queue_depths(&depths);
         self.notify.notify_one();
-        Ok(())
+        Ok(self.route_outcome(priority, queue_depth))
+    }
+
+    fn route_outcome(&self, priority: Priority, queue_depth: usize) -> RouteOutcome {
+        let config = self.dispatcher_config();
+        RouteOutcome {
+            priority,
+            queue_depth,
+            near_capacity: queue_depth >= config.queue_depth_warning,
+            critical: queue_depth >= config.queue_depth_critical,
+        }
+    }
+
+    pub async fn current_queue_depths(&self) -> [usize; PRIORITY_LEVELS] {
+        queue_depths(&self.queues).await
+    }
+
+    pub async fn drain(&self, timeout: Duration) -> usize {
+        let deadline = tokio::time::Instant::now() + timeout;
+        self.flush_scheduled().await;
+        loop {
+            let remaining: usize = self.current_queue_depths().await.iter().sum();
+            if remaining == 0 || tokio::time::Instant::now() >= deadline {
+                return remaining;
+            }
+            self.notify.notify_one();
+            tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+        }
+    }
+
+    async fn flush_scheduled(&self) {
+        let flushed = self.scheduled.lock().await.take_all();
+        if flushed.is_empty() {
+            return;
+        }
+        let now = self.clock.now_instant();
+        for queued in flushed {
+            let index = queued.effective_priority.as_index();
+            self.queues[index]
+                .write()
+                .await
+                .push_back(queued.released(now));
+        }
+        let depths = self.current_queue_depths().await;
+        self.metrics.update_queue_depths(&depths);
+        self.notify.notify_one();
     }
 
     pub async fn get_pending_messages(&self) -> Vec<Message> {
@@ -364,26 +925,21 @@ impl UnifiedMessageRouter {
             self.start_maintenance_if_needed().await;
             return;
         }
-        let queues = self.queues.iter().cloned().collect::<Vec<_>>();
-        let notify = Arc::clone(&self.notify);
-        let token_buckets = Arc::clone(&self.token_buckets);
-        let metrics = self.metrics.clone();
-        let deliveries = self.deliveries.clone();
+        let context = DispatcherContext {
+            queues: self.queues.iter().cloned().collect(),
+            scheduled: Arc::clone(&self.scheduled),
+            notify: Arc::clone(&self.notify),
+            token_buckets: Arc::clone(&self.token_buckets),
+            metrics: self.metrics.clone(),
+            deliveries: self.deliveries.clone(),
+            config_rx: self.config.subscribe(),
+            ledger: self.ledger.clone(),
+            clock: Arc::clone(&self.clock),
+        };
         let mut shutdown_rx = self.shutdown.subscribe();
-        let config = self.config;
-        let ledger = self.ledger.clone();
+        let mut paused_rx = self.paused.subscribe();
         let handle = tokio::spawn(async move {
-            run_dispatcher(
-                queues,
-                notify,
-                token_buckets,
-                metrics,
-                deliveries,
-                config,
-                ledger,
-                &mut shutdown_rx,
-            )
-            .await;
+            run_dispatcher(context, &mut shutdown_rx, &mut paused_rx).await;
         });
         *guard = Some(handle);
         drop(guard);
@@ -414,18 +970,18 @@ impl UnifiedMessageRouter {
         let token_buckets = Arc::clone(&self.token_buckets);
         let notify = Arc::clone(&self.notify);
         let shutdown_sender = self.shutdown.clone();
-        let config = self.config;
+        let mut config_rx = self.config.subscribe();
+        let clock = Arc::clone(&self.clock);
 
         {
             let queues = Arc::clone(&queues);
+            let clock = Arc::clone(&clock);
             let executor = executor.clone();
             let notify = notify.clone();
             let mut shutdown_rx = shutdown_sender.subscribe();
             tokio::spawn(async move {
-                let mut ticker = tokio::time::interval(std::cmp::max(
-                    config.aging_threshold,
-                    Duration::from_millis(20),
-                ));
+                let mut aging_tick = config_rx.borrow_and_update().aging_tick();
+                let mut ticker = tokio::time::interval(aging_tick);
                 loop {
                     tokio::select! {
                         result = shutdown_rx.changed() => {
@@ -438,11 +994,23 @@ impl UnifiedMessageRouter {
                                 Err(_) => break,
                             }
                         }
+                        result = config_rx.changed() => {
+                            if result.is_err() {
+                                break;
+                            }
+                            let tick = config_rx.borrow_and_update().aging_tick();
+                            if tick != aging_tick {
+                                aging_tick = tick;
+                                ticker = tokio::time::interval(aging_tick);
+                            }
+                        }
                         _ = ticker.tick() => {
                             let queues = Arc::clone(&queues);
                             let notify = notify.clone();
-                            executor.spawn(async move {
-                                apply_aging(queues.as_ref(), config).await;
+                            let config = config_rx.borrow().clone();
+                            let now = clock.now_instant();
+                            let _ = executor.spawn(async move {
+                                apply_aging(queues.as_ref(), config, now).await;
                                 notify.notify_waiters();
                             });
                         }
@@ -473,8 +1041,9 @@ impl UnifiedMessageRouter {
                         _ = ticker.tick() => {
                             let buckets = Arc::clone(&buckets);
                             let notify = notify.clone();
-                            executor.spawn(async move {
-                                refill_all_token_buckets(buckets, notify).await;
+                            let now = clock.now_instant();
+                            let _ = executor.spawn(async move {
+                                refill_all_token_buckets(buckets, notify, now).await;
                             });
                         }
                     }
@@ -495,58 +1064,153 @@ impl Drop for UnifiedMessageRouter {
     }
 }
 
-async fn run_dispatcher(
+#[derive(Debug, Clone, Copy)]
+struct IdleBackoff {
+    current: Duration,
+}
+
+impl IdleBackoff {
+    fn new(config: &DispatcherConfig) -> Self {
+        Self {
+            current: config.idle_backoff,
+        }
+    }
+
+    fn reset(&mut self, config: &DispatcherConfig) {
+        self.current = config.idle_backoff;
+    }
+
+    fn next_sleep(&mut self, config: &DispatcherConfig) -> Duration {
+        let cap = config.idle_backoff_max.max(config.idle_backoff);
+        let sleep = self.current.clamp(config.idle_backoff, cap);
+        self.current = sleep.saturating_mul(2).min(cap);
+        sleep
+    }
+}
+
+struct DispatcherContext {
     queues: Vec<Arc<RwLock<VecDeque<QueuedMessage>>>>,
+    scheduled: Arc<Mutex<DelayQueue>>,
     notify: Arc<Notify>,
     token_buckets: Arc<RwLock<HashMap<String, TokenBucket>>>,
     metrics: MetricsCollector,
     deliveries: broadcast::Sender<RouterDelivery>,
-    config: DispatcherConfig,
+    config_rx: watch::Receiver<DispatcherConfig>,
     ledger: Option<LedgerWriter>,
+    clock: SharedClock,
+}
+
+async fn run_dispatcher(
+    context: DispatcherContext,
     shutdown_rx: &mut watch::Receiver<bool>,
+    paused_rx: &mut watch::Receiver<bool>,
 ) {
+    let DispatcherContext {
+        queues,
+        scheduled,
+        notify,
+        token_buckets,
+        metrics,
+        deliveries,
+        config_rx,
+        ledger,
+        clock,
+    } = context;
+    let mut director_buckets: HashMap<String, TokenBucket> = HashMap::new();
+    let mut idle_backoff = IdleBackoff::new(&config_rx.borrow());
     loop {
         if *shutdown_rx.borrow() {
             break;
         }
+        if *paused_rx.borrow_and_update() {
+            tokio::select! {
+                _ = paused_rx.changed() => {}
+                _ = shutdown_rx.changed() => {}
+            }
+            continue;
+        }
+        let config = config_rx.borrow().clone();
+        let next_deadline = release_scheduled(&scheduled, &queues, &metrics, &clock).await;
         let mut dispatched = false;
+        let mut pending = false;
         for priority in (0..queues.len()).rev() {
             let maybe_message = {
                 let mut queue = queues[priority].write().await;
-                queue.pop_front()
+                let now = clock.now_instant();
+                match queue.iter().position(|queued| queued.is_eligible(now)) {
+                    Some(index) => queue.remove(index),
+                    None => {
+                        pending |= !queue.is_empty();
+                        None
+                    }
+                }
             };
             if let Some(mut queued) = maybe_message {
+                pending = true;
                 let sender_id = queued.message.sender.clone();
-                let now = Instant::now();
-                let (should_dispatch, tokens_remaining, capacity, refill_rate, since_last_refill) = {
-                    let mut buckets = token_buckets.write().await;
-                    let bucket = buckets.entry(sender_id.clone()).or_insert_with(|| {
-                        TokenBucket::new(
-                            config.token_capacity,
-                            config.token_refill_rate,
-                            config.initial_tokens,
+                let now = clock.now_instant();
+                let exempt = config.exempt_senders.contains(&sender_id);
+                let director_capacity = config
+                    .director_override_capacity
+                    .filter(|_| queued.effective_priority == Priority::DirectorOverride);
+                let (should_dispatch, tokens_remaining, capacity, refill_rate, since_last_refill) =
+                    if let Some(director_capacity) = director_capacity {
+                        let bucket =
+                            director_buckets
+                                .entry(sender_id.clone())
+                                .or_insert_with(|| {
+                                    TokenBucket::new(
+                                        director_capacity,
+                                        config.director_override_refill_rate,
+                                        director_capacity,
+                                        now,
+                                    )
+                                });
+                        bucket.reconfigure(director_capacity, config.director_override_refill_rate);
+                        bucket.top_up(now);
+                        let dispatched = exempt || bucket.try_consume(DIRECTOR_OVERRIDE_TOKEN_COST);
+                        let (tokens_remaining, capacity, refill_rate, since_last_refill) =
+                            bucket.snapshot(now);
+                        (
+                            dispatched,
+                            tokens_remaining,
+                            capacity,
+                            refill_rate,
+                            since_last_refill,
                         )
-                    });
-                    let dispatched = bucket.try_consume(queued.effective_priority.token_cost());
-                    let (tokens_remaining, capacity, refill_rate, since_last_refill) =
-                        bucket.snapshot(now);
-                    (
-                        dispatched,
+                    } else {
+                        let mut buckets = token_buckets.write().await;
+                        let bucket = buckets.entry(sender_id.clone()).or_insert_with(|| {
+                            TokenBucket::new(
+                                config.token_capacity,
+                                config.token_refill_rate,
+                                config.initial_tokens,
+                                now,
+                            )
+                        });
+                        let dispatched =
+                            exempt || bucket.try_consume(queued.effective_priority.token_cost());
+                        let (tokens_remaining, capacity, refill_rate, since_last_refill) =
+                            bucket.snapshot(now);
+                        (
+                            dispatched,
+                            tokens_remaining,
+                            capacity,
+                            refill_rate,
+                            since_last_refill,
+                        )
+                    };
+                if director_capacity.is_none() {
+                    let now = SystemTime::now();
+                    let last_refill = now.checked_sub(since_last_refill).unwrap_or(now);
+                    metrics.update_token_bucket(
+                        &sender_id,
                         tokens_remaining,
                         capacity,
                         refill_rate,
-                        since_last_refill,
-                    )
-                };
-                let now = SystemTime::now();
-                let last_refill = now.checked_sub(since_last_refill).unwrap_or(now);
-                metrics.update_token_bucket(
-                    &sender_id,
-                    tokens_remaining,
-                    capacity,
-                    refill_rate,
-                    Some(last_refill),
-                );
+                        Some(last_refill),
+                    );
+                }
                 if !should_dispatch {
                     let priority_label = queued.effective_priority.as_str().to_string();
                     let rate_event = ledger.as_ref().map(|writer| {
@@ -560,7 +1224,7 @@ async fn run_dispatcher(
                         )
                     });
                     metrics.increment_rate_limited(&sender_id);
-                    queued.record_attempt();
+                    queued.record_attempt(now, &config);
                     let index = queued.effective_priority.as_index();
                     let mut queue = queues[index].write().await;
                     queue.push_back(queued);
@@ -578,7 +1242,9 @@ async fn run_dispatcher(
                     }
                     continue;
                 }
-                let wait_time = queued.enqueued_at.elapsed();
+                let wait_time = clock
+                    .now_instant()
+                    .saturating_duration_since(queued.enqueued_at);
                 let queue_depths = queue_depths(&queues).await;
                 let delivery = RouterDelivery {
                     message: queued.message.clone(),
@@ -592,12 +1258,17 @@ async fn run_dispatcher(
                     (
                         writer.clone(),
                         RouterDispatchRecord {
-                            message_id: Some(format!(
-                                "{}-{}-{}",
-                                delivery.message.sender,
-                                delivery.message.recipient,
-                                delivery.retry_count
+                            message_id: Some(delivery.message.message_id.clone().unwrap_or_else(
+                                || {
+                                    format!(
+                                        "{}-{}-{}",
+                                        delivery.message.sender,
+                                        delivery.message.recipient,
+                                        delivery.retry_count
+                                    )
+                                },
                             )),
+                            trace_id: delivery.message.trace_id.clone(),
                             content_digest: Some(
                                 blake3_hash(delivery.message.content.as_bytes())
                                     .to_hex()
@@ -614,6 +1285,9 @@ async fn run_dispatcher(
                         },
                     )
                 });
+                if let Some(ack) = queued.ack.take() {
+                    let _ = ack.send(Ok(delivery.clone()));
+                }
                 let _ = deliveries.send(delivery.clone());
                 metrics.record_router_delivery(
                     queued.effective_priority,
@@ -634,20 +1308,50 @@ async fn run_dispatcher(
                 break;
             }
         }
+        if dispatched || pending {
+            idle_backoff.reset(&config);
+        }
         if !dispatched {
+            let mut sleep = idle_backoff.next_sleep(&config);
+            if let Some(deadline) = next_deadline {
+                sleep = sleep.min(deadline.saturating_duration_since(clock.now_instant()));
+            }
             tokio::select! {
-                _ = notify.notified() => {}
+                _ = notify.notified() => idle_backoff.reset(&config),
                 _ = shutdown_rx.changed() => {
                     if *shutdown_rx.borrow() {
                         break;
                     }
                 }
-                _ = tokio::time::sleep(config.idle_backoff) => {}
+                _ = tokio::time::sleep(sleep) => {}
             }
         }
     }
 }
 
+async fn release_scheduled(
+    scheduled: &Mutex<DelayQueue>,
+    queues: &[Arc<RwLock<VecDeque<QueuedMessage>>>],
+    metrics: &MetricsCollector,
+    clock: &SharedClock,
+) -> Option<Instant> {
+    let now = clock.now_instant();
+    let mut scheduled = scheduled.lock().await;
+    let mut released = false;
+    while let Some(queued) = scheduled.pop_due(now) {
+        let index = queued.effective_priority.as_index();
+        queues[index].write().await.push_back(queued.released(now));
+        released = true;
+    }
+    let next_deadline = scheduled.next_deadline();
+    drop(scheduled);
+    if released {
+        let depths = queue_depths(queues).await;
+        metrics.update_queue_depths(&depths);
+    }
+    next_deadline
+}
+
 async fn queue_depths(queues: &[Arc<RwLock<VecDeque<QueuedMessage>>>]) -> [usize; PRIORITY_LEVELS] {
     let mut depths = [0usize; PRIORITY_LEVELS];
     for (index, queue) in queues.iter().enumerate() {
@@ -659,12 +1363,12 @@ async fn queue_depths(queues: &[Arc<RwLock<VecDeque<QueuedMessage>>>]) -> [usize
 async fn refill_all_token_buckets(
     token_buckets: Arc<RwLock<HashMap<String, TokenBucket>>>,
     notify: Arc<Notify>,
+    now: Instant,
 ) {
     let mut buckets = token_buckets.write().await;
     if buckets.is_empty() {
         return;
     }
-    let now = Instant::now();
     for bucket in buckets.values_mut() {
         bucket.top_up(now);
     }
@@ -672,19 +1376,22 @@ async fn refill_all_token_buckets(
     notify.notify_waiters();
 }
 
-async fn apply_aging(queues: &[Arc<RwLock<VecDeque<QueuedMessage>>>], config: DispatcherConfig) {
+async fn apply_aging(
+    queues: &[Arc<RwLock<VecDeque<QueuedMessage>>>],
+    config: DispatcherConfig,
+    now: Instant,
+) {
     if queues.is_empty() {
         return;
     }
     for priority in 0..queues.len().saturating_sub(1) {
+        let threshold = config.aging_threshold_for(Priority::from_index(priority));
         let mut queue = queues[priority].write().await;
         let mut index = 0;
         while index < queue.len() {
             let should_boost = queue
                 .get(index)
-                .map(|queued| {
-                    queued.eligible_for_boost(config.aging_threshold, config.max_aging_boosts)
-                })
+                .map(|queued| queued.eligible_for_boost(threshold, config.max_aging_boosts, now))
                 .unwrap_or(false);
             if should_boost {
                 if let Some(mut queued) = queue.remove(index) {
@@ -715,12 +1422,82 @@ mod tests {
             token_bucket_initial: Some(128.0),
             aging_threshold: Some("250ms".to_string()),
             max_aging_boosts: Some(5),
+            aging_thresholds: Some(HashMap::from([
+                ("info".to_string(), "2s".to_string()),
+                ("blocking".to_string(), "100ms".to_string()),
+            ])),
             idle_backoff: Some("15ms".to_string()),
+            idle_backoff_max: Some("240ms".to_string()),
+            retry_backoff: Some("20ms".to_string()),
+            retry_backoff_max: Some("2s".to_string()),
             queue_depth_warning: Some(10),
             queue_depth_critical: Some(20),
+            director_override_capacity: Some(50.0),
+            director_override_refill_rate: Some(5.0),
+            max_message_bytes: Some(4096),
+            dedup_content_digest: Some(true),
+            exempt_senders: Some(vec!["director".to_string()]),
         }
     }
 
+    #[test]
+    fn priority_round_trips_through_str() {
+        for index in 0..PRIORITY_LEVELS {
+            let priority = Priority::from_index(index);
+            assert_eq!(priority.as_str().parse::<Priority>(), Ok(priority));
+            assert_eq!(Priority::try_from(priority.as_str()), Ok(priority));
+        }
+        assert_eq!(
+            "urgent".parse::<Priority>(),
+            Err(ParsePriorityError("urgent".to_string()))
+        );
+        assert!(Priority::try_from("Critical").is_err());
+    }
+
+    #[test]
+    fn idle_backoff_grows_while_idle_and_resets() {
+        let config = DispatcherConfig {
+            idle_backoff: Duration::from_millis(5),
+            idle_backoff_max: Duration::from_millis(30),
+            ..DispatcherConfig::default()
+        };
+        let mut backoff = IdleBackoff::new(&config);
+        let sleeps: Vec<Duration> = (0..5).map(|_| backoff.next_sleep(&config)).collect();
+        assert_eq!(
+            sleeps,
+            [5, 10, 20, 30, 30].map(Duration::from_millis).to_vec()
+        );
+        backoff.reset(&config);
+        assert_eq!(backoff.next_sleep(&config), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn route_errors_render_context() {
+        assert_eq!(
+            RouteError::ShuttingDown.to_string(),
+            "router is shutting down and no longer accepts messages"
+        );
+        assert_eq!(
+            RouteError::Duplicate {
+                message_id: "msg-7".to_string(),
+            }
+            .to_string(),
+            "duplicate message `msg-7` was dropped"
+        );
+        assert_eq!(
+            RouteError::TooLarge {
+                size: 2048,
+                limit: 1024,
+            }
+            .to_string(),
+            "message is 2048 bytes, exceeding the 1024 byte limit"
+        );
+        assert_eq!(
+            RouteError::Rejected("sender is banned".to_string()).to_string(),
+            "message rejected by middleware: sender is banned"
+        );
+    }
+
     #[test]
     fn dispatcher_config_applies_overrides() {
         let overrides = build_router_config();
@@ -730,7 +1507,82 @@ mod tests {
         assert_eq!(config.initial_tokens, 128.0);
         assert_eq!(config.max_aging_boosts, 5);
         assert_eq!(config.aging_threshold, Duration::from_millis(250));
+        assert_eq!(
+            config.aging_threshold_for(Priority::Info),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            config.aging_threshold_for(Priority::Coordinate),
+            Duration::from_millis(250)
+        );
+        assert_eq!(
+            config.aging_threshold_for(Priority::Blocking),
+            Duration::from_millis(100)
+        );
         assert_eq!(config.idle_backoff, Duration::from_millis(15));
+        assert_eq!(config.idle_backoff_max, Duration::from_millis(240));
+        assert_eq!(config.retry_backoff, Duration::from_millis(20));
+        assert_eq!(config.retry_backoff_max, Duration::from_secs(2));
+        assert_eq!(config.queue_depth_warning, 10);
+        assert_eq!(config.queue_depth_critical, 20);
+        assert_eq!(config.director_override_capacity, Some(50.0));
+        assert_eq!(config.director_override_refill_rate, 5.0);
+        assert_eq!(config.max_message_bytes, 4096);
+        assert!(config.dedup_content_digest);
+        assert!(config.exempt_senders.contains("director"));
+    }
+
+    #[test]
+    fn throttled_retries_are_staggered_with_growing_backoff() {
+        let config = DispatcherConfig::default();
+        let now = Instant::now();
+        let mut throttled: Vec<QueuedMessage> = (0..6)
+            .map(|index| {
+                QueuedMessage::new(
+                    Message {
+                        content: format!("burst {index}"),
+                        priority: Priority::Info,
+                        sender: "noisy".to_string(),
+                        recipient: "peer".to_string(),
+                        message_id: None,
+                        trace_id: None,
+                    },
+                    now,
+                )
+            })
+            .collect();
+
+        for queued in throttled.iter_mut() {
+            queued.record_attempt(now, &config);
+            assert!(!queued.is_eligible(now));
+        }
+        let first: Vec<Instant> = throttled
+            .iter()
+            .map(|queued| queued.next_eligible_at.unwrap())
+            .collect();
+        assert!(first.iter().all(|at| {
+            let delay = at.duration_since(now);
+            delay >= config.retry_backoff / 2 && delay <= config.retry_backoff
+        }));
+        let distinct: std::collections::HashSet<_> = first.iter().collect();
+        assert!(distinct.len() > 1);
+        let earliest = *first.iter().min().unwrap();
+        assert!(throttled.iter().any(|queued| !queued.is_eligible(earliest)));
+
+        for (queued, at) in throttled.iter_mut().zip(first) {
+            queued.record_attempt(at, &config);
+            let delay = queued.next_eligible_at.unwrap().duration_since(at);
+            assert!(delay >= config.retry_backoff);
+            assert!(delay <= config.retry_backoff * 2);
+        }
+
+        let mut stubborn = throttled.remove(0);
+        for _ in 0..20 {
+            stubborn.record_attempt(now, &config);
+        }
+        let capped = stubborn.next_eligible_at.unwrap().duration_since(now);
+        assert!(capped <= config.retry_backoff_max);
+        assert!(capped >= config.retry_backoff_max / 2);
     }
 
     #[test]
@@ -741,12 +1593,60 @@ mod tests {
             token_bucket_initial: None,
             aging_threshold: None,
             max_aging_boosts: None,
+            aging_thresholds: None,
             idle_backoff: None,
+            idle_backoff_max: None,
+            retry_backoff: None,
+            retry_backoff_max: None,
             queue_depth_warning: None,
             queue_depth_critical: None,
+            director_override_capacity: None,
+            director_override_refill_rate: None,
+            max_message_bytes: None,
+            dedup_content_digest: None,
+            exempt_senders: None,
         };
         let config = DispatcherConfig::from_router_config(Some(&overrides));
         assert_eq!(config.token_capacity, 300.0);
         assert_eq!(config.initial_tokens, 300.0);
     }
+
+    #[tokio::test]
+    async fn update_config_keeps_settings_missing_from_the_file() {
+        let router = UnifiedMessageRouter::with_config(
+            MetricsCollector::new(),
+            DispatcherConfig {
+                aging_threshold: Duration::from_secs(2),
+                exempt_senders: Arc::new(["director".to_string()].into_iter().collect()),
+                ..DispatcherConfig::default()
+            },
+        );
+        let overrides = RouterConfig {
+            token_bucket_capacity: None,
+            token_bucket_refill_rate: None,
+            token_bucket_initial: None,
+            aging_threshold: None,
+            max_aging_boosts: None,
+            aging_thresholds: None,
+            idle_backoff: None,
+            idle_backoff_max: None,
+            retry_backoff: None,
+            retry_backoff_max: None,
+            queue_depth_warning: Some(16),
+            queue_depth_critical: None,
+            director_override_capacity: None,
+            director_override_refill_rate: None,
+            max_message_bytes: None,
+            dedup_content_digest: None,
+            exempt_senders: None,
+        };
+        router.update_config(Some(&overrides)).await;
+        let config = router.dispatcher_config();
+        assert_eq!(config.queue_depth_warning, 16);
+        assert_eq!(config.aging_threshold, Duration::from_secs(2));
+        assert!(config.exempt_senders.contains("director"));
+
+        router.update_config(None).await;
+        assert_eq!(router.dispatcher_config().queue_depth_warning, 16);
+    }
 }
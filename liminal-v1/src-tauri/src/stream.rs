@@ -0,0 +1,40 @@
+use tokio::sync::broadcast::{self, error::RecvError, error::TryRecvError};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamItem<T> {
+    Event(T),
+    Gap { skipped: u64 },
+}
+
+#[derive(Debug)]
+pub struct EventStream<T> {
+    receiver: broadcast::Receiver<T>,
+}
+
+impl<T: Clone> EventStream<T> {
+    pub fn new(receiver: broadcast::Receiver<T>) -> Self {
+        Self { receiver }
+    }
+
+    pub async fn recv(&mut self) -> Option<StreamItem<T>> {
+        match self.receiver.recv().await {
+            Ok(event) => Some(StreamItem::Event(event)),
+            Err(RecvError::Lagged(skipped)) => Some(StreamItem::Gap { skipped }),
+            Err(RecvError::Closed) => None,
+        }
+    }
+
+    pub fn try_recv(&mut self) -> Option<StreamItem<T>> {
+        match self.receiver.try_recv() {
+            Ok(event) => Some(StreamItem::Event(event)),
+            Err(TryRecvError::Lagged(skipped)) => Some(StreamItem::Gap { skipped }),
+            Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => None,
+        }
+    }
+}
+
+impl<T: Clone> From<broadcast::Receiver<T>> for EventStream<T> {
+    fn from(receiver: broadcast::Receiver<T>) -> Self {
+        Self::new(receiver)
+    }
+}
@@ -1,18 +1,21 @@
+use crate::clock::{system_clock, Clock, SharedClock};
 use crate::config::{
     parse_duration as parse_duration_str, TerritoryConfig as TerritoryConfigOverrides,
 };
-use crate::executor::MaintenanceExecutor;
+use crate::executor::{block_on, MaintenanceExecutor};
 use crate::metrics::{HeatSummary, MetricsCollector, QuorumMetricsUpdate};
 
 #[allow(unused_imports)]
-use crate::consensus::{quorum_vote, ConsensusBroker};
+use crate::consensus::{quorum_vote, ConsensusBroker, ConsensusRound, RoundOutcome};
 
 #[allow(unused_imports)]
 use crate::ledger::{
-    LeaseEscalationRecord, LeaseEvent as LedgerLeaseEvent, LeaseQueueRecord, LeaseRecord,
-    LedgerEvent, LedgerWriter, QuorumVote,
+    heat_decay_factor, lease_trace_id, HeatParameters, LeaseEscalationRecord,
+    LeaseEvent as LedgerLeaseEvent, LeaseQueueRecord, LeaseRecord, LedgerEvent, LedgerWriter,
+    QuorumVote,
 };
 use crate::router::Priority;
+use crate::stream::{EventStream, StreamItem};
 use std::collections::HashMap;
 #[cfg(feature = "spatial-hash")]
 use std::collections::HashSet;
@@ -20,7 +23,7 @@ use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, UNIX_EPOCH};
 use tokio::sync::{broadcast, watch, Mutex, RwLock};
 
 pub type ResourcePath = String;
@@ -36,6 +39,8 @@ static LEASE_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
 static REQUEST_ID_COUNTER: AtomicU64 = AtomicU64::new(1);
 
 impl LeaseId {
+    pub const PREVIEW: Self = Self(0);
+
     fn new() -> Self {
         Self(LEASE_ID_COUNTER.fetch_add(1, Ordering::Relaxed))
     }
@@ -46,15 +51,23 @@ impl LeaseId {
 }
 
 impl RequestId {
+    pub const PREVIEW: Self = Self(0);
+
     fn new() -> Self {
         Self(REQUEST_ID_COUNTER.fetch_add(1, Ordering::Relaxed))
     }
+
+    pub fn as_u64(self) -> u64 {
+        self.0
+    }
 }
 
+pub type TerritoryEventStream = EventStream<TerritoryEvent>;
+
 #[derive(Clone)]
 pub struct TerritoryManager {
     state: Arc<RwLock<TerritoryState>>,
-    policy: TerritoryPolicy,
+    policy: watch::Sender<TerritoryPolicy>,
     metrics: MetricsCollector,
     events: broadcast::Sender<TerritoryEvent>,
     ledger: Option<LedgerWriter>,
@@ -63,22 +76,31 @@ pub struct TerritoryManager {
     shutdown: watch::Sender<bool>,
     maintenance_executor: Arc<Mutex<Option<MaintenanceExecutor>>>,
     maintenance_started: Arc<AtomicBool>,
+    clock: SharedClock,
 }
 
 #[derive(Clone, Debug)]
 struct TerritoryState {
     leases: HashMap<ResourcePath, Lease>,
     queues: HashMap<ResourcePath, Vec<LeaseQueueEntry>>,
+    wait_tallies: HashMap<AgentId, WaitTally>,
     #[cfg(feature = "spatial-hash")]
     spatial: SpatialHash,
 }
 
+#[derive(Clone, Debug, Default)]
+struct WaitTally {
+    grants: u64,
+    total_wait: Duration,
+}
+
 impl TerritoryState {
     #[cfg(feature = "spatial-hash")]
     fn new(cell_size: f64) -> Self {
         Self {
             leases: HashMap::new(),
             queues: HashMap::new(),
+            wait_tallies: HashMap::new(),
             spatial: SpatialHash::new(cell_size),
         }
     }
@@ -88,6 +110,7 @@ impl TerritoryState {
         Self {
             leases: HashMap::new(),
             queues: HashMap::new(),
+            wait_tallies: HashMap::new(),
         }
     }
 
@@ -102,13 +125,28 @@ impl TerritoryState {
             .unwrap_or(0)
     }
 
+    fn relocate(&mut self, resource: &ResourcePath, coordinates: Option<(f64, f64)>) {
+        let Some(lease) = self.leases.get_mut(resource) else {
+            return;
+        };
+        if lease.coordinates == coordinates {
+            return;
+        }
+        lease.coordinates = coordinates;
+        #[cfg(feature = "spatial-hash")]
+        {
+            let old_cell = lease.cell.take();
+            self.spatial.remove(lease.id, old_cell);
+            lease.cell = self.spatial.insert(lease.id, coordinates);
+        }
+    }
+
     fn queue_entries_mut(&mut self, resource: &ResourcePath) -> &mut Vec<LeaseQueueEntry> {
         self.queues.entry(resource.clone()).or_default()
     }
 
     fn enqueue(
         &mut self,
-        policy: &TerritoryPolicy,
         request: LeaseRequest,
         requested_at: Instant,
         state: NegotiationState,
@@ -120,6 +158,7 @@ impl TerritoryState {
             request_id,
             resource_id: request.resource_id.clone(),
             agent_id: request.agent_id.clone(),
+            priority: request.priority,
             queue_position: entries.len() + 1,
         };
         let entry = LeaseQueueEntry {
@@ -129,10 +168,12 @@ impl TerritoryState {
             enqueued_at: requested_at,
             deferred_until,
             state,
+            negotiation_round: 0,
+            round_started_at: requested_at,
             escalation_ticket: None,
         };
         entries.push(entry);
-        Self::reindex(entries, policy);
+        Self::reindex(entries);
         let position = entries
             .iter()
             .find(|entry| entry.id == request_id)
@@ -143,54 +184,188 @@ impl TerritoryState {
         (handle, self.total_queue_depth())
     }
 
-    fn take_next(
+    fn vacate(
         &mut self,
         policy: &TerritoryPolicy,
         resource: &ResourcePath,
         now: Instant,
-    ) -> Option<LeaseQueueEntry> {
+    ) -> Option<(Lease, Option<LeaseSnapshot>)> {
+        let lease = self.leases.remove(resource)?;
+        #[cfg(feature = "spatial-hash")]
+        self.spatial.remove(lease.id, lease.cell);
+        let promoted = self.take_next(resource, now).map(|entry| {
+            self.record_wait(&entry, now);
+            let request = entry.request.to_request(resource);
+            #[cfg(feature = "spatial-hash")]
+            let mut next = Lease::new(&request, entry.id, now, policy);
+            #[cfg(not(feature = "spatial-hash"))]
+            let next = Lease::new(&request, entry.id, now, policy);
+            #[cfg(feature = "spatial-hash")]
+            {
+                next.cell = self.spatial.insert(next.id, next.coordinates);
+            }
+            let snapshot = next.snapshot();
+            self.leases.insert(resource.clone(), next);
+            snapshot
+        });
+        Some((lease, promoted))
+    }
+
+    fn record_wait(&mut self, entry: &LeaseQueueEntry, now: Instant) {
+        let tally = self
+            .wait_tallies
+            .entry(entry.handle.agent_id.clone())
+            .or_default();
+        tally.grants += 1;
+        tally.total_wait += now.saturating_duration_since(entry.enqueued_at);
+    }
+
+    fn reassign(
+        &mut self,
+        request: &TransferRequest,
+        policy: &TerritoryPolicy,
+        now: Instant,
+    ) -> Option<(LeaseSnapshot, LeaseSnapshot)> {
+        let lease = self.leases.get_mut(&request.resource_id)?;
+        if lease.holder_id != request.from_agent {
+            return None;
+        }
+        let previous = lease.snapshot();
+        lease.holder_id = request.to_agent.clone();
+        if let Some(priority) = request.new_priority {
+            lease.priority = priority;
+        }
+        lease.granted_at = now;
+        lease.expires_at = now + policy.default_lease_duration;
+        lease.last_heartbeat_at = now;
+        lease.override_count += 1;
+        Some((previous, lease.snapshot()))
+    }
+
+    fn preview_handle(&self, request: &LeaseRequest) -> NegotiationHandle {
+        let ahead = self
+            .queues
+            .get(&request.resource_id)
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|entry| entry.request.priority >= request.priority)
+                    .count()
+            })
+            .unwrap_or(0);
+        NegotiationHandle {
+            request_id: RequestId::PREVIEW,
+            resource_id: request.resource_id.clone(),
+            agent_id: request.agent_id.clone(),
+            priority: request.priority,
+            queue_position: ahead + 1,
+        }
+    }
+
+    fn take_next(&mut self, resource: &ResourcePath, now: Instant) -> Option<LeaseQueueEntry> {
         let entries = self.queues.get_mut(resource)?;
-        Self::reindex(entries, policy);
+        Self::reindex(entries);
         if entries.is_empty() {
             return None;
         }
-        let idx = entries.iter().position(|entry| {
-            entry
-                .deferred_until
-                .map_or(true, |deadline| deadline <= now)
-        });
+        let idx = entries
+            .iter()
+            .position(|entry| Self::is_promotable(entry, now));
         idx.map(|i| entries.remove(i))
     }
 
-    fn reindex(entries: &mut [LeaseQueueEntry], policy: &TerritoryPolicy) {
-        entries.sort_by(|a, b| {
-            b.request
-                .priority
-                .cmp(&a.request.priority)
-                .then(a.enqueued_at.cmp(&b.enqueued_at))
-        });
+    fn peek_next(&self, resource: &ResourcePath, now: Instant) -> Option<NegotiationHandle> {
+        let entries = self.queues.get(resource)?;
+        let next = entries
+            .iter()
+            .filter(|entry| Self::is_promotable(entry, now))
+            .min_by(|a, b| Self::queue_order(a, b))?;
+        let ahead = entries
+            .iter()
+            .filter(|entry| Self::queue_order(entry, next).is_lt())
+            .count();
+        let mut handle = next.handle.clone();
+        handle.queue_position = ahead + 1;
+        Some(handle)
+    }
+
+    fn is_promotable(entry: &LeaseQueueEntry, now: Instant) -> bool {
+        entry.deferred_until.is_none_or(|deadline| deadline <= now)
+    }
+
+    fn queue_order(a: &LeaseQueueEntry, b: &LeaseQueueEntry) -> std::cmp::Ordering {
+        b.request
+            .priority
+            .cmp(&a.request.priority)
+            .then(a.enqueued_at.cmp(&b.enqueued_at))
+    }
+
+    fn reindex(entries: &mut [LeaseQueueEntry]) {
+        entries.sort_by(Self::queue_order);
         for (index, entry) in entries.iter_mut().enumerate() {
             entry.handle.queue_position = index + 1;
             if entry.deferred_until.is_some() && entry.state == NegotiationState::Queued {
                 entry.state = NegotiationState::Deferred;
             }
-            if entry.state == NegotiationState::Negotiating
-                && index as u32 >= policy.negotiation_max_rounds
-            {
-                entry.state = NegotiationState::Escalating;
+        }
+    }
+
+    fn advance_negotiations(
+        &mut self,
+        policy: &TerritoryPolicy,
+        now: Instant,
+    ) -> Vec<NegotiationTransition> {
+        let mut transitions = Vec::new();
+        for entries in self.queues.values_mut() {
+            for entry in entries.iter_mut() {
+                let advancing = matches!(
+                    entry.state,
+                    NegotiationState::Queued | NegotiationState::Negotiating
+                );
+                if !advancing
+                    || now.saturating_duration_since(entry.round_started_at)
+                        < policy.negotiation_timeout
+                {
+                    continue;
+                }
+                entry.negotiation_round += 1;
+                entry.round_started_at = now;
+                entry.state = if entry.negotiation_round > policy.negotiation_max_rounds {
+                    NegotiationState::Escalating
+                } else {
+                    NegotiationState::Negotiating
+                };
+                transitions.push(NegotiationTransition {
+                    handle: entry.handle.clone(),
+                    round: entry.negotiation_round,
+                    state: entry.state.clone(),
+                });
             }
         }
+        transitions
     }
 }
 
-#[derive(Debug)]
+enum RequestStatus {
+    Pending,
+    Granted(Box<LeaseSnapshot>),
+}
+
+#[derive(Clone, Debug)]
+pub struct NegotiationTransition {
+    pub handle: NegotiationHandle,
+    pub round: u32,
+    pub state: NegotiationState,
+}
+
+#[derive(Debug, Clone)]
 struct HeatCell {
     value: f64,
     updated_at: Instant,
 }
 
-#[derive(Debug)]
-struct HeatMap {
+#[derive(Debug, Clone)]
+pub(crate) struct HeatMap {
     decay_per_second: f64,
     increment: f64,
     max_value: f64,
@@ -198,7 +373,7 @@ struct HeatMap {
 }
 
 impl HeatMap {
-    fn new(decay_per_second: f64, increment: f64, max_value: f64) -> Self {
+    pub(crate) fn new(decay_per_second: f64, increment: f64, max_value: f64) -> Self {
         Self {
             decay_per_second: decay_per_second.max(0.0).min(1.0),
             increment: increment.max(0.0),
@@ -207,28 +382,34 @@ impl HeatMap {
         }
     }
 
-    fn bump(&mut self, resource: &ResourcePath, now: Instant) -> HeatSummary {
+    fn reconfigure(&mut self, decay_per_second: f64, increment: f64, max_value: f64) {
+        self.decay_per_second = decay_per_second.clamp(0.0, 1.0);
+        self.increment = increment.max(0.0);
+        self.max_value = max_value.max(0.0);
+        for cell in self.cells.values_mut() {
+            cell.value = cell.value.min(self.max_value);
+        }
+    }
+
+    pub(crate) fn bump(
+        &mut self,
+        resource: &ResourcePath,
+        priority: Priority,
+        now: Instant,
+    ) -> HeatSummary {
         let cell = self.cells.entry(resource.clone()).or_insert(HeatCell {
             value: 0.0,
             updated_at: now,
         });
         HeatMap::decay_cell(self.decay_per_second, cell, now);
-        cell.value = (cell.value + self.increment).min(self.max_value);
+        let weight = (priority.as_index() + 1) as f64;
+        cell.value = (cell.value + self.increment * weight).min(self.max_value);
         cell.updated_at = now;
         self.summary(now)
     }
 
-    fn summary(&mut self, now: Instant) -> HeatSummary {
-        let mut remove_keys = Vec::new();
-        for (resource, cell) in self.cells.iter_mut() {
-            HeatMap::decay_cell(self.decay_per_second, cell, now);
-            if cell.value < 0.01 {
-                remove_keys.push(resource.clone());
-            }
-        }
-        for key in remove_keys {
-            self.cells.remove(&key);
-        }
+    pub(crate) fn summary(&mut self, now: Instant) -> HeatSummary {
+        self.decay_all(now);
         let mut hottest_resource = None;
         let mut hottest_score = 0.0;
         for (resource, cell) in self.cells.iter() {
@@ -244,14 +425,42 @@ impl HeatMap {
         }
     }
 
+    fn full(&mut self, now: Instant) -> Vec<(ResourcePath, f64)> {
+        self.decay_all(now);
+        let mut cells: Vec<(ResourcePath, f64)> = self
+            .cells
+            .iter()
+            .map(|(resource, cell)| (resource.clone(), cell.value))
+            .collect();
+        cells.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        cells
+    }
+
+    fn value(&mut self, resource: &ResourcePath, now: Instant) -> f64 {
+        self.decay_all(now);
+        self.cells
+            .get(resource)
+            .map(|cell| cell.value)
+            .unwrap_or(0.0)
+    }
+
+    fn decay_all(&mut self, now: Instant) {
+        let mut remove_keys = Vec::new();
+        for (resource, cell) in self.cells.iter_mut() {
+            HeatMap::decay_cell(self.decay_per_second, cell, now);
+            cell.updated_at = now;
+            if cell.value < 0.01 {
+                remove_keys.push(resource.clone());
+            }
+        }
+        for key in remove_keys {
+            self.cells.remove(&key);
+        }
+    }
+
     fn decay_cell(decay_per_second: f64, cell: &mut HeatCell, now: Instant) {
         if let Some(elapsed) = now.checked_duration_since(cell.updated_at) {
-            let seconds = elapsed.as_secs_f64();
-            if seconds > 0.0 && decay_per_second > 0.0 {
-                let base = (1.0 - decay_per_second).clamp(0.0, 1.0);
-                let factor = if base == 0.0 { 0.0 } else { base.powf(seconds) };
-                cell.value *= factor;
-            }
+            cell.value *= heat_decay_factor(decay_per_second, elapsed);
         }
     }
 }
@@ -289,6 +498,7 @@ impl LeaseInventorySnapshot {
 #[derive(Clone, Debug)]
 struct Lease {
     id: LeaseId,
+    request_id: RequestId,
     resource_id: ResourcePath,
     holder_id: AgentId,
     holder_role: Option<String>,
@@ -303,21 +513,32 @@ struct Lease {
     override_count: u32,
     escalation_ticket: Option<String>,
     coordinates: Option<(f64, f64)>,
+    tags: Vec<String>,
     #[cfg(feature = "spatial-hash")]
     cell: Option<CellIndex>,
 }
 
 impl Lease {
-    fn new(request: &LeaseRequest, now: Instant, policy: &TerritoryPolicy) -> Self {
-        let default_duration = policy.default_lease_duration;
-        let max_duration = policy.max_lease_duration;
-        let effective_duration = if default_duration < max_duration {
-            default_duration
-        } else {
-            max_duration
-        };
+    fn new(
+        request: &LeaseRequest,
+        request_id: RequestId,
+        now: Instant,
+        policy: &TerritoryPolicy,
+    ) -> Self {
+        Self::with_id(LeaseId::new(), request, request_id, now, policy)
+    }
+
+    fn with_id(
+        id: LeaseId,
+        request: &LeaseRequest,
+        request_id: RequestId,
+        now: Instant,
+        policy: &TerritoryPolicy,
+    ) -> Self {
+        let effective_duration = request.lease_duration(policy);
         Self {
-            id: LeaseId::new(),
+            id,
+            request_id,
             resource_id: request.resource_id.clone(),
             holder_id: request.agent_id.clone(),
             holder_role: request.holder_role.clone(),
@@ -332,14 +553,35 @@ impl Lease {
             override_count: 0,
             escalation_ticket: None,
             coordinates: request.coordinates,
+            tags: request.tags.clone(),
             #[cfg(feature = "spatial-hash")]
             cell: None,
         }
     }
 
+    fn assume_holder(
+        &mut self,
+        request: &LeaseRequest,
+        request_id: RequestId,
+        now: Instant,
+        policy: &TerritoryPolicy,
+    ) {
+        self.request_id = request_id;
+        self.holder_id = request.agent_id.clone();
+        self.holder_role = request.holder_role.clone();
+        self.priority = request.priority;
+        self.granted_at = now;
+        self.expires_at = now + request.lease_duration(policy);
+        self.last_heartbeat_at = now;
+        self.holder_progress = request.progress_hint.unwrap_or(0.0).clamp(0.0, 1.0);
+        self.tags = request.tags.clone();
+        self.override_count += 1;
+    }
+
     fn snapshot(&self) -> LeaseSnapshot {
         LeaseSnapshot {
             lease_id: self.id,
+            request_id: self.request_id,
             resource_id: self.resource_id.clone(),
             holder_id: self.holder_id.clone(),
             holder_role: self.holder_role.clone(),
@@ -352,6 +594,7 @@ impl Lease {
             defer_count: self.defer_count,
             override_count: self.override_count,
             escalation_ticket: self.escalation_ticket.clone(),
+            tags: self.tags.clone(),
         }
     }
 }
@@ -359,6 +602,7 @@ impl Lease {
 #[derive(Clone, Debug)]
 pub struct LeaseSnapshot {
     pub lease_id: LeaseId,
+    pub request_id: RequestId,
     pub resource_id: ResourcePath,
     pub holder_id: AgentId,
     pub holder_role: Option<String>,
@@ -371,6 +615,7 @@ pub struct LeaseSnapshot {
     pub defer_count: u32,
     pub override_count: u32,
     pub escalation_ticket: Option<String>,
+    pub tags: Vec<String>,
 }
 
 #[derive(Clone, Debug)]
@@ -381,6 +626,8 @@ pub struct LeaseRequest {
     pub holder_role: Option<String>,
     pub progress_hint: Option<f32>,
     pub coordinates: Option<(f64, f64)>,
+    pub requested_duration: Option<Duration>,
+    pub tags: Vec<String>,
 }
 
 impl LeaseRequest {
@@ -392,7 +639,64 @@ impl LeaseRequest {
             holder_role: None,
             progress_hint: None,
             coordinates: None,
+            requested_duration: None,
+            tags: Vec::new(),
+        }
+    }
+
+    fn lease_duration(&self, policy: &TerritoryPolicy) -> Duration {
+        self.requested_duration
+            .unwrap_or(policy.default_lease_duration)
+            .min(policy.max_lease_duration)
+    }
+
+    pub fn builder(
+        agent_id: impl Into<AgentId>,
+        resource_id: impl Into<ResourcePath>,
+        priority: Priority,
+    ) -> LeaseRequestBuilder {
+        LeaseRequestBuilder {
+            request: Self::new(agent_id.into(), resource_id.into(), priority),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct LeaseRequestBuilder {
+    request: LeaseRequest,
+}
+
+impl LeaseRequestBuilder {
+    pub fn role(mut self, holder_role: impl Into<String>) -> Self {
+        self.request.holder_role = Some(holder_role.into());
+        self
+    }
+
+    pub fn progress(mut self, progress_hint: f32) -> Self {
+        self.request.progress_hint = Some(progress_hint);
+        self
+    }
+
+    pub fn coordinates(mut self, x: f64, y: f64) -> Self {
+        self.request.coordinates = Some((x, y));
+        self
+    }
+
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.request.requested_duration = Some(duration);
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !self.request.tags.contains(&tag) {
+            self.request.tags.push(tag);
         }
+        self
+    }
+
+    pub fn build(self) -> LeaseRequest {
+        self.request
     }
 }
 
@@ -409,6 +713,7 @@ pub struct NegotiationHandle {
     pub request_id: RequestId,
     pub resource_id: ResourcePath,
     pub agent_id: AgentId,
+    pub priority: Priority,
     pub queue_position: usize,
 }
 
@@ -446,11 +751,66 @@ pub enum NegotiationState {
     Expired,
 }
 
+impl NegotiationState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NegotiationState::Idle => "idle",
+            NegotiationState::Queued => "queued",
+            NegotiationState::Negotiating => "negotiating",
+            NegotiationState::Deferred => "deferred",
+            NegotiationState::Escalating => "escalating",
+            NegotiationState::Overridden => "overridden",
+            NegotiationState::Expired => "expired",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct QueuedLeaseInfo {
+    pub request_id: RequestId,
+    pub resource_id: ResourcePath,
+    pub agent_id: AgentId,
+    pub priority: Priority,
+    pub queue_position: usize,
+    pub age: Duration,
+    pub deferred_until: Option<Instant>,
+    pub state: NegotiationState,
+    pub negotiation_round: u32,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct AgentFairness {
+    pub agent_id: AgentId,
+    pub grants: u64,
+    pub total_wait: Duration,
+    pub average_wait: Duration,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FairnessReport {
+    pub agents: Vec<AgentFairness>,
+}
+
+impl FairnessReport {
+    pub fn agent(&self, agent_id: &str) -> Option<&AgentFairness> {
+        self.agents.iter().find(|entry| entry.agent_id == agent_id)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TerritoryInventory {
+    pub leases: Vec<LeaseSnapshot>,
+    pub queued: Vec<QueuedLeaseInfo>,
+}
+
 #[derive(Clone, Debug)]
 pub enum EscalationReason {
     QueueDepth,
     Starvation,
     Deadlock,
+    NegotiationExhausted,
+    QueueAge,
+    DirectorOverride,
 }
 
 #[derive(Clone, Debug)]
@@ -461,10 +821,23 @@ pub enum TerritoryEvent {
         grace_deadline: Instant,
     },
     Queued(NegotiationHandle),
+    Withdrawn(NegotiationHandle),
+    Negotiating {
+        handle: NegotiationHandle,
+        round: u32,
+    },
     Released(LeaseSnapshot),
-    Overridden {
-        previous: LeaseSnapshot,
+    ForceReleased {
         lease: LeaseSnapshot,
+        reason: String,
+    },
+    Overridden {
+        previous: Box<LeaseSnapshot>,
+        lease: Box<LeaseSnapshot>,
+    },
+    Transferred {
+        previous: Box<LeaseSnapshot>,
+        lease: Box<LeaseSnapshot>,
     },
     Escalated {
         handle: NegotiationHandle,
@@ -472,6 +845,31 @@ pub enum TerritoryEvent {
     },
 }
 
+pub const DEFAULT_OVERRIDE_PRIORITY_DELTA: u8 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OverridePolicy {
+    Never,
+    PriorityDelta(u8),
+    RequireQuorum,
+}
+
+impl OverridePolicy {
+    pub fn parse(name: &str, priority_delta: u8) -> Option<Self> {
+        let normalized: String = name
+            .chars()
+            .filter(|ch| ch.is_ascii_alphanumeric())
+            .map(|ch| ch.to_ascii_lowercase())
+            .collect();
+        match normalized.as_str() {
+            "never" => Some(OverridePolicy::Never),
+            "prioritydelta" => Some(OverridePolicy::PriorityDelta(priority_delta)),
+            "requirequorum" => Some(OverridePolicy::RequireQuorum),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TerritoryPolicy {
     pub default_lease_duration: Duration,
@@ -481,18 +879,22 @@ pub struct TerritoryPolicy {
     pub negotiation_max_rounds: u32,
     pub escalation_queue_threshold: usize,
     pub escalation_deadlock_timeout: Duration,
+    pub escalation_force_override_after: Option<Duration>,
     pub fairness_starvation_threshold: Duration,
     pub fairness_priority_boost_after: Duration,
-    pub override_priority_delta: u8,
+    pub fairness_minimum_lease: Duration,
+    pub override_policy: OverridePolicy,
     pub spatial_cell_size: f64,
     pub consensus_threshold: f32,
     pub heat_decay_per_second: f64,
     pub heat_increment: f64,
     pub heat_max: f64,
+    pub allow_operator_override: bool,
 }
 
 impl TerritoryPolicy {
     fn baseline() -> Self {
+        let heat = HeatParameters::default();
         Self {
             default_lease_duration: Duration::from_secs(900),
             max_lease_duration: Duration::from_secs(3600),
@@ -501,14 +903,17 @@ impl TerritoryPolicy {
             negotiation_max_rounds: 3,
             escalation_queue_threshold: 2,
             escalation_deadlock_timeout: Duration::from_secs(60),
+            escalation_force_override_after: None,
             fairness_starvation_threshold: Duration::from_secs(600),
             fairness_priority_boost_after: Duration::from_secs(300),
-            override_priority_delta: 1,
+            fairness_minimum_lease: Duration::from_secs(60),
+            override_policy: OverridePolicy::PriorityDelta(DEFAULT_OVERRIDE_PRIORITY_DELTA),
             spatial_cell_size: 64.0,
             consensus_threshold: 0.66,
-            heat_decay_per_second: 0.15,
-            heat_increment: 1.5,
-            heat_max: 10.0,
+            heat_decay_per_second: heat.decay_per_second,
+            heat_increment: heat.increment,
+            heat_max: heat.max_value,
+            allow_operator_override: false,
         }
     }
 
@@ -556,6 +961,13 @@ impl TerritoryPolicy {
             {
                 policy.escalation_deadlock_timeout = duration;
             }
+            if let Some(duration) = overrides
+                .escalation_force_override_after
+                .as_deref()
+                .and_then(parse_duration_str)
+            {
+                policy.escalation_force_override_after = Some(duration);
+            }
             if let Some(duration) = overrides
                 .fairness_starvation_threshold
                 .as_deref()
@@ -570,6 +982,13 @@ impl TerritoryPolicy {
             {
                 policy.fairness_priority_boost_after = duration;
             }
+            if let Some(duration) = overrides
+                .fairness_minimum_lease
+                .as_deref()
+                .and_then(parse_duration_str)
+            {
+                policy.fairness_minimum_lease = duration;
+            }
             if let Some(threshold) = overrides.consensus_threshold {
                 policy.consensus_threshold = threshold;
             }
@@ -582,6 +1001,25 @@ impl TerritoryPolicy {
             if let Some(max_value) = overrides.heat_max {
                 policy.heat_max = max_value.max(0.0);
             }
+            if let Some(allowed) = overrides.allow_operator_override {
+                policy.allow_operator_override = allowed;
+            }
+            let priority_delta = overrides
+                .override_priority_delta
+                .unwrap_or(DEFAULT_OVERRIDE_PRIORITY_DELTA);
+            match overrides.override_policy.as_deref() {
+                Some(name) => match OverridePolicy::parse(name, priority_delta) {
+                    Some(override_policy) => policy.override_policy = override_policy,
+                    None => println!(
+                        "[Territory]: unknown override_policy `{}`, keeping {:?}",
+                        name, policy.override_policy
+                    ),
+                },
+                None if overrides.override_priority_delta.is_some() => {
+                    policy.override_policy = OverridePolicy::PriorityDelta(priority_delta);
+                }
+                None => {}
+            }
         }
         policy
     }
@@ -593,21 +1031,48 @@ impl Default for TerritoryPolicy {
     }
 }
 
+impl From<&TerritoryPolicy> for HeatParameters {
+    fn from(policy: &TerritoryPolicy) -> Self {
+        Self {
+            decay_per_second: policy.heat_decay_per_second,
+            increment: policy.heat_increment,
+            max_value: policy.heat_max,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 struct LeaseQueueDescriptor {
     agent_id: AgentId,
     priority: Priority,
     holder_role: Option<String>,
     coordinates: Option<(f64, f64)>,
+    requested_duration: Option<Duration>,
+    tags: Vec<String>,
 }
 
 impl LeaseQueueDescriptor {
+    fn to_request(&self, resource: &ResourcePath) -> LeaseRequest {
+        LeaseRequest {
+            agent_id: self.agent_id.clone(),
+            resource_id: resource.clone(),
+            priority: self.priority,
+            holder_role: self.holder_role.clone(),
+            progress_hint: None,
+            coordinates: self.coordinates,
+            requested_duration: self.requested_duration,
+            tags: self.tags.clone(),
+        }
+    }
+
     fn from_request(request: &LeaseRequest) -> Self {
         Self {
             agent_id: request.agent_id.clone(),
             priority: request.priority,
             holder_role: request.holder_role.clone(),
             coordinates: request.coordinates,
+            requested_duration: request.requested_duration,
+            tags: request.tags.clone(),
         }
     }
 }
@@ -620,9 +1085,27 @@ struct LeaseQueueEntry {
     enqueued_at: Instant,
     deferred_until: Option<Instant>,
     state: NegotiationState,
+    negotiation_round: u32,
+    round_started_at: Instant,
     escalation_ticket: Option<String>,
 }
 
+impl LeaseQueueEntry {
+    fn queued_info(&self, now: Instant) -> QueuedLeaseInfo {
+        QueuedLeaseInfo {
+            request_id: self.id,
+            resource_id: self.handle.resource_id.clone(),
+            agent_id: self.handle.agent_id.clone(),
+            priority: self.request.priority,
+            queue_position: self.handle.queue_position,
+            age: now.saturating_duration_since(self.enqueued_at),
+            deferred_until: self.deferred_until,
+            state: self.state.clone(),
+            negotiation_round: self.negotiation_round,
+        }
+    }
+}
+
 #[cfg(feature = "spatial-hash")]
 #[derive(Clone, Debug)]
 struct SpatialHash {
@@ -707,15 +1190,33 @@ mod tests {
             negotiation_max_rounds: Some(5),
             escalation_queue_threshold: Some(4),
             escalation_deadlock_timeout: Some("180s".to_string()),
+            escalation_force_override_after: Some("20m".to_string()),
             fairness_starvation_threshold: Some("420s".to_string()),
             fairness_priority_boost_after: Some("120s".to_string()),
+            fairness_minimum_lease: Some("90s".to_string()),
             consensus_threshold: Some(0.75),
             heat_decay_per_second: Some(0.25),
             heat_increment: Some(2.0),
             heat_max: Some(9.0),
+            override_policy: Some("require_quorum".to_string()),
+            override_priority_delta: None,
+            allow_operator_override: Some(true),
         }
     }
 
+    #[test]
+    fn legacy_priority_delta_applies_only_without_an_override_policy() {
+        let mut config = sample_config();
+        config.override_policy = None;
+        config.override_priority_delta = Some(3);
+        let policy = TerritoryPolicy::from_config(Some(&config));
+        assert_eq!(policy.override_policy, OverridePolicy::PriorityDelta(3));
+
+        config.override_policy = Some("never".to_string());
+        let policy = TerritoryPolicy::from_config(Some(&config));
+        assert_eq!(policy.override_policy, OverridePolicy::Never);
+    }
+
     #[test]
     fn territory_policy_applies_config_overrides() {
         let config = sample_config();
@@ -727,6 +1228,10 @@ mod tests {
         assert_eq!(policy.negotiation_max_rounds, 5);
         assert_eq!(policy.escalation_queue_threshold, 4);
         assert_eq!(policy.escalation_deadlock_timeout, Duration::from_secs(180));
+        assert_eq!(
+            policy.escalation_force_override_after,
+            Some(Duration::from_secs(20 * 60))
+        );
         assert_eq!(
             policy.fairness_starvation_threshold,
             Duration::from_secs(420)
@@ -735,10 +1240,59 @@ mod tests {
             policy.fairness_priority_boost_after,
             Duration::from_secs(120)
         );
+        assert_eq!(policy.fairness_minimum_lease, Duration::from_secs(90));
         assert!((policy.consensus_threshold - 0.75).abs() < f32::EPSILON);
         assert!((policy.heat_decay_per_second - 0.25).abs() < f64::EPSILON);
         assert!((policy.heat_increment - 2.0).abs() < f64::EPSILON);
         assert!((policy.heat_max - 9.0).abs() < f64::EPSILON);
+        assert_eq!(policy.override_policy, OverridePolicy::RequireQuorum);
+        assert!(policy.allow_operator_override);
+        assert!(!TerritoryPolicy::default().allow_operator_override);
+    }
+
+    #[test]
+    fn override_policy_parses_config_names() {
+        assert_eq!(
+            OverridePolicy::parse("never", 1),
+            Some(OverridePolicy::Never)
+        );
+        assert_eq!(
+            OverridePolicy::parse("priorityDelta", 2),
+            Some(OverridePolicy::PriorityDelta(2))
+        );
+        assert_eq!(
+            OverridePolicy::parse("require-quorum", 1),
+            Some(OverridePolicy::RequireQuorum)
+        );
+        assert_eq!(OverridePolicy::parse("sometimes", 1), None);
+    }
+
+    #[test]
+    fn deferred_grace_deadline_uses_the_injected_wall_clock() {
+        let clock = crate::clock::MockClock::new();
+        clock.rewind_wall(Duration::from_secs(3_600));
+        let event = TerritoryEvent::Deferred {
+            handle: NegotiationHandle {
+                request_id: RequestId(1),
+                resource_id: "plan.json".to_string(),
+                agent_id: "Agent_A".to_string(),
+                priority: Priority::Blocking,
+                queue_position: 0,
+            },
+            grace_deadline: clock.now_instant() + Duration::from_secs(5),
+        };
+        let expected = (clock.now_system() + Duration::from_secs(5))
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+
+        match ledger_event_from_territory(&event, &clock) {
+            Some(LedgerLeaseEvent::Deferred(record)) => {
+                assert_eq!(record.grace_deadline_ms, Some(expected));
+                assert_eq!(record.request_id, "1");
+            }
+            other => panic!("expected a deferred record, got {:?}", other),
+        }
     }
 }
 
@@ -781,6 +1335,7 @@ impl TerritoryManager {
             policy.heat_increment,
             policy.heat_max,
         )));
+        let (policy, _) = watch::channel(policy);
         Self {
             state: Arc::new(RwLock::new(state)),
             policy,
@@ -792,22 +1347,80 @@ impl TerritoryManager {
             shutdown,
             maintenance_executor: Arc::new(Mutex::new(None)),
             maintenance_started: Arc::new(AtomicBool::new(false)),
+            clock: system_clock(),
         }
     }
 
+    pub fn with_clock(mut self, clock: SharedClock) -> Self {
+        self.clock = clock;
+        self
+    }
+
     pub fn subscribe(&self) -> broadcast::Receiver<TerritoryEvent> {
         self.events.subscribe()
     }
 
-    pub fn policy(&self) -> &TerritoryPolicy {
-        &self.policy
+    pub fn subscribe_stream(&self) -> TerritoryEventStream {
+        EventStream::new(self.events.subscribe())
     }
 
-    pub async fn set_maintenance_executor(&self, executor: MaintenanceExecutor) {
-        let mut guard = self.maintenance_executor.lock().await;
-        *guard = Some(executor);
-        drop(guard);
-        self.start_maintenance_if_needed().await;
+    pub async fn wait_for_grant(&self, request_id: RequestId) -> Option<LeaseSnapshot> {
+        let mut stream = self.subscribe_stream();
+        loop {
+            match self.request_status(request_id).await? {
+                RequestStatus::Granted(snapshot) => return Some(*snapshot),
+                RequestStatus::Pending => {}
+            }
+            if let StreamItem::Event(TerritoryEvent::Granted(snapshot)) = stream.recv().await? {
+                if snapshot.request_id == request_id {
+                    return Some(snapshot);
+                }
+            }
+        }
+    }
+
+    async fn request_status(&self, request_id: RequestId) -> Option<RequestStatus> {
+        let guard = self.state.read().await;
+        if let Some(lease) = guard
+            .leases
+            .values()
+            .find(|lease| lease.request_id == request_id)
+        {
+            return Some(RequestStatus::Granted(Box::new(lease.snapshot())));
+        }
+        guard
+            .queues
+            .values()
+            .flatten()
+            .any(|entry| entry.id == request_id)
+            .then_some(RequestStatus::Pending)
+    }
+
+    pub fn policy(&self) -> TerritoryPolicy {
+        self.policy.borrow().clone()
+    }
+
+    pub async fn update_policy(&self, policy: TerritoryPolicy) {
+        {
+            let mut heat = self.heat_map.lock().await;
+            heat.reconfigure(
+                policy.heat_decay_per_second,
+                policy.heat_increment,
+                policy.heat_max,
+            );
+        }
+        self.policy.send_replace(policy);
+    }
+
+    pub async fn set_maintenance_executor(&self, executor: MaintenanceExecutor) {
+        let mut guard = self.maintenance_executor.lock().await;
+        *guard = Some(executor);
+        drop(guard);
+        self.start_maintenance_if_needed().await;
+    }
+
+    pub fn consensus(&self) -> Option<ConsensusBroker> {
+        self.consensus.clone()
     }
 
     pub async fn maintenance_executor(&self) -> Option<MaintenanceExecutor> {
@@ -816,27 +1429,28 @@ impl TerritoryManager {
 
     pub async fn acquire_lease(&self, request: LeaseRequest) -> LeaseDecision {
         self.start_maintenance_if_needed().await;
-        let now = Instant::now();
+        let now = self.clock.now_instant();
+        let policy = self.policy();
         let requester_id = request.agent_id.clone();
         let requester_priority = request.priority;
+        let started = Instant::now();
+        let quorum_threshold = self.quorum_threshold(&request.resource_id, &policy);
         let mut guard = self.state.write().await;
+        let plan = AcquirePlan::decide(
+            guard.leases.get(&request.resource_id),
+            &request,
+            &policy,
+            quorum_threshold,
+            now,
+        );
+        let director_handle = (plan == AcquirePlan::Override
+            && requester_priority == Priority::DirectorOverride)
+            .then(|| guard.preview_handle(&request));
         if let Some(active) = guard.leases.get_mut(&request.resource_id) {
-            let priority_delta =
-                request.priority.as_index() as i32 - active.priority.as_index() as i32;
-            let mut quorum_votes = vec![
-                quorum_vote(
-                    &active.holder_id,
-                    (active.priority.as_index() + 1) as f32,
-                    false,
-                ),
-                quorum_vote(
-                    &requester_id,
-                    (requester_priority.as_index() + 1) as f32,
-                    true,
-                ),
-            ];
+            let mut quorum_votes = contention_votes(active, &request);
             let mut quorum_reason = String::from("maintain");
-            if priority_delta >= self.policy.override_priority_delta as i32 {
+            let rejection = override_rejection(active, &request, &policy, quorum_threshold);
+            if plan == AcquirePlan::Override {
                 let resource_key = request.resource_id.clone();
                 quorum_reason = String::from("override");
                 #[cfg(feature = "spatial-hash")]
@@ -850,15 +1464,7 @@ impl TerritoryManager {
                         active_ref.cell = None;
                     }
                     let previous_snapshot = active_ref.snapshot();
-                    active_ref.holder_id = request.agent_id.clone();
-                    active_ref.holder_role = request.holder_role.clone();
-                    active_ref.priority = request.priority;
-                    active_ref.granted_at = now;
-                    active_ref.expires_at = now + self.policy.default_lease_duration;
-                    active_ref.last_heartbeat_at = now;
-                    active_ref.holder_progress =
-                        request.progress_hint.unwrap_or(0.0).clamp(0.0, 1.0);
-                    active_ref.override_count += 1;
+                    active_ref.assume_holder(&request, RequestId::new(), now, &policy);
                     let snapshot = active_ref.snapshot();
                     (lease_id, pending_coords, previous_snapshot, snapshot)
                 };
@@ -867,15 +1473,7 @@ impl TerritoryManager {
                     let active_ref = active;
                     active_ref.coordinates = request.coordinates;
                     let previous_snapshot = active_ref.snapshot();
-                    active_ref.holder_id = request.agent_id.clone();
-                    active_ref.holder_role = request.holder_role.clone();
-                    active_ref.priority = request.priority;
-                    active_ref.granted_at = now;
-                    active_ref.expires_at = now + self.policy.default_lease_duration;
-                    active_ref.last_heartbeat_at = now;
-                    active_ref.holder_progress =
-                        request.progress_hint.unwrap_or(0.0).clamp(0.0, 1.0);
-                    active_ref.override_count += 1;
+                    active_ref.assume_holder(&request, RequestId::new(), now, &policy);
                     let snapshot = active_ref.snapshot();
                     (previous_snapshot, snapshot)
                 };
@@ -890,15 +1488,39 @@ impl TerritoryManager {
                 let inventory = LeaseInventorySnapshot::from_state(&guard);
                 let (active, pending, outstanding) = inventory.into_parts();
                 drop(guard);
-                self.bump_heat_map(&resource_key).await;
-                self.record_quorum_decision(&resource_key, quorum_votes, &quorum_reason)
+                self.metrics
+                    .record_lease_acquisition(started.elapsed().as_secs_f64() * 1000.0, true);
+                self.bump_heat_map(&resource_key, requester_priority).await;
+                let correlation_id = lease_trace_id(snapshot.lease_id.as_u64());
+                if requester_priority == Priority::DirectorOverride {
+                    if let Some(broker) = &self.consensus {
+                        broker
+                            .record_director_override(&resource_key, Some(&correlation_id))
+                            .await;
+                    }
+                } else {
+                    self.record_quorum_decision(
+                        &resource_key,
+                        quorum_votes,
+                        &quorum_reason,
+                        Some(&correlation_id),
+                    )
                     .await;
+                }
                 self.metrics.record_lease_override();
                 self.metrics
                     .update_lease_inventory(active, pending, outstanding);
+                if let Some(handle) = director_handle {
+                    self.metrics.record_lease_director_override();
+                    self.emit_event(TerritoryEvent::Escalated {
+                        handle,
+                        reason: EscalationReason::DirectorOverride,
+                    })
+                    .await;
+                }
                 self.emit_event(TerritoryEvent::Overridden {
-                    previous: previous_snapshot.clone(),
-                    lease: snapshot.clone(),
+                    previous: Box::new(previous_snapshot.clone()),
+                    lease: Box::new(snapshot.clone()),
                 })
                 .await;
                 return LeaseDecision::Overridden {
@@ -906,36 +1528,29 @@ impl TerritoryManager {
                     lease: snapshot,
                 };
             }
-            let time_left = active
-                .expires_at
-                .checked_duration_since(now)
-                .unwrap_or_default();
-            let (handle, _total_depth, decision_state) =
-                if time_left <= self.policy.auto_extend_threshold {
-                    active.defer_count += 1;
-                    let (handle, total) = guard.enqueue(
-                        &self.policy,
-                        request,
-                        now,
-                        NegotiationState::Deferred,
-                        Some(now + self.policy.auto_extend_threshold),
-                    );
-                    let handle_for_decision = handle.clone();
-                    (
-                        handle,
-                        total,
-                        LeaseDecision::Deferred {
-                            handle: handle_for_decision,
-                            grace_deadline: now + self.policy.auto_extend_threshold,
-                        },
-                    )
-                } else {
-                    active.conflict_attempts += 1;
-                    let (handle, total) =
-                        guard.enqueue(&self.policy, request, now, NegotiationState::Queued, None);
-                    let handle_for_decision = handle.clone();
-                    (handle, total, LeaseDecision::Queued(handle_for_decision))
-                };
+            let (handle, _total_depth, decision_state) = if plan == AcquirePlan::Defer {
+                active.defer_count += 1;
+                let (handle, total) = guard.enqueue(
+                    request,
+                    now,
+                    NegotiationState::Deferred,
+                    Some(now + policy.auto_extend_threshold),
+                );
+                let handle_for_decision = handle.clone();
+                (
+                    handle,
+                    total,
+                    LeaseDecision::Deferred {
+                        handle: handle_for_decision,
+                        grace_deadline: now + policy.auto_extend_threshold,
+                    },
+                )
+            } else {
+                active.conflict_attempts += 1;
+                let (handle, total) = guard.enqueue(request, now, NegotiationState::Queued, None);
+                let handle_for_decision = handle.clone();
+                (handle, total, LeaseDecision::Queued(handle_for_decision))
+            };
             match &decision_state {
                 LeaseDecision::Deferred { .. } => {
                     quorum_reason = String::from("defer");
@@ -955,17 +1570,16 @@ impl TerritoryManager {
                     ));
                 }
             }
-            let should_escalate = entries.len() >= self.policy.escalation_queue_threshold
+            let should_escalate = entries.len() >= policy.escalation_queue_threshold
                 || entries.iter().any(|entry| {
-                    now.duration_since(entry.enqueued_at)
-                        >= self.policy.fairness_starvation_threshold
+                    now.duration_since(entry.enqueued_at) >= policy.fairness_starvation_threshold
                 });
             if should_escalate {
                 self.metrics.record_lease_escalation();
                 quorum_reason = String::from("escalate");
                 self.emit_event(TerritoryEvent::Escalated {
                     handle: handle.clone(),
-                    reason: if entries.len() >= self.policy.escalation_queue_threshold {
+                    reason: if entries.len() >= policy.escalation_queue_threshold {
                         EscalationReason::QueueDepth
                     } else {
                         EscalationReason::Starvation
@@ -983,8 +1597,11 @@ impl TerritoryManager {
             ) {
                 self.metrics.record_lease_deferral();
             }
-            self.bump_heat_map(&heat_resource).await;
-            self.record_quorum_decision(&heat_resource, quorum_votes, &quorum_reason)
+            if let Some(reason) = rejection {
+                self.metrics.record_lease_rejected(reason);
+            }
+            self.bump_heat_map(&heat_resource, requester_priority).await;
+            self.record_quorum_decision(&heat_resource, quorum_votes, &quorum_reason, None)
                 .await;
             self.metrics
                 .update_lease_inventory(active, pending, outstanding);
@@ -1007,9 +1624,9 @@ impl TerritoryManager {
             return decision_state;
         }
         #[cfg(feature = "spatial-hash")]
-        let mut lease = Lease::new(&request, now, &self.policy);
+        let mut lease = Lease::new(&request, RequestId::new(), now, &policy);
         #[cfg(not(feature = "spatial-hash"))]
-        let lease = Lease::new(&request, now, &self.policy);
+        let lease = Lease::new(&request, RequestId::new(), now, &policy);
         #[cfg(feature = "spatial-hash")]
         {
             lease.cell = guard.spatial.insert(lease.id, lease.coordinates);
@@ -1019,6 +1636,8 @@ impl TerritoryManager {
         let inventory = LeaseInventorySnapshot::from_state(&guard);
         let (active, pending, outstanding) = inventory.into_parts();
         drop(guard);
+        self.metrics
+            .record_lease_acquisition(started.elapsed().as_secs_f64() * 1000.0, false);
         self.metrics.record_lease_grant();
         self.metrics
             .update_lease_inventory(active, pending, outstanding);
@@ -1028,49 +1647,109 @@ impl TerritoryManager {
         LeaseDecision::Granted(snapshot)
     }
 
+    pub async fn would_acquire(&self, request: &LeaseRequest) -> LeaseDecision {
+        let now = self.clock.now_instant();
+        let policy = self.policy();
+        let quorum_threshold = self.quorum_threshold(&request.resource_id, &policy);
+        let guard = self.state.read().await;
+        let active = guard.leases.get(&request.resource_id);
+        let plan = AcquirePlan::decide(active, request, &policy, quorum_threshold, now);
+        match (plan, active) {
+            (AcquirePlan::Override, Some(active)) => {
+                let mut lease = active.clone();
+                lease.assume_holder(request, RequestId::PREVIEW, now, &policy);
+                LeaseDecision::Overridden {
+                    previous: active.snapshot(),
+                    lease: lease.snapshot(),
+                }
+            }
+            (AcquirePlan::Defer, Some(_)) => LeaseDecision::Deferred {
+                handle: guard.preview_handle(request),
+                grace_deadline: now + policy.auto_extend_threshold,
+            },
+            (AcquirePlan::Queue, Some(_)) => LeaseDecision::Queued(guard.preview_handle(request)),
+            _ => LeaseDecision::Granted(
+                Lease::with_id(LeaseId::PREVIEW, request, RequestId::PREVIEW, now, &policy)
+                    .snapshot(),
+            ),
+        }
+    }
+
     pub async fn release_lease(
         &self,
         agent_id: &AgentId,
         resource: &ResourcePath,
     ) -> Option<LeaseSnapshot> {
         self.start_maintenance_if_needed().await;
-        let now = Instant::now();
+        self.release_held_lease(agent_id, resource).await
+    }
+
+    pub async fn force_release(
+        &self,
+        resource: &ResourcePath,
+        reason: &str,
+    ) -> Option<LeaseSnapshot> {
+        if !self.policy().allow_operator_override {
+            return None;
+        }
+        self.start_maintenance_if_needed().await;
+        let snapshot = self
+            .vacate_resource(resource, None, |lease| TerritoryEvent::ForceReleased {
+                lease,
+                reason: reason.to_string(),
+            })
+            .await?;
+        self.metrics.record_operator_override();
+        Some(snapshot)
+    }
+
+    /// Blocking variant of [`Self::acquire_lease`]. Must not be called from a current-thread async task.
+    pub fn acquire_lease_blocking(&self, request: LeaseRequest) -> LeaseDecision {
+        block_on(self.acquire_lease(request))
+    }
+
+    /// Blocking variant of [`Self::release_lease`]. Must not be called from a current-thread async task.
+    pub fn release_lease_blocking(
+        &self,
+        agent_id: &AgentId,
+        resource: &ResourcePath,
+    ) -> Option<LeaseSnapshot> {
+        block_on(self.release_lease(agent_id, resource))
+    }
+
+    async fn release_held_lease(
+        &self,
+        agent_id: &AgentId,
+        resource: &ResourcePath,
+    ) -> Option<LeaseSnapshot> {
+        self.vacate_resource(resource, Some(agent_id), TerritoryEvent::Released)
+            .await
+    }
+
+    async fn vacate_resource(
+        &self,
+        resource: &ResourcePath,
+        holder: Option<&AgentId>,
+        released: impl FnOnce(LeaseSnapshot) -> TerritoryEvent,
+    ) -> Option<LeaseSnapshot> {
+        let now = self.clock.now_instant();
+        let policy = self.policy();
         let mut guard = self.state.write().await;
         let lease = guard.leases.get(resource)?;
-        if lease.holder_id != *agent_id {
+        if holder.is_some_and(|holder| lease.holder_id != *holder) {
             return None;
         }
-        let lease = guard.leases.remove(resource)?;
-        #[cfg(feature = "spatial-hash")]
-        guard.spatial.remove(lease.id, lease.cell);
+        let (lease, granted_snapshot) = guard.vacate(&policy, resource, now)?;
         let snapshot = lease.snapshot();
-        let next_entry = guard.take_next(&self.policy, resource, now);
-        let mut granted_snapshot: Option<LeaseSnapshot> = None;
-        if let Some(entry) = next_entry {
-            let request = LeaseRequest {
-                agent_id: entry.request.agent_id.clone(),
-                resource_id: resource.clone(),
-                priority: entry.request.priority,
-                holder_role: entry.request.holder_role.clone(),
-                progress_hint: None,
-                coordinates: entry.request.coordinates,
-            };
-            let mut lease = Lease::new(&request, now, &self.policy);
-            #[cfg(feature = "spatial-hash")]
-            {
-                lease.cell = guard.spatial.insert(lease.id, lease.coordinates);
-            }
-            granted_snapshot = Some(lease.snapshot());
-            guard.leases.insert(resource.clone(), lease);
-        }
+        self.metrics
+            .record_lease_release(now.saturating_duration_since(lease.granted_at));
         let inventory = LeaseInventorySnapshot::from_state(&guard);
         let (active, pending, outstanding) = inventory.into_parts();
         drop(guard);
         self.metrics
             .update_lease_inventory(active, pending, outstanding);
         self.publish_heat_summary().await;
-        self.emit_event(TerritoryEvent::Released(snapshot.clone()))
-            .await;
+        self.emit_event(released(snapshot.clone())).await;
         if let Some(granted) = granted_snapshot.clone() {
             self.metrics.record_lease_grant();
             self.emit_event(TerritoryEvent::Granted(granted)).await;
@@ -1078,42 +1757,453 @@ impl TerritoryManager {
         Some(snapshot)
     }
 
+    pub async fn release_all_for_agent(&self, agent_id: &AgentId) -> Vec<LeaseSnapshot> {
+        self.release_where(agent_id, |_| true).await
+    }
+
+    pub async fn release_by_tag(&self, agent_id: &AgentId, tag: &str) -> Vec<LeaseSnapshot> {
+        self.release_where(agent_id, |tags| {
+            tags.iter().any(|candidate| candidate == tag)
+        })
+        .await
+    }
+
+    async fn release_where<F>(&self, agent_id: &AgentId, matches: F) -> Vec<LeaseSnapshot>
+    where
+        F: Fn(&[String]) -> bool,
+    {
+        self.start_maintenance_if_needed().await;
+        let now = self.clock.now_instant();
+        let policy = self.policy();
+        let mut guard = self.state.write().await;
+        let mut withdrawn = Vec::new();
+        for entries in guard.queues.values_mut() {
+            let before = entries.len();
+            entries.retain(|entry| {
+                let keep = entry.handle.agent_id != *agent_id || !matches(&entry.request.tags);
+                if !keep {
+                    withdrawn.push(entry.handle.clone());
+                }
+                keep
+            });
+            if entries.len() != before {
+                TerritoryState::reindex(entries);
+            }
+        }
+        withdrawn.sort_by(|a, b| a.resource_id.cmp(&b.resource_id));
+        guard.queues.retain(|_, entries| !entries.is_empty());
+        let mut held: Vec<ResourcePath> = guard
+            .leases
+            .iter()
+            .filter(|(_, lease)| lease.holder_id == *agent_id && matches(&lease.tags))
+            .map(|(resource, _)| resource.clone())
+            .collect();
+        held.sort();
+        let mut released = Vec::new();
+        let mut granted = Vec::new();
+        for resource in held {
+            if let Some((lease, promoted)) = guard.vacate(&policy, &resource, now) {
+                self.metrics
+                    .record_lease_release(now.saturating_duration_since(lease.granted_at));
+                released.push(lease.snapshot());
+                granted.extend(promoted);
+            }
+        }
+        let inventory = LeaseInventorySnapshot::from_state(&guard);
+        let (active, pending, outstanding) = inventory.into_parts();
+        drop(guard);
+        self.metrics
+            .update_lease_inventory(active, pending, outstanding);
+        if !released.is_empty() {
+            self.publish_heat_summary().await;
+        }
+        for handle in withdrawn {
+            self.emit_event(TerritoryEvent::Withdrawn(handle)).await;
+        }
+        for snapshot in released.iter() {
+            self.emit_event(TerritoryEvent::Released(snapshot.clone()))
+                .await;
+        }
+        for snapshot in granted {
+            self.metrics.record_lease_grant();
+            self.emit_event(TerritoryEvent::Granted(snapshot)).await;
+        }
+        released
+    }
+
     pub async fn transfer_lease(&self, request: TransferRequest) -> TransferDecision {
-        let now = Instant::now();
+        let now = self.clock.now_instant();
+        let policy = self.policy();
+        let mut guard = self.state.write().await;
+        let Some((previous, lease)) = guard.reassign(&request, &policy, now) else {
+            return TransferDecision::Rejected;
+        };
+        let inventory = LeaseInventorySnapshot::from_state(&guard);
+        let (active, pending, outstanding) = inventory.into_parts();
+        drop(guard);
+        self.metrics.record_lease_transfer();
+        self.metrics
+            .update_lease_inventory(active, pending, outstanding);
+        self.emit_event(TerritoryEvent::Transferred {
+            previous: Box::new(previous.clone()),
+            lease: Box::new(lease.clone()),
+        })
+        .await;
+        TransferDecision::Transferred { previous, lease }
+    }
+
+    pub async fn transfer_lease_with_quorum(
+        &self,
+        request: TransferRequest,
+        votes: Vec<QuorumVote>,
+    ) -> TransferDecision {
+        let now = self.clock.now_instant();
+        let policy = self.policy();
         let mut guard = self.state.write().await;
-        let lease = guard.leases.get_mut(&request.resource_id);
-        if lease.is_none() {
+        let correlation_id = match guard.leases.get(&request.resource_id) {
+            Some(lease) if lease.holder_id == request.from_agent => {
+                lease_trace_id(lease.id.as_u64())
+            }
+            _ => return TransferDecision::Rejected,
+        };
+        let approved = self
+            .record_quorum_decision(
+                &request.resource_id,
+                votes,
+                "transfer",
+                Some(&correlation_id),
+            )
+            .await;
+        if !approved {
             return TransferDecision::Rejected;
         }
-        let lease = lease.unwrap();
-        if lease.holder_id != request.from_agent {
+        let Some((previous, lease)) = guard.reassign(&request, &policy, now) else {
             return TransferDecision::Rejected;
+        };
+        let inventory = LeaseInventorySnapshot::from_state(&guard);
+        let (active, pending, outstanding) = inventory.into_parts();
+        drop(guard);
+        self.metrics.record_lease_transfer();
+        self.metrics
+            .update_lease_inventory(active, pending, outstanding);
+        self.emit_event(TerritoryEvent::Overridden {
+            previous: Box::new(previous.clone()),
+            lease: Box::new(lease.clone()),
+        })
+        .await;
+        TransferDecision::Transferred { previous, lease }
+    }
+
+    pub async fn enforce_fairness(&self) -> Vec<LeaseSnapshot> {
+        let now = self.clock.now_instant();
+        let policy = self.policy();
+        let mut escalations = Vec::new();
+        let mut forced = Vec::new();
+        {
+            let mut guard = self.state.write().await;
+            let starving: Vec<(ResourcePath, NegotiationHandle)> = guard
+                .queues
+                .iter()
+                .filter_map(|(resource, entries)| {
+                    entries
+                        .iter()
+                        .find(|entry| {
+                            now.saturating_duration_since(entry.enqueued_at)
+                                >= policy.fairness_starvation_threshold
+                        })
+                        .map(|entry| (resource.clone(), entry.handle.clone()))
+                })
+                .collect();
+            for (resource, handle) in starving {
+                let Some(lease) = guard.leases.get_mut(&resource) else {
+                    continue;
+                };
+                let fairness_deadline =
+                    std::cmp::max(now, lease.granted_at + policy.fairness_minimum_lease);
+                if fairness_deadline < lease.expires_at {
+                    lease.expires_at = fairness_deadline;
+                    escalations.push(handle);
+                }
+                if lease.expires_at <= now {
+                    forced.push((lease.holder_id.clone(), resource));
+                }
+            }
         }
-        let previous_snapshot = lease.snapshot();
-        lease.holder_id = request.to_agent.clone();
-        if let Some(priority) = request.new_priority {
-            lease.priority = priority;
+        for handle in escalations {
+            self.metrics.record_lease_escalation();
+            self.emit_event(TerritoryEvent::Escalated {
+                handle,
+                reason: EscalationReason::Starvation,
+            })
+            .await;
         }
-        lease.granted_at = now;
-        lease.expires_at = now + self.policy.default_lease_duration;
-        lease.last_heartbeat_at = now;
-        lease.override_count += 1;
-        let snapshot = lease.snapshot();
+        let mut released = Vec::new();
+        for (holder, resource) in forced {
+            if let Some(snapshot) = self.release_held_lease(&holder, &resource).await {
+                released.push(snapshot);
+            }
+        }
+        released
+    }
+
+    pub async fn force_overdue_overrides(&self) -> Vec<LeaseSnapshot> {
+        let policy = self.policy();
+        let Some(limit) = policy.escalation_force_override_after else {
+            return Vec::new();
+        };
+        let now = self.clock.now_instant();
+        let overdue: Vec<(ResourcePath, RequestId)> = {
+            let guard = self.state.read().await;
+            guard
+                .queues
+                .iter()
+                .filter(|(resource, _)| guard.leases.contains_key(*resource))
+                .filter_map(|(resource, entries)| {
+                    entries
+                        .iter()
+                        .filter(|entry| entry.escalation_ticket.is_none())
+                        .filter(|entry| now.saturating_duration_since(entry.enqueued_at) >= limit)
+                        .min_by(|a, b| TerritoryState::queue_order(a, b))
+                        .map(|entry| (resource.clone(), entry.id))
+                })
+                .collect()
+        };
+        let mut granted = Vec::new();
+        for (resource, request_id) in overdue {
+            if let Some(snapshot) = self
+                .force_override(&policy, &resource, request_id, now)
+                .await
+            {
+                granted.push(snapshot);
+            }
+        }
+        granted
+    }
+
+    async fn force_override(
+        &self,
+        policy: &TerritoryPolicy,
+        resource: &ResourcePath,
+        request_id: RequestId,
+        now: Instant,
+    ) -> Option<LeaseSnapshot> {
+        let (handle, request, votes, correlation_id) = {
+            let mut guard = self.state.write().await;
+            let state = &mut *guard;
+            let active = state.leases.get(resource)?;
+            let entry = state
+                .queues
+                .get_mut(resource)?
+                .iter_mut()
+                .find(|entry| entry.id == request_id)?;
+            let request = entry.request.to_request(resource);
+            let mut escalated = request.clone();
+            escalated.priority = Priority::DirectorOverride;
+            let votes = contention_votes(active, &escalated);
+            let correlation_id = lease_trace_id(active.id.as_u64());
+            entry.escalation_ticket = Some(correlation_id.clone());
+            (entry.handle.clone(), request, votes, correlation_id)
+        };
+        self.metrics.record_lease_escalation();
+        self.emit_event(TerritoryEvent::Escalated {
+            handle,
+            reason: EscalationReason::QueueAge,
+        })
+        .await;
+        if policy.override_policy == OverridePolicy::RequireQuorum
+            && !self
+                .record_quorum_decision(resource, votes, "forceOverride", Some(&correlation_id))
+                .await
+        {
+            return None;
+        }
+        self.apply_force_override(policy, resource, request_id, &request, now)
+            .await
+    }
+
+    pub async fn settle_round(&self, round: ConsensusRound) -> RoundOutcome {
+        let resource = round.resource_id().to_string();
+        let outcome = round.outcome().await;
+        if outcome == RoundOutcome::Escalated {
+            self.force_resolve(&resource).await;
+        }
+        outcome
+    }
+
+    async fn force_resolve(&self, resource: &ResourcePath) -> Option<LeaseSnapshot> {
+        let policy = self.policy();
+        let now = self.clock.now_instant();
+        let (request_id, handle, request) = {
+            let guard = self.state.read().await;
+            guard.leases.get(resource)?;
+            let entry = guard
+                .queues
+                .get(resource)?
+                .iter()
+                .min_by(|a, b| TerritoryState::queue_order(a, b))?;
+            let request = entry.request.to_request(resource);
+            (entry.id, entry.handle.clone(), request)
+        };
+        self.metrics.record_lease_escalation();
+        self.emit_event(TerritoryEvent::Escalated {
+            handle,
+            reason: EscalationReason::DirectorOverride,
+        })
+        .await;
+        self.apply_force_override(&policy, resource, request_id, &request, now)
+            .await
+    }
+
+    async fn apply_force_override(
+        &self,
+        policy: &TerritoryPolicy,
+        resource: &ResourcePath,
+        request_id: RequestId,
+        request: &LeaseRequest,
+        now: Instant,
+    ) -> Option<LeaseSnapshot> {
+        let mut guard = self.state.write().await;
+        if !guard.leases.contains_key(resource) {
+            return None;
+        }
+        let entries = guard.queues.get_mut(resource)?;
+        let index = entries.iter().position(|entry| entry.id == request_id)?;
+        let entry = entries.remove(index);
+        TerritoryState::reindex(entries);
+        guard.record_wait(&entry, now);
+        let previous = guard.leases.get(resource)?.snapshot();
+        guard.relocate(resource, request.coordinates);
+        let active = guard.leases.get_mut(resource)?;
+        active.assume_holder(request, request_id, now, policy);
+        let lease = active.snapshot();
         let inventory = LeaseInventorySnapshot::from_state(&guard);
         let (active, pending, outstanding) = inventory.into_parts();
         drop(guard);
+        self.bump_heat_map(resource, request.priority).await;
         self.metrics.record_lease_override();
         self.metrics
             .update_lease_inventory(active, pending, outstanding);
         self.emit_event(TerritoryEvent::Overridden {
-            previous: previous_snapshot.clone(),
-            lease: snapshot.clone(),
+            previous: Box::new(previous),
+            lease: Box::new(lease.clone()),
         })
         .await;
-        TransferDecision::Transferred {
-            previous: previous_snapshot,
-            lease: snapshot,
+        Some(lease)
+    }
+
+    pub async fn advance_negotiations(&self) -> Vec<NegotiationTransition> {
+        let now = self.clock.now_instant();
+        let policy = self.policy();
+        let transitions = {
+            let mut guard = self.state.write().await;
+            guard.advance_negotiations(&policy, now)
+        };
+        for transition in transitions.iter() {
+            let handle = transition.handle.clone();
+            if transition.state == NegotiationState::Escalating {
+                self.metrics.record_lease_escalation();
+                self.emit_event(TerritoryEvent::Escalated {
+                    handle,
+                    reason: EscalationReason::NegotiationExhausted,
+                })
+                .await;
+            } else {
+                self.emit_event(TerritoryEvent::Negotiating {
+                    handle,
+                    round: transition.round,
+                })
+                .await;
+            }
         }
+        transitions
+    }
+
+    pub async fn list_leases(&self) -> Vec<LeaseSnapshot> {
+        let guard = self.state.read().await;
+        let mut leases: Vec<LeaseSnapshot> = guard
+            .leases
+            .values()
+            .map(|lease| lease.snapshot())
+            .collect();
+        leases.sort_by(|a, b| a.resource_id.cmp(&b.resource_id));
+        leases
+    }
+
+    pub async fn list_leases_tagged(&self, tag: &str) -> Vec<LeaseSnapshot> {
+        let mut leases = self.list_leases().await;
+        leases.retain(|lease| lease.tags.iter().any(|candidate| candidate == tag));
+        leases
+    }
+
+    pub async fn lease_records(&self) -> Vec<LeaseRecord> {
+        self.list_leases()
+            .await
+            .iter()
+            .map(lease_record_from)
+            .collect()
+    }
+
+    pub async fn fairness_report(&self) -> FairnessReport {
+        let guard = self.state.read().await;
+        let mut agents: Vec<AgentFairness> = guard
+            .wait_tallies
+            .iter()
+            .map(|(agent_id, tally)| AgentFairness {
+                agent_id: agent_id.clone(),
+                grants: tally.grants,
+                total_wait: tally.total_wait,
+                average_wait: tally
+                    .total_wait
+                    .checked_div(tally.grants as u32)
+                    .unwrap_or_default(),
+            })
+            .collect();
+        agents.sort_by(|a, b| {
+            b.average_wait
+                .cmp(&a.average_wait)
+                .then_with(|| a.agent_id.cmp(&b.agent_id))
+        });
+        FairnessReport { agents }
+    }
+
+    pub async fn list_queue(&self, resource: &ResourcePath) -> Vec<QueuedLeaseInfo> {
+        let now = self.clock.now_instant();
+        let guard = self.state.read().await;
+        let Some(entries) = guard.queues.get(resource) else {
+            return Vec::new();
+        };
+        let mut listing: Vec<QueuedLeaseInfo> =
+            entries.iter().map(|entry| entry.queued_info(now)).collect();
+        listing.sort_by_key(|info| info.queue_position);
+        listing
+    }
+
+    pub async fn inventory(&self) -> TerritoryInventory {
+        let now = self.clock.now_instant();
+        let guard = self.state.read().await;
+        let mut leases: Vec<LeaseSnapshot> = guard
+            .leases
+            .values()
+            .map(|lease| lease.snapshot())
+            .collect();
+        leases.sort_by(|a, b| a.resource_id.cmp(&b.resource_id));
+        let mut queued: Vec<QueuedLeaseInfo> = guard
+            .queues
+            .values()
+            .flatten()
+            .map(|entry| entry.queued_info(now))
+            .collect();
+        queued.sort_by(|a, b| {
+            a.resource_id
+                .cmp(&b.resource_id)
+                .then(a.queue_position.cmp(&b.queue_position))
+        });
+        TerritoryInventory { leases, queued }
+    }
+
+    pub async fn next_in_line(&self, resource: &ResourcePath) -> Option<NegotiationHandle> {
+        let now = self.clock.now_instant();
+        self.state.read().await.peek_next(resource, now)
     }
 
     pub async fn current_lease(&self, resource: &ResourcePath) -> Option<LeaseSnapshot> {
@@ -1133,7 +2223,7 @@ impl TerritoryManager {
             return None;
         }
         lease.holder_progress = progress.clamp(0.0, 1.0);
-        lease.last_heartbeat_at = Instant::now();
+        lease.last_heartbeat_at = self.clock.now_instant();
         Some(lease.snapshot())
     }
 
@@ -1147,38 +2237,39 @@ impl TerritoryManager {
         resource: &ResourcePath,
         votes: Vec<QuorumVote>,
         reason: &str,
-    ) {
+        correlation_id: Option<&str>,
+    ) -> bool {
         if votes.is_empty() {
-            return;
+            return false;
         }
         if let Some(broker) = &self.consensus {
-            broker.record_quorum(resource, votes, reason).await;
+            broker
+                .record_correlated_quorum(resource, votes, reason, correlation_id)
+                .await
         } else {
-            let total: f32 = votes.iter().map(|vote| vote.weight.max(0.0)).sum();
-            let agree: f32 = votes
-                .iter()
-                .filter(|vote| vote.vote)
-                .map(|vote| vote.weight.max(0.0))
-                .sum();
-            let threshold = self.policy.consensus_threshold;
-            let achieved = if total > f32::EPSILON {
-                (agree / total) >= threshold
-            } else {
-                false
-            };
+            let threshold = self.quorum_threshold(resource, &self.policy());
+            let achieved = quorum_achieved(&votes, threshold);
             self.metrics.record_quorum_metrics(QuorumMetricsUpdate {
                 resource_id: resource.clone(),
                 achieved,
                 threshold,
                 reason: reason.to_string(),
             });
+            achieved
         }
     }
 
-    async fn bump_heat_map(&self, resource: &ResourcePath) {
+    fn quorum_threshold(&self, resource: &ResourcePath, policy: &TerritoryPolicy) -> f32 {
+        self.consensus
+            .as_ref()
+            .map(|broker| broker.threshold_for(resource))
+            .unwrap_or(policy.consensus_threshold)
+    }
+
+    async fn bump_heat_map(&self, resource: &ResourcePath, priority: Priority) {
         let summary = {
             let mut heat = self.heat_map.lock().await;
-            heat.bump(resource, Instant::now())
+            heat.bump(resource, priority, self.clock.now_instant())
         };
         self.metrics.update_heat_summary(summary);
     }
@@ -1186,7 +2277,7 @@ impl TerritoryManager {
     async fn publish_heat_summary(&self) {
         let summary = {
             let mut heat = self.heat_map.lock().await;
-            heat.summary(Instant::now())
+            heat.summary(self.clock.now_instant())
         };
         self.metrics.update_heat_summary(summary);
     }
@@ -1195,15 +2286,26 @@ impl TerritoryManager {
         self.start_maintenance_if_needed().await;
         let summary = {
             let mut heat = self.heat_map.lock().await;
-            heat.summary(Instant::now())
+            heat.summary(self.clock.now_instant())
         };
         self.metrics.update_heat_summary(summary.clone());
         summary
     }
 
+    pub async fn heat_map_full(&self) -> Vec<(ResourcePath, f64)> {
+        let mut heat = self.heat_map.lock().await;
+        heat.full(self.clock.now_instant())
+    }
+
+    pub async fn resource_heat(&self, resource: &ResourcePath) -> f64 {
+        let mut heat = self.heat_map.lock().await;
+        heat.value(resource, self.clock.now_instant())
+    }
+
     async fn emit_event(&self, event: TerritoryEvent) {
         let ledger_payload = self.ledger.as_ref().and_then(|writer| {
-            ledger_event_from_territory(&event).map(|payload| (writer.clone(), payload))
+            ledger_event_from_territory(&event, self.clock.as_ref())
+                .map(|payload| (writer.clone(), payload))
         });
         let _ = self.events.send(event);
         if let Some((ledger_writer, payload)) = ledger_payload {
@@ -1259,8 +2361,11 @@ impl TerritoryManager {
                     _ = ticker.tick() => {
                         let executor = executor.clone();
                         let manager = manager.clone();
-                        executor.spawn(async move {
+                        let _ = executor.spawn(async move {
                             manager.publish_heat_summary().await;
+                            manager.advance_negotiations().await;
+                            manager.enforce_fairness().await;
+                            manager.force_overdue_overrides().await;
                         });
                     }
                 }
@@ -1275,7 +2380,109 @@ impl Drop for TerritoryManager {
     }
 }
 
-fn ledger_event_from_territory(event: &TerritoryEvent) -> Option<LedgerLeaseEvent> {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AcquirePlan {
+    Grant,
+    Override,
+    Defer,
+    Queue,
+}
+
+impl AcquirePlan {
+    fn decide(
+        active: Option<&Lease>,
+        request: &LeaseRequest,
+        policy: &TerritoryPolicy,
+        quorum_threshold: f32,
+        now: Instant,
+    ) -> Self {
+        let Some(active) = active else {
+            return AcquirePlan::Grant;
+        };
+        if request.priority == Priority::DirectorOverride {
+            return AcquirePlan::Override;
+        }
+        let priority_delta = request.priority.as_index() as i32 - active.priority.as_index() as i32;
+        let should_override = match policy.override_policy {
+            OverridePolicy::Never => false,
+            OverridePolicy::PriorityDelta(delta) => priority_delta >= delta as i32,
+            OverridePolicy::RequireQuorum => {
+                priority_delta > 0
+                    && quorum_achieved(&contention_votes(active, request), quorum_threshold)
+            }
+        };
+        if should_override {
+            return AcquirePlan::Override;
+        }
+        let time_left = active
+            .expires_at
+            .checked_duration_since(now)
+            .unwrap_or_default();
+        if time_left <= policy.auto_extend_threshold {
+            AcquirePlan::Defer
+        } else {
+            AcquirePlan::Queue
+        }
+    }
+}
+
+fn contention_votes(active: &Lease, request: &LeaseRequest) -> Vec<QuorumVote> {
+    vec![
+        quorum_vote(
+            &active.holder_id,
+            (active.priority.as_index() + 1) as f32 * (1.0 + active.holder_progress),
+            false,
+        ),
+        quorum_vote(
+            &request.agent_id,
+            (request.priority.as_index() + 1) as f32,
+            true,
+        ),
+    ]
+}
+
+fn override_rejection(
+    active: &Lease,
+    request: &LeaseRequest,
+    policy: &TerritoryPolicy,
+    quorum_threshold: f32,
+) -> Option<&'static str> {
+    if request.priority.as_index() <= active.priority.as_index() {
+        return None;
+    }
+    match policy.override_policy {
+        OverridePolicy::Never => Some("overrideDisabled"),
+        OverridePolicy::PriorityDelta(delta) => {
+            let priority_delta =
+                request.priority.as_index() as i32 - active.priority.as_index() as i32;
+            (priority_delta < delta as i32).then_some("priorityDelta")
+        }
+        OverridePolicy::RequireQuorum => {
+            (!quorum_achieved(&contention_votes(active, request), quorum_threshold))
+                .then_some("quorumFailed")
+        }
+    }
+}
+
+fn quorum_achieved(votes: &[QuorumVote], threshold: f32) -> bool {
+    let total: f32 = votes.iter().map(|vote| vote.weight.max(0.0)).sum();
+    let agree: f32 = votes
+        .iter()
+        .filter(|vote| vote.vote)
+        .map(|vote| vote.weight.max(0.0))
+        .sum();
+    if total > f32::EPSILON {
+        (agree / total) >= threshold
+    } else {
+        false
+    }
+}
+
+fn ledger_event_from_territory(
+    event: &TerritoryEvent,
+    clock: &dyn Clock,
+) -> Option<LedgerLeaseEvent> {
+    let now = clock.now_instant();
     match event {
         TerritoryEvent::Granted(snapshot) => {
             Some(LedgerLeaseEvent::Granted(lease_record_from(snapshot)))
@@ -1286,17 +2493,40 @@ fn ledger_event_from_territory(event: &TerritoryEvent) -> Option<LedgerLeaseEven
         } => Some(LedgerLeaseEvent::Deferred(queue_record_from(
             handle,
             Some(*grace_deadline),
+            clock,
         ))),
-        TerritoryEvent::Queued(handle) => {
-            Some(LedgerLeaseEvent::Deferred(queue_record_from(handle, None)))
-        }
-        TerritoryEvent::Released(snapshot) => {
-            Some(LedgerLeaseEvent::Released(lease_record_from(snapshot)))
-        }
+        TerritoryEvent::Queued(handle) => Some(LedgerLeaseEvent::Deferred(queue_record_from(
+            handle, None, clock,
+        ))),
+        TerritoryEvent::Withdrawn(handle) => Some(LedgerLeaseEvent::Withdrawn(queue_record_from(
+            handle, None, clock,
+        ))),
+        TerritoryEvent::Negotiating { .. } => None,
+        TerritoryEvent::Released(snapshot) => Some(LedgerLeaseEvent::Released(LeaseRecord {
+            held_for_ms: Some(
+                now.saturating_duration_since(snapshot.granted_at)
+                    .as_millis() as u64,
+            ),
+            ..lease_record_from(snapshot)
+        })),
+        TerritoryEvent::ForceReleased { lease, reason } => Some(LedgerLeaseEvent::ForceReleased {
+            lease: LeaseRecord {
+                held_for_ms: Some(
+                    now.saturating_duration_since(lease.granted_at).as_millis() as u64
+                ),
+                ..lease_record_from(lease)
+            },
+            reason: reason.clone(),
+        }),
         TerritoryEvent::Overridden { previous, lease } => Some(LedgerLeaseEvent::Overridden {
             previous: lease_record_from(previous),
             lease: lease_record_from(lease),
         }),
+        TerritoryEvent::Transferred { previous, lease } => Some(LedgerLeaseEvent::Transferred {
+            from: previous.holder_id.clone(),
+            to: lease.holder_id.clone(),
+            lease: lease_record_from(lease),
+        }),
         TerritoryEvent::Escalated { handle, reason } => Some(LedgerLeaseEvent::Escalated(
             escalation_record_from(handle, reason),
         )),
@@ -1309,19 +2539,22 @@ fn lease_record_from(snapshot: &LeaseSnapshot) -> LeaseRecord {
         resource_id: snapshot.resource_id.clone(),
         holder_id: snapshot.holder_id.clone(),
         priority: snapshot.priority.as_str().to_string(),
+        held_for_ms: None,
     }
 }
 
 fn queue_record_from(
     handle: &NegotiationHandle,
     grace_deadline: Option<Instant>,
+    clock: &dyn Clock,
 ) -> LeaseQueueRecord {
     LeaseQueueRecord {
-        request_id: format!("{}:{}", handle.agent_id, handle.queue_position),
+        request_id: handle.request_id.as_u64().to_string(),
         agent_id: handle.agent_id.clone(),
         resource_id: handle.resource_id.clone(),
         queue_position: handle.queue_position,
-        grace_deadline_ms: grace_deadline.map(instant_to_epoch_ms),
+        grace_deadline_ms: grace_deadline.map(|deadline| instant_to_epoch_ms(deadline, clock)),
+        priority: Some(handle.priority.as_str().to_string()),
     }
 }
 
@@ -1333,6 +2566,9 @@ fn escalation_record_from(
         EscalationReason::QueueDepth => "queueDepth",
         EscalationReason::Starvation => "starvation",
         EscalationReason::Deadlock => "deadlock",
+        EscalationReason::NegotiationExhausted => "negotiationExhausted",
+        EscalationReason::QueueAge => "queueAge",
+        EscalationReason::DirectorOverride => "directorOverride",
     };
     LeaseEscalationRecord {
         agent_id: handle.agent_id.clone(),
@@ -1341,9 +2577,9 @@ fn escalation_record_from(
     }
 }
 
-fn instant_to_epoch_ms(target: Instant) -> u64 {
-    let now = Instant::now();
-    let system_now = SystemTime::now();
+fn instant_to_epoch_ms(target: Instant, clock: &dyn Clock) -> u64 {
+    let now = clock.now_instant();
+    let system_now = clock.now_system();
     let target_system = if target <= now {
         system_now
     } else {
@@ -1,17 +1,37 @@
-use liminal_v1::config::{AppConfig, LedgerConfig};
-use liminal_v1::consensus::{quorum_vote, ConsensusBroker};
+use liminal_v1::clock::MockClock;
+use liminal_v1::config::{
+    AppConfig, ConfigError, ConsensusSuccessConfig, HealthMonitoringConfig, LedgerConfig,
+    QueueHealthConfig, RouterConfig,
+};
+use liminal_v1::config_watcher::{ConfigDiff, ConfigWatcher};
+use liminal_v1::consensus::{
+    quorum_vote, ConsensusBroker, RoundOutcome, DIRECTOR_OVERRIDE_REASON, TIMEOUT_ESCALATION_REASON,
+};
 use liminal_v1::executor::MaintenanceExecutor;
+use liminal_v1::health::{HealthCategory, HealthMonitor};
 use liminal_v1::ledger::{
-    ConsensusEvent, LeaseEvent, LeaseReplayState, LedgerEvent, LedgerReader, LedgerWriter,
-    ReplayCoordinator, RouterEvent, RouterReplayState, StateCheckpoint,
+    lease_trace_id, CheckpointMismatch, CheckpointRetention, ConsensusEvent, ConsensusSignal,
+    DirectorEvent, EventEnvelope, EventFilter, ExportFormat, HealthEvent, HeatParameters,
+    LeaseEvent, LeaseQueueRecord, LeaseRecord, LeaseReplayState, LedgerError, LedgerEvent,
+    LedgerEventKind, LedgerReader, LedgerWriter, PtyEvent, QuorumVector, RateLimitedRecord,
+    ReplayCoordinator, ReplayOptions, RouterDispatchRecord, RouterEvent, RouterReplayState,
+    SequenceGap, StateCheckpoint, CLOCK_REGRESSION_TOLERANCE,
+};
+use liminal_v1::metrics::{
+    MetricsCollector, MetricsSnapshot, MetricsSubsystem, QuorumMetricsUpdate,
+    CONSENSUS_OUTCOME_WINDOW,
+};
+use liminal_v1::router::{
+    DispatcherConfig, Message, MiddlewareDecision, Priority, RouteError, UnifiedMessageRouter,
 };
-use liminal_v1::metrics::MetricsCollector;
-use liminal_v1::router::{DispatcherConfig, Message, Priority, UnifiedMessageRouter};
+use liminal_v1::stream::StreamItem;
 use liminal_v1::territory::{
-    LeaseDecision, LeaseRequest, TerritoryEvent, TerritoryManager, TerritoryPolicy,
+    EscalationReason, LeaseDecision, LeaseId, LeaseRequest, NegotiationState, OverridePolicy,
+    RequestId, TerritoryEvent, TerritoryManager, TerritoryPolicy, TransferDecision,
+    TransferRequest,
 };
 use serde_json;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::Write;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -36,18 +56,24 @@ async fn router_dispatches_by_priority() {
         priority: Priority::Info,
         sender: "agent".to_string(),
         recipient: "peer".to_string(),
+        message_id: None,
+        trace_id: None,
     };
     let coordinate = Message {
         content: "coordinate".to_string(),
         priority: Priority::Coordinate,
         sender: "agent".to_string(),
         recipient: "peer".to_string(),
+        message_id: None,
+        trace_id: None,
     };
     let critical = Message {
         content: "critical".to_string(),
         priority: Priority::Critical,
         sender: "agent".to_string(),
         recipient: "peer".to_string(),
+        message_id: None,
+        trace_id: None,
     };
 
     router.route_message(info).await.unwrap();
@@ -94,6 +120,8 @@ async fn router_applies_aging_boosts() {
         priority: Priority::Info,
         sender: "slow".to_string(),
         recipient: "peer".to_string(),
+        message_id: None,
+        trace_id: None,
     };
 
     router.route_message(info).await.unwrap();
@@ -110,945 +138,5805 @@ async fn router_applies_aging_boosts() {
 }
 
 #[tokio::test]
-async fn router_enforces_token_quota() {
+async fn per_band_aging_boosts_blocking_before_info() {
+    let clock = MockClock::new();
+    let mut thresholds = [Duration::from_secs(10); 5];
+    thresholds[Priority::Blocking.as_index()] = Duration::from_millis(100);
+    let config = DispatcherConfig {
+        aging_threshold: Duration::from_secs(10),
+        aging_thresholds: Some(thresholds),
+        max_aging_boosts: 1,
+        ..DispatcherConfig::default()
+    };
+    assert_eq!(
+        DispatcherConfig::default().aging_threshold_for(Priority::Blocking),
+        DispatcherConfig::default().aging_threshold
+    );
+    let router = Arc::new(
+        UnifiedMessageRouter::with_config(MetricsCollector::new(), config)
+            .with_clock(clock.shared()),
+    );
+    router
+        .set_maintenance_executor(MaintenanceExecutor::new(2))
+        .await;
+    router.pause();
+
+    for priority in [Priority::Info, Priority::Blocking] {
+        router
+            .route_message(Message {
+                content: priority.as_str().to_string(),
+                priority,
+                sender: "ager".to_string(),
+                recipient: "peer".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+    }
+    assert_eq!(router.current_queue_depths().await, [1, 0, 1, 0, 0]);
+
+    clock.advance(Duration::from_millis(150));
+    time::timeout(Duration::from_secs(2), async {
+        while router.current_queue_depths().await != [1, 0, 0, 1, 0] {
+            time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("blocking message boosted while info waits");
+
+    clock.advance(Duration::from_secs(10));
+    time::timeout(Duration::from_secs(2), async {
+        while router.current_queue_depths().await != [0, 1, 0, 1, 0] {
+            time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("info message boosted after its longer threshold");
+}
+
+#[tokio::test]
+async fn exempt_senders_bypass_token_buckets() {
     let metrics = MetricsCollector::new();
     let config = DispatcherConfig {
         idle_backoff: Duration::from_millis(5),
         token_capacity: 2.0,
-        token_refill_rate: 4.0,
+        token_refill_rate: 0.0,
         initial_tokens: 2.0,
+        exempt_senders: Arc::new(["director".to_string()].into_iter().collect()),
         ..DispatcherConfig::default()
     };
-    let router = Arc::new(UnifiedMessageRouter::with_config(metrics, config));
-    let executor = MaintenanceExecutor::new(2);
-    router.set_maintenance_executor(executor).await;
+    let router = UnifiedMessageRouter::with_config(metrics.clone(), config);
     let mut deliveries = router.subscribe();
 
-    for label in ["first", "second"] {
-        let message = Message {
-            content: label.to_string(),
-            priority: Priority::Info,
-            sender: "quota".to_string(),
-            recipient: "peer".to_string(),
-        };
-        router.route_message(message).await.unwrap();
+    for index in 0..5 {
+        for sender in ["director", "worker"] {
+            router
+                .route_message(Message {
+                    content: format!("{sender} {index}"),
+                    priority: Priority::Info,
+                    sender: sender.to_string(),
+                    recipient: "peer".to_string(),
+                    message_id: None,
+                    trace_id: None,
+                })
+                .await
+                .unwrap();
+        }
     }
 
-    let one = time::timeout(Duration::from_millis(200), deliveries.recv())
-        .await
-        .unwrap()
-        .unwrap();
-    let two = time::timeout(Duration::from_millis(200), deliveries.recv())
-        .await
-        .unwrap()
-        .unwrap();
+    let mut delivered: BTreeMap<String, usize> = BTreeMap::new();
+    while let Ok(Ok(delivery)) = time::timeout(Duration::from_millis(300), deliveries.recv()).await
+    {
+        *delivered.entry(delivery.message.sender).or_default() += 1;
+    }
 
-    assert_eq!(one.message.content, "first");
-    assert_eq!(two.message.content, "second");
+    assert_eq!(delivered.get("director"), Some(&5));
+    assert_eq!(delivered.get("worker"), Some(&2));
+    let hits = |sender: &str| {
+        metrics
+            .get_snapshot()
+            .rate_limits
+            .iter()
+            .find(|entry| entry.sender == sender)
+            .map(|entry| entry.rate_limit_hits)
+            .unwrap_or_default()
+    };
+    assert_eq!(hits("director"), 0);
+    assert!(hits("worker") > 0);
+}
 
-    let throttled = Message {
-        content: "third".to_string(),
-        priority: Priority::Info,
-        sender: "quota".to_string(),
-        recipient: "peer".to_string(),
+#[tokio::test]
+async fn middleware_redacts_content_and_drops_banned_senders() {
+    let router = UnifiedMessageRouter::new();
+    let mut deliveries = router.subscribe();
+    router.add_middleware(Box::new(|msg: &mut Message| {
+        msg.content = msg.content.replace("hunter2", "[redacted]");
+        MiddlewareDecision::Continue
+    }));
+    router.add_middleware(Box::new(|msg: &mut Message| {
+        if msg.sender == "banned" {
+            MiddlewareDecision::Drop(format!("sender `{}` is banned", msg.sender))
+        } else {
+            MiddlewareDecision::Continue
+        }
+    }));
+    let message = |sender: &str, content: &str| Message {
+        content: content.to_string(),
+        priority: Priority::Coordinate,
+        sender: sender.to_string(),
+        recipient: "agent-b".to_string(),
+        message_id: None,
+        trace_id: None,
     };
-    router.route_message(throttled).await.unwrap();
 
-    let immediate = time::timeout(Duration::from_millis(50), deliveries.recv()).await;
-    assert!(immediate.is_err());
+    let rejected = router.route_message(message("banned", "let me in")).await;
+    assert_eq!(
+        rejected,
+        Err(RouteError::Rejected(
+            "sender `banned` is banned".to_string()
+        ))
+    );
 
-    let eventual = time::timeout(Duration::from_millis(400), deliveries.recv())
+    router
+        .route_message(message("agent-a", "password is hunter2"))
         .await
-        .unwrap()
-        .unwrap();
-    assert_eq!(eventual.message.content, "third");
-    assert!(eventual.retry_count > 0);
+        .expect("allowed sender is routed");
+    let delivery = time::timeout(Duration::from_millis(500), deliveries.recv())
+        .await
+        .expect("delivery before timeout")
+        .expect("delivery received");
+    assert_eq!(delivery.message.sender, "agent-a");
+    assert_eq!(delivery.message.content, "password is [redacted]");
+    assert!(deliveries.try_recv().is_err());
 }
 
 #[tokio::test]
-async fn router_rate_limiting_updates_metrics_snapshot() {
-    let metrics = MetricsCollector::new();
+async fn router_rejects_messages_over_size_limit() {
     let config = DispatcherConfig {
-        token_capacity: 1.0,
-        token_refill_rate: 0.0,
-        initial_tokens: 1.0,
-        idle_backoff: Duration::from_millis(5),
+        max_message_bytes: 16,
         ..DispatcherConfig::default()
     };
-    let router = Arc::new(UnifiedMessageRouter::with_config(metrics.clone(), config));
-    let executor = MaintenanceExecutor::new(2);
-    router.set_maintenance_executor(executor).await;
+    let router = UnifiedMessageRouter::with_config(MetricsCollector::new(), config);
+    let mut deliveries = router.subscribe();
+    let message = |content: &str| Message {
+        content: content.to_string(),
+        priority: Priority::Coordinate,
+        sender: "agent-a".to_string(),
+        recipient: "agent-b".to_string(),
+        message_id: None,
+        trace_id: None,
+    };
 
-    for index in 0..3 {
-        let message = Message {
-            content: format!("limited_{index}"),
-            priority: Priority::Info,
-            sender: "rate_limited_agent".to_string(),
-            recipient: "observer".to_string(),
-        };
-        router.route_message(message).await.unwrap();
-    }
+    let rejected = router.route_message(message(&"x".repeat(17))).await;
+    assert_eq!(
+        rejected,
+        Err(RouteError::TooLarge {
+            size: 17,
+            limit: 16,
+        })
+    );
 
-    time::sleep(Duration::from_millis(30)).await;
-    let snapshot = metrics.get_snapshot();
-    let rate_limit_entry = snapshot
-        .rate_limits
-        .iter()
-        .find(|entry| entry.sender == "rate_limited_agent")
-        .cloned();
+    router
+        .route_message(message(&"y".repeat(16)))
+        .await
+        .expect("message at the limit is accepted");
+    let delivery = time::timeout(Duration::from_millis(500), deliveries.recv())
+        .await
+        .expect("delivery before timeout")
+        .expect("delivery");
+    assert_eq!(delivery.message.content.len(), 16);
+}
 
-    assert!(snapshot.router.rate_limited_messages > 0);
-    assert!(rate_limit_entry.as_ref().is_some());
-    assert!(rate_limit_entry
-        .as_ref()
-        .map(|entry| entry.rate_limit_hits > 0)
-        .unwrap_or(false));
-    assert!(
-        snapshot
-            .router
-            .queue_depths
-            .get("info")
-            .copied()
-            .unwrap_or_default()
-            >= 1
-    );
+#[test]
+fn blocking_facade_serves_synchronous_callers() {
+    let territory = TerritoryManager::new(MetricsCollector::new(), None);
+    let decision = territory.acquire_lease_blocking(LeaseRequest::new(
+        "agent-a".into(),
+        "src/lib.rs".into(),
+        Priority::Coordinate,
+    ));
+    assert!(matches!(decision, LeaseDecision::Granted(_)));
+    let released = territory.release_lease_blocking(&"agent-a".into(), &"src/lib.rs".into());
+    assert!(released.is_some());
 
-    drop(router);
+    let router = UnifiedMessageRouter::with_metrics(MetricsCollector::new());
+    let mut deliveries = router.subscribe();
+    router
+        .route_message_blocking(Message {
+            content: "from sync code".to_string(),
+            priority: Priority::Coordinate,
+            sender: "agent-a".to_string(),
+            recipient: "agent-b".to_string(),
+            message_id: None,
+            trace_id: None,
+        })
+        .expect("route from synchronous caller");
+    let deadline = std::time::Instant::now() + Duration::from_secs(1);
+    let delivery = loop {
+        match deliveries.try_recv() {
+            Ok(delivery) => break delivery,
+            Err(_) if std::time::Instant::now() < deadline => {
+                std::thread::sleep(Duration::from_millis(5))
+            }
+            Err(err) => panic!("no delivery from blocking route: {err:?}"),
+        }
+    };
+    assert_eq!(delivery.message.content, "from sync code");
 }
 
 #[tokio::test]
-async fn maintenance_executor_handles_router_and_territory_load() {
+async fn tracked_route_resolves_with_matching_delivery() {
     let metrics = MetricsCollector::new();
-    let dispatcher_config = DispatcherConfig {
-        aging_threshold: Duration::from_millis(30),
+    let config = DispatcherConfig {
+        aging_threshold: Duration::from_millis(60),
         idle_backoff: Duration::from_millis(5),
-        token_capacity: 2.0,
-        token_refill_rate: 12.0,
-        initial_tokens: 1.0,
-        max_aging_boosts: 2,
+        token_capacity: 5.0,
+        token_refill_rate: 10.0,
+        initial_tokens: 0.0,
+        max_aging_boosts: 1,
         ..DispatcherConfig::default()
     };
-    let router = Arc::new(UnifiedMessageRouter::with_config(
-        metrics.clone(),
-        dispatcher_config,
-    ));
-    let executor = MaintenanceExecutor::new(4);
-    router.set_maintenance_executor(executor.clone()).await;
-    let warmup = Message {
-        content: "warmup".to_string(),
-        priority: Priority::Info,
-        sender: "quota_agent".to_string(),
-        recipient: "peer".to_string(),
-    };
-    router.route_message(warmup).await.unwrap();
-
-    for index in 0..4 {
-        let message = Message {
-            content: format!("load_{index}"),
-            priority: Priority::Info,
-            sender: format!("high_priority_{index}"),
-            recipient: "peer".to_string(),
-        };
-        router.route_message(message).await.unwrap();
-    }
+    let router = Arc::new(UnifiedMessageRouter::with_config(metrics, config));
+    router
+        .set_maintenance_executor(MaintenanceExecutor::new(2))
+        .await;
 
-    let maintenance_target = Message {
-        content: "maintenance_target".to_string(),
+    let message = Message {
+        content: "tracked".to_string(),
         priority: Priority::Info,
-        sender: "quota_agent".to_string(),
+        sender: "slow".to_string(),
         recipient: "peer".to_string(),
+        message_id: Some("tracked-1".to_string()),
+        trace_id: None,
     };
-    router.route_message(maintenance_target).await.unwrap();
+    let token = router.route_message_tracked(message.clone()).await.unwrap();
+    let duplicate = router.route_message_tracked(message).await.unwrap_err();
 
-    tokio::time::sleep(Duration::from_millis(500)).await;
-    let snapshot_precheck = metrics.get_snapshot();
-    let quota_tokens = snapshot_precheck
-        .rate_limits
-        .iter()
-        .find(|entry| entry.sender == "quota_agent")
-        .map(|entry| entry.tokens_remaining)
-        .unwrap_or(0.0);
-    assert!(quota_tokens > 0.0, "token refill did not execute");
-    assert!(
-        snapshot_precheck.router.rate_limited_messages > 0,
-        "router did not record any rate limiting"
+    assert_eq!(
+        duplicate,
+        RouteError::Duplicate {
+            message_id: "tracked-1".to_string(),
+        }
     );
 
-    let post_snapshot = metrics.get_snapshot();
-    assert!(
-        post_snapshot.router.rate_limited_messages > 0,
-        "router should record rate limiting during maintenance"
-    );
+    let delivery = time::timeout(Duration::from_millis(1200), token)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(delivery.message.message_id.as_deref(), Some("tracked-1"));
+    assert_eq!(delivery.effective_priority, Priority::Coordinate);
+    assert!(delivery.wait_time >= Duration::from_millis(60));
+    assert!(delivery.aging_boosts >= 1);
 }
 
 #[tokio::test]
-async fn ledger_replay_matches_live_metrics() {
-    let temp_dir = tempdir().expect("temp dir");
-    let mut ledger_config = LedgerConfig::default();
-    ledger_config.root_path = temp_dir.path().to_path_buf();
-    ledger_config.current_epoch = Some("test-epoch".to_string());
-    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
-    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
-
-    let metrics = MetricsCollector::new();
-    let router = Arc::new(UnifiedMessageRouter::with_settings_and_ledger(
-        metrics.clone(),
-        None,
-        Some(ledger_writer.clone()),
-    ));
-    let territory =
-        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
-
+async fn paused_router_holds_messages_until_resumed() {
+    let router = Arc::new(UnifiedMessageRouter::new());
     let mut deliveries = router.subscribe();
+    router.pause();
+    assert!(router.is_paused());
 
-    let messages = vec![
-        Message {
-            content: "coord".into(),
-            priority: Priority::Coordinate,
-            sender: "agent-a".into(),
-            recipient: "agent-b".into(),
-        },
-        Message {
-            content: "critical".into(),
-            priority: Priority::Critical,
-            sender: "agent-c".into(),
-            recipient: "agent-d".into(),
-        },
-        Message {
-            content: "info".into(),
-            priority: Priority::Info,
-            sender: "agent-a".into(),
-            recipient: "agent-b".into(),
-        },
-    ];
-
-    for msg in messages {
-        router.route_message(msg).await.unwrap();
+    for idx in 0..3 {
+        router
+            .route_message(Message {
+                content: format!("held_{idx}"),
+                priority: Priority::Coordinate,
+                sender: "agent_a".to_string(),
+                recipient: "agent_b".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
     }
 
+    assert!(time::timeout(Duration::from_millis(200), deliveries.recv())
+        .await
+        .is_err());
+    assert_eq!(
+        router.current_queue_depths().await[Priority::Coordinate.as_index()],
+        3
+    );
+
+    router.resume();
+    assert!(!router.is_paused());
+    let mut received = Vec::new();
     for _ in 0..3 {
-        let _ = time::timeout(Duration::from_millis(500), deliveries.recv())
+        let delivery = time::timeout(Duration::from_millis(500), deliveries.recv())
             .await
-            .unwrap()
-            .unwrap();
+            .expect("delivery after resume")
+            .expect("delivery");
+        received.push(delivery.message.content);
     }
+    assert_eq!(received, vec!["held_0", "held_1", "held_2"]);
+}
 
-    let lease_request = LeaseRequest::new(
-        "agent-a".into(),
-        "resource-path".into(),
-        Priority::Coordinate,
-    );
-    let _ = territory.acquire_lease(lease_request).await;
+#[tokio::test]
+async fn peak_queue_depth_outlives_drained_burst() {
+    let metrics = MetricsCollector::new();
+    let router = Arc::new(UnifiedMessageRouter::with_metrics(metrics.clone()));
+    let mut deliveries = router.subscribe();
+    router.pause();
 
-    time::sleep(Duration::from_millis(50)).await;
+    for idx in 0..6 {
+        router
+            .route_message(Message {
+                content: format!("burst_{idx}"),
+                priority: Priority::Blocking,
+                sender: format!("agent_{idx}"),
+                recipient: "target".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+    }
 
-    ledger_writer.flush().await.expect("flush ledger");
+    router.resume();
+    for _ in 0..6 {
+        time::timeout(Duration::from_millis(500), deliveries.recv())
+            .await
+            .expect("burst delivery")
+            .expect("delivery");
+    }
 
-    let snapshot = metrics.get_snapshot();
-    let router_state = RouterReplayState {
-        total_dispatched: snapshot.performance.total_messages_routed,
-        last_priority: snapshot.router.last_dispatched_priority.clone(),
-        queue_depths: priority_vec_from_map(&snapshot.router.queue_depths),
-    };
-    let mut lease_state = LeaseReplayState::default();
-    lease_state.deferrals = snapshot.leases.deferrals;
-    lease_state.overrides = snapshot.leases.overrides;
-    lease_state.escalations = snapshot.leases.escalations;
+    let router_snapshot = metrics.get_snapshot().router;
+    let label = Priority::Blocking.as_str();
+    assert_eq!(router_snapshot.queue_depths[label], 0);
+    assert_eq!(router_snapshot.peak_queue_depths[label], 6);
+    assert_eq!(
+        router_snapshot.peak_queue_depths[Priority::Info.as_str()],
+        0
+    );
 
-    let checkpoint = StateCheckpoint {
-        checkpoint_id: format!(
-            "checkpoint-{}",
-            SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_millis()
-        ),
-        captured_at_ms: SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64,
-        router: router_state,
-        leases: lease_state,
-        metrics: snapshot.clone(),
-    };
+    metrics.reset_peak_queue_depths();
+    assert_eq!(metrics.get_snapshot().router.peak_queue_depths[label], 0);
+}
 
-    ledger_writer
-        .record_checkpoint(checkpoint)
-        .await
-        .expect("record checkpoint");
-    ledger_writer
-        .flush()
-        .await
-        .expect("flush ledger after checkpoint");
+#[tokio::test]
+async fn dispatcher_idle_age_grows_without_dispatches() {
+    let metrics = MetricsCollector::new();
+    assert_eq!(metrics.get_snapshot().router.dispatcher_idle_ms, None);
 
-    let coordinator = ReplayCoordinator::new(ledger_reader);
-    let outcome = coordinator
-        .replay_epoch(&ledger_writer.epoch_id())
-        .expect("replay to succeed");
-    let replay_snapshot = outcome.metrics.expect("replay snapshot available");
+    metrics.record_router_delivery(Priority::Info, Duration::from_millis(1), &[0; 5]);
+    let first = metrics.get_snapshot().router;
+    assert!(first.last_dispatched_at.is_some());
+    let first_idle = first.dispatcher_idle_ms.expect("idle age after dispatch");
 
-    let baseline = serde_json::to_string(&snapshot).expect("serialize live snapshot");
-    let replayed = serde_json::to_string(&replay_snapshot).expect("serialize replay snapshot");
-    assert_eq!(baseline, replayed);
+    time::sleep(Duration::from_millis(40)).await;
+    let second = metrics.get_snapshot().router;
+    assert_eq!(second.last_dispatched_at, first.last_dispatched_at);
+    assert!(second.dispatcher_idle_ms.expect("idle age") >= first_idle + 40);
 }
 
 #[tokio::test]
-async fn quorum_override_records_success() {
-    let temp_dir = tempdir().expect("temp dir");
-    let mut ledger_config = LedgerConfig::default();
-    ledger_config.root_path = temp_dir.path().to_path_buf();
-    ledger_config.current_epoch = Some("quorum-test".to_string());
-    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
-    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+async fn director_override_flood_is_counted_without_rate_limiting() {
     let metrics = MetricsCollector::new();
-    let territory =
-        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
-
-    let _ = territory
-        .acquire_lease(LeaseRequest::new(
-            "holder".into(),
-            "shared-resource".into(),
-            Priority::Coordinate,
-        ))
-        .await;
+    let config = DispatcherConfig {
+        idle_backoff: Duration::from_millis(5),
+        token_capacity: 5.0,
+        initial_tokens: 0.0,
+        ..DispatcherConfig::default()
+    };
+    let router = Arc::new(UnifiedMessageRouter::with_config(metrics.clone(), config));
+    let mut deliveries = router.subscribe();
 
-    let decision = territory
-        .acquire_lease(LeaseRequest::new(
-            "contender".into(),
-            "shared-resource".into(),
-            Priority::Critical,
-        ))
-        .await;
+    for index in 0..25 {
+        router
+            .route_message(Message {
+                content: format!("override_{index}"),
+                priority: Priority::DirectorOverride,
+                sender: "director".to_string(),
+                recipient: "agent".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+    }
+    for _ in 0..25 {
+        time::timeout(Duration::from_millis(500), deliveries.recv())
+            .await
+            .unwrap()
+            .unwrap();
+    }
 
-    assert!(matches!(decision, LeaseDecision::Overridden { .. }));
+    let snapshot = metrics.get_snapshot();
+    assert_eq!(snapshot.router.director_override_messages, 25);
+    assert_eq!(snapshot.router.rate_limited_messages, 0);
+}
 
-    time::sleep(Duration::from_millis(50)).await;
-    ledger_writer.flush().await.expect("flush ledger");
+#[tokio::test]
+async fn director_override_bucket_bounds_runaway_director() {
+    let metrics = MetricsCollector::new();
+    let config = DispatcherConfig {
+        idle_backoff: Duration::from_millis(5),
+        director_override_capacity: Some(3.0),
+        director_override_refill_rate: 0.0,
+        ..DispatcherConfig::default()
+    };
+    let router = Arc::new(UnifiedMessageRouter::with_config(metrics.clone(), config));
+    let mut deliveries = router.subscribe();
 
-    let events = ledger_reader
-        .read_epoch(&ledger_writer.epoch_id())
-        .expect("read ledger");
-    let mut commits = Vec::new();
-    for envelope in events {
-        if let LedgerEvent::Consensus(ConsensusEvent::Commit(signal)) = envelope.event {
-            commits.push(signal);
-        }
+    for index in 0..5 {
+        router
+            .route_message(Message {
+                content: format!("override_{index}"),
+                priority: Priority::DirectorOverride,
+                sender: "director".to_string(),
+                recipient: "agent".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
     }
-    assert!(!commits.is_empty());
-    let last_signal = commits.last().expect("consensus commit present");
-    let vector = last_signal.vector.as_ref().expect("vector attached");
-    assert!(vector.achieved, "quorum should succeed on override");
-    assert_eq!(vector.reason, "override");
-    assert_eq!(vector.resource_id, "shared-resource");
+    for _ in 0..3 {
+        time::timeout(Duration::from_millis(500), deliveries.recv())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+    let extra = time::timeout(Duration::from_millis(100), deliveries.recv()).await;
+    assert!(extra.is_err());
 
     let snapshot = metrics.get_snapshot();
-    assert!(snapshot.consensus.success >= 1);
-    assert!(snapshot.consensus.success_ratio >= 1.0);
+    assert_eq!(snapshot.router.director_override_messages, 3);
+    assert!(snapshot.router.rate_limited_messages > 0);
 }
 
-#[tokio::test]
-async fn heat_map_decays_under_load() {
+#[test]
+fn snapshot_delta_reports_counter_differences() {
     let metrics = MetricsCollector::new();
-    let mut policy = TerritoryPolicy::from_config(None);
-    policy.heat_decay_per_second = 0.8;
-    policy.heat_increment = 4.0;
-    policy.heat_max = 10.0;
-    let territory = TerritoryManager::with_policy(metrics.clone(), policy);
+    metrics.record_message_routing(2.0);
+    metrics.record_lease_escalation();
+    let previous = metrics.get_snapshot();
 
-    let resource = "heat-resource".to_string();
-    let _ = territory
-        .acquire_lease(LeaseRequest::new(
-            "heat-holder".into(),
-            resource.clone(),
-            Priority::Coordinate,
-        ))
-        .await;
+    std::thread::sleep(Duration::from_millis(20));
+    for _ in 0..3 {
+        metrics.record_message_routing(1.0);
+    }
+    metrics.record_lease_escalation();
+    metrics.record_lease_escalation();
+    let current = metrics.get_snapshot();
 
-    let _ = territory
-        .acquire_lease(LeaseRequest::new(
-            "heat-contender".into(),
-            resource.clone(),
-            Priority::Critical,
-        ))
-        .await;
+    let delta = current.delta(&previous);
+    assert_eq!(delta.messages_routed, 3);
+    assert_eq!(delta.escalations, 2);
+    assert_eq!(delta.deferrals, 0);
+    assert!(delta.elapsed >= Duration::from_millis(20));
+    assert!(delta.routed_per_minute() > 0.0);
+    assert!(delta.escalations_per_minute() < delta.routed_per_minute());
+    assert_eq!(previous.delta(&current).messages_routed, 0);
+}
 
-    time::sleep(Duration::from_millis(40)).await;
-    let hot_snapshot = territory.heat_snapshot().await;
-    assert_eq!(
-        hot_snapshot.hottest_resource.as_deref(),
-        Some(resource.as_str())
-    );
-    assert!(hot_snapshot.hottest_score > 0.0);
+#[test]
+fn agent_spawn_times_are_kept_as_a_distribution() {
+    let metrics = MetricsCollector::new();
+    for duration_ms in [120.0, 80.0, 400.0, 100.0, 90.0] {
+        metrics.record_agent_spawn(duration_ms);
+    }
 
-    time::sleep(Duration::from_millis(220)).await;
-    let cooled_snapshot = territory.heat_snapshot().await;
-    assert!(cooled_snapshot.hottest_score < hot_snapshot.hottest_score);
+    let performance = metrics.get_snapshot().performance;
+    assert_eq!(performance.agent_spawn_time_ms, 90.0);
+    assert_eq!(performance.agent_spawn_samples, 5);
+    assert_eq!(performance.agent_spawn_mean_ms, 158.0);
+    assert_eq!(performance.agent_spawn_max_ms, 400.0);
+    assert_eq!(performance.agent_spawn_p99_ms, 400.0);
 }
 
-fn priority_vec_from_map(depths: &BTreeMap<String, usize>) -> Vec<usize> {
-    let mut values = Vec::new();
-    for index in 0..=Priority::DirectorOverride.as_index() {
-        let key = Priority::from_index(index).as_str();
-        values.push(*depths.get(key).unwrap_or(&0));
+#[test]
+fn pty_snapshot_separates_activity_per_agent() {
+    let metrics = MetricsCollector::new();
+    metrics.record_agent_event("alpha", Some("turn_complete"));
+    metrics.record_agent_event("alpha", Some("turn_complete"));
+    metrics.record_agent_event("alpha", None);
+    metrics.record_agent_event("beta", Some("turn_complete"));
+
+    let snapshot = metrics.get_snapshot();
+    assert_eq!(snapshot.pty.total_events, 4);
+    let alpha = &snapshot.pty.agents["alpha"];
+    let beta = &snapshot.pty.agents["beta"];
+    assert_eq!(alpha.total_events, 3);
+    assert_eq!(alpha.events_by_name["turn_complete"], 2);
+    assert_eq!(alpha.events_by_name["unknown"], 1);
+    assert_eq!(beta.total_events, 1);
+    assert_eq!(beta.events_by_name.get("unknown"), None);
+    assert!(alpha.last_seen.unwrap() <= beta.last_seen.unwrap());
+}
+
+#[test]
+fn consensus_tallies_are_tracked_per_resource() {
+    let metrics = MetricsCollector::new();
+    let outcomes = [
+        ("plan.json", false),
+        ("plan.json", false),
+        ("plan.json", true),
+        ("plan.json", false),
+        ("src/lib.rs", true),
+        ("src/lib.rs", true),
+    ];
+    for (resource, achieved) in outcomes {
+        metrics.record_quorum_metrics(QuorumMetricsUpdate {
+            resource_id: resource.to_string(),
+            achieved,
+            threshold: 0.66,
+            reason: "test".to_string(),
+        });
     }
-    values
+
+    let consensus = metrics.get_snapshot().consensus;
+    assert_eq!(consensus.success, 3);
+    assert_eq!(consensus.failure, 3);
+    let plan = &consensus.by_resource["plan.json"];
+    assert_eq!((plan.success, plan.failure), (1, 3));
+    assert_eq!(plan.success_ratio, 0.25);
+    let lib = &consensus.by_resource["src/lib.rs"];
+    assert_eq!((lib.success, lib.failure), (2, 0));
+    assert_eq!(lib.success_ratio, 1.0);
 }
 
-fn build_manager_with_policy(policy: TerritoryPolicy) -> TerritoryManager {
-    TerritoryManager::with_policy(MetricsCollector::new(), policy)
+#[test]
+fn consensus_tallies_evict_least_recently_updated_resource() {
+    let metrics = MetricsCollector::new();
+    for index in 0..300 {
+        metrics.record_quorum_metrics(QuorumMetricsUpdate {
+            resource_id: format!("resource-{index}"),
+            achieved: true,
+            threshold: 0.66,
+            reason: "test".to_string(),
+        });
+        if index == 200 {
+            metrics.record_quorum_metrics(QuorumMetricsUpdate {
+                resource_id: "resource-0".to_string(),
+                achieved: false,
+                threshold: 0.66,
+                reason: "refresh".to_string(),
+            });
+        }
+    }
+
+    let by_resource = metrics.get_snapshot().consensus.by_resource;
+    assert_eq!(by_resource.len(), 256);
+    assert!(by_resource.contains_key("resource-0"));
+    assert!(!by_resource.contains_key("resource-1"));
+    assert!(by_resource.contains_key("resource-299"));
 }
 
-#[tokio::test]
-async fn territory_promotes_waiting_request_on_release() {
-    let mut policy = TerritoryPolicy::default();
-    policy.auto_extend_threshold = Duration::from_millis(1);
-    let manager = build_manager_with_policy(policy);
-    let resource = "shared_file.txt".to_string();
+#[test]
+fn windowed_consensus_ratio_reacts_to_recent_failures() {
+    let metrics = MetricsCollector::new();
+    let record = |achieved: bool| {
+        metrics.record_quorum_metrics(QuorumMetricsUpdate {
+            resource_id: "plan.json".to_string(),
+            achieved,
+            threshold: 0.66,
+            reason: "test".to_string(),
+        });
+    };
+    for _ in 0..1000 {
+        record(true);
+    }
+    let healthy = metrics.get_snapshot().consensus;
+    assert_eq!(healthy.window_size, CONSENSUS_OUTCOME_WINDOW);
+    assert_eq!(healthy.window_success_ratio, 1.0);
 
-    let first = manager
-        .acquire_lease(LeaseRequest::new(
-            "Agent_A".to_string(),
-            resource.clone(),
-            Priority::Coordinate,
-        ))
-        .await;
-    assert!(matches!(first, LeaseDecision::Granted(_)));
+    for _ in 0..40 {
+        record(false);
+    }
+    let consensus = metrics.get_snapshot().consensus;
+    assert_eq!(consensus.window_size, CONSENSUS_OUTCOME_WINDOW);
+    assert!((consensus.window_success_ratio - 0.6).abs() < f64::EPSILON);
+    assert!(consensus.success_ratio > 0.96);
 
-    let second = manager
-        .acquire_lease(LeaseRequest::new(
-            "Agent_B".to_string(),
-            resource.clone(),
-            Priority::Coordinate,
-        ))
-        .await;
-    assert!(matches!(second, LeaseDecision::Queued(_)));
+    let config = HealthMonitoringConfig {
+        queue_health: None,
+        escalation_rate: None,
+        deadlock_frequency: None,
+        consensus_success: Some(ConsensusSuccessConfig {
+            warning_ratio: Some(0.9),
+            critical_ratio: Some(0.5),
+        }),
+        heat_hotspot: None,
+    };
+    let mut monitor = HealthMonitor::new(Some(&config));
+    let snapshot = MetricsSnapshot {
+        consensus,
+        ..MetricsSnapshot::default()
+    };
+    let alerts = monitor.evaluate(&snapshot);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].category, HealthCategory::Consensus);
+    assert_eq!(alerts[0].severity, "warning");
+}
 
-    let released = manager
-        .release_lease(&"Agent_A".to_string(), &resource)
-        .await;
-    assert!(released.is_some());
+#[test]
+fn rate_limit_senders_are_bounded_by_capacity() {
+    let metrics = MetricsCollector::new().with_rate_limit_capacity(16);
+    metrics.update_token_bucket("steady", 5.0, 10.0, 2.0, None);
+    for index in 0..40 {
+        metrics.increment_rate_limited(&format!("synthetic_sender_{index:02}"));
+        metrics.update_token_bucket("steady", 5.0, 10.0, 2.0, None);
+    }
 
-    let active = manager.current_lease(&resource).await;
-    assert_eq!(active.unwrap().holder_id, "Agent_B".to_string());
+    let snapshot = metrics.get_snapshot();
+    assert_eq!(snapshot.rate_limits.len(), 16);
+    assert_eq!(snapshot.router.rate_limited_messages, 40);
+    let senders: Vec<&str> = snapshot
+        .rate_limits
+        .iter()
+        .map(|entry| entry.sender.as_str())
+        .collect();
+    let mut sorted = senders.clone();
+    sorted.sort();
+    assert_eq!(senders, sorted);
+    assert!(senders.contains(&"steady"));
+    assert!(senders.contains(&"synthetic_sender_39"));
+    assert!(!senders.contains(&"synthetic_sender_00"));
 }
 
-#[tokio::test]
-async fn territory_defers_when_holder_near_expiry() {
+#[test]
+fn reset_subsystem_clears_only_the_target() {
+    let metrics = MetricsCollector::new();
+    metrics.increment_rate_limited("burst");
+    metrics.update_token_bucket("burst", 1.0, 10.0, 2.0, None);
+    metrics.record_lease_deferral();
+    metrics.record_lease_override();
+    metrics.record_lease_escalation();
+
+    metrics.reset_subsystem(MetricsSubsystem::RateLimits);
+
+    let snapshot = metrics.get_snapshot();
+    assert!(snapshot.rate_limits.is_empty());
+    assert_eq!(snapshot.leases.deferrals, 1);
+    assert_eq!(snapshot.leases.overrides, 1);
+    assert_eq!(snapshot.leases.escalations, 1);
+    assert_eq!(snapshot.router.rate_limited_messages, 1);
+
+    metrics.reset_subsystem(MetricsSubsystem::Leases);
+    assert_eq!(metrics.get_snapshot().leases.deferrals, 0);
+}
+
+#[tokio::test]
+async fn wait_time_distribution_separates_throttled_from_fast_path() {
+    let fast_metrics = MetricsCollector::new();
+    let fast_router = Arc::new(UnifiedMessageRouter::with_config(
+        fast_metrics.clone(),
+        DispatcherConfig {
+            idle_backoff: Duration::from_millis(5),
+            ..DispatcherConfig::default()
+        },
+    ));
+    let mut fast_deliveries = fast_router.subscribe();
+    for index in 0..5 {
+        fast_router
+            .route_message(Message {
+                content: format!("fast_{index}"),
+                priority: Priority::Info,
+                sender: "fast".to_string(),
+                recipient: "peer".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+        time::timeout(Duration::from_millis(200), fast_deliveries.recv())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    let slow_metrics = MetricsCollector::new();
+    let slow_router = Arc::new(UnifiedMessageRouter::with_config(
+        slow_metrics.clone(),
+        DispatcherConfig {
+            idle_backoff: Duration::from_millis(5),
+            token_capacity: 5.0,
+            token_refill_rate: 20.0,
+            initial_tokens: 0.0,
+            max_aging_boosts: 0,
+            ..DispatcherConfig::default()
+        },
+    ));
+    slow_router
+        .set_maintenance_executor(MaintenanceExecutor::new(2))
+        .await;
+    let mut slow_deliveries = slow_router.subscribe();
+    for index in 0..3 {
+        slow_router
+            .route_message(Message {
+                content: format!("slow_{index}"),
+                priority: Priority::Info,
+                sender: "slow".to_string(),
+                recipient: "peer".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+    }
+    for _ in 0..3 {
+        time::timeout(Duration::from_millis(1500), slow_deliveries.recv())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    let fast = fast_metrics.get_snapshot().router.wait_time;
+    let slow = slow_metrics.get_snapshot().router.wait_time;
+    assert_eq!(fast.samples, 5);
+    assert!(fast.p99_ms < 20.0);
+    assert_eq!(slow.samples, 3);
+    assert!(slow.min_ms >= 30.0);
+    assert!(slow.max_ms >= slow.p50_ms && slow.p50_ms >= slow.min_ms);
+    assert!(slow.mean_ms > fast.mean_ms);
+}
+
+#[tokio::test]
+async fn router_enforces_token_quota() {
+    let metrics = MetricsCollector::new();
+    let config = DispatcherConfig {
+        idle_backoff: Duration::from_millis(5),
+        token_capacity: 2.0,
+        token_refill_rate: 4.0,
+        initial_tokens: 2.0,
+        ..DispatcherConfig::default()
+    };
+    let router = Arc::new(UnifiedMessageRouter::with_config(metrics, config));
+    let executor = MaintenanceExecutor::new(2);
+    router.set_maintenance_executor(executor).await;
+    let mut deliveries = router.subscribe();
+
+    for label in ["first", "second"] {
+        let message = Message {
+            content: label.to_string(),
+            priority: Priority::Info,
+            sender: "quota".to_string(),
+            recipient: "peer".to_string(),
+            message_id: None,
+            trace_id: None,
+        };
+        router.route_message(message).await.unwrap();
+    }
+
+    let one = time::timeout(Duration::from_millis(200), deliveries.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    let two = time::timeout(Duration::from_millis(200), deliveries.recv())
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(one.message.content, "first");
+    assert_eq!(two.message.content, "second");
+
+    let throttled = Message {
+        content: "third".to_string(),
+        priority: Priority::Info,
+        sender: "quota".to_string(),
+        recipient: "peer".to_string(),
+        message_id: None,
+        trace_id: None,
+    };
+    router.route_message(throttled).await.unwrap();
+
+    let immediate = time::timeout(Duration::from_millis(50), deliveries.recv()).await;
+    assert!(immediate.is_err());
+
+    let eventual = time::timeout(Duration::from_millis(400), deliveries.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(eventual.message.content, "third");
+    assert!(eventual.retry_count > 0);
+}
+
+#[tokio::test]
+async fn token_refill_projection_matches_refill_math() {
+    let clock = MockClock::new();
+    let config = DispatcherConfig {
+        idle_backoff: Duration::from_millis(5),
+        token_capacity: 10.0,
+        token_refill_rate: 2.0,
+        initial_tokens: 10.0,
+        ..DispatcherConfig::default()
+    };
+    let router = Arc::new(
+        UnifiedMessageRouter::with_config(MetricsCollector::new(), config)
+            .with_clock(clock.shared()),
+    );
+    let mut deliveries = router.subscribe();
+    let cost = Priority::Coordinate.token_cost();
+
+    assert_eq!(router.time_until_tokens("producer", cost).await, None);
+
+    for label in ["first", "second"] {
+        router
+            .route_message(Message {
+                content: label.to_string(),
+                priority: Priority::Coordinate,
+                sender: "producer".to_string(),
+                recipient: "peer".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+        time::timeout(Duration::from_millis(200), deliveries.recv())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    assert_eq!(
+        router.time_until_tokens("producer", cost).await,
+        Some(Duration::from_secs_f64(cost / 2.0))
+    );
+    assert_eq!(
+        router.time_until_tokens("producer", 1.0).await,
+        Some(Duration::from_millis(500))
+    );
+    assert_eq!(router.time_until_tokens("producer", 50.0).await, None);
+
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(
+        router.time_until_tokens("producer", cost).await,
+        Some(Duration::from_secs_f64((cost - 2.0) / 2.0))
+    );
+
+    clock.advance(Duration::from_millis(1500));
+    assert_eq!(router.time_until_tokens("producer", cost).await, None);
+
+    let stalled = UnifiedMessageRouter::with_config(
+        MetricsCollector::new(),
+        DispatcherConfig {
+            token_refill_rate: 0.0,
+            initial_tokens: 0.0,
+            ..DispatcherConfig::default()
+        },
+    );
+    assert_eq!(stalled.time_until_tokens("producer", cost).await, None);
+}
+
+#[tokio::test]
+async fn router_rate_limiting_updates_metrics_snapshot() {
+    let metrics = MetricsCollector::new();
+    let config = DispatcherConfig {
+        token_capacity: 1.0,
+        token_refill_rate: 0.0,
+        initial_tokens: 1.0,
+        idle_backoff: Duration::from_millis(5),
+        ..DispatcherConfig::default()
+    };
+    let router = Arc::new(UnifiedMessageRouter::with_config(metrics.clone(), config));
+    let executor = MaintenanceExecutor::new(2);
+    router.set_maintenance_executor(executor).await;
+
+    for index in 0..3 {
+        let message = Message {
+            content: format!("limited_{index}"),
+            priority: Priority::Info,
+            sender: "rate_limited_agent".to_string(),
+            recipient: "observer".to_string(),
+            message_id: None,
+            trace_id: None,
+        };
+        router.route_message(message).await.unwrap();
+    }
+
+    time::sleep(Duration::from_millis(30)).await;
+    let snapshot = metrics.get_snapshot();
+    let rate_limit_entry = snapshot
+        .rate_limits
+        .iter()
+        .find(|entry| entry.sender == "rate_limited_agent")
+        .cloned();
+
+    assert!(snapshot.router.rate_limited_messages > 0);
+    assert!(rate_limit_entry.as_ref().is_some());
+    assert!(rate_limit_entry
+        .as_ref()
+        .map(|entry| entry.rate_limit_hits > 0)
+        .unwrap_or(false));
+    assert!(
+        snapshot
+            .router
+            .queue_depths
+            .get("info")
+            .copied()
+            .unwrap_or_default()
+            >= 1
+    );
+
+    drop(router);
+}
+
+#[tokio::test]
+async fn maintenance_executor_handles_router_and_territory_load() {
+    let metrics = MetricsCollector::new();
+    let dispatcher_config = DispatcherConfig {
+        aging_threshold: Duration::from_millis(30),
+        idle_backoff: Duration::from_millis(5),
+        token_capacity: 2.0,
+        token_refill_rate: 12.0,
+        initial_tokens: 1.0,
+        max_aging_boosts: 2,
+        ..DispatcherConfig::default()
+    };
+    let router = Arc::new(UnifiedMessageRouter::with_config(
+        metrics.clone(),
+        dispatcher_config,
+    ));
+    let executor = MaintenanceExecutor::new(4);
+    router.set_maintenance_executor(executor.clone()).await;
+    let warmup = Message {
+        content: "warmup".to_string(),
+        priority: Priority::Info,
+        sender: "quota_agent".to_string(),
+        recipient: "peer".to_string(),
+        message_id: None,
+        trace_id: None,
+    };
+    router.route_message(warmup).await.unwrap();
+
+    for index in 0..4 {
+        let message = Message {
+            content: format!("load_{index}"),
+            priority: Priority::Info,
+            sender: format!("high_priority_{index}"),
+            recipient: "peer".to_string(),
+            message_id: None,
+            trace_id: None,
+        };
+        router.route_message(message).await.unwrap();
+    }
+
+    let maintenance_target = Message {
+        content: "maintenance_target".to_string(),
+        priority: Priority::Info,
+        sender: "quota_agent".to_string(),
+        recipient: "peer".to_string(),
+        message_id: None,
+        trace_id: None,
+    };
+    router.route_message(maintenance_target).await.unwrap();
+
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    let snapshot_precheck = metrics.get_snapshot();
+    let quota_tokens = snapshot_precheck
+        .rate_limits
+        .iter()
+        .find(|entry| entry.sender == "quota_agent")
+        .map(|entry| entry.tokens_remaining)
+        .unwrap_or(0.0);
+    assert!(quota_tokens > 0.0, "token refill did not execute");
+    assert!(
+        snapshot_precheck.router.rate_limited_messages > 0,
+        "router did not record any rate limiting"
+    );
+
+    let post_snapshot = metrics.get_snapshot();
+    assert!(
+        post_snapshot.router.rate_limited_messages > 0,
+        "router should record rate limiting during maintenance"
+    );
+}
+
+#[tokio::test]
+async fn ledger_rotates_idle_segments_on_schedule() {
+    let dir = tempdir().expect("temp dir");
+    let mut config = LedgerConfig::default();
+    config.root_path = dir.path().to_path_buf();
+    config.current_epoch = Some("idle-rotation".to_string());
+    config.segment_duration_secs = 1;
+    let writer = LedgerWriter::new(&config).expect("ledger writer");
+    let ticker = tokio::spawn(writer.clone().run_rotation_ticker());
+
+    writer
+        .append_async(LedgerEvent::Health(HealthEvent {
+            severity: "info".to_string(),
+            message: "before idle".to_string(),
+            timestamp_ms: 0,
+        }))
+        .await
+        .expect("append before idle");
+    assert_eq!(writer.segment_index(), 0);
+
+    time::sleep(Duration::from_millis(2400)).await;
+    assert_eq!(writer.segment_index(), 1);
+    let idle_index = writer.segment_index();
+    assert!(dir
+        .path()
+        .join("idle-rotation")
+        .join(format!("segment_{idle_index:04}.log"))
+        .exists());
+
+    writer
+        .append_async(LedgerEvent::Health(HealthEvent {
+            severity: "info".to_string(),
+            message: "after idle".to_string(),
+            timestamp_ms: 0,
+        }))
+        .await
+        .expect("append after idle");
+    writer.flush().await.expect("flush");
+    ticker.abort();
+
+    assert_eq!(writer.segment_index(), idle_index);
+    let events = LedgerReader::new(config.root_path.clone())
+        .read_epoch("idle-rotation")
+        .expect("read epoch");
+    assert_eq!(events.len(), 2);
+}
+
+#[tokio::test]
+async fn ledger_replay_matches_live_metrics() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("test-epoch".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let metrics = MetricsCollector::new();
+    let router = Arc::new(UnifiedMessageRouter::with_settings_and_ledger(
+        metrics.clone(),
+        None,
+        Some(ledger_writer.clone()),
+    ));
+    let territory =
+        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
+
+    let mut deliveries = router.subscribe();
+
+    let messages = vec![
+        Message {
+            content: "coord".into(),
+            priority: Priority::Coordinate,
+            sender: "agent-a".into(),
+            recipient: "agent-b".into(),
+            message_id: None,
+            trace_id: None,
+        },
+        Message {
+            content: "critical".into(),
+            priority: Priority::Critical,
+            sender: "agent-c".into(),
+            recipient: "agent-d".into(),
+            message_id: None,
+            trace_id: None,
+        },
+        Message {
+            content: "info".into(),
+            priority: Priority::Info,
+            sender: "agent-a".into(),
+            recipient: "agent-b".into(),
+            message_id: None,
+            trace_id: None,
+        },
+    ];
+
+    for msg in messages {
+        router.route_message(msg).await.unwrap();
+    }
+
+    for _ in 0..3 {
+        let _ = time::timeout(Duration::from_millis(500), deliveries.recv())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    let lease_request = LeaseRequest::new(
+        "agent-a".into(),
+        "resource-path".into(),
+        Priority::Coordinate,
+    );
+    let _ = territory.acquire_lease(lease_request).await;
+
+    time::sleep(Duration::from_millis(50)).await;
+
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let snapshot = metrics.get_snapshot();
+    let router_state = RouterReplayState {
+        total_dispatched: snapshot.performance.total_messages_routed,
+        last_priority: snapshot.router.last_dispatched_priority.clone(),
+        queue_depths: priority_vec_from_map(&snapshot.router.queue_depths),
+        director_override_dispatched: snapshot.router.director_override_messages,
+        total_rate_limited: snapshot.router.rate_limited_messages,
+    };
+    let mut lease_state = LeaseReplayState::default();
+    lease_state.deferrals = snapshot.leases.deferrals;
+    lease_state.overrides = snapshot.leases.overrides;
+    lease_state.escalations = snapshot.leases.escalations;
+
+    let checkpoint = StateCheckpoint {
+        checkpoint_id: format!(
+            "checkpoint-{}",
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis()
+        ),
+        captured_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64,
+        router: router_state,
+        leases: lease_state,
+        metrics: snapshot.clone(),
+        baseline: None,
+    };
+
+    ledger_writer
+        .record_checkpoint(checkpoint)
+        .await
+        .expect("record checkpoint");
+    ledger_writer
+        .flush()
+        .await
+        .expect("flush ledger after checkpoint");
+
+    let coordinator = ReplayCoordinator::new(ledger_reader);
+    let outcome = coordinator
+        .replay_epoch(&ledger_writer.epoch_id())
+        .expect("replay to succeed");
+    let replay_snapshot = outcome.metrics.expect("replay snapshot available");
+
+    let baseline = serde_json::to_string(&snapshot).expect("serialize live snapshot");
+    let replayed = serde_json::to_string(&replay_snapshot).expect("serialize replay snapshot");
+    assert_eq!(baseline, replayed);
+}
+
+#[test]
+fn outstanding_lease_ids_are_sorted_and_stable() {
+    let ids = [41_u64, 7, 19, 3, 88, 12];
+    let forward = MetricsCollector::new();
+    forward.update_lease_inventory(ids.len(), HashMap::new(), ids.to_vec());
+    let backward = MetricsCollector::new();
+    backward.update_lease_inventory(
+        ids.len(),
+        HashMap::new(),
+        ids.iter().rev().copied().collect(),
+    );
+
+    let mut sorted = ids.to_vec();
+    sorted.sort_unstable();
+    let forward_leases = forward.get_snapshot().leases;
+    assert_eq!(forward_leases.outstanding_lease_ids, sorted);
+    assert_eq!(
+        serde_json::to_string(&forward_leases).unwrap(),
+        serde_json::to_string(&backward.get_snapshot().leases).unwrap()
+    );
+
+    let replay_state = |order: &[u64]| {
+        let mut state = LeaseReplayState::default();
+        for lease_id in order {
+            state.active.insert(
+                format!("resource-{}", lease_id),
+                LeaseRecord {
+                    lease_id: *lease_id,
+                    resource_id: format!("resource-{}", lease_id),
+                    holder_id: "agent".to_string(),
+                    priority: "coordinate".to_string(),
+                    held_for_ms: None,
+                },
+            );
+        }
+        state.to_summary()
+    };
+    let replayed = replay_state(&ids);
+    assert_eq!(replayed.outstanding_lease_ids, sorted);
+    let reversed: Vec<u64> = ids.iter().rev().copied().collect();
+    assert_eq!(
+        serde_json::to_string(&replayed).unwrap(),
+        serde_json::to_string(&replay_state(&reversed)).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn replay_checkpoint_retention_bounds_outcome() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("retention-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    for index in 0..4 {
+        ledger_writer
+            .record_checkpoint(StateCheckpoint {
+                checkpoint_id: format!("checkpoint-{index}"),
+                ..StateCheckpoint::default()
+            })
+            .await
+            .expect("record checkpoint");
+    }
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let replay_ids = |options: Option<ReplayOptions>| {
+        let mut coordinator =
+            ReplayCoordinator::new(LedgerReader::new(ledger_config.root_path.clone()));
+        if let Some(options) = options {
+            coordinator = coordinator.with_options(options);
+        }
+        coordinator
+            .replay_epoch("retention-test")
+            .expect("replay epoch")
+            .checkpoints
+            .into_iter()
+            .map(|checkpoint| checkpoint.checkpoint_id)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(replay_ids(None), vec!["checkpoint-3"]);
+    assert_eq!(
+        replay_ids(Some(ReplayOptions {
+            keep_checkpoints: CheckpointRetention::All,
+        })),
+        vec![
+            "checkpoint-0",
+            "checkpoint-1",
+            "checkpoint-2",
+            "checkpoint-3"
+        ]
+    );
+    assert_eq!(
+        replay_ids(Some(ReplayOptions {
+            keep_checkpoints: CheckpointRetention::LastN(2),
+        })),
+        vec!["checkpoint-2", "checkpoint-3"]
+    );
+}
+
+#[tokio::test]
+async fn replay_reconciliation_flags_missing_sequence() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("reconcile-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+
+    for index in 0..3u32 {
+        ledger_writer
+            .append_async(LedgerEvent::Router(RouterEvent::Dispatched(
+                RouterDispatchRecord {
+                    message_id: Some(format!("message-{index}")),
+                    trace_id: None,
+                    content_digest: None,
+                    sender: "agent-a".to_string(),
+                    recipient: "agent-b".to_string(),
+                    priority: Priority::Coordinate.as_str().to_string(),
+                    effective_priority: Priority::Coordinate.as_str().to_string(),
+                    wait_time_ms: 0,
+                    queue_depths: vec![0; 5],
+                    aging_boosts: 0,
+                    retry_count: index,
+                },
+            )))
+            .await
+            .expect("append dispatch");
+    }
+    ledger_writer
+        .append_async(LedgerEvent::Router(RouterEvent::RateLimited(
+            RateLimitedRecord {
+                sender: "agent-a".to_string(),
+                priority: Priority::Coordinate.as_str().to_string(),
+                tokens_remaining: 0.0,
+            },
+        )))
+        .await
+        .expect("append rate limited");
+
+    let mut snapshot = MetricsSnapshot::default();
+    snapshot.performance.total_messages_routed = 3;
+    snapshot.router.rate_limited_messages = 1;
+    ledger_writer
+        .record_checkpoint(StateCheckpoint {
+            checkpoint_id: "checkpoint-reconcile".to_string(),
+            metrics: snapshot,
+            ..StateCheckpoint::default()
+        })
+        .await
+        .expect("record checkpoint");
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let coordinator = ReplayCoordinator::new(LedgerReader::new(ledger_config.root_path.clone()));
+    let outcome = coordinator
+        .replay_epoch("reconcile-test")
+        .expect("replay epoch");
+    assert_eq!(outcome.reconciliation.dispatched, 3);
+    assert_eq!(outcome.reconciliation.rate_limited, 1);
+    assert!(outcome.reconciliation.is_consistent());
+
+    let segment = temp_dir
+        .path()
+        .join("reconcile-test")
+        .join("segment_0000.log");
+    let contents = std::fs::read_to_string(&segment).expect("read segment");
+    let kept: Vec<&str> = contents
+        .lines()
+        .enumerate()
+        .filter(|(index, _)| *index != 1)
+        .map(|(_, line)| line)
+        .collect();
+    std::fs::write(&segment, format!("{}\n", kept.join("\n"))).expect("rewrite segment");
+
+    let outcome = coordinator
+        .replay_epoch("reconcile-test")
+        .expect("replay epoch with gap");
+    let reconciliation = outcome.reconciliation;
+    assert!(!reconciliation.is_consistent());
+    assert_eq!(
+        reconciliation.sequence_gaps,
+        vec![SequenceGap {
+            epoch_id: "reconcile-test".to_string(),
+            first_missing: 2,
+            last_missing: 2,
+        }]
+    );
+    assert_eq!(
+        reconciliation.checkpoint_mismatches,
+        vec![CheckpointMismatch {
+            checkpoint_id: "checkpoint-reconcile".to_string(),
+            expected_dispatched: 3,
+            observed_dispatched: 2,
+            expected_rate_limited: 1,
+            observed_rate_limited: 1,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn compacted_epoch_replays_to_the_same_state() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("compact-source".to_string());
+    ledger_config.segment_size_bytes = 1024;
+    let source_writer = LedgerWriter::new(&ledger_config).expect("source writer");
+
+    let override_lease = |lease_id: u64| LeaseRecord {
+        lease_id,
+        resource_id: "resource-hot".to_string(),
+        holder_id: "agent-b".to_string(),
+        priority: Priority::Critical.as_str().to_string(),
+        held_for_ms: None,
+    };
+    let quorum_commit = |lease_id: u64| {
+        LedgerEvent::Consensus(ConsensusEvent::Commit(ConsensusSignal {
+            topic: lease_trace_id(lease_id),
+            phase: "commit".to_string(),
+            agent_id: None,
+            territory_id: Some("resource-hot".to_string()),
+            quorum_threshold: Some(0.5),
+            payload_digest: None,
+            vector: Some(QuorumVector {
+                resource_id: "resource-hot".to_string(),
+                threshold: 0.5,
+                total_weight: 1.0,
+                agree_weight: 1.0,
+                achieved: true,
+                reason: "quorumAchieved".to_string(),
+                votes: vec![],
+            }),
+        }))
+    };
+    let prelude = vec![
+        LedgerEvent::Lease(LeaseEvent::Deferred(LeaseQueueRecord {
+            request_id: "request-hot".to_string(),
+            agent_id: "agent-b".to_string(),
+            resource_id: "resource-hot".to_string(),
+            queue_position: 0,
+            grace_deadline_ms: None,
+            priority: Some(Priority::Critical.as_str().to_string()),
+        })),
+        quorum_commit(7),
+        LedgerEvent::Lease(LeaseEvent::Overridden {
+            previous: LeaseRecord {
+                holder_id: "agent-a".to_string(),
+                ..override_lease(6)
+            },
+            lease: override_lease(7),
+        }),
+        quorum_commit(9),
+        LedgerEvent::Director(DirectorEvent::RunbookStarted {
+            runbook_id: "runbook-compact".to_string(),
+            total_turns: 2,
+        }),
+    ];
+    for event in prelude {
+        source_writer
+            .append_async(event)
+            .await
+            .expect("append prelude");
+    }
+
+    let mut routed = 0u64;
+    for round in 0..2u32 {
+        for index in 0..4u32 {
+            source_writer
+                .append_async(LedgerEvent::Router(RouterEvent::Dispatched(
+                    RouterDispatchRecord {
+                        message_id: Some(format!("message-{round}-{index}")),
+                        trace_id: None,
+                        content_digest: None,
+                        sender: "agent-a".to_string(),
+                        recipient: "agent-b".to_string(),
+                        priority: Priority::Coordinate.as_str().to_string(),
+                        effective_priority: Priority::Coordinate.as_str().to_string(),
+                        wait_time_ms: 0,
+                        queue_depths: vec![0; 5],
+                        aging_boosts: 0,
+                        retry_count: 0,
+                    },
+                )))
+                .await
+                .expect("append dispatch");
+            routed += 1;
+        }
+        let mut snapshot = MetricsSnapshot::default();
+        snapshot.performance.total_messages_routed = routed;
+        source_writer
+            .record_checkpoint(StateCheckpoint {
+                checkpoint_id: format!("checkpoint-{round}"),
+                metrics: snapshot,
+                ..StateCheckpoint::default()
+            })
+            .await
+            .expect("record checkpoint");
+    }
+    for lease_id in 1..=3u64 {
+        source_writer
+            .append_async(LedgerEvent::Lease(LeaseEvent::Granted(LeaseRecord {
+                lease_id,
+                resource_id: format!("resource-{lease_id}"),
+                holder_id: "agent-a".to_string(),
+                priority: Priority::Coordinate.as_str().to_string(),
+                held_for_ms: None,
+            })))
+            .await
+            .expect("append lease");
+    }
+    source_writer
+        .append_async(LedgerEvent::Lease(LeaseEvent::Overridden {
+            previous: override_lease(7),
+            lease: override_lease(9),
+        }))
+        .await
+        .expect("append late override");
+    source_writer.close().await.expect("close source writer");
+
+    let reader = LedgerReader::new(ledger_config.root_path.clone());
+    let coordinator = ReplayCoordinator::new(reader.clone());
+    let before = coordinator
+        .replay_epoch("compact-source")
+        .expect("replay before compaction");
+    assert_eq!(before.overrides.len(), 2);
+    assert!(before.overrides.iter().all(|link| link.quorum.is_some()));
+    assert_eq!(before.director_timeline.len(), 1);
+    assert!(before.heat.tracked > 0);
+    assert!(reader.epoch_stats("compact-source").unwrap().segment_count > 1);
+
+    ledger_config.current_epoch = Some("compact-active".to_string());
+    let active_writer = LedgerWriter::new(&ledger_config).expect("active writer");
+    assert!(matches!(
+        active_writer.compact_epoch("compact-active").await,
+        Err(LedgerError::ActiveEpoch(epoch)) if epoch == "compact-active"
+    ));
+
+    let report = active_writer
+        .compact_epoch("compact-source")
+        .await
+        .expect("compact epoch");
+    assert_eq!(report.checkpoint_id, "checkpoint-1");
+    assert_eq!(report.events_removed, 14);
+    assert_eq!(report.events_retained, 5);
+
+    assert!(reader.verify_epoch("compact-source").expect("verify"));
+    let events = reader.read_epoch("compact-source").expect("read compacted");
+    assert_eq!(events.len(), 5);
+    assert!(matches!(
+        &events[0].event,
+        LedgerEvent::Checkpoint(checkpoint) if checkpoint.baseline.is_some()
+    ));
+    assert_eq!(
+        events
+            .iter()
+            .map(|event| event.sequence)
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3, 4, 5]
+    );
+    assert!(!temp_dir.path().join(".compacting-compact-source").exists());
+    assert_eq!(
+        reader
+            .list_epochs()
+            .unwrap()
+            .iter()
+            .filter(|epoch| epoch.epoch_id.starts_with('.'))
+            .count(),
+        0
+    );
+    assert_eq!(events.last().unwrap().hash_chain, report.tail_hash);
+
+    let after = coordinator
+        .replay_epoch("compact-source")
+        .expect("replay after compaction");
+    assert!(after.reconciliation.is_consistent());
+    assert_eq!(
+        serde_json::to_value(&after.router).unwrap(),
+        serde_json::to_value(&before.router).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&after.leases).unwrap(),
+        serde_json::to_value(&before.leases).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&after.metrics).unwrap(),
+        serde_json::to_value(&before.metrics).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&after.checkpoints).unwrap(),
+        serde_json::to_value(&before.checkpoints).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&after.heat).unwrap(),
+        serde_json::to_value(&before.heat).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&after.overrides).unwrap(),
+        serde_json::to_value(&before.overrides).unwrap()
+    );
+    assert_eq!(
+        serde_json::to_value(&after.director_timeline).unwrap(),
+        serde_json::to_value(&before.director_timeline).unwrap()
+    );
+    assert_eq!(
+        after.reconciliation.dispatched,
+        before.reconciliation.dispatched
+    );
+
+    let reopened = LedgerWriter::new(&LedgerConfig {
+        current_epoch: Some("compact-source".to_string()),
+        ..ledger_config.clone()
+    })
+    .expect("reopen compacted epoch");
+    let appended = reopened
+        .append_async(LedgerEvent::Lease(LeaseEvent::Released(LeaseRecord {
+            lease_id: 1,
+            resource_id: "resource-1".to_string(),
+            holder_id: "agent-a".to_string(),
+            priority: Priority::Coordinate.as_str().to_string(),
+            held_for_ms: Some(10),
+        })))
+        .await
+        .expect("append after compaction");
+    assert_eq!(appended.sequence, 6);
+    reopened.flush().await.expect("flush reopened");
+    assert!(reader.verify_epoch("compact-source").expect("verify"));
+}
+
+#[tokio::test]
+async fn list_epochs_reports_counts_and_integrity() {
+    let temp_dir = tempdir().expect("temp dir");
+    let reader = LedgerReader::new(temp_dir.path().to_path_buf());
+    assert!(reader.list_epochs().expect("list empty root").is_empty());
+
+    for (epoch_id, count) in [("epoch-2000", 5u64), ("epoch-1000", 2), ("epoch-3000", 1)] {
+        let mut ledger_config = LedgerConfig::default();
+        ledger_config.root_path = temp_dir.path().to_path_buf();
+        ledger_config.current_epoch = Some(epoch_id.to_string());
+        let writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+        for index in 0..count {
+            writer
+                .append_async(LedgerEvent::Pty(PtyEvent {
+                    agent_id: "agent-a".to_string(),
+                    event_name: Some("HEARTBEAT".to_string()),
+                    timestamp_ms: index,
+                }))
+                .await
+                .expect("append event");
+        }
+        writer.close().await.expect("close writer");
+    }
+    std::fs::create_dir_all(temp_dir.path().join("scratch")).expect("scratch dir");
+    let tampered = temp_dir.path().join("epoch-3000").join("segment_0000.log");
+    let contents = std::fs::read_to_string(&tampered).expect("read segment");
+    std::fs::write(&tampered, contents.replace("HEARTBEAT", "TAMPERED")).expect("tamper");
+
+    let epochs = reader.list_epochs().expect("list epochs");
+    let summary: Vec<(&str, usize, bool)> = epochs
+        .iter()
+        .map(|info| (info.epoch_id.as_str(), info.event_count, info.verified))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("epoch-1000", 2, true),
+            ("epoch-2000", 5, true),
+            ("epoch-3000", 1, false),
+        ]
+    );
+    assert!(epochs
+        .iter()
+        .all(|info| info.segment_count == 1 && info.total_bytes > 0));
+}
+
+#[tokio::test]
+async fn unwritable_ledger_root_degrades_and_raises_critical_alert() {
+    let temp_dir = tempdir().expect("temp dir");
+    let blocker = temp_dir.path().join("blocker");
+    std::fs::write(&blocker, b"not a directory").expect("write blocker");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = blocker.join("ledger");
+    ledger_config.current_epoch = Some("degraded-test".to_string());
+    ledger_config.degraded_after_failures = 3;
+    assert!(LedgerWriter::new(&ledger_config).is_err());
+
+    let metrics = MetricsCollector::new();
+    let ledger_writer = LedgerWriter::degraded(&ledger_config);
+    ledger_writer.set_metrics(metrics.clone());
+    let mut monitor = HealthMonitor::new(None);
+
+    let pty_event = |index: u64| {
+        LedgerEvent::Pty(PtyEvent {
+            agent_id: "agent-a".to_string(),
+            event_name: Some("HEARTBEAT".to_string()),
+            timestamp_ms: index,
+        })
+    };
+    for index in 0..2 {
+        assert!(ledger_writer.append_async(pty_event(index)).await.is_err());
+    }
+    assert!(!ledger_writer.is_degraded());
+    assert!(!metrics.get_snapshot().ledger.degraded);
+    assert!(monitor.evaluate(&metrics.get_snapshot()).is_empty());
+
+    assert!(ledger_writer.append_async(pty_event(2)).await.is_err());
+    assert!(ledger_writer.is_degraded());
+    let snapshot = metrics.get_snapshot();
+    assert!(snapshot.ledger.degraded);
+    assert_eq!(snapshot.ledger.consecutive_failures, 3);
+    let alerts = monitor.evaluate(&snapshot);
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].category, HealthCategory::Ledger);
+    assert_eq!(alerts[0].severity, "critical");
+    assert_eq!(monitor.summarize(&snapshot).ledger, "critical");
+
+    std::fs::remove_file(&blocker).expect("remove blocker");
+    let envelope = ledger_writer
+        .append_async(pty_event(3))
+        .await
+        .expect("append after recovery");
+    assert_eq!(envelope.sequence, 1);
+    assert!(!ledger_writer.is_degraded());
+    let snapshot = metrics.get_snapshot();
+    assert!(!snapshot.ledger.degraded);
+    assert_eq!(snapshot.ledger.consecutive_failures, 0);
+    assert!(monitor.evaluate(&snapshot).is_empty());
+    assert_eq!(monitor.summarize(&snapshot).ledger, "normal");
+}
+
+#[tokio::test]
+async fn quorum_override_records_success() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("quorum-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+    let metrics = MetricsCollector::new();
+    let territory =
+        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
+
+    let _ = territory
+        .acquire_lease(LeaseRequest::new(
+            "holder".into(),
+            "shared-resource".into(),
+            Priority::Coordinate,
+        ))
+        .await;
+
+    let decision = territory
+        .acquire_lease(LeaseRequest::new(
+            "contender".into(),
+            "shared-resource".into(),
+            Priority::Critical,
+        ))
+        .await;
+
+    assert!(matches!(decision, LeaseDecision::Overridden { .. }));
+
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let events = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger");
+    let mut commits = Vec::new();
+    for envelope in events {
+        if let LedgerEvent::Consensus(ConsensusEvent::Commit(signal)) = envelope.event {
+            commits.push(signal);
+        }
+    }
+    assert!(!commits.is_empty());
+    let last_signal = commits.last().expect("consensus commit present");
+    let vector = last_signal.vector.as_ref().expect("vector attached");
+    assert!(vector.achieved, "quorum should succeed on override");
+    assert_eq!(vector.reason, "override");
+    assert_eq!(vector.resource_id, "shared-resource");
+
+    let snapshot = metrics.get_snapshot();
+    assert!(snapshot.consensus.success >= 1);
+    assert!(snapshot.consensus.success_ratio >= 1.0);
+}
+
+#[tokio::test]
+async fn heat_map_decays_under_load() {
+    let metrics = MetricsCollector::new();
+    let mut policy = TerritoryPolicy::from_config(None);
+    policy.heat_decay_per_second = 0.8;
+    policy.heat_increment = 4.0;
+    policy.heat_max = 10.0;
+    let territory = TerritoryManager::with_policy(metrics.clone(), policy);
+
+    let resource = "heat-resource".to_string();
+    let _ = territory
+        .acquire_lease(LeaseRequest::new(
+            "heat-holder".into(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+
+    let _ = territory
+        .acquire_lease(LeaseRequest::new(
+            "heat-contender".into(),
+            resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+
+    time::sleep(Duration::from_millis(40)).await;
+    let hot_snapshot = territory.heat_snapshot().await;
+    assert_eq!(
+        hot_snapshot.hottest_resource.as_deref(),
+        Some(resource.as_str())
+    );
+    assert!(hot_snapshot.hottest_score > 0.0);
+
+    time::sleep(Duration::from_millis(220)).await;
+    let cooled_snapshot = territory.heat_snapshot().await;
+    assert!(cooled_snapshot.hottest_score < hot_snapshot.hottest_score);
+}
+
+fn priority_vec_from_map(depths: &BTreeMap<String, usize>) -> Vec<usize> {
+    let mut values = Vec::new();
+    for index in 0..=Priority::DirectorOverride.as_index() {
+        let key = Priority::from_index(index).as_str();
+        values.push(*depths.get(key).unwrap_or(&0));
+    }
+    values
+}
+
+fn build_manager_with_policy(policy: TerritoryPolicy) -> TerritoryManager {
+    TerritoryManager::with_policy(MetricsCollector::new(), policy)
+}
+
+#[tokio::test]
+async fn release_all_for_agent_frees_leases_and_promotes_waiters() {
+    let mut policy = TerritoryPolicy::default();
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    let manager = build_manager_with_policy(policy);
+    let mut events = manager.subscribe();
+
+    for resource in ["alpha.rs", "beta.rs"] {
+        let decision = manager
+            .acquire_lease(LeaseRequest::new(
+                "dead-agent".into(),
+                resource.into(),
+                Priority::Coordinate,
+            ))
+            .await;
+        assert!(matches!(decision, LeaseDecision::Granted(_)));
+    }
+    for (waiter, resource) in [("waiter-a", "alpha.rs"), ("waiter-b", "beta.rs")] {
+        let decision = manager
+            .acquire_lease(LeaseRequest::new(
+                waiter.into(),
+                resource.into(),
+                Priority::Coordinate,
+            ))
+            .await;
+        assert!(matches!(decision, LeaseDecision::Queued(_)));
+    }
+    manager
+        .acquire_lease(LeaseRequest::new(
+            "holder-c".into(),
+            "gamma.rs".into(),
+            Priority::Coordinate,
+        ))
+        .await;
+    let queued = manager
+        .acquire_lease(LeaseRequest::new(
+            "dead-agent".into(),
+            "gamma.rs".into(),
+            Priority::Coordinate,
+        ))
+        .await;
+    let LeaseDecision::Queued(queued) = queued else {
+        panic!("expected dead agent to queue on gamma.rs");
+    };
+    while events.try_recv().is_ok() {}
+
+    let released = manager.release_all_for_agent(&"dead-agent".into()).await;
+    let released: Vec<_> = released
+        .iter()
+        .map(|snapshot| snapshot.resource_id.as_str())
+        .collect();
+    assert_eq!(released, vec!["alpha.rs", "beta.rs"]);
+
+    assert_eq!(
+        manager
+            .current_lease(&"alpha.rs".into())
+            .await
+            .unwrap()
+            .holder_id,
+        "waiter-a"
+    );
+    assert_eq!(
+        manager
+            .current_lease(&"beta.rs".into())
+            .await
+            .unwrap()
+            .holder_id,
+        "waiter-b"
+    );
+    assert_eq!(manager.queue_depth(&"gamma.rs".into()).await, 0);
+
+    let mut released_events = 0;
+    let mut granted_events = 0;
+    let mut withdrawn = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        match event {
+            TerritoryEvent::Released(_) => released_events += 1,
+            TerritoryEvent::Granted(_) => granted_events += 1,
+            TerritoryEvent::Withdrawn(handle) => withdrawn.push(handle),
+            _ => {}
+        }
+    }
+    assert_eq!((released_events, granted_events), (2, 2));
+    assert_eq!(withdrawn.len(), 1);
+    assert_eq!(withdrawn[0].request_id, queued.request_id);
+    assert_eq!(withdrawn[0].resource_id, "gamma.rs");
+    assert!(manager
+        .release_all_for_agent(&"dead-agent".into())
+        .await
+        .is_empty());
+}
+
+#[tokio::test]
+async fn release_by_tag_frees_the_whole_group() {
+    let manager = Arc::new(build_manager_with_policy(TerritoryPolicy::default()));
+    for resource in ["feature/a.rs", "feature/b.rs", "feature/c.rs"] {
+        let decision = manager
+            .acquire_lease(
+                LeaseRequest::builder("agent-a", resource, Priority::Coordinate)
+                    .tag("feature-x")
+                    .build(),
+            )
+            .await;
+        assert!(matches!(decision, LeaseDecision::Granted(_)));
+    }
+    manager
+        .acquire_lease(
+            LeaseRequest::builder("agent-a", "shared/config.rs", Priority::Coordinate)
+                .tag("feature-y")
+                .build(),
+        )
+        .await;
+    manager
+        .acquire_lease(LeaseRequest::new(
+            "agent-b".into(),
+            "feature/d.rs".into(),
+            Priority::Coordinate,
+        ))
+        .await;
+    let LeaseDecision::Queued(queued) = manager
+        .acquire_lease(
+            LeaseRequest::builder("agent-a", "feature/d.rs", Priority::Coordinate)
+                .tag("feature-x")
+                .build(),
+        )
+        .await
+    else {
+        panic!("expected tagged request to queue behind agent-b");
+    };
+    let waiter = {
+        let manager = Arc::clone(&manager);
+        tokio::spawn(async move { manager.wait_for_grant(queued.request_id).await })
+    };
+    tokio::task::yield_now().await;
+    let mut events = manager.subscribe();
+
+    let tagged = manager.list_leases_tagged("feature-x").await;
+    assert_eq!(tagged.len(), 3);
+    assert!(tagged
+        .iter()
+        .all(|lease| lease.tags == vec!["feature-x".to_string()]));
+
+    assert!(manager
+        .release_by_tag(&"agent-b".into(), "feature-x")
+        .await
+        .is_empty());
+    let released = manager.release_by_tag(&"agent-a".into(), "feature-x").await;
+    let released: Vec<_> = released
+        .iter()
+        .map(|lease| lease.resource_id.as_str())
+        .collect();
+    assert_eq!(
+        released,
+        vec!["feature/a.rs", "feature/b.rs", "feature/c.rs"]
+    );
+
+    assert!(manager.list_leases_tagged("feature-x").await.is_empty());
+    let remaining: Vec<_> = manager
+        .list_leases()
+        .await
+        .into_iter()
+        .map(|lease| lease.resource_id)
+        .collect();
+    assert_eq!(remaining, vec!["feature/d.rs", "shared/config.rs"]);
+    assert_eq!(manager.queue_depth(&"feature/d.rs".into()).await, 0);
+
+    let mut withdrawn = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let TerritoryEvent::Withdrawn(handle) = event {
+            withdrawn.push(handle.request_id);
+        }
+    }
+    assert_eq!(withdrawn, vec![queued.request_id]);
+    let outcome = time::timeout(Duration::from_millis(500), waiter)
+        .await
+        .expect("waiter resolves after tag release")
+        .expect("waiter task");
+    assert!(outcome.is_none());
+}
+
+#[tokio::test]
+async fn territory_promotes_waiting_request_on_release() {
+    let mut policy = TerritoryPolicy::default();
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    let manager = build_manager_with_policy(policy);
+    let resource = "shared_file.txt".to_string();
+
+    let first = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_A".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(first, LeaseDecision::Granted(_)));
+
+    let second = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_B".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(second, LeaseDecision::Queued(_)));
+
+    let released = manager
+        .release_lease(&"Agent_A".to_string(), &resource)
+        .await;
+    assert!(released.is_some());
+
+    let active = manager.current_lease(&resource).await;
+    assert_eq!(active.unwrap().holder_id, "Agent_B".to_string());
+}
+
+#[tokio::test]
+async fn lease_release_advances_release_counter() {
+    let metrics = MetricsCollector::new();
+    let manager = TerritoryManager::with_policy(metrics.clone(), TerritoryPolicy::default());
+    let resource = "release_counter.txt".to_string();
+    let before = metrics.get_snapshot();
+
+    let decision = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_A".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(decision, LeaseDecision::Granted(_)));
+    assert!(manager
+        .release_lease(&"Agent_A".to_string(), &resource)
+        .await
+        .is_some());
+    assert!(manager
+        .release_lease(&"Agent_A".to_string(), &resource)
+        .await
+        .is_none());
+
+    let after = metrics.get_snapshot();
+    assert_eq!(after.performance.total_leases_acquired, 1);
+    assert_eq!(after.performance.total_leases_released, 1);
+    let delta = after.delta(&before);
+    assert_eq!(delta.leases_acquired, 1);
+    assert_eq!(delta.leases_released, 1);
+}
+
+#[tokio::test]
+async fn operator_force_release_promotes_waiter() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("force-release".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let metrics = MetricsCollector::new();
+    let mut policy = TerritoryPolicy::default();
+    policy.override_policy = OverridePolicy::Never;
+    policy.escalation_queue_threshold = 10;
+    let manager = TerritoryManager::with_policy_and_ledger(
+        metrics.clone(),
+        policy.clone(),
+        Some(ledger_writer.clone()),
+    );
+    let resource = "stuck.lock".to_string();
+
+    let held = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_A".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(held, LeaseDecision::Granted(_)));
+    let waiting = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_B".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(waiting, LeaseDecision::Queued(_)));
+
+    assert!(manager
+        .force_release(&resource, "holder unresponsive")
+        .await
+        .is_none());
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        "Agent_A"
+    );
+
+    policy.allow_operator_override = true;
+    manager.update_policy(policy).await;
+    let mut events = manager.subscribe();
+    let released = manager
+        .force_release(&resource, "holder unresponsive")
+        .await
+        .expect("operator frees the resource");
+    assert_eq!(released.holder_id, "Agent_A");
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        "Agent_B"
+    );
+    assert!(matches!(
+        events.try_recv(),
+        Ok(TerritoryEvent::ForceReleased { ref reason, .. }) if reason == "holder unresponsive"
+    ));
+    assert!(
+        matches!(events.try_recv(), Ok(TerritoryEvent::Granted(ref lease)) if lease.holder_id == "Agent_B")
+    );
+    assert!(manager
+        .force_release(&"idle.lock".to_string(), "noop")
+        .await
+        .is_none());
+
+    let snapshot = metrics.get_snapshot();
+    assert_eq!(snapshot.leases.operator_overrides, 1);
+    assert_eq!(snapshot.performance.total_leases_released, 1);
+
+    ledger_writer.flush().await.expect("flush ledger");
+    let events = LedgerReader::new(temp_dir.path().to_path_buf())
+        .read_epoch("force-release")
+        .expect("read ledger");
+    let mut replay = LeaseReplayState::default();
+    for envelope in events.iter() {
+        if let LedgerEvent::Lease(event) = &envelope.event {
+            replay.apply(event);
+        }
+    }
+    let forced: Vec<_> = events
+        .iter()
+        .filter_map(|envelope| match &envelope.event {
+            LedgerEvent::Lease(LeaseEvent::ForceReleased { lease, reason }) => {
+                Some((lease.holder_id.clone(), reason.clone()))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        forced,
+        vec![("Agent_A".to_string(), "holder unresponsive".to_string())]
+    );
+    assert_eq!(replay.operator_overrides, 1);
+    assert_eq!(replay.active[&resource].holder_id, "Agent_B");
+}
+
+#[tokio::test]
+async fn next_in_line_predicts_queue_promotion() {
+    let mut policy = TerritoryPolicy::default();
+    policy.default_lease_duration = Duration::from_secs(10);
+    policy.auto_extend_threshold = Duration::from_secs(1);
+    policy.override_policy = OverridePolicy::Never;
+    policy.escalation_queue_threshold = 10;
+    let clock = MockClock::new();
+    let manager =
+        TerritoryManager::with_policy(MetricsCollector::new(), policy).with_clock(clock.shared());
+    let resource = "plan.json".to_string();
+
+    assert!(manager.next_in_line(&resource).await.is_none());
+    manager
+        .acquire_lease(LeaseRequest::new(
+            "Holder".to_string(),
+            resource.clone(),
+            Priority::Info,
+        ))
+        .await;
+    for (agent, priority) in [
+        ("Agent_A", Priority::Info),
+        ("Agent_B", Priority::Coordinate),
+    ] {
+        let decision = manager
+            .acquire_lease(LeaseRequest::new(
+                agent.to_string(),
+                resource.clone(),
+                priority,
+            ))
+            .await;
+        assert!(matches!(decision, LeaseDecision::Queued(_)));
+    }
+    clock.advance(Duration::from_millis(9_500));
+    let deferred = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_C".to_string(),
+            resource.clone(),
+            Priority::Blocking,
+        ))
+        .await;
+    assert!(matches!(deferred, LeaseDecision::Deferred { .. }));
+
+    let predicted = manager
+        .next_in_line(&resource)
+        .await
+        .expect("a queued request");
+    assert_eq!(predicted.agent_id, "Agent_B");
+    assert_eq!(predicted.queue_position, 2);
+    assert_eq!(manager.list_queue(&resource).await.len(), 3);
+    manager
+        .release_lease(&"Holder".to_string(), &resource)
+        .await
+        .expect("release holder");
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        predicted.agent_id
+    );
+
+    clock.advance(Duration::from_secs(2));
+    let predicted = manager
+        .next_in_line(&resource)
+        .await
+        .expect("a queued request");
+    assert_eq!(predicted.agent_id, "Agent_C");
+    assert_eq!(predicted.queue_position, 1);
+    manager
+        .release_lease(&"Agent_B".to_string(), &resource)
+        .await
+        .expect("release Agent_B");
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        predicted.agent_id
+    );
+
+    let predicted = manager
+        .next_in_line(&resource)
+        .await
+        .expect("a queued request");
+    assert_eq!(predicted.agent_id, "Agent_A");
+}
+
+#[tokio::test]
+async fn fairness_report_surfaces_repeatedly_preempted_agent() {
+    let mut policy = TerritoryPolicy::default();
+    policy.default_lease_duration = Duration::from_secs(10);
+    policy.auto_extend_threshold = Duration::from_secs(1);
+    policy.override_policy = OverridePolicy::Never;
+    policy.escalation_queue_threshold = 10;
+    let clock = MockClock::new();
+    let manager =
+        TerritoryManager::with_policy(MetricsCollector::new(), policy).with_clock(clock.shared());
+    let resource = "shared.rs".to_string();
+
+    for (agent, priority) in [
+        ("Holder", Priority::Coordinate),
+        ("Patient", Priority::Info),
+    ] {
+        manager
+            .acquire_lease(LeaseRequest::new(
+                agent.to_string(),
+                resource.clone(),
+                priority,
+            ))
+            .await;
+    }
+    for _ in 0..3 {
+        for (waiter, holder) in [("Eager", "Holder"), ("Holder", "Eager")] {
+            let decision = manager
+                .acquire_lease(LeaseRequest::new(
+                    waiter.to_string(),
+                    resource.clone(),
+                    Priority::Blocking,
+                ))
+                .await;
+            assert!(matches!(decision, LeaseDecision::Queued(_)));
+            clock.advance(Duration::from_millis(20));
+            manager
+                .release_lease(&holder.to_string(), &resource)
+                .await
+                .expect("release holder");
+            assert_eq!(
+                manager.current_lease(&resource).await.unwrap().holder_id,
+                waiter
+            );
+        }
+    }
+    manager
+        .release_lease(&"Holder".to_string(), &resource)
+        .await
+        .expect("release final holder");
+
+    let report = manager.fairness_report().await;
+    let patient = report.agent("Patient").expect("patient was granted");
+    let eager = report.agent("Eager").expect("eager was granted");
+    assert_eq!(patient.grants, 1);
+    assert_eq!(patient.average_wait, Duration::from_millis(120));
+    assert_eq!(eager.grants, 3);
+    assert_eq!(eager.average_wait, Duration::from_millis(20));
+    assert!(patient.average_wait > eager.average_wait);
+    assert_eq!(
+        report.agents.first().map(|entry| entry.agent_id.as_str()),
+        Some("Patient")
+    );
+}
+
+#[tokio::test]
+async fn territory_defers_when_holder_near_expiry() {
+    let mut policy = TerritoryPolicy::default();
+    policy.default_lease_duration = Duration::from_millis(100);
+    policy.max_lease_duration = Duration::from_millis(100);
+    policy.auto_extend_threshold = Duration::from_secs(5);
+    let manager = build_manager_with_policy(policy);
+    let resource = "doc.md".to_string();
+
+    let first = manager
+        .acquire_lease(LeaseRequest::new(
+            "Holder".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(first, LeaseDecision::Granted(_)));
+
+    let second = manager
+        .acquire_lease(LeaseRequest::new(
+            "Contender".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(second, LeaseDecision::Deferred { .. }));
+}
+
+#[tokio::test]
+async fn territory_overrides_on_priority_delta() {
+    let mut policy = TerritoryPolicy::default();
+    policy.override_policy = OverridePolicy::PriorityDelta(1);
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    let manager = build_manager_with_policy(policy);
+    let resource = "plan.json".to_string();
+
+    let base = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_Low".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(base, LeaseDecision::Granted(_)));
+
+    let override_decision = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_High".to_string(),
+            resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+    assert!(matches!(
+        override_decision,
+        LeaseDecision::Overridden { .. }
+    ));
+
+    let holder = manager.current_lease(&resource).await.unwrap();
+    assert_eq!(holder.holder_id, "Agent_High");
+    assert_eq!(holder.priority, Priority::Critical);
+}
+
+#[tokio::test]
+async fn acquire_lease_records_uncontended_and_contended_latency() {
+    let metrics = MetricsCollector::new();
+    let mut policy = TerritoryPolicy::default();
+    policy.override_policy = OverridePolicy::PriorityDelta(1);
+    let manager = TerritoryManager::with_policy(metrics.clone(), policy);
+    let resource = "plan.json".to_string();
+
+    let before = metrics.get_snapshot().performance;
+    assert_eq!(before.lease_acquisition_samples, 0);
+
+    let granted = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_Low".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(granted, LeaseDecision::Granted(_)));
+    let after_grant = metrics.get_snapshot().performance;
+    assert_eq!(after_grant.lease_acquisition_samples, 1);
+    assert!(after_grant.uncontended_lease_acquisition_ms > 0.0);
+    assert_eq!(after_grant.contended_lease_acquisition_ms, 0.0);
+    assert_eq!(
+        after_grant.lease_acquisition_time_ms,
+        after_grant.uncontended_lease_acquisition_ms
+    );
+
+    let queued = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_Peer".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(queued, LeaseDecision::Queued(_)));
+    assert_eq!(
+        metrics.get_snapshot().performance.lease_acquisition_samples,
+        1
+    );
+
+    let overridden = manager
+        .acquire_lease(LeaseRequest::new(
+            "Agent_High".to_string(),
+            resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+    assert!(matches!(overridden, LeaseDecision::Overridden { .. }));
+    let performance = metrics.get_snapshot().performance;
+    assert_eq!(performance.lease_acquisition_samples, 2);
+    assert!(performance.contended_lease_acquisition_ms > 0.0);
+    assert_eq!(
+        performance.uncontended_lease_acquisition_ms,
+        after_grant.uncontended_lease_acquisition_ms
+    );
+}
+
+#[tokio::test]
+async fn territory_escalates_on_queue_pressure() {
+    let mut policy = TerritoryPolicy::default();
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    policy.escalation_queue_threshold = 2;
+    let manager = build_manager_with_policy(policy);
+    let resource = "shared_resource.txt".to_string();
+    let mut events = manager.subscribe();
+
+    let grant = manager
+        .acquire_lease(LeaseRequest::new(
+            "Primary".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(grant, LeaseDecision::Granted(_)));
+
+    let queued_one = manager
+        .acquire_lease(LeaseRequest::new(
+            "Waiter_1".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(queued_one, LeaseDecision::Queued(_)));
+
+    let queued_two = manager
+        .acquire_lease(LeaseRequest::new(
+            "Waiter_2".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(queued_two, LeaseDecision::Queued(_)));
+
+    let mut escalated = false;
+    for _ in 0..5 {
+        let event = time::timeout(Duration::from_millis(200), events.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        if matches!(event, TerritoryEvent::Escalated { .. }) {
+            escalated = true;
+            break;
+        }
+    }
+
+    assert!(escalated);
+}
+
+#[tokio::test]
+async fn territory_metrics_reflect_escalation() {
+    let metrics = MetricsCollector::new();
+    let mut policy = TerritoryPolicy::default();
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    policy.escalation_queue_threshold = 2;
+    let manager = TerritoryManager::with_policy(metrics.clone(), policy);
+    let resource = "metrics_resource.txt".to_string();
+
+    let _ = manager
+        .acquire_lease(LeaseRequest::new(
+            "Primary".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+
+    for idx in 0..2 {
+        let _ = manager
+            .acquire_lease(LeaseRequest::new(
+                format!("Waiter_{idx}"),
+                resource.clone(),
+                Priority::Coordinate,
+            ))
+            .await;
+    }
+
+    time::sleep(Duration::from_millis(10)).await;
+    let snapshot = metrics.get_snapshot();
+    assert!(snapshot.leases.escalations > 0);
+    assert!(snapshot.leases.total_pending >= 2);
+    assert!(
+        snapshot
+            .leases
+            .pending_by_resource
+            .get(&resource)
+            .copied()
+            .unwrap_or_default()
+            >= 2
+    );
+}
+
+#[test]
+fn config_overrides_apply_to_router_and_territory() {
+    let mut temp_config = NamedTempFile::new().expect("create temp config");
+    writeln!(
+        temp_config,
+        "{}",
+        r#"
+territory_config:
+  default_lease_duration: 90s
+  auto_extend_threshold: 20s
+  negotiation:
+    max_rounds: 6
+    timeout: 12s
+  escalation:
+    queue_threshold: 5
+    deadlock_timeout: 240s
+  fairness:
+    priority_boost_after: 75s
+    starvation_threshold: 360s
+performance_slas:
+  queue_depths:
+    blocking_max: 8
+    critical_max: 12
+  message_routing:
+    p50: 0.2s
+health_monitoring_kpis:
+  queue_health:
+    warning_depth: 3
+    max_depth: 7
+"#
+    )
+    .expect("write config");
+
+    let previous = std::env::var("LIMINAL_CONFIG_PATH").ok();
+    std::env::set_var("LIMINAL_CONFIG_PATH", temp_config.path());
+
+    let app_config = AppConfig::load();
+    let metrics = MetricsCollector::new();
+    let router = UnifiedMessageRouter::with_settings(metrics.clone(), app_config.router.as_ref());
+    let dispatcher = router.dispatcher_config();
+    assert_eq!(dispatcher.aging_threshold, Duration::from_millis(200));
+    assert_eq!(dispatcher.max_aging_boosts, 2);
+
+    let territory_manager = TerritoryManager::new(metrics, app_config.territory.as_ref());
+    let policy = territory_manager.policy();
+    assert_eq!(policy.default_lease_duration, Duration::from_secs(90));
+    assert_eq!(policy.auto_extend_threshold, Duration::from_secs(20));
+    assert_eq!(policy.negotiation_max_rounds, 6);
+    assert_eq!(policy.negotiation_timeout, Duration::from_secs(12));
+    assert_eq!(policy.escalation_queue_threshold, 5);
+    assert_eq!(policy.escalation_deadlock_timeout, Duration::from_secs(240));
+    assert_eq!(
+        policy.fairness_priority_boost_after,
+        Duration::from_secs(75)
+    );
+    assert_eq!(
+        policy.fairness_starvation_threshold,
+        Duration::from_secs(360)
+    );
+
+    if let Some(value) = previous {
+        std::env::set_var("LIMINAL_CONFIG_PATH", value);
+    } else {
+        std::env::remove_var("LIMINAL_CONFIG_PATH");
+    }
+}
+
+#[test]
+fn pty_metrics_capture_structured_events() {
+    let metrics = MetricsCollector::new();
+    metrics.record_agent_event("Agent_A", Some("forgeEvent"));
+    metrics.record_agent_event("Agent_B", None);
+
+    let snapshot = metrics.get_snapshot();
+    assert_eq!(snapshot.pty.total_events, 2);
+    assert_eq!(snapshot.pty.events_by_name.get("forgeEvent"), Some(&1));
+    assert!(
+        snapshot
+            .pty
+            .events_by_name
+            .get("unknown")
+            .copied()
+            .unwrap_or_default()
+            >= 1
+    );
+    let last_event = snapshot.pty.last_event.expect("last event present");
+    assert!(last_event.agent_id == "Agent_A" || last_event.agent_id == "Agent_B");
+}
+
+#[tokio::test]
+async fn ledger_captures_router_messages() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("router-capture-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let metrics = MetricsCollector::new();
+    let router = Arc::new(UnifiedMessageRouter::with_settings_and_ledger(
+        metrics,
+        None,
+        Some(ledger_writer.clone()),
+    ));
+    let mut deliveries = router.subscribe();
+
+    let priorities = [
+        Priority::Info,
+        Priority::Coordinate,
+        Priority::Critical,
+        Priority::Info,
+        Priority::Coordinate,
+        Priority::Info,
+        Priority::Critical,
+        Priority::Info,
+        Priority::Coordinate,
+        Priority::Info,
+    ];
+
+    for (idx, priority) in priorities.iter().enumerate() {
+        router
+            .route_message(Message {
+                content: format!("message_{idx}"),
+                priority: *priority,
+                sender: format!("agent_{idx}"),
+                recipient: "target".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+    }
+
+    for _ in 0..10 {
+        let _ = time::timeout(Duration::from_millis(500), deliveries.recv()).await;
+    }
+
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let events = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger");
+
+    let mut dispatch_count = 0;
+    let mut last_sequence = 0;
+    for envelope in events {
+        assert!(envelope.sequence > last_sequence);
+        last_sequence = envelope.sequence;
+        if let LedgerEvent::Router(RouterEvent::Dispatched(_)) = envelope.event {
+            dispatch_count += 1;
+        }
+    }
+    assert_eq!(dispatch_count, 10);
+}
+
+#[tokio::test]
+async fn ledger_router_events_carry_message_trace_id() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("router-trace-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let router = Arc::new(UnifiedMessageRouter::with_settings_and_ledger(
+        MetricsCollector::new(),
+        None,
+        Some(ledger_writer.clone()),
+    ));
+    let mut deliveries = router.subscribe();
+
+    for (idx, trace_id) in [Some("epoch-7:turn-3"), None].into_iter().enumerate() {
+        router
+            .route_message(Message {
+                content: format!("coordinate_{idx}"),
+                priority: Priority::Coordinate,
+                sender: "director".to_string(),
+                recipient: "systems".to_string(),
+                message_id: Some(format!("msg-{idx}")),
+                trace_id: trace_id.map(str::to_string),
+            })
+            .await
+            .unwrap();
+        let delivery = time::timeout(Duration::from_millis(500), deliveries.recv())
+            .await
+            .expect("delivery")
+            .expect("delivery");
+        assert_eq!(delivery.message.trace_id.as_deref(), trace_id);
+    }
+
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let traces: Vec<_> = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger")
+        .into_iter()
+        .filter_map(|envelope| match envelope.event {
+            LedgerEvent::Router(RouterEvent::Dispatched(record)) => {
+                Some((record.trace_id, envelope.metadata.trace_id))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        traces,
+        vec![
+            (
+                Some("epoch-7:turn-3".to_string()),
+                Some("epoch-7:turn-3".to_string())
+            ),
+            (None, Some("msg-1".to_string())),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn released_lease_records_hold_duration() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("lease-hold-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let clock = MockClock::new();
+    let metrics = MetricsCollector::new();
+    let territory =
+        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()))
+            .with_clock(clock.shared());
+    let resource = "held.json".to_string();
+
+    for (holder, hold) in [("agent-a", 2_500), ("agent-b", 400)] {
+        let granted = territory
+            .acquire_lease(LeaseRequest::new(
+                holder.to_string(),
+                resource.clone(),
+                Priority::Coordinate,
+            ))
+            .await;
+        assert!(matches!(granted, LeaseDecision::Granted(_)));
+        clock.advance(Duration::from_millis(hold));
+        territory
+            .release_lease(&holder.to_string(), &resource)
+            .await
+            .expect("release lease");
+    }
+
+    ledger_writer.flush().await.expect("flush ledger");
+    let held: Vec<Option<u64>> = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger")
+        .into_iter()
+        .filter_map(|envelope| match envelope.event {
+            LedgerEvent::Lease(LeaseEvent::Released(record)) => Some(record.held_for_ms),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(held, vec![Some(2_500), Some(400)]);
+
+    let hold_duration = metrics.get_snapshot().leases.hold_duration;
+    assert_eq!(hold_duration.samples, 2);
+    assert_eq!(hold_duration.min_ms, 400.0);
+    assert_eq!(hold_duration.max_ms, 2_500.0);
+    assert_eq!(hold_duration.mean_ms, 1_450.0);
+}
+
+#[tokio::test]
+async fn replay_reconstructs_heat_from_lease_contention() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("heat-replay-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let metrics = MetricsCollector::new();
+    let territory =
+        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
+
+    for resource in ["alpha", "beta"] {
+        let granted = territory
+            .acquire_lease(LeaseRequest::new(
+                "holder".into(),
+                resource.into(),
+                Priority::Coordinate,
+            ))
+            .await;
+        assert!(matches!(granted, LeaseDecision::Granted(_)));
+    }
+    for contender in ["agent-b", "agent-c", "agent-d"] {
+        let _ = territory
+            .acquire_lease(LeaseRequest::new(
+                contender.into(),
+                "alpha".into(),
+                Priority::Coordinate,
+            ))
+            .await;
+    }
+    let overridden = territory
+        .acquire_lease(LeaseRequest::new(
+            "agent-e".into(),
+            "beta".into(),
+            Priority::Blocking,
+        ))
+        .await;
+    assert!(matches!(overridden, LeaseDecision::Overridden { .. }));
+
+    let live = territory.heat_snapshot().await;
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let coordinator = ReplayCoordinator::new(LedgerReader::new(ledger_config.root_path.clone()))
+        .with_heat_parameters(HeatParameters::from(&territory.policy()));
+    let outcome = coordinator
+        .replay_epoch("heat-replay-test")
+        .expect("replay epoch");
+
+    assert_eq!(live.hottest_resource.as_deref(), Some("alpha"));
+    assert_eq!(outcome.heat.hottest_resource, live.hottest_resource);
+    assert_eq!(outcome.heat.tracked, live.tracked);
+    assert!((outcome.heat.hottest_score - live.hottest_score).abs() < 0.1);
+}
+
+#[tokio::test]
+async fn ledger_records_territory_lease_lifecycle() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("lease-lifecycle-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let metrics = MetricsCollector::new();
+    let territory =
+        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
+
+    let resource = "test-resource".to_string();
+    let holder = "lease-holder".to_string();
+
+    let granted = territory
+        .acquire_lease(LeaseRequest::new(
+            holder.clone(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(granted, LeaseDecision::Granted(_)));
+
+    territory
+        .release_lease(&holder, &resource)
+        .await
+        .expect("release lease");
+
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let events = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger");
+
+    let mut granted_count = 0;
+    let mut released_count = 0;
+    let mut found_heat_score = false;
+
+    for envelope in events {
+        if let LedgerEvent::Lease(lease_event) = &envelope.event {
+            match lease_event {
+                LeaseEvent::Granted(record) => {
+                    assert_eq!(record.holder_id, holder);
+                    assert_eq!(record.resource_id, resource);
+                    granted_count += 1;
+                }
+                LeaseEvent::Released(record) => {
+                    assert_eq!(record.resource_id, resource);
+                    released_count += 1;
+                }
+                _ => {}
+            }
+        }
+        if envelope.metadata.territory_id.is_some() {
+            found_heat_score = true;
+        }
+    }
+
+    assert_eq!(granted_count, 1);
+    assert_eq!(released_count, 1);
+    assert!(found_heat_score);
+}
+
+#[tokio::test]
+async fn resource_threshold_overrides_default_quorum() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("resource-threshold".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+    let consensus =
+        ConsensusBroker::new(Some(ledger_writer.clone()), MetricsCollector::new(), 0.66);
+    consensus.set_resource_threshold("config/production.yaml", 0.9);
+    let votes = || {
+        vec![
+            quorum_vote("agent_a", 2.0, true),
+            quorum_vote("agent_b", 1.5, true),
+            quorum_vote("agent_c", 1.0, false),
+        ]
+    };
+
+    assert!(
+        consensus
+            .record_quorum("src/lib.rs", votes(), "priority-override")
+            .await
+    );
+    assert!(
+        !consensus
+            .record_quorum("config/production.yaml", votes(), "priority-override")
+            .await
+    );
+    assert_eq!(consensus.threshold_for("config/production.yaml"), 0.9);
+    assert_eq!(consensus.threshold_for("src/lib.rs"), 0.66);
+
+    ledger_writer.flush().await.expect("flush ledger");
+    let commits: Vec<_> = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger")
+        .into_iter()
+        .filter_map(|envelope| match envelope.event {
+            LedgerEvent::Consensus(ConsensusEvent::Commit(signal)) => signal.vector,
+            _ => None,
+        })
+        .collect();
+    assert_eq!(commits.len(), 2);
+    assert_eq!(commits[0].threshold, 0.66);
+    assert!(commits[0].achieved);
+    assert_eq!(commits[1].resource_id, "config/production.yaml");
+    assert_eq!(commits[1].threshold, 0.9);
+    assert!(!commits[1].achieved);
+}
+
+#[tokio::test]
+async fn consensus_broker_keeps_bounded_recent_decisions() {
+    let consensus =
+        ConsensusBroker::new(None, MetricsCollector::new(), 0.66).with_decision_history(3);
+    assert!(consensus.recent_decisions(10).is_empty());
+
+    for index in 0..5 {
+        let votes = vec![
+            quorum_vote("agent_a", 1.0, index % 2 == 0),
+            quorum_vote("agent_b", 1.0, true),
+        ];
+        consensus
+            .record_quorum(&format!("resource-{index}"), votes, "override")
+            .await;
+    }
+
+    let recent = consensus.recent_decisions(10);
+    let resources: Vec<_> = recent
+        .iter()
+        .map(|vector| vector.resource_id.as_str())
+        .collect();
+    assert_eq!(resources, vec!["resource-4", "resource-3", "resource-2"]);
+    assert!(recent[0].achieved);
+    assert!(!recent[1].achieved);
+    assert_eq!(consensus.recent_decisions(2).len(), 2);
+    assert!(consensus.active_rounds().is_empty());
+}
+
+#[tokio::test]
+async fn consensus_round_timeouts_are_counted_and_optionally_escalated() {
+    let metrics = MetricsCollector::new();
+    let broker = ConsensusBroker::new(None, metrics.clone(), 0.66);
+    let round = broker.open_round("src/lib.rs", "override", 2, Duration::from_secs(2));
+    let round_id = round.round_id();
+    assert_eq!(broker.active_rounds()[0].round_id, round_id);
+    assert!(broker.cast_vote(round_id, quorum_vote("agent_a", 1.0, true)));
+    assert!(broker.cast_vote(round_id, quorum_vote("agent_b", 1.0, true)));
+    assert_eq!(round.outcome().await, RoundOutcome::Resolved(true));
+    let consensus = metrics.get_snapshot().consensus;
+    assert_eq!((consensus.success, consensus.timeouts), (1, 0));
+
+    let round = broker.open_round("src/main.rs", "override", 3, Duration::from_millis(100));
+    let round_id = round.round_id();
+    assert!(broker.cast_vote(round_id, quorum_vote("agent_a", 1.0, true)));
+    assert_eq!(round.outcome().await, RoundOutcome::TimedOut);
+    assert!(!broker.cast_vote(round_id, quorum_vote("agent_b", 1.0, true)));
+    assert!(broker.active_rounds().is_empty());
+    let consensus = metrics.get_snapshot().consensus;
+    assert_eq!(
+        (consensus.success, consensus.failure, consensus.timeouts),
+        (1, 0, 1)
+    );
+    assert_eq!(broker.recent_decisions(1)[0].resource_id, "src/lib.rs");
+
+    let resource = "src/config.rs".to_string();
+    let territory = TerritoryManager::with_policy(metrics.clone(), TerritoryPolicy::default());
+    territory
+        .acquire_lease(LeaseRequest::new(
+            "Holder".to_string(),
+            resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+    let LeaseDecision::Queued(handle) = territory
+        .acquire_lease(LeaseRequest::new(
+            "Waiter".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await
+    else {
+        panic!("expected the waiter to be queued");
+    };
+    let escalating =
+        ConsensusBroker::new(None, metrics.clone(), 0.66).with_timeout_escalation(true);
+    let round = escalating.open_round(&resource, "override", 2, Duration::from_millis(50));
+    assert_eq!(territory.settle_round(round).await, RoundOutcome::Escalated);
+    assert_eq!(metrics.get_snapshot().consensus.timeouts, 2);
+    let forced = &escalating.recent_decisions(1)[0];
+    assert_eq!(forced.resource_id, "src/config.rs");
+    assert!(forced.achieved);
+    assert_eq!(forced.reason, TIMEOUT_ESCALATION_REASON);
+    assert!(forced.votes.is_empty());
+    let holder = territory.current_lease(&resource).await.unwrap();
+    assert_eq!(holder.holder_id, "Waiter");
+    assert_eq!(holder.priority, Priority::Coordinate);
+    assert_eq!(holder.request_id, handle.request_id);
+    assert!(territory.list_queue(&resource).await.is_empty());
+}
+
+#[tokio::test]
+async fn lagging_ledger_follower_backfills_missed_events_from_disk() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("follow-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let pty_event = |index: u64| {
+        LedgerEvent::Pty(PtyEvent {
+            agent_id: format!("agent-{index}"),
+            event_name: None,
+            timestamp_ms: index,
+        })
+    };
+
+    ledger_writer
+        .append_async(pty_event(0))
+        .await
+        .expect("append before follow");
+    let mut follower = ledger_writer.follow(1);
+    let mut plain = ledger_writer.subscribe();
+    for index in 1..600 {
+        ledger_writer
+            .append_async(pty_event(index))
+            .await
+            .expect("append while follower lags");
+    }
+
+    assert!(matches!(
+        plain.recv().await,
+        Err(tokio::sync::broadcast::error::RecvError::Lagged(_))
+    ));
+    for expected in 1..=600 {
+        let envelope = time::timeout(Duration::from_secs(2), follower.recv())
+            .await
+            .expect("follower yields before timeout")
+            .expect("backfill read")
+            .expect("ledger still open");
+        assert_eq!(envelope.sequence, expected);
+    }
+    assert!(follower.backfilled() >= 88);
+    assert_eq!(follower.next_sequence(), 601);
+
+    ledger_writer
+        .append_async(pty_event(600))
+        .await
+        .expect("append after catch up");
+    let live = follower.recv().await.unwrap().unwrap();
+    assert_eq!(live.sequence, 601);
+}
+
+#[tokio::test]
+async fn ledger_counts_wall_clock_regressions_and_keeps_ordering() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("clock-regression".to_string());
+    let clock = MockClock::new();
+    let ledger_writer =
+        LedgerWriter::with_wall_clock(&ledger_config, clock.shared()).expect("ledger writer");
+    let metrics = MetricsCollector::new();
+    ledger_writer.set_metrics(metrics.clone());
+    let append = |index: u64| {
+        let writer = ledger_writer.clone();
+        async move {
+            writer
+                .append_async(LedgerEvent::Pty(PtyEvent {
+                    agent_id: format!("agent-{index}"),
+                    event_name: None,
+                    timestamp_ms: index,
+                }))
+                .await
+                .expect("append")
+        }
+    };
+
+    let mut envelopes = vec![append(0).await];
+    clock.advance(Duration::from_millis(10));
+    envelopes.push(append(1).await);
+    assert_eq!(metrics.get_snapshot().ledger.clock_regressions, 0);
+
+    clock.rewind_wall(Duration::from_secs(5));
+    envelopes.push(append(2).await);
+    envelopes.push(append(3).await);
+    assert_eq!(metrics.get_snapshot().ledger.clock_regressions, 1);
+    assert_eq!(
+        envelopes[2].logical_clock.wall_millis,
+        envelopes[1].logical_clock.wall_millis
+    );
+
+    clock.rewind_wall(CLOCK_REGRESSION_TOLERANCE / 2);
+    envelopes.push(append(4).await);
+    assert_eq!(metrics.get_snapshot().ledger.clock_regressions, 1);
+
+    clock.rewind_wall(Duration::from_secs(1));
+    envelopes.push(append(5).await);
+    assert_eq!(metrics.get_snapshot().ledger.clock_regressions, 2);
+
+    for pair in envelopes.windows(2) {
+        assert!(pair[1].logical_clock > pair[0].logical_clock);
+    }
+}
+
+#[tokio::test]
+async fn closed_ledger_keeps_buffered_events_and_rejects_late_appends() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("close-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+
+    for index in 0..5 {
+        ledger_writer
+            .append_async(LedgerEvent::Pty(PtyEvent {
+                agent_id: format!("agent-{index}"),
+                event_name: Some("PING".to_string()),
+                timestamp_ms: index,
+            }))
+            .await
+            .expect("append before close");
+    }
+    ledger_writer.close().await.expect("close ledger");
+    assert!(ledger_writer.is_closed());
+
+    let late = ledger_writer
+        .append_async(LedgerEvent::Pty(PtyEvent {
+            agent_id: "late".to_string(),
+            event_name: None,
+            timestamp_ms: 99,
+        }))
+        .await;
+    assert!(matches!(late, Err(LedgerError::Closed(epoch)) if epoch == "close-test"));
+
+    let events = LedgerReader::new(ledger_config.root_path.clone())
+        .read_epoch("close-test")
+        .expect("read closed epoch");
+    assert_eq!(events.len(), 5);
+    assert_eq!(events.last().unwrap().sequence, 5);
+}
+
+#[tokio::test]
+async fn ledger_reopens_after_torn_tail_write() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("torn-tail".to_string());
+    let pty_event = |index: u64| {
+        LedgerEvent::Pty(PtyEvent {
+            agent_id: format!("agent-{index}"),
+            event_name: None,
+            timestamp_ms: index,
+        })
+    };
+    {
+        let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+        for index in 0..3 {
+            ledger_writer
+                .append_async(pty_event(index))
+                .await
+                .expect("append before crash");
+        }
+        ledger_writer.flush().await.expect("flush before crash");
+    }
+    let segment = temp_dir.path().join("torn-tail").join("segment_0000.log");
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&segment)
+        .expect("open segment");
+    file.write_all(br#"{"epochId":"torn-tail","sequence":4,"logicalCl"#)
+        .expect("write torn tail");
+    drop(file);
+
+    let reopened = LedgerWriter::new(&ledger_config).expect("reopen after torn tail");
+    let appended = reopened
+        .append_async(pty_event(3))
+        .await
+        .expect("append after reopen");
+    assert_eq!(appended.sequence, 4);
+    reopened.flush().await.expect("flush after reopen");
+
+    let reader = LedgerReader::new(ledger_config.root_path.clone());
+    let events = reader.read_epoch("torn-tail").expect("read repaired epoch");
+    let sequences: Vec<u64> = events.iter().map(|event| event.sequence).collect();
+    assert_eq!(sequences, vec![1, 2, 3, 4]);
+    assert!(reader
+        .verify_epoch("torn-tail")
+        .expect("verify repaired epoch"));
+}
+
+#[tokio::test]
+async fn router_drain_waits_for_queued_messages() {
+    let router = UnifiedMessageRouter::with_config(
+        MetricsCollector::new(),
+        DispatcherConfig {
+            idle_backoff: Duration::from_millis(5),
+            ..DispatcherConfig::default()
+        },
+    );
+    router.pause();
+    for index in 0..4 {
+        router
+            .route_message(Message {
+                content: format!("pending {index}"),
+                priority: Priority::Info,
+                sender: "drainer".to_string(),
+                recipient: "peer".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+    }
+    assert_eq!(router.drain(Duration::from_millis(30)).await, 4);
+
+    router.resume();
+    assert_eq!(router.drain(Duration::from_millis(500)).await, 0);
+}
+
+#[tokio::test]
+async fn router_drain_flushes_scheduled_messages() {
+    let router = UnifiedMessageRouter::with_config(
+        MetricsCollector::new(),
+        DispatcherConfig {
+            idle_backoff: Duration::from_millis(5),
+            ..DispatcherConfig::default()
+        },
+    );
+    let mut deliveries = router.subscribe();
+    router
+        .route_message_at(
+            Message {
+                content: "wake up later".to_string(),
+                priority: Priority::Info,
+                sender: "drainer".to_string(),
+                recipient: "peer".to_string(),
+                message_id: None,
+                trace_id: None,
+            },
+            Duration::from_secs(3600),
+        )
+        .await
+        .unwrap();
+    assert_eq!(router.scheduled_count().await, 1);
+
+    router.pause();
+    assert_eq!(router.drain(Duration::from_millis(30)).await, 1);
+    assert_eq!(router.scheduled_count().await, 0);
+
+    router.resume();
+    assert_eq!(router.drain(Duration::from_millis(500)).await, 0);
+    let delivered = time::timeout(Duration::from_secs(1), deliveries.recv())
+        .await
+        .expect("flushed delivery")
+        .expect("delivery channel open");
+    assert_eq!(delivered.message.content, "wake up later");
+}
+
+#[tokio::test]
+async fn scheduled_message_is_held_until_its_deadline() {
+    let router = UnifiedMessageRouter::new();
+    let mut deliveries = router.subscribe();
+    let message = |content: &str| Message {
+        content: content.to_string(),
+        priority: Priority::Coordinate,
+        sender: "director".to_string(),
+        recipient: "agent-b".to_string(),
+        message_id: None,
+        trace_id: None,
+    };
+
+    router
+        .route_message_at(message("remind agent B"), Duration::from_millis(200))
+        .await
+        .unwrap();
+    router.route_message(message("right away")).await.unwrap();
+    assert_eq!(router.scheduled_count().await, 1);
+
+    let first = time::timeout(Duration::from_millis(500), deliveries.recv())
+        .await
+        .expect("immediate delivery")
+        .unwrap();
+    assert_eq!(first.message.content, "right away");
+    assert!(
+        time::timeout(Duration::from_millis(100), deliveries.recv())
+            .await
+            .is_err(),
+        "scheduled message delivered before its deadline"
+    );
+    assert_eq!(router.scheduled_count().await, 1);
+
+    let delayed = time::timeout(Duration::from_secs(2), deliveries.recv())
+        .await
+        .expect("delayed delivery")
+        .unwrap();
+    assert_eq!(delayed.message.content, "remind agent B");
+    assert_eq!(delayed.effective_priority, Priority::Coordinate);
+    assert!(delayed.wait_time < Duration::from_millis(150));
+    assert_eq!(router.scheduled_count().await, 0);
+}
+
+#[tokio::test]
+async fn ledger_records_consensus_quorum_events() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("consensus-quorum-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let metrics = MetricsCollector::new();
+    let consensus = ConsensusBroker::new(Some(ledger_writer.clone()), metrics, 0.66);
+
+    let votes = vec![
+        quorum_vote("agent_a", 2.0, true),
+        quorum_vote("agent_b", 1.5, true),
+        quorum_vote("agent_c", 1.0, false),
+    ];
+
+    let achieved = consensus
+        .record_quorum("shared-resource", votes, "priority-override")
+        .await;
+
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let events = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger");
+
+    let mut proposal_count = 0;
+    let mut vote_count = 0;
+    let mut commit_count = 0;
+
+    for envelope in events {
+        if let LedgerEvent::Consensus(consensus_event) = &envelope.event {
+            match consensus_event {
+                ConsensusEvent::Proposal(signal) => {
+                    assert_eq!(signal.phase, "proposal");
+                    if let Some(vector) = &signal.vector {
+                        assert_eq!(vector.resource_id, "shared-resource");
+                        assert_eq!(vector.threshold, 0.66);
+                        assert_eq!(vector.total_weight, 4.5);
+                        assert_eq!(vector.agree_weight, 3.5);
+                        assert_eq!(vector.achieved, achieved);
+                        assert_eq!(vector.reason, "priority-override");
+                    }
+                    proposal_count += 1;
+                }
+                ConsensusEvent::Vote(signal) => {
+                    assert_eq!(signal.phase, "vote");
+                    vote_count += 1;
+                }
+                ConsensusEvent::Commit(signal) => {
+                    assert_eq!(signal.phase, "commit");
+                    if let Some(vector) = &signal.vector {
+                        assert!(vector.achieved);
+                    }
+                    commit_count += 1;
+                }
+                ConsensusEvent::Idle => {}
+            }
+        }
+    }
+
+    assert_eq!(proposal_count, 1);
+    assert_eq!(vote_count, 1);
+    assert_eq!(commit_count, 1);
+}
+
+#[tokio::test]
+async fn ledger_replay_rebuilds_state_deterministically() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("replay-deterministic-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let metrics = MetricsCollector::new();
+    let router = Arc::new(UnifiedMessageRouter::with_settings_and_ledger(
+        metrics.clone(),
+        None,
+        Some(ledger_writer.clone()),
+    ));
+    let mut deliveries = router.subscribe();
+
+    let messages = vec![
+        Message {
+            content: "msg1".into(),
+            priority: Priority::Critical,
+            sender: "agent1".into(),
+            recipient: "target".into(),
+            message_id: None,
+            trace_id: None,
+        },
+        Message {
+            content: "msg2".into(),
+            priority: Priority::Coordinate,
+            sender: "agent2".into(),
+            recipient: "target".into(),
+            message_id: None,
+            trace_id: None,
+        },
+        Message {
+            content: "msg3".into(),
+            priority: Priority::Info,
+            sender: "agent1".into(),
+            recipient: "target".into(),
+            message_id: None,
+            trace_id: None,
+        },
+    ];
+
+    for msg in messages {
+        router.route_message(msg).await.unwrap();
+    }
+
+    for _ in 0..3 {
+        let _ = time::timeout(Duration::from_millis(500), deliveries.recv())
+            .await
+            .unwrap()
+            .unwrap();
+    }
+
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let coordinator = ReplayCoordinator::new(ledger_reader);
+    let outcome = coordinator
+        .replay_epoch(&ledger_writer.epoch_id())
+        .expect("replay");
+
+    assert_eq!(outcome.router.total_dispatched, 3);
+    assert!(outcome.router.last_priority.is_some());
+
+    let snapshot = metrics.get_snapshot();
+    assert_eq!(
+        outcome.router.total_dispatched,
+        snapshot.performance.total_messages_routed
+    );
+
+    let mut queue_depths_match = true;
+    for (idx, depth) in outcome.router.queue_depths.iter().enumerate() {
+        let key = Priority::from_index(idx).as_str();
+        let live_depth = snapshot.router.queue_depths.get(key).copied().unwrap_or(0);
+        if *depth != live_depth {
+            queue_depths_match = false;
+            break;
+        }
+    }
+    assert!(queue_depths_match);
+}
+
+#[tokio::test]
+async fn ledger_query_filters_by_agent_id() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("query-filter-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    for (index, agent) in ["agent-a", "agent-b", "agent-a"].iter().enumerate() {
+        ledger_writer
+            .append_async(LedgerEvent::Lease(LeaseEvent::Granted(LeaseRecord {
+                lease_id: index as u64 + 1,
+                resource_id: format!("resource-{index}"),
+                holder_id: agent.to_string(),
+                priority: Priority::Coordinate.as_str().to_string(),
+                held_for_ms: None,
+            })))
+            .await
+            .expect("append lease");
+        ledger_writer
+            .append_async(LedgerEvent::Pty(PtyEvent {
+                agent_id: agent.to_string(),
+                event_name: Some("HEARTBEAT".to_string()),
+                timestamp_ms: index as u64,
+            }))
+            .await
+            .expect("append pty");
+    }
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let epoch = ledger_writer.epoch_id();
+    let filtered = ledger_reader
+        .query_epoch(
+            &epoch,
+            EventFilter {
+                agent_id: Some("agent-a".to_string()),
+                ..EventFilter::default()
+            },
+        )
+        .expect("query epoch");
+    assert_eq!(filtered.len(), 4);
+    assert!(filtered
+        .iter()
+        .all(|envelope| envelope.metadata.agent_id.as_deref() == Some("agent-a")));
+
+    let leases_only = ledger_reader
+        .query_epoch(
+            &epoch,
+            EventFilter {
+                agent_id: Some("agent-a".to_string()),
+                kind: Some(LedgerEventKind::Lease),
+                ..EventFilter::default()
+            },
+        )
+        .expect("query lease history");
+    assert_eq!(leases_only.len(), 2);
+
+    let tail = ledger_reader
+        .query_epoch(
+            &epoch,
+            EventFilter {
+                min_sequence: Some(5),
+                ..EventFilter::default()
+            },
+        )
+        .expect("query sequence range");
+    assert_eq!(tail.len(), 2);
+}
+
+#[tokio::test]
+async fn ledger_export_round_trips_ndjson() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("export-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    for index in 0..3u64 {
+        ledger_writer
+            .append_async(LedgerEvent::Pty(PtyEvent {
+                agent_id: format!("agent-{index}"),
+                event_name: Some("STATUS".to_string()),
+                timestamp_ms: index,
+            }))
+            .await
+            .expect("append pty");
+    }
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let epoch = ledger_writer.epoch_id();
+    let original = ledger_reader.read_epoch(&epoch).expect("read epoch");
+
+    let mut ndjson = Vec::new();
+    let exported = ledger_reader
+        .export_epoch(&epoch, ExportFormat::Ndjson, &mut ndjson)
+        .expect("export ndjson");
+    assert_eq!(exported, 3);
+
+    let parsed: Vec<EventEnvelope> = String::from_utf8(ndjson)
+        .expect("utf8 export")
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("parse envelope"))
+        .collect();
+    assert_eq!(parsed.len(), original.len());
+    for (left, right) in parsed.iter().zip(original.iter()) {
+        assert_eq!(left.sequence, right.sequence);
+        assert_eq!(left.hash_chain, right.hash_chain);
+        assert_eq!(left.metadata.agent_id, right.metadata.agent_id);
+    }
+
+    let mut csv = Vec::new();
+    ledger_reader
+        .export_epoch(&epoch, ExportFormat::Csv, &mut csv)
+        .expect("export csv");
+    let csv = String::from_utf8(csv).expect("utf8 csv");
+    let mut rows = csv.lines();
+    assert_eq!(
+        rows.next(),
+        Some("sequence,wall_millis,counter,kind,agent_id,territory_id,priority,payload_digest")
+    );
+    let first = rows.next().expect("first csv row");
+    assert!(first.starts_with("1,"));
+    assert!(first.contains(",pty,agent-0,,,"));
+    assert_eq!(rows.count(), 2);
+}
+
+#[tokio::test]
+async fn reopened_epoch_resumes_hash_chain() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("reopen-test".to_string());
+    let pty_event = |index: u64| {
+        LedgerEvent::Pty(PtyEvent {
+            agent_id: format!("agent-{index}"),
+            event_name: Some("STATUS".to_string()),
+            timestamp_ms: index,
+        })
+    };
+
+    {
+        let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+        for index in 0..3u64 {
+            ledger_writer
+                .append_async(pty_event(index))
+                .await
+                .expect("append pty");
+        }
+        ledger_writer.flush().await.expect("flush ledger");
+    }
+
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("reopened ledger writer");
+    let resumed = ledger_writer
+        .append_async(pty_event(3))
+        .await
+        .expect("append after reopen");
+    assert_eq!(resumed.sequence, 4);
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+    let events = ledger_reader.read_epoch("reopen-test").expect("read epoch");
+    let sequences: Vec<u64> = events.iter().map(|event| event.sequence).collect();
+    assert_eq!(sequences, vec![1, 2, 3, 4]);
+    assert!(ledger_reader
+        .verify_epoch("reopen-test")
+        .expect("verify epoch"));
+}
+
+#[tokio::test]
+async fn restarted_writer_continues_tail_segment() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("restart-test".to_string());
+    ledger_config.segment_size_bytes = 1024;
+    let health_event = |index: u64| {
+        LedgerEvent::Health(HealthEvent {
+            severity: "info".to_string(),
+            message: format!("heartbeat {index}"),
+            timestamp_ms: index,
+        })
+    };
+
+    let segment_before_restart = {
+        let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+        for index in 0..6u64 {
+            ledger_writer
+                .append_async(health_event(index))
+                .await
+                .expect("append before restart");
+        }
+        ledger_writer.flush().await.expect("flush ledger");
+        ledger_writer.segment_index()
+    };
+    assert!(segment_before_restart >= 1);
+
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("restarted ledger writer");
+    assert_eq!(ledger_writer.segment_index(), segment_before_restart);
+    for index in 6..12u64 {
+        ledger_writer
+            .append_async(health_event(index))
+            .await
+            .expect("append after restart");
+    }
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+    let events = ledger_reader
+        .read_epoch("restart-test")
+        .expect("read epoch");
+    let sequences: Vec<u64> = events.iter().map(|event| event.sequence).collect();
+    assert_eq!(sequences, (1..=12).collect::<Vec<u64>>());
+    assert!(events.windows(2).all(|pair| {
+        let left = &pair[0].logical_clock;
+        let right = &pair[1].logical_clock;
+        (left.wall_millis, left.counter) < (right.wall_millis, right.counter)
+    }));
+    assert!(ledger_reader
+        .verify_epoch("restart-test")
+        .expect("verify epoch"));
+}
+
+#[tokio::test]
+async fn ledger_stats_reports_counts_and_sizes() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("stats-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let events = vec![
+        LedgerEvent::Pty(PtyEvent {
+            agent_id: "agent-a".to_string(),
+            event_name: None,
+            timestamp_ms: 1,
+        }),
+        LedgerEvent::Pty(PtyEvent {
+            agent_id: "agent-b".to_string(),
+            event_name: None,
+            timestamp_ms: 2,
+        }),
+        LedgerEvent::Health(HealthEvent {
+            severity: "warning".to_string(),
+            message: "queue depth".to_string(),
+            timestamp_ms: 3,
+        }),
+        LedgerEvent::Lease(LeaseEvent::Granted(LeaseRecord {
+            lease_id: 7,
+            resource_id: "resource".to_string(),
+            holder_id: "agent-a".to_string(),
+            priority: Priority::Blocking.as_str().to_string(),
+            held_for_ms: None,
+        })),
+    ];
+    let mut expected_bytes = 0u64;
+    let mut appended = Vec::new();
+    for event in events {
+        let envelope = ledger_writer.append_async(event).await.expect("append");
+        expected_bytes += serde_json::to_vec(&envelope).expect("serialize").len() as u64 + 1;
+        appended.push(envelope);
+    }
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let stats = ledger_reader
+        .epoch_stats(&ledger_writer.epoch_id())
+        .expect("epoch stats");
+    assert_eq!(stats.event_count, 4);
+    assert_eq!(stats.segment_count, 1);
+    assert_eq!(stats.total_bytes, expected_bytes);
+    assert_eq!(stats.events_by_kind.get(&LedgerEventKind::Pty), Some(&2));
+    assert_eq!(stats.events_by_kind.get(&LedgerEventKind::Health), Some(&1));
+    assert_eq!(stats.events_by_kind.get(&LedgerEventKind::Lease), Some(&1));
+    assert_eq!(stats.events_by_kind.get(&LedgerEventKind::Router), None);
+    let earliest = stats.earliest_clock.expect("earliest clock");
+    let latest = stats.latest_clock.expect("latest clock");
+    assert_eq!(earliest.wall_millis, appended[0].logical_clock.wall_millis);
+    assert_eq!(latest.wall_millis, appended[3].logical_clock.wall_millis);
+    assert_eq!(latest.counter, appended[3].logical_clock.counter);
+}
+
+#[tokio::test]
+async fn replay_epochs_merges_state_across_epochs() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut epoch_ids = Vec::new();
+    let mut expected_dispatched = 0u64;
+    let mut expected_deferrals = 0u64;
+    for (epoch, resources) in [
+        ("epoch-a", vec!["alpha", "beta"]),
+        ("epoch-b", vec!["gamma"]),
+    ] {
+        let mut ledger_config = LedgerConfig::default();
+        ledger_config.root_path = temp_dir.path().to_path_buf();
+        ledger_config.current_epoch = Some(epoch.to_string());
+        let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+        for (idx, resource) in resources.iter().enumerate() {
+            ledger_writer
+                .append_async(LedgerEvent::Router(RouterEvent::Dispatched(
+                    RouterDispatchRecord {
+                        message_id: None,
+                        trace_id: None,
+                        content_digest: None,
+                        sender: "agent-a".to_string(),
+                        recipient: "agent-b".to_string(),
+                        priority: Priority::Coordinate.as_str().to_string(),
+                        effective_priority: Priority::Coordinate.as_str().to_string(),
+                        wait_time_ms: 0,
+                        queue_depths: vec![0; 5],
+                        aging_boosts: 0,
+                        retry_count: 0,
+                    },
+                )))
+                .await
+                .expect("append dispatch");
+            ledger_writer
+                .append_async(LedgerEvent::Lease(LeaseEvent::Granted(LeaseRecord {
+                    lease_id: idx as u64,
+                    resource_id: resource.to_string(),
+                    holder_id: "agent-a".to_string(),
+                    priority: Priority::Coordinate.as_str().to_string(),
+                    held_for_ms: None,
+                })))
+                .await
+                .expect("append lease");
+            ledger_writer
+                .append_async(LedgerEvent::Lease(LeaseEvent::Deferred(LeaseQueueRecord {
+                    request_id: format!("{epoch}-{idx}"),
+                    agent_id: "agent-b".to_string(),
+                    resource_id: resource.to_string(),
+                    queue_position: 0,
+                    grace_deadline_ms: None,
+                    priority: None,
+                })))
+                .await
+                .expect("append deferral");
+            expected_dispatched += 1;
+            expected_deferrals += 1;
+        }
+        ledger_writer.flush().await.expect("flush ledger");
+        epoch_ids.push(epoch.to_string());
+        time::sleep(Duration::from_millis(5)).await;
+    }
+    epoch_ids.reverse();
+
+    let coordinator = ReplayCoordinator::new(LedgerReader::new(temp_dir.path().to_path_buf()));
+    let first = coordinator.replay_epoch("epoch-a").expect("replay epoch a");
+    let second = coordinator.replay_epoch("epoch-b").expect("replay epoch b");
+    let merged = coordinator
+        .replay_epochs(&epoch_ids)
+        .expect("replay epochs");
+
+    assert_eq!(
+        merged.router.total_dispatched,
+        first.router.total_dispatched + second.router.total_dispatched
+    );
+    assert_eq!(merged.router.total_dispatched, expected_dispatched);
+    assert_eq!(
+        merged.leases.deferrals,
+        first.leases.deferrals + second.leases.deferrals
+    );
+    assert_eq!(merged.leases.deferrals, expected_deferrals);
+    assert_eq!(
+        merged.leases.active.len(),
+        first.leases.active.len() + second.leases.active.len()
+    );
+    assert_eq!(merged.tail_hash, second.tail_hash);
+    assert_eq!(merged.last_sequence, second.last_sequence);
+}
+
+#[tokio::test]
+async fn encrypted_ledger_requires_key_to_read() {
+    let temp_dir = tempdir().expect("temp dir");
+    let key = [7u8; 32];
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("encrypted-test".to_string());
+    ledger_config.encryption_key = Some(key);
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+
+    for idx in 0..3 {
+        ledger_writer
+            .append_async(LedgerEvent::Pty(PtyEvent {
+                agent_id: "secret-agent".to_string(),
+                event_name: Some(format!("prompt-{idx}")),
+                timestamp_ms: idx,
+            }))
+            .await
+            .expect("append");
+    }
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let segment = temp_dir
+        .path()
+        .join("encrypted-test")
+        .join("segment_0000.log");
+    let raw = std::fs::read_to_string(segment).expect("read segment");
+    assert!(!raw.contains("secret-agent"));
+
+    let unkeyed = LedgerReader::new(ledger_config.root_path.clone());
+    let err = unkeyed
+        .read_epoch("encrypted-test")
+        .expect_err("unkeyed read should fail");
+    assert!(matches!(err, LedgerError::Decrypt(_)));
+
+    let wrong_key =
+        LedgerReader::with_encryption_key(ledger_config.root_path.clone(), Some([1u8; 32]));
+    assert!(matches!(
+        wrong_key.verify_epoch("encrypted-test"),
+        Err(LedgerError::Decrypt(_))
+    ));
+
+    let keyed = LedgerReader::with_encryption_key(ledger_config.root_path.clone(), Some(key));
+    let events = keyed.read_epoch("encrypted-test").expect("keyed read");
+    assert_eq!(events.len(), 3);
+    assert!(keyed.verify_epoch("encrypted-test").expect("verify"));
+}
+
+#[tokio::test]
+async fn ledger_window_selects_events_by_wall_clock() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("window-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let mut clocks = Vec::new();
+    for idx in 0..5 {
+        let envelope = ledger_writer
+            .append_async(LedgerEvent::Pty(PtyEvent {
+                agent_id: format!("agent-{idx}"),
+                event_name: None,
+                timestamp_ms: idx,
+            }))
+            .await
+            .expect("append");
+        clocks.push(envelope.logical_clock.wall_millis);
+        time::sleep(Duration::from_millis(5)).await;
+    }
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let window = ledger_reader
+        .read_window("window-test", Some(clocks[1]), Some(clocks[3]), None)
+        .expect("window");
+    let sequences: Vec<u64> = window.iter().map(|envelope| envelope.sequence).collect();
+    assert_eq!(sequences, vec![2, 3, 4]);
+
+    let limited = ledger_reader
+        .read_window("window-test", Some(clocks[1]), Some(clocks[3]), Some(2))
+        .expect("limited window");
+    let sequences: Vec<u64> = limited.iter().map(|envelope| envelope.sequence).collect();
+    assert_eq!(sequences, vec![3, 4]);
+
+    let open_ended = ledger_reader
+        .read_window("window-test", Some(clocks[4]), None, None)
+        .expect("open window");
+    assert_eq!(open_ended.len(), 1);
+    assert_eq!(open_ended[0].sequence, 5);
+}
+
+#[tokio::test]
+async fn replay_links_override_to_authorizing_quorum() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("override-correlation-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let metrics = MetricsCollector::new();
+    let territory =
+        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
+    let resource = "contested-resource".to_string();
+
+    let granted = territory
+        .acquire_lease(LeaseRequest::new(
+            "low-priority".to_string(),
+            resource.clone(),
+            Priority::Info,
+        ))
+        .await;
+    assert!(matches!(granted, LeaseDecision::Granted(_)));
+    let overridden = territory
+        .acquire_lease(LeaseRequest::new(
+            "director".to_string(),
+            resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+    let lease_id = match overridden {
+        LeaseDecision::Overridden { lease, .. } => lease.lease_id.as_u64(),
+        other => panic!("expected override, got {other:?}"),
+    };
+
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+
+    let events = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger");
+    let commit_topic = events.iter().find_map(|envelope| match &envelope.event {
+        LedgerEvent::Consensus(ConsensusEvent::Commit(signal)) => Some(signal.topic.clone()),
+        _ => None,
+    });
+    let override_trace = events.iter().find_map(|envelope| match &envelope.event {
+        LedgerEvent::Lease(LeaseEvent::Overridden { .. }) => envelope.metadata.trace_id.clone(),
+        _ => None,
+    });
+    assert_eq!(commit_topic, Some(lease_trace_id(lease_id)));
+    assert_eq!(override_trace, commit_topic);
+
+    let outcome = ReplayCoordinator::new(ledger_reader)
+        .replay_epoch(&ledger_writer.epoch_id())
+        .expect("replay");
+    assert_eq!(outcome.overrides.len(), 1);
+    let link = &outcome.overrides[0];
+    assert_eq!(link.trace_id, lease_trace_id(lease_id));
+    assert_eq!(link.lease.holder_id, "director");
+    assert_eq!(link.previous.holder_id, "low-priority");
+    let quorum = link.quorum.as_ref().expect("linked quorum");
+    assert_eq!(quorum.resource_id, resource);
+    assert_eq!(quorum.reason, "override");
+}
+
+#[tokio::test]
+async fn router_drops_duplicate_message_ids() {
+    let metrics = MetricsCollector::new();
+    let config = DispatcherConfig {
+        idle_backoff: Duration::from_millis(5),
+        ..DispatcherConfig::default()
+    };
+    let router = Arc::new(UnifiedMessageRouter::with_config(metrics.clone(), config));
+    let mut deliveries = router.subscribe();
+
+    let attempt = |content: &str| Message {
+        content: content.to_string(),
+        priority: Priority::Coordinate,
+        sender: "producer".to_string(),
+        recipient: "consumer".to_string(),
+        message_id: Some("msg-42".to_string()),
+        trace_id: None,
+    };
+    router.route_message(attempt("attempt_0")).await.unwrap();
+    assert_eq!(
+        router.route_message(attempt("attempt_1")).await,
+        Err(RouteError::Duplicate {
+            message_id: "msg-42".to_string(),
+        })
+    );
+    router
+        .route_message(Message {
+            content: "distinct".to_string(),
+            priority: Priority::Coordinate,
+            sender: "producer".to_string(),
+            recipient: "consumer".to_string(),
+            message_id: Some("msg-43".to_string()),
+            trace_id: None,
+        })
+        .await
+        .unwrap();
+
+    let first = time::timeout(Duration::from_millis(200), deliveries.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    let second = time::timeout(Duration::from_millis(200), deliveries.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.message.content, "attempt_0");
+    assert_eq!(second.message.content, "distinct");
+    let extra = time::timeout(Duration::from_millis(50), deliveries.recv()).await;
+    assert!(extra.is_err());
+    assert_eq!(metrics.get_snapshot().router.deduplicated_messages, 1);
+}
+
+#[tokio::test]
+async fn content_digest_dedup_is_opt_in() {
+    let message = Message {
+        content: "heartbeat".to_string(),
+        priority: Priority::Info,
+        sender: "producer".to_string(),
+        recipient: "consumer".to_string(),
+        message_id: None,
+        trace_id: None,
+    };
+    for (dedup_content_digest, expected) in [(false, 2), (true, 1)] {
+        let metrics = MetricsCollector::new();
+        let config = DispatcherConfig {
+            idle_backoff: Duration::from_millis(5),
+            dedup_content_digest,
+            ..DispatcherConfig::default()
+        };
+        let router = UnifiedMessageRouter::with_config(metrics.clone(), config);
+        let mut deliveries = router.subscribe();
+        router.route_message(message.clone()).await.unwrap();
+        let duplicate = router.route_message(message.clone()).await;
+        assert_eq!(duplicate.is_err(), expected == 1);
+
+        let mut delivered = 0;
+        while time::timeout(Duration::from_millis(100), deliveries.recv())
+            .await
+            .is_ok()
+        {
+            delivered += 1;
+        }
+        assert_eq!(delivered, expected);
+        assert_eq!(
+            metrics.get_snapshot().router.deduplicated_messages,
+            2 - expected as u64
+        );
+    }
+}
+
+#[tokio::test]
+async fn try_route_message_reports_near_capacity() {
+    let metrics = MetricsCollector::new();
+    let config = DispatcherConfig {
+        token_capacity: 0.0,
+        token_refill_rate: 0.0,
+        initial_tokens: 0.0,
+        idle_backoff: Duration::from_millis(5),
+        queue_depth_warning: 3,
+        queue_depth_critical: 10,
+        ..DispatcherConfig::default()
+    };
+    let router = UnifiedMessageRouter::with_config(metrics, config);
+
+    let mut outcomes = Vec::new();
+    for index in 0..6 {
+        let outcome = router
+            .try_route_message(Message {
+                content: format!("backlog_{index}"),
+                priority: Priority::Info,
+                sender: "starved".to_string(),
+                recipient: "peer".to_string(),
+                message_id: None,
+                trace_id: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(outcome.priority, Priority::Info);
+        assert!(!outcome.critical);
+        outcomes.push(outcome);
+    }
+
+    assert!(!outcomes[0].near_capacity);
+    assert!(!outcomes[1].near_capacity);
+    assert!(outcomes[5].near_capacity);
+    assert!(outcomes[5].queue_depth >= 3);
+    let first_near = outcomes
+        .iter()
+        .position(|outcome| outcome.near_capacity)
+        .unwrap();
+    assert!(outcomes[first_near..]
+        .iter()
+        .all(|outcome| outcome.near_capacity));
+}
+
+#[tokio::test]
+async fn transfer_lease_with_quorum_requires_agreement() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("quorum-transfer-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let metrics = MetricsCollector::new();
+    let territory =
+        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
+    let resource = "sensitive-resource".to_string();
+    let granted = territory
+        .acquire_lease(LeaseRequest::new(
+            "owner".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    let lease_id = match granted {
+        LeaseDecision::Granted(lease) => lease.lease_id.as_u64(),
+        other => panic!("expected grant, got {other:?}"),
+    };
+    let request = TransferRequest {
+        from_agent: "owner".to_string(),
+        to_agent: "successor".to_string(),
+        resource_id: resource.clone(),
+        new_priority: None,
+    };
+    let mut territory_events = territory.subscribe();
+
+    let rejected = territory
+        .transfer_lease_with_quorum(
+            request.clone(),
+            vec![
+                quorum_vote("owner", 1.0, true),
+                quorum_vote("reviewer", 3.0, false),
+            ],
+        )
+        .await;
+    assert!(matches!(rejected, TransferDecision::Rejected));
+    assert_eq!(metrics.get_snapshot().consensus.failure, 1);
+    let holder = territory.current_lease(&resource).await.expect("lease");
+    assert_eq!(holder.holder_id, "owner");
+
+    let transferred = territory
+        .transfer_lease_with_quorum(
+            request,
+            vec![
+                quorum_vote("owner", 3.0, true),
+                quorum_vote("reviewer", 1.0, false),
+            ],
+        )
+        .await;
+    assert!(matches!(transferred, TransferDecision::Transferred { .. }));
+    assert_eq!(metrics.get_snapshot().consensus.success, 1);
+    let holder = territory.current_lease(&resource).await.expect("lease");
+    assert_eq!(holder.holder_id, "successor");
+    let mut overridden = Vec::new();
+    while let Ok(event) = territory_events.try_recv() {
+        match event {
+            TerritoryEvent::Overridden { previous, lease } => {
+                overridden.push((previous.holder_id, lease.holder_id));
+            }
+            TerritoryEvent::Transferred { .. } => panic!("quorum transfer emitted Transferred"),
+            _ => {}
+        }
+    }
+    assert_eq!(
+        overridden,
+        vec![("owner".to_string(), "successor".to_string())]
+    );
+
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+    let events = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger");
+    let commits: Vec<bool> = events
+        .iter()
+        .filter_map(|envelope| match &envelope.event {
+            LedgerEvent::Consensus(ConsensusEvent::Commit(signal)) => {
+                assert_eq!(signal.topic, lease_trace_id(lease_id));
+                signal.vector.as_ref().map(|vector| vector.achieved)
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(commits, vec![false, true]);
+}
+
+#[tokio::test]
+async fn ledger_records_transfer_distinct_from_override() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("transfer-event-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+
+    let metrics = MetricsCollector::new();
+    let territory =
+        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
+    let resource = "handoff-resource".to_string();
+    let granted = territory
+        .acquire_lease(LeaseRequest::new(
+            "giver".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(granted, LeaseDecision::Granted(_)));
+    let transferred = territory
+        .transfer_lease(TransferRequest {
+            from_agent: "giver".to_string(),
+            to_agent: "receiver".to_string(),
+            resource_id: resource.clone(),
+            new_priority: None,
+        })
+        .await;
+    assert!(matches!(transferred, TransferDecision::Transferred { .. }));
+
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+    let events = ledger_reader
+        .read_epoch(&ledger_writer.epoch_id())
+        .expect("read ledger");
+    let mut transfers = 0;
+    for envelope in events.iter() {
+        match &envelope.event {
+            LedgerEvent::Lease(LeaseEvent::Transferred { from, to, lease }) => {
+                assert_eq!(from, "giver");
+                assert_eq!(to, "receiver");
+                assert_eq!(lease.holder_id, "receiver");
+                transfers += 1;
+            }
+            LedgerEvent::Lease(LeaseEvent::Overridden { .. }) => {
+                panic!("transfer must not be recorded as an override")
+            }
+            _ => {}
+        }
+    }
+    assert_eq!(transfers, 1);
+
+    let snapshot = metrics.get_snapshot();
+    assert_eq!(snapshot.leases.transfers, 1);
+    assert_eq!(snapshot.leases.overrides, 0);
+
+    let outcome = ReplayCoordinator::new(ledger_reader)
+        .replay_epoch(&ledger_writer.epoch_id())
+        .expect("replay");
+    assert_eq!(outcome.leases.transfers, 1);
+    assert_eq!(outcome.leases.overrides, 0);
+    assert_eq!(
+        outcome
+            .leases
+            .active
+            .get(&resource)
+            .map(|record| record.holder_id.as_str()),
+        Some("receiver")
+    );
+}
+
+async fn contend_with_override_policy(
+    override_policy: OverridePolicy,
+    holder_priority: Priority,
+    challenger_priority: Priority,
+) -> (LeaseDecision, String) {
+    let mut policy = TerritoryPolicy::default();
+    policy.override_policy = override_policy;
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    let manager = build_manager_with_policy(policy);
+    let resource = "contested.json".to_string();
+
+    let base = manager
+        .acquire_lease(LeaseRequest::new(
+            "Holder".to_string(),
+            resource.clone(),
+            holder_priority,
+        ))
+        .await;
+    assert!(matches!(base, LeaseDecision::Granted(_)));
+    let decision = manager
+        .acquire_lease(LeaseRequest::new(
+            "Challenger".to_string(),
+            resource.clone(),
+            challenger_priority,
+        ))
+        .await;
+    let holder = manager.current_lease(&resource).await.unwrap().holder_id;
+    (decision, holder)
+}
+
+#[tokio::test]
+async fn override_policy_never_always_queues() {
+    let (decision, holder) =
+        contend_with_override_policy(OverridePolicy::Never, Priority::Info, Priority::Critical)
+            .await;
+    assert!(matches!(decision, LeaseDecision::Queued(_)));
+    assert_eq!(holder, "Holder");
+}
+
+#[tokio::test]
+async fn director_override_preempts_regardless_of_policy() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("director-override-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let metrics = MetricsCollector::new();
+    let mut policy = TerritoryPolicy::default();
+    policy.override_policy = OverridePolicy::PriorityDelta(2);
+    let manager = TerritoryManager::with_policy_and_ledger(
+        metrics.clone(),
+        policy,
+        Some(ledger_writer.clone()),
+    );
+    let resource = "incident.json".to_string();
+
+    manager
+        .acquire_lease(LeaseRequest::new(
+            "Holder".to_string(),
+            resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+    let decision = manager
+        .acquire_lease(LeaseRequest::new(
+            "Operator".to_string(),
+            resource.clone(),
+            Priority::DirectorOverride,
+        ))
+        .await;
+    assert!(matches!(decision, LeaseDecision::Overridden { .. }));
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        "Operator"
+    );
+    let leases = metrics.get_snapshot().leases;
+    assert_eq!(leases.director_overrides, 1);
+    assert_eq!(leases.overrides, 1);
+    assert!(leases.rejections.is_empty());
+
+    time::sleep(Duration::from_millis(50)).await;
+    ledger_writer.flush().await.expect("flush ledger");
+    let outcome = ReplayCoordinator::new(LedgerReader::new(ledger_config.root_path.clone()))
+        .replay_epoch("director-override-test")
+        .expect("replay epoch");
+    assert_eq!(outcome.leases.director_overrides, 1);
+    assert_eq!(outcome.leases.overrides, 1);
+    let quorum = outcome.overrides[0]
+        .quorum
+        .as_ref()
+        .expect("override linked to its decision");
+    assert!(quorum.achieved);
+    assert_eq!(quorum.reason, DIRECTOR_OVERRIDE_REASON);
+    assert!(quorum.votes.is_empty());
+}
+
+#[tokio::test]
+async fn blocked_override_is_counted_as_a_rejection() {
+    let metrics = MetricsCollector::new();
+    let mut policy = TerritoryPolicy::default();
+    policy.override_policy = OverridePolicy::Never;
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    let manager = TerritoryManager::with_policy(metrics.clone(), policy);
+    let resource = "contested.json".to_string();
+
+    manager
+        .acquire_lease(LeaseRequest::new(
+            "Holder".to_string(),
+            resource.clone(),
+            Priority::Info,
+        ))
+        .await;
+    manager
+        .acquire_lease(LeaseRequest::new(
+            "Peer".to_string(),
+            resource.clone(),
+            Priority::Info,
+        ))
+        .await;
+    assert!(metrics.get_snapshot().leases.rejections.is_empty());
+
+    let decision = manager
+        .acquire_lease(LeaseRequest::new(
+            "Challenger".to_string(),
+            resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+    assert!(matches!(decision, LeaseDecision::Queued(_)));
+    let rejections = metrics.get_snapshot().leases.rejections;
+    assert_eq!(rejections.get("overrideDisabled"), Some(&1));
+    assert_eq!(rejections.len(), 1);
+}
+
+#[tokio::test]
+async fn override_policy_priority_delta_respects_band_gap() {
+    let (decision, holder) = contend_with_override_policy(
+        OverridePolicy::PriorityDelta(2),
+        Priority::Coordinate,
+        Priority::Blocking,
+    )
+    .await;
+    assert!(matches!(decision, LeaseDecision::Queued(_)));
+    assert_eq!(holder, "Holder");
+
+    let (decision, holder) = contend_with_override_policy(
+        OverridePolicy::PriorityDelta(2),
+        Priority::Coordinate,
+        Priority::Critical,
+    )
+    .await;
+    assert!(matches!(decision, LeaseDecision::Overridden { .. }));
+    assert_eq!(holder, "Challenger");
+}
+
+#[tokio::test]
+async fn override_policy_require_quorum_needs_weighted_agreement() {
+    let (decision, holder) = contend_with_override_policy(
+        OverridePolicy::RequireQuorum,
+        Priority::Blocking,
+        Priority::Critical,
+    )
+    .await;
+    assert!(matches!(decision, LeaseDecision::Queued(_)));
+    assert_eq!(holder, "Holder");
+
+    let (decision, holder) = contend_with_override_policy(
+        OverridePolicy::RequireQuorum,
+        Priority::Info,
+        Priority::Critical,
+    )
+    .await;
+    assert!(matches!(decision, LeaseDecision::Overridden { .. }));
+    assert_eq!(holder, "Challenger");
+}
+
+#[tokio::test]
+async fn require_quorum_uses_the_broker_threshold_for_the_resource() {
+    let temp_dir = tempdir().expect("temp dir");
+    let mut ledger_config = LedgerConfig::default();
+    ledger_config.root_path = temp_dir.path().to_path_buf();
+    ledger_config.current_epoch = Some("quorum-threshold-test".to_string());
+    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
+    let mut policy = TerritoryPolicy::default();
+    policy.override_policy = OverridePolicy::RequireQuorum;
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    let manager = TerritoryManager::with_policy_and_ledger(
+        MetricsCollector::new(),
+        policy,
+        Some(ledger_writer),
+    );
+    let broker = manager.consensus().expect("ledger-backed broker");
+    let resource = "contested.json".to_string();
+    broker.set_resource_threshold(&resource, 0.9);
+
+    manager
+        .acquire_lease(LeaseRequest::new(
+            "Holder".to_string(),
+            resource.clone(),
+            Priority::Info,
+        ))
+        .await;
+    let request = LeaseRequest::new(
+        "Challenger".to_string(),
+        resource.clone(),
+        Priority::Critical,
+    );
+    assert!(matches!(
+        manager.would_acquire(&request).await,
+        LeaseDecision::Queued(_)
+    ));
+    let decision = manager.acquire_lease(request).await;
+    assert!(matches!(decision, LeaseDecision::Queued(_)));
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        "Holder"
+    );
+    let recorded = &broker.recent_decisions(1)[0];
+    assert!((recorded.threshold - 0.9).abs() < f32::EPSILON);
+    assert!(!recorded.achieved);
+}
+
+#[tokio::test]
+async fn holder_progress_weights_quorum_against_preemption() {
+    let (decision, holder) = contend_with_override_policy(
+        OverridePolicy::RequireQuorum,
+        Priority::Coordinate,
+        Priority::Critical,
+    )
+    .await;
+    assert!(matches!(decision, LeaseDecision::Overridden { .. }));
+    assert_eq!(holder, "Challenger");
+
     let mut policy = TerritoryPolicy::default();
-    policy.default_lease_duration = Duration::from_millis(100);
-    policy.max_lease_duration = Duration::from_millis(100);
-    policy.auto_extend_threshold = Duration::from_secs(5);
+    policy.override_policy = OverridePolicy::RequireQuorum;
+    policy.auto_extend_threshold = Duration::from_millis(1);
     let manager = build_manager_with_policy(policy);
-    let resource = "doc.md".to_string();
-
-    let first = manager
+    let resource = "contested.json".to_string();
+    let base = manager
         .acquire_lease(LeaseRequest::new(
             "Holder".to_string(),
             resource.clone(),
             Priority::Coordinate,
         ))
         .await;
-    assert!(matches!(first, LeaseDecision::Granted(_)));
+    assert!(matches!(base, LeaseDecision::Granted(_)));
+    manager
+        .update_progress(&resource, &"Holder".to_string(), 0.95)
+        .await
+        .expect("holder progress");
 
-    let second = manager
+    let decision = manager
         .acquire_lease(LeaseRequest::new(
-            "Contender".to_string(),
+            "Challenger".to_string(),
             resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+    assert!(matches!(decision, LeaseDecision::Queued(_)));
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        "Holder"
+    );
+}
+
+#[tokio::test]
+async fn grants_are_attributed_to_their_originating_request() {
+    let mut policy = TerritoryPolicy::default();
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    policy.escalation_queue_threshold = 10;
+    let manager = Arc::new(TerritoryManager::with_policy(
+        MetricsCollector::new(),
+        policy,
+    ));
+    let mut events = manager.subscribe();
+
+    let mut request_ids = Vec::new();
+    for (holder, resource) in [("holder-1", "first.rs"), ("holder-2", "second.rs")] {
+        manager
+            .acquire_lease(LeaseRequest::new(
+                holder.into(),
+                resource.into(),
+                Priority::Coordinate,
+            ))
+            .await;
+        let LeaseDecision::Queued(handle) = manager
+            .acquire_lease(LeaseRequest::new(
+                "agent-a".into(),
+                resource.into(),
+                Priority::Coordinate,
+            ))
+            .await
+        else {
+            panic!("expected {resource} to be queued");
+        };
+        request_ids.push(handle.request_id);
+    }
+    assert_ne!(request_ids[0], request_ids[1]);
+
+    let waiters: Vec<_> = request_ids
+        .iter()
+        .map(|request_id| {
+            let manager = Arc::clone(&manager);
+            let request_id = *request_id;
+            tokio::spawn(async move { manager.wait_for_grant(request_id).await })
+        })
+        .collect();
+    tokio::task::yield_now().await;
+
+    manager
+        .release_lease(&"holder-2".into(), &"second.rs".into())
+        .await
+        .expect("release second");
+    manager
+        .release_lease(&"holder-1".into(), &"first.rs".into())
+        .await
+        .expect("release first");
+
+    let mut granted = Vec::new();
+    for waiter in waiters {
+        let snapshot = time::timeout(Duration::from_millis(500), waiter)
+            .await
+            .expect("grant before timeout")
+            .expect("waiter task")
+            .expect("request granted");
+        granted.push((snapshot.request_id, snapshot.resource_id));
+    }
+    assert_eq!(
+        granted,
+        vec![
+            (request_ids[0], "first.rs".to_string()),
+            (request_ids[1], "second.rs".to_string()),
+        ]
+    );
+
+    let mut event_grants = Vec::new();
+    while let Ok(event) = events.try_recv() {
+        if let TerritoryEvent::Granted(snapshot) = event {
+            if snapshot.holder_id == "agent-a" {
+                event_grants.push((snapshot.request_id, snapshot.resource_id));
+            }
+        }
+    }
+    assert_eq!(
+        event_grants,
+        vec![
+            (request_ids[1], "second.rs".to_string()),
+            (request_ids[0], "first.rs".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn wait_for_grant_returns_none_once_request_leaves_queue() {
+    let manager = Arc::new(build_manager_with_policy(TerritoryPolicy::default()));
+    manager
+        .acquire_lease(LeaseRequest::new(
+            "holder".into(),
+            "shared.rs".into(),
             Priority::Coordinate,
         ))
         .await;
-    assert!(matches!(second, LeaseDecision::Deferred { .. }));
+    let LeaseDecision::Queued(handle) = manager
+        .acquire_lease(LeaseRequest::new(
+            "waiter".into(),
+            "shared.rs".into(),
+            Priority::Coordinate,
+        ))
+        .await
+    else {
+        panic!("expected waiter to queue");
+    };
+    let waiter = {
+        let manager = Arc::clone(&manager);
+        tokio::spawn(async move { manager.wait_for_grant(handle.request_id).await })
+    };
+    tokio::task::yield_now().await;
+
+    manager.release_all_for_agent(&"waiter".into()).await;
+
+    let outcome = time::timeout(Duration::from_millis(500), waiter)
+        .await
+        .expect("waiter resolves once request is withdrawn")
+        .expect("waiter task");
+    assert!(outcome.is_none());
+    assert_eq!(
+        manager
+            .current_lease(&"shared.rs".into())
+            .await
+            .unwrap()
+            .holder_id,
+        "holder"
+    );
 }
 
 #[tokio::test]
-async fn territory_overrides_on_priority_delta() {
+async fn dry_run_acquire_matches_real_outcome() {
     let mut policy = TerritoryPolicy::default();
-    policy.override_priority_delta = 1;
     policy.auto_extend_threshold = Duration::from_millis(1);
+    policy.override_policy = OverridePolicy::PriorityDelta(2);
+    policy.escalation_queue_threshold = 10;
     let manager = build_manager_with_policy(policy);
-    let resource = "plan.json".to_string();
+    let mut events = manager.subscribe();
+    let resource: String = "plan.rs".into();
 
-    let base = manager
+    let first = LeaseRequest::new("holder".into(), resource.clone(), Priority::Info);
+    let preview = manager.would_acquire(&first).await;
+    assert!(events.try_recv().is_err());
+    assert!(manager.current_lease(&resource).await.is_none());
+    let LeaseDecision::Granted(previewed) = preview else {
+        panic!("expected a granted preview, got {preview:?}");
+    };
+    let LeaseDecision::Granted(granted) = manager.acquire_lease(first).await else {
+        panic!("expected the real acquire to be granted");
+    };
+    assert_eq!(previewed.holder_id, granted.holder_id);
+    assert_eq!(previewed.priority, granted.priority);
+    assert_eq!(previewed.lease_id, LeaseId::PREVIEW);
+    assert_eq!(previewed.request_id, RequestId::PREVIEW);
+    assert_ne!(granted.lease_id, LeaseId::PREVIEW);
+    while events.try_recv().is_ok() {}
+
+    let waiting = LeaseRequest::new("waiter".into(), resource.clone(), Priority::Info);
+    let preview = manager.would_acquire(&waiting).await;
+    assert!(events.try_recv().is_err());
+    assert!(manager.list_queue(&resource).await.is_empty());
+    let LeaseDecision::Queued(previewed) = preview else {
+        panic!("expected a queued preview, got {preview:?}");
+    };
+    let LeaseDecision::Queued(queued) = manager.acquire_lease(waiting).await else {
+        panic!("expected the real acquire to be queued");
+    };
+    assert_eq!(previewed.queue_position, queued.queue_position);
+    assert_eq!(previewed.request_id, RequestId::PREVIEW);
+    while events.try_recv().is_ok() {}
+
+    let urgent = LeaseRequest::new("urgent".into(), resource.clone(), Priority::Critical);
+    let preview = manager.would_acquire(&urgent).await;
+    assert!(events.try_recv().is_err());
+    assert_eq!(
+        manager
+            .current_lease(&resource)
+            .await
+            .map(|lease| lease.holder_id),
+        Some("holder".to_string())
+    );
+    assert_eq!(manager.list_queue(&resource).await.len(), 1);
+    let LeaseDecision::Overridden {
+        previous: previewed_previous,
+        lease: previewed_lease,
+    } = preview
+    else {
+        panic!("expected an override preview, got {preview:?}");
+    };
+    let LeaseDecision::Overridden { previous, lease } = manager.acquire_lease(urgent).await else {
+        panic!("expected the real acquire to override");
+    };
+    assert_eq!(previewed_previous.lease_id, previous.lease_id);
+    assert_eq!(previewed_lease.holder_id, lease.holder_id);
+    assert_eq!(previewed_lease.override_count, lease.override_count);
+}
+
+#[tokio::test]
+async fn queued_request_progresses_through_negotiation_rounds() {
+    let mut policy = TerritoryPolicy::default();
+    policy.auto_extend_threshold = Duration::from_secs(1);
+    policy.escalation_queue_threshold = 10;
+    policy.negotiation_timeout = Duration::from_secs(30);
+    policy.negotiation_max_rounds = 2;
+    let clock = MockClock::new();
+    let manager =
+        TerritoryManager::with_policy(MetricsCollector::new(), policy).with_clock(clock.shared());
+    let resource = "negotiated.json".to_string();
+    let mut events = manager.subscribe();
+
+    manager
         .acquire_lease(LeaseRequest::new(
-            "Agent_Low".to_string(),
+            "Holder".to_string(),
             resource.clone(),
             Priority::Coordinate,
         ))
         .await;
-    assert!(matches!(base, LeaseDecision::Granted(_)));
+    let queued = manager
+        .acquire_lease(LeaseRequest::new(
+            "Waiter".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(queued, LeaseDecision::Queued(_)));
 
-    let override_decision = manager
+    let expected = [
+        (NegotiationState::Negotiating, 1),
+        (NegotiationState::Negotiating, 2),
+        (NegotiationState::Escalating, 3),
+    ];
+    for (state, round) in expected {
+        assert!(manager.advance_negotiations().await.is_empty());
+        clock.advance(Duration::from_secs(30));
+        let transitions = manager.advance_negotiations().await;
+        assert_eq!(transitions.len(), 1);
+        assert_eq!(transitions[0].state, state);
+        let queue = manager.list_queue(&resource).await;
+        assert_eq!(queue[0].state, state);
+        assert_eq!(queue[0].negotiation_round, round);
+    }
+    clock.advance(Duration::from_secs(30));
+    assert!(manager.advance_negotiations().await.is_empty());
+
+    let mut rounds = Vec::new();
+    let mut exhausted = false;
+    while let Ok(event) = events.try_recv() {
+        match event {
+            TerritoryEvent::Negotiating { handle, round } => {
+                assert_eq!(handle.agent_id, "Waiter");
+                rounds.push(round);
+            }
+            TerritoryEvent::Escalated {
+                reason: EscalationReason::NegotiationExhausted,
+                ..
+            } => exhausted = true,
+            _ => {}
+        }
+    }
+    assert_eq!(rounds, vec![1, 2]);
+    assert!(exhausted);
+}
+
+#[tokio::test]
+async fn mock_clock_expires_starved_lease_without_sleeping() {
+    let mut policy = TerritoryPolicy::default();
+    policy.default_lease_duration = Duration::from_secs(600);
+    policy.max_lease_duration = Duration::from_secs(600);
+    policy.auto_extend_threshold = Duration::from_secs(1);
+    policy.escalation_queue_threshold = 10;
+    policy.fairness_starvation_threshold = Duration::from_secs(30);
+    policy.fairness_minimum_lease = Duration::from_secs(120);
+    let clock = MockClock::new();
+    let manager =
+        TerritoryManager::with_policy(MetricsCollector::new(), policy).with_clock(clock.shared());
+    let resource = "clocked.json".to_string();
+
+    let grant = manager
         .acquire_lease(LeaseRequest::new(
-            "Agent_High".to_string(),
+            "Hog".to_string(),
             resource.clone(),
-            Priority::Critical,
+            Priority::Coordinate,
         ))
         .await;
-    assert!(matches!(
-        override_decision,
-        LeaseDecision::Overridden { .. }
-    ));
+    let LeaseDecision::Granted(granted) = grant else {
+        panic!("expected grant");
+    };
+    let queued = manager
+        .acquire_lease(LeaseRequest::new(
+            "Waiter".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(queued, LeaseDecision::Queued(_)));
 
+    clock.advance(Duration::from_secs(45));
+    assert!(manager.enforce_fairness().await.is_empty());
+    let shortened = manager.current_lease(&resource).await.unwrap();
+    assert_eq!(shortened.holder_id, "Hog");
+    assert_eq!(
+        shortened.expires_at,
+        granted.granted_at + Duration::from_secs(120)
+    );
+    let queue = manager.list_queue(&resource).await;
+    assert_eq!(queue[0].age, Duration::from_secs(45));
+
+    clock.advance(Duration::from_secs(75));
+    let released = manager.enforce_fairness().await;
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].holder_id, "Hog");
     let holder = manager.current_lease(&resource).await.unwrap();
-    assert_eq!(holder.holder_id, "Agent_High");
-    assert_eq!(holder.priority, Priority::Critical);
+    assert_eq!(holder.holder_id, "Waiter");
+    assert_eq!(
+        holder.granted_at,
+        granted.granted_at + Duration::from_secs(120)
+    );
 }
 
 #[tokio::test]
-async fn territory_escalates_on_queue_pressure() {
+async fn requested_lease_duration_sets_expiry() {
+    let mut policy = TerritoryPolicy::default();
+    policy.default_lease_duration = Duration::from_secs(600);
+    policy.max_lease_duration = Duration::from_secs(1200);
+    policy.auto_extend_threshold = Duration::from_secs(1);
+    policy.escalation_queue_threshold = 10;
+    policy.fairness_starvation_threshold = Duration::from_secs(5);
+    policy.fairness_minimum_lease = Duration::from_secs(60);
+    let clock = MockClock::new();
+    let manager =
+        TerritoryManager::with_policy(MetricsCollector::new(), policy).with_clock(clock.shared());
+
+    let mut expiries = Vec::new();
+    for (resource, duration) in [
+        ("short.json", Some(Duration::from_secs(10))),
+        ("default.json", None),
+        ("long.json", Some(Duration::from_secs(7200))),
+    ] {
+        let mut builder = LeaseRequest::builder("Holder", resource, Priority::Coordinate);
+        if let Some(duration) = duration {
+            builder = builder.duration(duration);
+        }
+        let LeaseDecision::Granted(lease) = manager.acquire_lease(builder.build()).await else {
+            panic!("expected grant for {resource}");
+        };
+        expiries.push(lease.expires_at.duration_since(lease.granted_at));
+        let queued = manager
+            .acquire_lease(LeaseRequest::new(
+                "Waiter".to_string(),
+                resource.to_string(),
+                Priority::Coordinate,
+            ))
+            .await;
+        assert!(matches!(queued, LeaseDecision::Queued(_)));
+    }
+    assert_eq!(
+        expiries,
+        vec![
+            Duration::from_secs(10),
+            Duration::from_secs(600),
+            Duration::from_secs(1200),
+        ]
+    );
+
+    clock.advance(Duration::from_secs(11));
+    let released = manager.enforce_fairness().await;
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].resource_id, "short.json");
+    let short = manager
+        .current_lease(&"short.json".to_string())
+        .await
+        .unwrap();
+    assert_eq!(short.holder_id, "Waiter");
+    let default = manager
+        .current_lease(&"default.json".to_string())
+        .await
+        .unwrap();
+    assert_eq!(default.holder_id, "Holder");
+}
+
+#[tokio::test]
+async fn lagging_territory_stream_reports_gap() {
+    let manager = TerritoryManager::new(MetricsCollector::new(), None);
+    let mut stream = manager.subscribe_stream();
+    let resource = "busy.json".to_string();
+    let holder = "Churner".to_string();
+
+    for _ in 0..150 {
+        let decision = manager
+            .acquire_lease(LeaseRequest::new(
+                holder.clone(),
+                resource.clone(),
+                Priority::Coordinate,
+            ))
+            .await;
+        assert!(matches!(decision, LeaseDecision::Granted(_)));
+        manager.release_lease(&holder, &resource).await.unwrap();
+    }
+
+    assert!(matches!(
+        stream.recv().await,
+        Some(StreamItem::Gap { skipped: 44 })
+    ));
+    let mut delivered = 0;
+    while let Some(item) = stream.try_recv() {
+        assert!(matches!(item, StreamItem::Event(_)));
+        delivered += 1;
+    }
+    assert_eq!(delivered, 256);
+}
+
+#[tokio::test]
+async fn starving_waiter_forces_holder_handoff() {
     let mut policy = TerritoryPolicy::default();
     policy.auto_extend_threshold = Duration::from_millis(1);
-    policy.escalation_queue_threshold = 2;
+    policy.escalation_queue_threshold = 10;
+    policy.fairness_starvation_threshold = Duration::from_millis(20);
+    policy.fairness_minimum_lease = Duration::from_millis(30);
     let manager = build_manager_with_policy(policy);
-    let resource = "shared_resource.txt".to_string();
     let mut events = manager.subscribe();
+    let resource = "fairness.json".to_string();
 
     let grant = manager
         .acquire_lease(LeaseRequest::new(
-            "Primary".to_string(),
+            "Hog".to_string(),
             resource.clone(),
             Priority::Coordinate,
         ))
         .await;
     assert!(matches!(grant, LeaseDecision::Granted(_)));
+    let queued = manager
+        .acquire_lease(LeaseRequest::new(
+            "Waiter".to_string(),
+            resource.clone(),
+            Priority::Coordinate,
+        ))
+        .await;
+    assert!(matches!(queued, LeaseDecision::Queued(_)));
 
-    let queued_one = manager
+    assert!(manager.enforce_fairness().await.is_empty());
+    time::sleep(Duration::from_millis(40)).await;
+    let released = manager.enforce_fairness().await;
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].holder_id, "Hog");
+    let holder = manager.current_lease(&resource).await.unwrap();
+    assert_eq!(holder.holder_id, "Waiter");
+
+    let mut saw_starvation = false;
+    while let Ok(event) = events.try_recv() {
+        if matches!(
+            event,
+            TerritoryEvent::Escalated {
+                reason: EscalationReason::Starvation,
+                ..
+            }
+        ) {
+            saw_starvation = true;
+        }
+    }
+    assert!(saw_starvation);
+}
+
+#[tokio::test]
+async fn overaged_queue_request_is_force_granted() {
+    let metrics = MetricsCollector::new();
+    let mut policy = TerritoryPolicy::default();
+    policy.escalation_queue_threshold = 10;
+    policy.escalation_force_override_after = Some(Duration::from_secs(30));
+    let clock = MockClock::new();
+    let manager = TerritoryManager::with_policy(metrics.clone(), policy).with_clock(clock.shared());
+    let resource = "plan.json".to_string();
+
+    manager
         .acquire_lease(LeaseRequest::new(
-            "Waiter_1".to_string(),
+            "Hog".to_string(),
+            resource.clone(),
+            Priority::Critical,
+        ))
+        .await;
+    let LeaseDecision::Queued(handle) = manager
+        .acquire_lease(LeaseRequest::new(
+            "Waiter".to_string(),
+            resource.clone(),
+            Priority::Info,
+        ))
+        .await
+    else {
+        panic!("expected the waiter to be queued");
+    };
+    let mut events = manager.subscribe();
+
+    let consensus = metrics.get_snapshot().consensus;
+    let decisions_before_override = consensus.success + consensus.failure;
+
+    clock.advance(Duration::from_secs(29));
+    assert!(manager.force_overdue_overrides().await.is_empty());
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        "Hog"
+    );
+
+    clock.advance(Duration::from_secs(2));
+    let forced = manager.force_overdue_overrides().await;
+    assert_eq!(forced.len(), 1);
+    assert_eq!(forced[0].holder_id, "Waiter");
+    assert_eq!(forced[0].priority, Priority::Info);
+    assert_eq!(forced[0].request_id, handle.request_id);
+    assert_eq!(forced[0].override_count, 1);
+    let holder = manager.current_lease(&resource).await.unwrap();
+    assert_eq!(holder.holder_id, "Waiter");
+    assert!(manager.list_queue(&resource).await.is_empty());
+    assert!(manager.force_overdue_overrides().await.is_empty());
+    let consensus = metrics.get_snapshot().consensus;
+    assert_eq!(
+        consensus.success + consensus.failure,
+        decisions_before_override
+    );
+    let fairness = manager.fairness_report().await;
+    let waiter = fairness
+        .agents
+        .iter()
+        .find(|agent| agent.agent_id == "Waiter")
+        .expect("force-granted wait is tallied");
+    assert_eq!(waiter.grants, 1);
+    assert_eq!(waiter.total_wait, Duration::from_secs(31));
+
+    let mut saw_escalation = false;
+    let mut saw_override = false;
+    while let Ok(event) = events.try_recv() {
+        match event {
+            TerritoryEvent::Escalated {
+                reason: EscalationReason::QueueAge,
+                handle: escalated,
+            } => {
+                assert_eq!(escalated.request_id, handle.request_id);
+                saw_escalation = true;
+            }
+            TerritoryEvent::Overridden { previous, lease } => {
+                assert_eq!(previous.holder_id, "Hog");
+                assert_eq!(lease.holder_id, "Waiter");
+                saw_override = true;
+            }
+            _ => {}
+        }
+    }
+    assert!(saw_escalation);
+    assert!(saw_override);
+}
+
+#[tokio::test]
+async fn failed_quorum_force_override_escalates_once() {
+    let metrics = MetricsCollector::new();
+    let mut policy = TerritoryPolicy::default();
+    policy.escalation_queue_threshold = 10;
+    policy.escalation_force_override_after = Some(Duration::from_secs(30));
+    policy.override_policy = OverridePolicy::RequireQuorum;
+    let clock = MockClock::new();
+    let manager = TerritoryManager::with_policy(metrics.clone(), policy).with_clock(clock.shared());
+    let resource = "plan.json".to_string();
+
+    let mut hog = LeaseRequest::new("Hog".to_string(), resource.clone(), Priority::Critical);
+    hog.progress_hint = Some(0.9);
+    manager.acquire_lease(hog).await;
+    let LeaseDecision::Queued(handle) = manager
+        .acquire_lease(LeaseRequest::new(
+            "Waiter".to_string(),
+            resource.clone(),
+            Priority::Info,
+        ))
+        .await
+    else {
+        panic!("expected the waiter to be queued");
+    };
+    let mut events = manager.subscribe();
+    let escalations_before = metrics.get_snapshot().leases.escalations;
+
+    for _ in 0..4 {
+        clock.advance(Duration::from_secs(31));
+        assert!(manager.force_overdue_overrides().await.is_empty());
+    }
+
+    assert_eq!(
+        manager.current_lease(&resource).await.unwrap().holder_id,
+        "Hog"
+    );
+    let queue = manager.list_queue(&resource).await;
+    assert_eq!(queue.len(), 1);
+    assert_eq!(queue[0].request_id, handle.request_id);
+    assert_eq!(
+        metrics.get_snapshot().leases.escalations,
+        escalations_before + 1
+    );
+    let mut escalations = 0;
+    while let Ok(event) = events.try_recv() {
+        if matches!(
+            event,
+            TerritoryEvent::Escalated {
+                reason: EscalationReason::QueueAge,
+                ..
+            }
+        ) {
+            escalations += 1;
+        }
+    }
+    assert_eq!(escalations, 1);
+}
+
+#[tokio::test]
+async fn fairness_deadline_never_shortens_below_minimum_lease() {
+    let mut policy = TerritoryPolicy::default();
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    policy.escalation_queue_threshold = 10;
+    policy.fairness_starvation_threshold = Duration::from_millis(10);
+    policy.fairness_minimum_lease = Duration::from_secs(5);
+    let manager = build_manager_with_policy(policy);
+    let resource = "bounded.json".to_string();
+
+    let grant = manager
+        .acquire_lease(LeaseRequest::new(
+            "Holder".to_string(),
             resource.clone(),
             Priority::Coordinate,
         ))
         .await;
-    assert!(matches!(queued_one, LeaseDecision::Queued(_)));
-
-    let queued_two = manager
+    let granted_at = match grant {
+        LeaseDecision::Granted(lease) => lease.granted_at,
+        other => panic!("expected grant, got {other:?}"),
+    };
+    let _ = manager
         .acquire_lease(LeaseRequest::new(
-            "Waiter_2".to_string(),
+            "Waiter".to_string(),
             resource.clone(),
             Priority::Coordinate,
         ))
         .await;
-    assert!(matches!(queued_two, LeaseDecision::Queued(_)));
 
-    let mut escalated = false;
-    for _ in 0..5 {
-        let event = time::timeout(Duration::from_millis(200), events.recv())
-            .await
-            .unwrap()
-            .unwrap();
-        if matches!(event, TerritoryEvent::Escalated { .. }) {
-            escalated = true;
-            break;
-        }
-    }
+    time::sleep(Duration::from_millis(20)).await;
+    assert!(manager.enforce_fairness().await.is_empty());
+    let holder = manager.current_lease(&resource).await.unwrap();
+    assert_eq!(holder.holder_id, "Holder");
+    assert_eq!(holder.expires_at, granted_at + Duration::from_secs(5));
+}
 
-    assert!(escalated);
+#[test]
+fn lease_request_builder_sets_optional_fields() {
+    let plain = LeaseRequest::builder("agent-a", "src/lib.rs", Priority::Blocking).build();
+    let expected = LeaseRequest::new(
+        "agent-a".to_string(),
+        "src/lib.rs".to_string(),
+        Priority::Blocking,
+    );
+    assert_eq!(plain.agent_id, expected.agent_id);
+    assert_eq!(plain.resource_id, expected.resource_id);
+    assert_eq!(plain.priority, expected.priority);
+    assert_eq!(plain.holder_role, expected.holder_role);
+    assert_eq!(plain.progress_hint, expected.progress_hint);
+    assert_eq!(plain.coordinates, expected.coordinates);
+    assert_eq!(plain.requested_duration, expected.requested_duration);
+
+    let detailed = LeaseRequest::builder("agent-b", "src/main.rs", Priority::Critical)
+        .role("systems")
+        .progress(0.75)
+        .coordinates(3.0, -1.5)
+        .build();
+    assert_eq!(detailed.agent_id, "agent-b");
+    assert_eq!(detailed.resource_id, "src/main.rs");
+    assert_eq!(detailed.priority, Priority::Critical);
+    assert_eq!(detailed.holder_role.as_deref(), Some("systems"));
+    assert_eq!(detailed.progress_hint, Some(0.75));
+    assert_eq!(detailed.coordinates, Some((3.0, -1.5)));
 }
 
 #[tokio::test]
-async fn territory_metrics_reflect_escalation() {
-    let metrics = MetricsCollector::new();
+async fn territory_lists_held_leases_and_queue() {
     let mut policy = TerritoryPolicy::default();
     policy.auto_extend_threshold = Duration::from_millis(1);
-    policy.escalation_queue_threshold = 2;
-    let manager = TerritoryManager::with_policy(metrics.clone(), policy);
-    let resource = "metrics_resource.txt".to_string();
+    let manager = build_manager_with_policy(policy);
+    let resource = "inventory.txt".to_string();
 
-    let _ = manager
+    let granted = manager
         .acquire_lease(LeaseRequest::new(
-            "Primary".to_string(),
+            "Holder".to_string(),
             resource.clone(),
             Priority::Coordinate,
         ))
         .await;
+    let granted = match granted {
+        LeaseDecision::Granted(snapshot) => snapshot,
+        other => panic!("expected grant, got {:?}", other),
+    };
+    let other = manager
+        .acquire_lease(LeaseRequest::new(
+            "Solo".to_string(),
+            "other.txt".to_string(),
+            Priority::Info,
+        ))
+        .await;
+    assert!(matches!(other, LeaseDecision::Granted(_)));
 
-    for idx in 0..2 {
-        let _ = manager
+    for waiter in ["Waiter_1", "Waiter_2"] {
+        let decision = manager
             .acquire_lease(LeaseRequest::new(
-                format!("Waiter_{idx}"),
+                waiter.to_string(),
                 resource.clone(),
                 Priority::Coordinate,
             ))
             .await;
+        assert!(matches!(decision, LeaseDecision::Queued(_)));
     }
 
-    time::sleep(Duration::from_millis(10)).await;
-    let snapshot = metrics.get_snapshot();
-    assert!(snapshot.leases.escalations > 0);
-    assert!(snapshot.leases.total_pending >= 2);
-    assert!(
-        snapshot
-            .leases
-            .pending_by_resource
-            .get(&resource)
-            .copied()
-            .unwrap_or_default()
-            >= 2
-    );
-}
-
-#[test]
-fn config_overrides_apply_to_router_and_territory() {
-    let mut temp_config = NamedTempFile::new().expect("create temp config");
-    writeln!(
-        temp_config,
-        "{}",
-        r#"
-territory_config:
-  default_lease_duration: 90s
-  auto_extend_threshold: 20s
-  negotiation:
-    max_rounds: 6
-    timeout: 12s
-  escalation:
-    queue_threshold: 5
-    deadlock_timeout: 240s
-  fairness:
-    priority_boost_after: 75s
-    starvation_threshold: 360s
-performance_slas:
-  queue_depths:
-    blocking_max: 8
-    critical_max: 12
-  message_routing:
-    p50: 0.2s
-health_monitoring_kpis:
-  queue_health:
-    warning_depth: 3
-    max_depth: 7
-"#
-    )
-    .expect("write config");
-
-    let previous = std::env::var("LIMINAL_CONFIG_PATH").ok();
-    std::env::set_var("LIMINAL_CONFIG_PATH", temp_config.path());
-
-    let app_config = AppConfig::load();
-    let metrics = MetricsCollector::new();
-    let router = UnifiedMessageRouter::with_settings(metrics.clone(), app_config.router.as_ref());
-    let dispatcher = router.dispatcher_config();
-    assert_eq!(dispatcher.aging_threshold, Duration::from_millis(200));
-    assert_eq!(dispatcher.max_aging_boosts, 2);
-
-    let territory_manager = TerritoryManager::new(metrics, app_config.territory.as_ref());
-    let policy = territory_manager.policy();
-    assert_eq!(policy.default_lease_duration, Duration::from_secs(90));
-    assert_eq!(policy.auto_extend_threshold, Duration::from_secs(20));
-    assert_eq!(policy.negotiation_max_rounds, 6);
-    assert_eq!(policy.negotiation_timeout, Duration::from_secs(12));
-    assert_eq!(policy.escalation_queue_threshold, 5);
-    assert_eq!(policy.escalation_deadlock_timeout, Duration::from_secs(240));
-    assert_eq!(
-        policy.fairness_priority_boost_after,
-        Duration::from_secs(75)
-    );
-    assert_eq!(
-        policy.fairness_starvation_threshold,
-        Duration::from_secs(360)
-    );
-
-    if let Some(value) = previous {
-        std::env::set_var("LIMINAL_CONFIG_PATH", value);
-    } else {
-        std::env::remove_var("LIMINAL_CONFIG_PATH");
-    }
-}
-
-#[test]
-fn pty_metrics_capture_structured_events() {
-    let metrics = MetricsCollector::new();
-    metrics.record_agent_event("Agent_A", Some("forgeEvent"));
-    metrics.record_agent_event("Agent_B", None);
+    let leases = manager.list_leases().await;
+    assert_eq!(leases.len(), 2);
+    assert_eq!(leases[0].resource_id, resource);
+    assert_eq!(leases[0].lease_id, granted.lease_id);
+    assert_eq!(leases[0].holder_id, "Holder");
+    assert_eq!(leases[1].resource_id, "other.txt");
+    assert_eq!(leases[1].holder_id, "Solo");
 
-    let snapshot = metrics.get_snapshot();
-    assert_eq!(snapshot.pty.total_events, 2);
-    assert_eq!(snapshot.pty.events_by_name.get("forgeEvent"), Some(&1));
-    assert!(
-        snapshot
-            .pty
-            .events_by_name
-            .get("unknown")
-            .copied()
-            .unwrap_or_default()
-            >= 1
-    );
-    let last_event = snapshot.pty.last_event.expect("last event present");
-    assert!(last_event.agent_id == "Agent_A" || last_event.agent_id == "Agent_B");
+    let queue = manager.list_queue(&resource).await;
+    assert_eq!(queue.len(), 2);
+    assert_eq!(queue[0].agent_id, "Waiter_1");
+    assert_eq!(queue[0].queue_position, 1);
+    assert_eq!(queue[0].state, NegotiationState::Queued);
+    assert_eq!(queue[1].agent_id, "Waiter_2");
+    assert_eq!(queue[1].queue_position, 2);
+    assert!(queue[0].age >= queue[1].age);
+    assert!(manager
+        .list_queue(&"other.txt".to_string())
+        .await
+        .is_empty());
 }
 
 #[tokio::test]
-async fn ledger_captures_router_messages() {
-    let temp_dir = tempdir().expect("temp dir");
-    let mut ledger_config = LedgerConfig::default();
-    ledger_config.root_path = temp_dir.path().to_path_buf();
-    ledger_config.current_epoch = Some("router-capture-test".to_string());
-    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
-    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
-
-    let metrics = MetricsCollector::new();
-    let router = Arc::new(UnifiedMessageRouter::with_settings_and_ledger(
-        metrics,
-        None,
-        Some(ledger_writer.clone()),
-    ));
-    let mut deliveries = router.subscribe();
-
-    let priorities = [
-        Priority::Info,
-        Priority::Coordinate,
-        Priority::Critical,
-        Priority::Info,
-        Priority::Coordinate,
-        Priority::Info,
-        Priority::Critical,
-        Priority::Info,
-        Priority::Coordinate,
-        Priority::Info,
-    ];
+async fn territory_heat_map_full_orders_by_score() {
+    let mut policy = TerritoryPolicy::default();
+    policy.auto_extend_threshold = Duration::from_millis(1);
+    let manager = build_manager_with_policy(policy);
 
-    for (idx, priority) in priorities.iter().enumerate() {
-        router
-            .route_message(Message {
-                content: format!("message_{idx}"),
-                priority: *priority,
-                sender: format!("agent_{idx}"),
-                recipient: "target".to_string(),
-            })
-            .await
-            .unwrap();
+    for (resource, waiters) in [("cool.txt", 1), ("hot.txt", 3), ("warm.txt", 2)] {
+        let grant = manager
+            .acquire_lease(LeaseRequest::new(
+                format!("{}_holder", resource),
+                resource.to_string(),
+                Priority::Coordinate,
+            ))
+            .await;
+        assert!(matches!(grant, LeaseDecision::Granted(_)));
+        for index in 0..waiters {
+            let decision = manager
+                .acquire_lease(LeaseRequest::new(
+                    format!("{}_waiter_{}", resource, index),
+                    resource.to_string(),
+                    Priority::Coordinate,
+                ))
+                .await;
+            assert!(matches!(decision, LeaseDecision::Queued(_)));
+        }
     }
 
-    for _ in 0..10 {
-        let _ = time::timeout(Duration::from_millis(500), deliveries.recv()).await;
-    }
+    let full = manager.heat_map_full().await;
+    let order: Vec<&str> = full.iter().map(|(resource, _)| resource.as_str()).collect();
+    assert_eq!(order, vec!["hot.txt", "warm.txt", "cool.txt"]);
+    assert!(full.windows(2).all(|pair| pair[0].1 >= pair[1].1));
 
-    ledger_writer.flush().await.expect("flush ledger");
+    let hot = manager.resource_heat(&"hot.txt".to_string()).await;
+    assert!(hot > 0.0 && hot <= full[0].1);
+    assert_eq!(manager.resource_heat(&"idle.txt".to_string()).await, 0.0);
 
-    let events = ledger_reader
-        .read_epoch(&ledger_writer.epoch_id())
-        .expect("read ledger");
+    let summary = manager.heat_snapshot().await;
+    assert_eq!(summary.hottest_resource.as_deref(), Some("hot.txt"));
+    assert_eq!(summary.tracked, full.len());
+}
 
-    let mut dispatch_count = 0;
-    let mut last_sequence = 0;
-    for envelope in events {
-        assert!(envelope.sequence > last_sequence);
-        last_sequence = envelope.sequence;
-        if let LedgerEvent::Router(RouterEvent::Dispatched(_)) = envelope.event {
-            dispatch_count += 1;
-        }
+#[tokio::test]
+async fn territory_heat_scales_with_contender_priority() {
+    let resource = "contended.txt".to_string();
+    let mut scores = Vec::new();
+    for priority in [Priority::Coordinate, Priority::Critical] {
+        let mut policy = TerritoryPolicy::default();
+        policy.auto_extend_threshold = Duration::from_millis(1);
+        let manager = build_manager_with_policy(policy);
+        let grant = manager
+            .acquire_lease(LeaseRequest::new(
+                "Holder".to_string(),
+                resource.clone(),
+                priority,
+            ))
+            .await;
+        assert!(matches!(grant, LeaseDecision::Granted(_)));
+        let contender = manager
+            .acquire_lease(LeaseRequest::new(
+                "Contender".to_string(),
+                resource.clone(),
+                priority,
+            ))
+            .await;
+        assert!(matches!(contender, LeaseDecision::Queued(_)));
+        scores.push(manager.resource_heat(&resource).await);
     }
-    assert_eq!(dispatch_count, 10);
+    assert!(scores[0] > 0.0);
+    assert!(scores[1] > scores[0]);
 }
 
-#[tokio::test]
-async fn ledger_records_territory_lease_lifecycle() {
+#[test]
+fn router_exempt_senders_load_from_config() {
     let temp_dir = tempdir().expect("temp dir");
-    let mut ledger_config = LedgerConfig::default();
-    ledger_config.root_path = temp_dir.path().to_path_buf();
-    ledger_config.current_epoch = Some("lease-lifecycle-test".to_string());
-    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
-    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
-
-    let metrics = MetricsCollector::new();
-    let territory =
-        TerritoryManager::new_with_ledger(metrics.clone(), None, Some(ledger_writer.clone()));
-
-    let resource = "test-resource".to_string();
-    let holder = "lease-holder".to_string();
-
-    let granted = territory
-        .acquire_lease(LeaseRequest::new(
-            holder.clone(),
-            resource.clone(),
-            Priority::Coordinate,
-        ))
-        .await;
-    assert!(matches!(granted, LeaseDecision::Granted(_)));
-
-    territory
-        .release_lease(&holder, &resource)
-        .await
-        .expect("release lease");
-
-    time::sleep(Duration::from_millis(50)).await;
-    ledger_writer.flush().await.expect("flush ledger");
-
-    let events = ledger_reader
-        .read_epoch(&ledger_writer.epoch_id())
-        .expect("read ledger");
+    let path = temp_dir.path().join("liminal.config.yaml");
+    std::fs::write(
+        &path,
+        r#"
+router:
+  exempt_senders:
+    - director
+    - operator
+"#,
+    )
+    .expect("write config");
+    let config = AppConfig::load_from(&path).expect("config");
+    let router = config.router.expect("router config");
+    assert_eq!(
+        router.exempt_senders,
+        Some(vec!["director".to_string(), "operator".to_string()])
+    );
+    let dispatcher = DispatcherConfig::from_router_config(Some(&router));
+    assert!(dispatcher.exempt_senders.contains("operator"));
 
-    let mut granted_count = 0;
-    let mut released_count = 0;
-    let mut found_heat_score = false;
+    std::fs::write(
+        &path,
+        r#"
+router:
+  exempt_senders:
+    - operator
+"#,
+    )
+    .expect("rewrite config");
+    let reloaded = AppConfig::load_validated_from(&path).expect("validated config");
+    let dispatcher = DispatcherConfig::from_router_config(reloaded.router.as_ref());
+    assert!(dispatcher.exempt_senders.contains("operator"));
+    assert!(dispatcher.exempt_senders.contains("director"));
+}
 
-    for envelope in events {
-        if let LedgerEvent::Lease(lease_event) = &envelope.event {
-            match lease_event {
-                LeaseEvent::Granted(record) => {
-                    assert_eq!(record.holder_id, holder);
-                    assert_eq!(record.resource_id, resource);
-                    granted_count += 1;
-                }
-                LeaseEvent::Released(record) => {
-                    assert_eq!(record.resource_id, resource);
-                    released_count += 1;
-                }
-                _ => {}
-            }
-        }
-        if envelope.metadata.territory_id.is_some() {
-            found_heat_score = true;
+#[test]
+fn router_aging_thresholds_load_and_validate_from_config() {
+    let temp_dir = tempdir().expect("temp dir");
+    let path = temp_dir.path().join("liminal.config.yaml");
+    std::fs::write(
+        &path,
+        r#"
+router:
+  aging_thresholds:
+    info: 2s
+    blocking: soon
+"#,
+    )
+    .expect("write config");
+    let parsed = AppConfig::load_from(&path).expect("syntactically valid");
+    let thresholds = parsed
+        .router
+        .and_then(|router| router.aging_thresholds)
+        .expect("aging thresholds");
+    assert_eq!(thresholds.get("info").map(String::as_str), Some("2s"));
+    match AppConfig::load_validated_from(&path) {
+        Err(ConfigError::Invalid { field, .. }) => {
+            assert_eq!(field, "router.aging_thresholds.blocking");
         }
+        other => panic!("expected validation error, got {:?}", other),
     }
-
-    assert_eq!(granted_count, 1);
-    assert_eq!(released_count, 1);
-    assert!(found_heat_score);
 }
 
 #[tokio::test]
-async fn ledger_records_consensus_quorum_events() {
+async fn config_watcher_surfaces_reloaded_values() {
     let temp_dir = tempdir().expect("temp dir");
-    let mut ledger_config = LedgerConfig::default();
-    ledger_config.root_path = temp_dir.path().to_path_buf();
-    ledger_config.current_epoch = Some("consensus-quorum-test".to_string());
-    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
-    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+    let path = temp_dir.path().join("liminal.config.yaml");
+    std::fs::write(
+        &path,
+        r#"
+territory_config:
+  default_lease_duration: 90s
+performance_slas:
+  queue_depths:
+    blocking_max: 8
+"#,
+    )
+    .expect("write config");
+    let initial = AppConfig::load_from(&path).expect("initial config");
+    let watcher = ConfigWatcher::new(&path, initial.clone()).expect("watch config");
+    let mut updates = watcher.subscribe();
 
-    let metrics = MetricsCollector::new();
-    let consensus = ConsensusBroker::new(Some(ledger_writer.clone()), metrics, 0.66);
+    std::fs::write(&path, "territory_config: [unterminated\n").expect("write invalid config");
+    time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(watcher.current(), initial);
 
-    let votes = vec![
-        quorum_vote("agent_a", 2.0, true),
-        quorum_vote("agent_b", 1.5, true),
-        quorum_vote("agent_c", 1.0, false),
-    ];
+    std::fs::write(
+        &path,
+        r#"
+territory_config:
+  default_lease_duration: 45s
+performance_slas:
+  queue_depths:
+    blocking_max: 16
+"#,
+    )
+    .expect("rewrite config");
 
-    let achieved = consensus
-        .record_quorum("shared-resource", votes, "priority-override")
+    let reloaded = time::timeout(Duration::from_secs(5), async {
+        loop {
+            updates.changed().await.expect("watcher alive");
+            let current = updates.borrow_and_update().clone();
+            if current != initial {
+                return current;
+            }
+        }
+    })
+    .await
+    .expect("config reload");
+
+    let diff = ConfigDiff::between(&initial, &reloaded);
+    assert!(diff.router && diff.territory);
+    assert!(!diff.health && !diff.ledger);
+
+    let metrics = MetricsCollector::new();
+    let router = UnifiedMessageRouter::with_settings(metrics.clone(), initial.router.as_ref());
+    router.update_config(reloaded.router.as_ref()).await;
+    assert_eq!(router.dispatcher_config().queue_depth_warning, 16);
+
+    let territory_manager = TerritoryManager::new(metrics, initial.territory.as_ref());
+    territory_manager
+        .update_policy(TerritoryPolicy::from_config(reloaded.territory.as_ref()))
         .await;
+    assert_eq!(
+        territory_manager.policy().default_lease_duration,
+        Duration::from_secs(45)
+    );
+}
 
-    time::sleep(Duration::from_millis(50)).await;
-    ledger_writer.flush().await.expect("flush ledger");
+#[test]
+fn config_validation_reports_malformed_field_path() {
+    let mut temp_config = NamedTempFile::new().expect("create temp config");
+    writeln!(
+        temp_config,
+        r#"
+territory_config:
+  negotiation:
+    max_rounds: three
+"#
+    )
+    .expect("write config");
 
-    let events = ledger_reader
-        .read_epoch(&ledger_writer.epoch_id())
-        .expect("read ledger");
+    match AppConfig::load_validated_from(temp_config.path()) {
+        Err(ConfigError::Parse { field, .. }) => {
+            assert_eq!(field, "territory_config.negotiation.max_rounds");
+        }
+        other => panic!("expected parse error, got {:?}", other),
+    }
+}
 
-    let mut proposal_count = 0;
-    let mut vote_count = 0;
-    let mut commit_count = 0;
+#[test]
+fn config_validation_rejects_semantic_violations() {
+    let mut temp_config = NamedTempFile::new().expect("create temp config");
+    writeln!(
+        temp_config,
+        r#"
+territory_config:
+  consensus_threshold: 0.7
+health_monitoring_kpis:
+  queue_health:
+    warning_depth: 80
+    max_depth: 50
+"#
+    )
+    .expect("write config");
 
-    for envelope in events {
-        if let LedgerEvent::Consensus(consensus_event) = &envelope.event {
-            match consensus_event {
-                ConsensusEvent::Proposal(signal) => {
-                    assert_eq!(signal.phase, "proposal");
-                    if let Some(vector) = &signal.vector {
-                        assert_eq!(vector.resource_id, "shared-resource");
-                        assert_eq!(vector.threshold, 0.66);
-                        assert_eq!(vector.total_weight, 4.5);
-                        assert_eq!(vector.agree_weight, 3.5);
-                        assert_eq!(vector.achieved, achieved);
-                        assert_eq!(vector.reason, "priority-override");
-                    }
-                    proposal_count += 1;
-                }
-                ConsensusEvent::Vote(signal) => {
-                    assert_eq!(signal.phase, "vote");
-                    vote_count += 1;
-                }
-                ConsensusEvent::Commit(signal) => {
-                    assert_eq!(signal.phase, "commit");
-                    if let Some(vector) = &signal.vector {
-                        assert!(vector.achieved);
-                    }
-                    commit_count += 1;
-                }
-                ConsensusEvent::Idle => {}
+    let parsed = AppConfig::load_from(temp_config.path()).expect("syntactically valid");
+    assert!(parsed.health_monitoring_kpis.is_some());
+    match AppConfig::load_validated_from(temp_config.path()) {
+        Err(ConfigError::Invalid { field, .. }) => {
+            assert_eq!(field, "health_monitoring_kpis.queue_health.warning_depth");
+        }
+        other => panic!("expected validation error, got {:?}", other),
+    }
+
+    let mut threshold = parsed.clone();
+    threshold.health_monitoring_kpis = None;
+    if let Some(territory) = threshold.territory.as_mut() {
+        territory.consensus_threshold = Some(1.5);
+        territory.negotiation_timeout = Some("soon".to_string());
+    }
+    let err = threshold.validate().expect_err("invalid territory config");
+    assert!(err
+        .to_string()
+        .contains("territory_config.negotiation.timeout"));
+    if let Some(territory) = threshold.territory.as_mut() {
+        territory.negotiation_timeout = None;
+    }
+    let err = threshold.validate().expect_err("threshold out of range");
+    assert!(err
+        .to_string()
+        .contains("territory_config.consensus_threshold"));
+}
+
+#[test]
+fn config_validation_rejects_values_that_cannot_become_durations() {
+    for threshold in ["-5s", "NaN", "inf"] {
+        let config = AppConfig {
+            router: Some(RouterConfig {
+                aging_threshold: Some(threshold.to_string()),
+                ..RouterConfig::default()
+            }),
+            ..AppConfig::default()
+        };
+        match config.validate() {
+            Err(ConfigError::Invalid { field, .. }) => {
+                assert_eq!(field, "performance_slas.message_routing.p50");
             }
+            other => panic!("expected {} to be rejected, got {:?}", threshold, other),
         }
     }
 
-    assert_eq!(proposal_count, 1);
-    assert_eq!(vote_count, 1);
-    assert_eq!(commit_count, 1);
+    for refill in [-1.0, f64::NAN, f64::INFINITY] {
+        let config = AppConfig {
+            router: Some(RouterConfig {
+                token_bucket_refill_rate: Some(refill),
+                ..RouterConfig::default()
+            }),
+            ..AppConfig::default()
+        };
+        match config.validate() {
+            Err(ConfigError::Invalid { field, .. }) => {
+                assert_eq!(field, "router.token_bucket_refill_rate");
+            }
+            other => panic!("expected {} to be rejected, got {:?}", refill, other),
+        }
+    }
 }
 
-#[tokio::test]
-async fn ledger_replay_rebuilds_state_deterministically() {
+#[test]
+fn config_loads_equivalently_from_yaml_toml_and_json() {
     let temp_dir = tempdir().expect("temp dir");
-    let mut ledger_config = LedgerConfig::default();
-    ledger_config.root_path = temp_dir.path().to_path_buf();
-    ledger_config.current_epoch = Some("replay-deterministic-test".to_string());
-    let ledger_writer = LedgerWriter::new(&ledger_config).expect("ledger writer");
-    let ledger_reader = LedgerReader::new(ledger_config.root_path.clone());
+    let sources = [
+        (
+            "liminal.config.yaml",
+            r#"
+territory_config:
+  default_lease_duration: 90s
+  consensus_threshold: 0.75
+  negotiation:
+    max_rounds: 6
+performance_slas:
+  queue_depths:
+    blocking_max: 8
+health_monitoring_kpis:
+  queue_health:
+    warning_depth: 3
+    max_depth: 7
+ledger:
+  rootPath: ledger-data
+  retainEpochs: 3
+"#,
+        ),
+        (
+            "liminal.config.toml",
+            r#"
+[territory_config]
+default_lease_duration = "90s"
+consensus_threshold = 0.75
 
-    let metrics = MetricsCollector::new();
-    let router = Arc::new(UnifiedMessageRouter::with_settings_and_ledger(
-        metrics.clone(),
-        None,
-        Some(ledger_writer.clone()),
-    ));
-    let mut deliveries = router.subscribe();
+[territory_config.negotiation]
+max_rounds = 6
 
-    let messages = vec![
-        Message {
-            content: "msg1".into(),
-            priority: Priority::Critical,
-            sender: "agent1".into(),
-            recipient: "target".into(),
-        },
-        Message {
-            content: "msg2".into(),
-            priority: Priority::Coordinate,
-            sender: "agent2".into(),
-            recipient: "target".into(),
-        },
-        Message {
-            content: "msg3".into(),
-            priority: Priority::Info,
-            sender: "agent1".into(),
-            recipient: "target".into(),
-        },
+[performance_slas.queue_depths]
+blocking_max = 8
+
+[health_monitoring_kpis.queue_health]
+warning_depth = 3
+max_depth = 7
+
+[ledger]
+rootPath = "ledger-data"
+retainEpochs = 3
+"#,
+        ),
+        (
+            "liminal.config.json",
+            r#"{
+  "territory_config": {
+    "default_lease_duration": "90s",
+    "consensus_threshold": 0.75,
+    "negotiation": { "max_rounds": 6 }
+  },
+  "performance_slas": { "queue_depths": { "blocking_max": 8 } },
+  "health_monitoring_kpis": {
+    "queue_health": { "warning_depth": 3, "max_depth": 7 }
+  },
+  "ledger": { "rootPath": "ledger-data", "retainEpochs": 3 }
+}"#,
+        ),
     ];
 
-    for msg in messages {
-        router.route_message(msg).await.unwrap();
-    }
+    let loaded: Vec<AppConfig> = sources
+        .iter()
+        .map(|(name, contents)| {
+            let path = temp_dir.path().join(name);
+            std::fs::write(&path, contents).expect("write config");
+            AppConfig::load_validated_from(&path).expect("load config")
+        })
+        .collect();
 
-    for _ in 0..3 {
-        let _ = time::timeout(Duration::from_millis(500), deliveries.recv())
-            .await
-            .unwrap()
-            .unwrap();
-    }
+    let yaml = &loaded[0];
+    assert_eq!(
+        yaml.territory
+            .as_ref()
+            .and_then(|territory| territory.negotiation_max_rounds),
+        Some(6)
+    );
+    assert_eq!(
+        yaml.router
+            .as_ref()
+            .and_then(|router| router.queue_depth_warning),
+        Some(8)
+    );
+    assert_eq!(
+        yaml.ledger.as_ref().map(|ledger| ledger.retain_epochs),
+        Some(3)
+    );
+    assert_eq!(&loaded[1], yaml);
+    assert_eq!(&loaded[2], yaml);
+}
 
-    time::sleep(Duration::from_millis(50)).await;
-    ledger_writer.flush().await.expect("flush ledger");
+#[test]
+fn health_summary_reflects_breaching_snapshot_without_latching() {
+    let config = HealthMonitoringConfig {
+        queue_health: Some(QueueHealthConfig {
+            max_depth: Some(5),
+            warning_depth: Some(2),
+            stale_threshold: None,
+        }),
+        escalation_rate: None,
+        deadlock_frequency: None,
+        consensus_success: None,
+        heat_hotspot: None,
+    };
+    let mut monitor = HealthMonitor::new(Some(&config));
+    let mut snapshot = MetricsSnapshot::default();
+    snapshot
+        .router
+        .queue_depths
+        .insert(Priority::Blocking.as_str().to_string(), 7);
 
-    let coordinator = ReplayCoordinator::new(ledger_reader);
-    let outcome = coordinator
-        .replay_epoch(&ledger_writer.epoch_id())
-        .expect("replay");
+    let summary = monitor.summarize(&snapshot);
+    assert_eq!(summary.overall, "critical");
+    assert_eq!(summary.queue, "critical");
+    assert_eq!(summary.heat, "normal");
+    assert_eq!(summary.alerts.len(), 1);
+    assert_eq!(summary.alerts[0].severity, "critical");
 
-    assert_eq!(outcome.router.total_dispatched, 3);
-    assert!(outcome.router.last_priority.is_some());
+    let repeated = monitor.summarize(&snapshot);
+    assert_eq!(repeated.alerts.len(), 1);
 
-    let snapshot = metrics.get_snapshot();
+    let alerts = monitor.evaluate(&snapshot);
+    assert_eq!(alerts.len(), 1);
+    assert!(monitor.evaluate(&snapshot).is_empty());
+    assert_eq!(monitor.summarize(&snapshot).queue, "critical");
+}
+
+#[test]
+fn stale_dispatcher_with_queued_messages_raises_queue_warning() {
+    let config = HealthMonitoringConfig {
+        queue_health: Some(QueueHealthConfig {
+            max_depth: None,
+            warning_depth: None,
+            stale_threshold: Some("60s".to_string()),
+        }),
+        escalation_rate: None,
+        deadlock_frequency: None,
+        consensus_success: None,
+        heat_hotspot: None,
+    };
+    let monitor = HealthMonitor::new(Some(&config));
+    let snapshot_with = |depth: usize, idle_ms: u64| {
+        let mut snapshot = MetricsSnapshot::default();
+        snapshot
+            .router
+            .queue_depths
+            .insert(Priority::Info.as_str().to_string(), depth);
+        snapshot.router.dispatcher_idle_ms = Some(idle_ms);
+        snapshot
+    };
+
+    assert_eq!(monitor.summarize(&snapshot_with(3, 1_000)).queue, "normal");
     assert_eq!(
-        outcome.router.total_dispatched,
-        snapshot.performance.total_messages_routed
+        monitor.summarize(&snapshot_with(0, 120_000)).queue,
+        "normal"
     );
+    let stale = monitor.summarize(&snapshot_with(3, 120_000));
+    assert_eq!(stale.queue, "warning");
+    assert_eq!(stale.alerts.len(), 1);
+    assert!(stale.alerts[0].message.contains("idle for 120000ms"));
+}
 
-    let mut queue_depths_match = true;
-    for (idx, depth) in outcome.router.queue_depths.iter().enumerate() {
-        let key = Priority::from_index(idx).as_str();
-        let live_depth = snapshot.router.queue_depths.get(key).copied().unwrap_or(0);
-        if *depth != live_depth {
-            queue_depths_match = false;
-            break;
-        }
-    }
-    assert!(queue_depths_match);
+#[test]
+fn acknowledged_alert_is_suppressed_until_severity_rises() {
+    let config = HealthMonitoringConfig {
+        queue_health: Some(QueueHealthConfig {
+            max_depth: Some(5),
+            warning_depth: Some(2),
+            stale_threshold: None,
+        }),
+        escalation_rate: None,
+        deadlock_frequency: None,
+        consensus_success: None,
+        heat_hotspot: None,
+    };
+    let mut monitor = HealthMonitor::new(Some(&config));
+    let with_depth = |depth: usize| {
+        let mut snapshot = MetricsSnapshot::default();
+        snapshot
+            .router
+            .queue_depths
+            .insert(Priority::Blocking.as_str().to_string(), depth);
+        snapshot
+    };
+
+    assert!(!monitor.acknowledge(HealthCategory::Queue));
+    let alerts = monitor.evaluate(&with_depth(3));
+    assert_eq!(alerts.len(), 1);
+    assert_eq!(alerts[0].category, HealthCategory::Queue);
+    assert!(monitor.acknowledge(HealthCategory::Queue));
+    assert!(monitor.evaluate(&with_depth(4)).is_empty());
+
+    assert!(monitor.evaluate(&with_depth(0)).is_empty());
+    let recurred = monitor.evaluate(&with_depth(3));
+    assert_eq!(recurred.len(), 1);
+    assert_eq!(recurred[0].severity, "warning");
+    assert!(monitor.acknowledge(HealthCategory::Queue));
+
+    let escalated = monitor.evaluate(&with_depth(7));
+    assert_eq!(escalated.len(), 1);
+    assert_eq!(escalated[0].severity, "critical");
+
+    assert!(monitor.evaluate(&with_depth(0)).is_empty());
+    assert_eq!(monitor.evaluate(&with_depth(7)).len(), 1);
 }
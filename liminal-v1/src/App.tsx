@@ -14,6 +14,10 @@ interface PerformanceMetrics {
   leaseAcquisitionTimeMs: number;
   totalMessagesRouted: number;
   totalLeasesAcquired: number;
+  totalLeasesReleased?: number;
+  leaseAcquisitionSamples?: number;
+  uncontendedLeaseAcquisitionMs?: number;
+  contendedLeaseAcquisitionMs?: number;
   memoryUsageMb: number;
   rateLimitedMessages: number;
 }
@@ -22,6 +26,7 @@ interface RouterSnapshot {
   queueDepths: Record<string, number>;
   lastDispatchedPriority?: string | null;
   lastDispatchedAt?: SerializedSystemTime | null;
+  dispatcherIdleMs?: number | null;
   rateLimitedMessages: number;
 }
 
@@ -41,6 +46,9 @@ interface LeaseSnapshotSummary {
   deferrals: number;
   overrides: number;
   escalations: number;
+  rejections?: Record<string, number>;
+  directorOverrides?: number;
+  operatorOverrides?: number;
   outstandingLeaseIds: number[];
 }
 
@@ -61,6 +69,15 @@ interface SystemSnapshot {
   lastUpdated?: SerializedSystemTime | null;
 }
 
+interface LedgerSnapshot {
+  lastAppendLatencyMs: number;
+  appendFailures: number;
+  integrityErrors: number;
+  consecutiveFailures?: number;
+  degraded?: boolean;
+  clockRegressions?: number;
+}
+
 interface MetricsSnapshot {
   performance: PerformanceMetrics;
   router: RouterSnapshot;
@@ -68,9 +85,11 @@ interface MetricsSnapshot {
   leases: LeaseSnapshotSummary;
   pty: PtySnapshot;
   system: SystemSnapshot;
+  ledger?: LedgerSnapshot;
 }
 
 interface HealthAlert {
+  category: string;
   severity: string;
   message: string;
   context: Record<string, unknown>;
@@ -332,6 +351,12 @@ function App() {
         </div>
 
         {metricsError && <div className="metrics-error">{metricsError}</div>}
+        {metricsSnapshot?.ledger?.degraded && (
+          <div className="metrics-error">
+            Ledger durability compromised: {metricsSnapshot.ledger.consecutiveFailures ?? 0}{" "}
+            consecutive write failures
+          </div>
+        )}
 
         {metricsSnapshot ? (
           <div className="metrics-grid">
@@ -349,6 +374,14 @@ function App() {
                 <span>Last dispatch</span>
                 <strong>{formatSystemTime(metricsSnapshot.router.lastDispatchedAt)}</strong>
               </div>
+              <div className="metric-row">
+                <span>Dispatcher idle (ms)</span>
+                <strong>
+                  {metricsSnapshot.router.dispatcherIdleMs != null
+                    ? formatNumber(metricsSnapshot.router.dispatcherIdleMs)
+                    : "—"}
+                </strong>
+              </div>
               <div className="metric-row">
                 <span>Rate-limited messages</span>
                 <strong>{metricsSnapshot.router.rateLimitedMessages}</strong>
@@ -425,6 +458,14 @@ function App() {
                     <span>Escalations</span>
                     <strong>{leaseSummary.escalations}</strong>
                   </div>
+                  <div className="metric-row">
+                    <span>Director overrides</span>
+                    <strong>{leaseSummary.directorOverrides ?? 0}</strong>
+                  </div>
+                  <div className="metric-row">
+                    <span>Operator overrides</span>
+                    <strong>{leaseSummary.operatorOverrides ?? 0}</strong>
+                  </div>
                   <div className="subsection">
                     <strong>Pending by resource</strong>
                     {Object.keys(leaseSummary.pendingByResource).length === 0 ? (
@@ -508,6 +549,13 @@ function App() {
                     <span>Avg lease acquisition (ms)</span>
                     <strong>{formatNumber(performance.leaseAcquisitionTimeMs)}</strong>
                   </div>
+                  <div>
+                    <span>Uncontended / contended (ms)</span>
+                    <strong>
+                      {formatNumber(performance.uncontendedLeaseAcquisitionMs ?? 0)} /{" "}
+                      {formatNumber(performance.contendedLeaseAcquisitionMs ?? 0)}
+                    </strong>
+                  </div>
                   <div>
                     <span>Agent spawn (ms)</span>
                     <strong>{formatNumber(performance.agentSpawnTimeMs)}</strong>
@@ -520,6 +568,10 @@ function App() {
                     <span>Total leases</span>
                     <strong>{performance.totalLeasesAcquired}</strong>
                   </div>
+                  <div>
+                    <span>Released leases</span>
+                    <strong>{performance.totalLeasesReleased ?? 0}</strong>
+                  </div>
                   <div>
                     <span>Rate-limited</span>
                     <strong>{performance.rateLimitedMessages}</strong>